@@ -0,0 +1,82 @@
+use std::io::Write;
+
+use clap::{ArgMatches, FromArgMatches};
+use genshin::application::{LogFormat, LoggerConfig};
+
+/// 从命令行参数读取日志配置，解析失败时使用默认配置
+pub fn config_from_matches(matches: &ArgMatches) -> LoggerConfig {
+    LoggerConfig::from_arg_matches(matches).unwrap_or_default()
+}
+
+/// 将一条日志格式化为单行 JSON，消息中的换行等字符会被转义
+pub fn format_json_line(level: log::Level, target: &str, msg: &str) -> String {
+    serde_json::json!({
+        "level": level.as_str(),
+        "msg": msg,
+        "target": target,
+    })
+    .to_string()
+}
+
+/// 初始化日志系统
+///
+/// 默认使用带颜色的英文格式，去掉时间戳和模块路径；`--log-format json` 时每行输出一个 JSON 对象。
+/// 日志等级优先使用 `--log-level`，其次为 `RUST_LOG` 环境变量，都未设置时为 `Info`
+pub fn init(config: &LoggerConfig) {
+    let mut builder = env_logger::Builder::new();
+    // 日志始终写入标准错误，保证 --stdout 时标准输出只包含导出内容
+    builder.target(env_logger::Target::Stderr);
+    match (config.log_level, std::env::var("RUST_LOG")) {
+        (Some(level), _) => {
+            builder.filter_level(level);
+        },
+        (None, Ok(filters)) if !filters.is_empty() => {
+            builder.parse_filters(&filters);
+        },
+        _ => {
+            builder.filter_level(log::LevelFilter::Info);
+        },
+    }
+
+    match config.log_format {
+        LogFormat::Text => {
+            builder.format(|buf, record| {
+                // 自定义日志格式：使用带颜色的英文状态标识
+                let level_str = match record.level() {
+                    log::Level::Error => "\x1b[31m[ERROR]\x1b[0m >>>", // 红色
+                    log::Level::Warn => "\x1b[33m[WARN] \x1b[0m >>>",  // 黄色
+                    log::Level::Info => "\x1b[32m[INFO] \x1b[0m >>>",  // 绿色
+                    log::Level::Debug => "\x1b[34m[DEBUG]\x1b[0m >>>", // 蓝色
+                    log::Level::Trace => "\x1b[36m[TRACE]\x1b[0m >>>", // 青色
+                };
+
+                writeln!(buf, "{} {}", level_str, record.args())
+            });
+        },
+        LogFormat::Json => {
+            builder.format(|buf, record| {
+                let line =
+                    format_json_line(record.level(), record.target(), &record.args().to_string());
+                writeln!(buf, "{line}")
+            });
+        },
+    }
+
+    builder.init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_line_escapes_multiline_message() {
+        let line = format_json_line(log::Level::Warn, "genshin", "第一行\n第二行 \"引号\"");
+        assert!(!line.contains('\n'));
+
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["level"], "WARN");
+        assert_eq!(value["target"], "genshin");
+        assert_eq!(value["msg"], "第一行\n第二行 \"引号\"");
+    }
+}
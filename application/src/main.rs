@@ -1,9 +1,13 @@
 use std::io::{self, Write};
 
 use furina_core::utils::press_any_key_to_continue;
-use genshin::application::ArtifactScannerApplication;
+use genshin::application::{
+    env_enables_non_interactive, ArtifactScannerApplication, NON_INTERACTIVE_ENV,
+};
 use genshin::export::artifact::GenshinArtifactExportFormat;
 
+mod logger;
+
 /// 显示程序启动Logo和作者信息
 fn show_logo() {
     println!("\n{}", "═".repeat(72));
@@ -31,34 +35,14 @@ fn show_logo() {
     println!("\n💡 使用说明:");
     println!("    1. 确保原神游戏窗口处于可见状态");
     println!("    2. 打开背包中的圣遗物页面");
-    println!("    3. 支持分辨率: 2560×1440, 1920×1080, 1600×900");
+    println!(
+        "    3. 支持分辨率: {}",
+        ArtifactScannerApplication::get_window_info_repository().supported_sizes_text()
+    );
 
     println!("{}", "═".repeat(72));
 }
 
-/// 初始化应用程序环境
-///
-/// 配置日志系统，使用带颜色的英文格式，去掉时间戳和模块路径
-fn init() {
-    env_logger::Builder::new()
-        .filter_level(log::LevelFilter::Info)
-        .format(|buf, record| {
-            use std::io::Write;
-
-            // 自定义日志格式：使用带颜色的英文状态标识
-            let level_str = match record.level() {
-                log::Level::Error => "\x1b[31m[ERROR]\x1b[0m >>>", // 红色
-                log::Level::Warn => "\x1b[33m[WARN] \x1b[0m >>>",  // 黄色
-                log::Level::Info => "\x1b[32m[INFO] \x1b[0m >>>",  // 绿色
-                log::Level::Debug => "\x1b[34m[DEBUG]\x1b[0m >>>", // 蓝色
-                log::Level::Trace => "\x1b[36m[TRACE]\x1b[0m >>>", // 青色
-            };
-
-            writeln!(buf, "{} {}", level_str, record.args())
-        })
-        .init();
-}
-
 /// 获取用户输入
 fn get_user_input(prompt: &str) -> String {
     print!("{prompt}");
@@ -128,14 +112,16 @@ fn interactive_custom_config() -> clap::ArgMatches {
     println!("  2. mingyu-lab - 原魔计算器");
     println!("  3. good - GOOD通用格式");
     println!("  4. csv - CSV表格");
-    println!("  5. all - 所有格式");
-    let format_choice = get_user_input("请选择导出格式 (1-5): ");
+    println!("  5. seelie - Seelie.me");
+    println!("  6. all - 所有格式");
+    let format_choice = get_user_input("请选择导出格式 (1-6): ");
     let format = match format_choice.as_str() {
         "1" => "mona",
         "2" => "mingyu-lab",
         "3" => "good",
         "4" => "csv",
-        "5" => "all",
+        "5" => "seelie",
+        "6" => "all",
         _ => "mona",
     };
     if format != "mona" {
@@ -172,6 +158,7 @@ fn show_config_options(matches: &clap::ArgMatches) {
         GenshinArtifactExportFormat::MingyuLab => "原魔计算器",
         GenshinArtifactExportFormat::Good => "GOOD通用格式",
         GenshinArtifactExportFormat::CSV => "CSV表格",
+        GenshinArtifactExportFormat::Seelie => "Seelie.me",
         GenshinArtifactExportFormat::All => "所有格式",
     };
     let format_name = match format {
@@ -179,6 +166,7 @@ fn show_config_options(matches: &clap::ArgMatches) {
         GenshinArtifactExportFormat::MingyuLab => "mingyu-lab",
         GenshinArtifactExportFormat::Good => "good",
         GenshinArtifactExportFormat::CSV => "csv",
+        GenshinArtifactExportFormat::Seelie => "seelie",
         GenshinArtifactExportFormat::All => "all",
     };
     println!("   导出格式: {format_name} ({format_desc})");
@@ -204,35 +192,61 @@ fn show_config_options(matches: &clap::ArgMatches) {
     }
 }
 
+/// 是否为非交互模式：命令行包含 `--non-interactive`，或设置了 [`NON_INTERACTIVE_ENV`] 环境变量
+fn is_non_interactive(args: &[String], env_value: Option<&str>) -> bool {
+    args.iter().skip(1).any(|arg| arg == "--non-interactive")
+        || env_value.is_some_and(env_enables_non_interactive)
+}
+
+/// 仅在没有命令行参数且不是非交互模式时显示交互式配置界面
+fn should_select_config_interactively(args: &[String], non_interactive: bool) -> bool {
+    args.len() <= 1 && !non_interactive
+}
+
 /// FurinaOCR 应用程序主入口
 ///
 /// 主要功能：
 /// 1. 显示程序Logo和作者信息
-/// 2. 初始化应用程序环境
-/// 3. 交互式配置选择
+/// 2. 交互式配置选择
+/// 3. 根据参数初始化日志系统
 /// 4. 显示配置选项并确认
 /// 5. 运行圣遗物扫描应用
 /// 6. 处理运行结果
 fn main() {
-    // 显示程序Logo
-    show_logo();
-
-    // 初始化环境
-    init();
-
     // 检查是否有命令行参数
     let args: Vec<String> = std::env::args().collect();
-    let matches = if args.len() > 1 {
-        // 如果有命令行参数，直接解析
-        let cmd = ArtifactScannerApplication::build_command();
-        cmd.get_matches()
-    } else {
+    // 导出到标准输出时不显示Logo和交互提示，保持标准输出干净
+    let stdout_mode = args.iter().any(|arg| arg == "--stdout");
+    // 自检、单区域识别和输出窗口信息不扫描，无需确认配置
+    let self_test_mode = args.iter().any(|arg| {
+        arg == "--self-test" || arg == "--print-window-info" || arg.starts_with("--ocr-rect")
+    });
+    let non_interactive =
+        is_non_interactive(&args, std::env::var(NON_INTERACTIVE_ENV).ok().as_deref());
+    // 标准输出模式和非交互模式下不等待用户输入
+    let prompt_mode = !stdout_mode && !non_interactive;
+
+    // 显示程序Logo
+    if !stdout_mode {
+        show_logo();
+    }
+
+    let matches = if should_select_config_interactively(&args, non_interactive) {
         // 如果没有命令行参数，启动交互式界面
         interactive_config_selection()
+    } else {
+        // 如果有命令行参数或为非交互模式，直接解析（没有参数时即为默认配置）
+        let cmd = ArtifactScannerApplication::build_command();
+        cmd.get_matches()
     };
 
+    // 初始化日志系统
+    logger::init(&logger::config_from_matches(&matches));
+
     // 显示当前配置选项并确认
-    show_config_options(&matches);
+    if prompt_mode && !self_test_mode {
+        show_config_options(&matches);
+    }
 
     // 创建并运行应用程序
     let application = ArtifactScannerApplication::new(matches);
@@ -242,11 +256,54 @@ fn main() {
     match res {
         Ok(_) => {
             log::info!("程序执行成功");
-            press_any_key_to_continue();
+            if prompt_mode {
+                press_any_key_to_continue();
+            }
         },
         Err(e) => {
             log::error!("程序执行出错: {e}");
-            press_any_key_to_continue();
+            if prompt_mode {
+                press_any_key_to_continue();
+            }
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_interactive_selection_decision() {
+        let no_args = args(&["furinaocr"]);
+        assert!(!is_non_interactive(&no_args, None));
+        assert!(should_select_config_interactively(&no_args, false));
+
+        // 没有参数但设置了环境变量时使用默认配置
+        let non_interactive = is_non_interactive(&no_args, Some("1"));
+        assert!(non_interactive);
+        assert!(!should_select_config_interactively(&no_args, non_interactive));
+        assert!(!is_non_interactive(&no_args, Some("0")));
+
+        let flag = args(&["furinaocr", "--non-interactive"]);
+        assert!(is_non_interactive(&flag, None));
+        assert!(!should_select_config_interactively(&flag, true));
+
+        // 有其他参数时本来就不显示交互界面，但仍会显示确认提示
+        let other = args(&["furinaocr", "--min-star", "4"]);
+        assert!(!is_non_interactive(&other, None));
+        assert!(!should_select_config_interactively(&other, false));
+    }
+
+    #[test]
+    fn test_non_interactive_flag_is_accepted() {
+        let matches = ArtifactScannerApplication::build_command()
+            .try_get_matches_from(["furinaocr", "--non-interactive"])
+            .unwrap();
+        assert!(matches.get_flag("non-interactive"));
+    }
+}
@@ -0,0 +1,55 @@
+use image::RgbImage;
+
+/// 各通道最大值与最小值之差不超过该值时视为纯色画面
+pub const BLANK_CAPTURE_TOLERANCE: u8 = 8;
+
+/// 截图是否为纯色画面（全黑或近似单一颜色）
+///
+/// 没有以管理员身份运行、开启 HDR 或画面受保护时，部分截图方式会静默返回全黑的图像，
+/// 真实的游戏画面不会是单一颜色，可以据此在识别前给出明确的提示；空图像也视为纯色
+pub fn is_blank_capture(image: &RgbImage, tolerance: u8) -> bool {
+    let mut min = [u8::MAX; 3];
+    let mut max = [u8::MIN; 3];
+    for pixel in image.pixels() {
+        for ((lo, hi), &value) in min.iter_mut().zip(max.iter_mut()).zip(&pixel.0) {
+            *lo = (*lo).min(value);
+            *hi = (*hi).max(value);
+        }
+        if min.iter().zip(&max).any(|(lo, hi)| hi - lo > tolerance) {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use image::Rgb;
+
+    use super::*;
+
+    #[test]
+    fn test_black_image_is_blank() {
+        assert!(is_blank_capture(&RgbImage::new(160, 90), BLANK_CAPTURE_TOLERANCE));
+        assert!(is_blank_capture(&RgbImage::new(0, 0), BLANK_CAPTURE_TOLERANCE));
+    }
+
+    #[test]
+    fn test_near_uniform_image_is_blank() {
+        let image = RgbImage::from_fn(64, 64, |x, y| {
+            let noise = ((x + y) % 4) as u8;
+            Rgb([30 + noise, 30, 32 - noise])
+        });
+        assert!(is_blank_capture(&image, BLANK_CAPTURE_TOLERANCE));
+    }
+
+    #[test]
+    fn test_image_with_content_is_not_blank() {
+        let mut image = RgbImage::from_pixel(64, 64, Rgb([40, 42, 60]));
+        // 面板上的一行文字
+        for x in 10..50 {
+            image.put_pixel(x, 20, Rgb([236, 229, 216]));
+        }
+        assert!(!is_blank_capture(&image, BLANK_CAPTURE_TOLERANCE));
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt;
+
+use crate::error_recovery::{ErrorCategory, RecoverableError};
+use crate::positioning::Rect;
+
+/// 屏幕捕获错误
+///
+/// 实现了 [`RecoverableError`]，类别为 [`ErrorCategory::ImageProcessing`]，
+/// 错误恢复管理器可以据此对截图失败进行重试
+#[derive(Debug, Clone, PartialEq)]
+pub enum CaptureError {
+    /// 截取区域超出可截取的范围
+    OutOfBounds { rect: Rect<i32>, width: u32, height: u32 },
+    /// 截图后端（系统接口或截图库）返回的错误
+    Backend(String),
+}
+
+impl fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CaptureError::OutOfBounds { rect, width, height } => {
+                write!(f, "截取区域 {rect:?} 超出图像范围 {width}x{height}")
+            },
+            CaptureError::Backend(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+impl CaptureError {
+    /// 检查 `rect` 是否完整位于 `width`x`height` 的范围内，且宽高均大于 0
+    pub fn check_bounds(rect: Rect<i32>, width: u32, height: u32) -> Result<(), CaptureError> {
+        let inside = rect.left >= 0
+            && rect.top >= 0
+            && rect.width > 0
+            && rect.height > 0
+            && (rect.left + rect.width) as u32 <= width
+            && (rect.top + rect.height) as u32 <= height;
+        if inside {
+            Ok(())
+        } else {
+            Err(CaptureError::OutOfBounds { rect, width, height })
+        }
+    }
+}
+
+impl RecoverableError for CaptureError {
+    fn error_category(&self) -> ErrorCategory {
+        ErrorCategory::ImageProcessing
+    }
+}
+
+/// 截图后端大多返回 `anyhow::Error`，转换后可以在实现 `Capturer` 时直接使用 `?`
+impl From<anyhow::Error> for CaptureError {
+    fn from(e: anyhow::Error) -> Self {
+        CaptureError::Backend(format!("{e:#}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_error_category() {
+        let errors = [
+            CaptureError::OutOfBounds { rect: Rect::new(-1, 0, 10, 10), width: 100, height: 80 },
+            CaptureError::Backend("BitBlt 失败".to_string()),
+        ];
+        for error in errors {
+            assert_eq!(error.error_category(), ErrorCategory::ImageProcessing);
+        }
+    }
+
+    #[test]
+    fn test_check_bounds() {
+        assert!(CaptureError::check_bounds(Rect::new(0, 0, 100, 80), 100, 80).is_ok());
+        assert!(CaptureError::check_bounds(Rect::new(90, 70, 10, 10), 100, 80).is_ok());
+        for rect in [Rect::new(95, 0, 10, 10), Rect::new(-1, 0, 10, 10), Rect::new(0, 0, 0, 10)] {
+            assert_eq!(
+                CaptureError::check_bounds(rect, 100, 80),
+                Err(CaptureError::OutOfBounds { rect, width: 100, height: 80 })
+            );
+        }
+    }
+
+    #[test]
+    fn test_capture_error_anyhow_conversion() {
+        let error: CaptureError = anyhow::anyhow!("BitBlt 失败").context("截图失败").into();
+        assert_eq!(error, CaptureError::Backend("截图失败: BitBlt 失败".to_string()));
+
+        // 仍可通过 `?` 转换为 anyhow::Error，并能还原出原始类型
+        let wrapped = || -> anyhow::Result<()> { Err(error.clone())? };
+        let recovered = wrapped().unwrap_err().downcast::<CaptureError>().unwrap();
+        assert_eq!(recovered, error);
+    }
+}
@@ -1,17 +1,20 @@
-use anyhow::Result;
-
+use crate::capture::CaptureError;
 use crate::positioning::{Pos, Rect};
 
 pub trait Capturer<T: image::GenericImage> {
     // it's necessary to use signed int, because capture region may be out of the screen
-    fn capture_rect(&self, rect: Rect<i32>) -> Result<T>;
+    fn capture_rect(&self, rect: Rect<i32>) -> Result<T, CaptureError>;
 
-    fn capture_color(&self, pos: Pos<i32>) -> Result<T::Pixel> {
+    fn capture_color(&self, pos: Pos<i32>) -> Result<T::Pixel, CaptureError> {
         let image = self.capture_rect(Rect { left: pos.x, top: pos.y, width: 1, height: 1 })?;
         Ok(image.get_pixel(0, 0))
     }
 
-    fn capture_relative_to(&self, rect: Rect<i32>, relative_to: Pos<i32>) -> Result<T> {
+    fn capture_relative_to(
+        &self,
+        rect: Rect<i32>,
+        relative_to: Pos<i32>,
+    ) -> Result<T, CaptureError> {
         let new_rect = Rect {
             left: rect.left + relative_to.x,
             top: rect.top + relative_to.y,
@@ -0,0 +1,96 @@
+use image::{imageops, GrayImage, ImageBuffer, Luma, RgbImage};
+
+use crate::capture::{CaptureError, Capturer};
+use crate::common::image_ext::ToF32GrayImage;
+use crate::positioning::Rect;
+
+/// 将 RGB 捕获器的截图转换为灰度图的适配器
+///
+/// 识别前的预处理和 OCR 模型都在灰度图上进行，通过该适配器截图时可以直接得到
+/// `Capturer<GrayImage>` 或 `Capturer<ImageBuffer<Luma<f32>, Vec<f32>>>`，
+/// 不必在每个识别区域截图后再单独转换。灰度值与 [`imageops::grayscale`] 相同，
+/// `Luma<f32>` 为其除以 255 后的值
+pub struct GrayscaleCapturer<C> {
+    inner: C,
+}
+
+impl<C: Capturer<RgbImage>> GrayscaleCapturer<C> {
+    pub fn new(inner: C) -> Self {
+        GrayscaleCapturer { inner }
+    }
+
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C: Capturer<RgbImage>> Capturer<GrayImage> for GrayscaleCapturer<C> {
+    fn capture_rect(&self, rect: Rect<i32>) -> Result<GrayImage, CaptureError> {
+        Ok(imageops::grayscale(&self.inner.capture_rect(rect)?))
+    }
+}
+
+impl<C: Capturer<RgbImage>> Capturer<ImageBuffer<Luma<f32>, Vec<f32>>> for GrayscaleCapturer<C> {
+    fn capture_rect(
+        &self,
+        rect: Rect<i32>,
+    ) -> Result<ImageBuffer<Luma<f32>, Vec<f32>>, CaptureError> {
+        let gray = Capturer::<GrayImage>::capture_rect(self, rect)?;
+        Ok(gray.to_f32_gray_image())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::Rgb;
+
+    use super::*;
+    use crate::capture::StaticImageCapturer;
+    use crate::positioning::Pos;
+
+    fn fixture_capturer() -> GrayscaleCapturer<StaticImageCapturer> {
+        let image = RgbImage::from_fn(64, 48, |x, y| Rgb([x as u8 * 4, y as u8 * 5, 200]));
+        GrayscaleCapturer::new(StaticImageCapturer::new(image))
+    }
+
+    #[test]
+    fn test_yields_luma_of_source() {
+        let capturer = fixture_capturer();
+        let rect = Rect::new(8, 6, 20, 10);
+
+        let source = capturer.inner().capture_rect(rect).unwrap();
+        let gray: GrayImage = capturer.capture_rect(rect).unwrap();
+
+        assert_eq!(gray, imageops::grayscale(&source));
+        assert_eq!(gray.dimensions(), (20, 10));
+    }
+
+    #[test]
+    fn test_f32_luma_is_normalized_gray() {
+        let capturer = fixture_capturer();
+        let rect = Rect::new(0, 0, 64, 48);
+
+        let gray: GrayImage = capturer.capture_rect(rect).unwrap();
+        let luma: ImageBuffer<Luma<f32>, Vec<f32>> = capturer.capture_rect(rect).unwrap();
+
+        for (g, l) in gray.pixels().zip(luma.pixels()) {
+            assert_eq!(l[0], g[0] as f32 / 255.0);
+        }
+    }
+
+    #[test]
+    fn test_color_and_errors_pass_through() {
+        let capturer = fixture_capturer();
+        let expected = imageops::grayscale(&RgbImage::from_pixel(1, 1, Rgb([40, 50, 200])));
+
+        let color = Capturer::<GrayImage>::capture_color(&capturer, Pos::new(10, 10)).unwrap();
+        assert_eq!(color, *expected.get_pixel(0, 0));
+
+        let result: Result<GrayImage, _> = capturer.capture_rect(Rect::new(60, 0, 10, 10));
+        assert!(matches!(result, Err(CaptureError::OutOfBounds { .. })));
+    }
+}
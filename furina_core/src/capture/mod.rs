@@ -1,6 +1,10 @@
 // 公共模块声明
+mod blank_check;
+mod capture_error;
 mod capturer;
 mod generic_capturer;
+mod grayscale_capturer;
+mod static_image_capturer;
 mod stream_capturer;
 
 // Windows平台特定模块
@@ -12,8 +16,12 @@ mod winapi_capturer;
 mod windows_capturer;
 
 // 公共导出
+pub use blank_check::{is_blank_capture, BLANK_CAPTURE_TOLERANCE};
+pub use capture_error::CaptureError;
 pub use capturer::Capturer;
 pub use generic_capturer::GenericCapturer;
+pub use grayscale_capturer::GrayscaleCapturer;
+pub use static_image_capturer::StaticImageCapturer;
 // Windows平台导出
 #[cfg(target_os = "windows")]
 pub use screenshots_capturer::ScreenshotsCapturer;
@@ -66,12 +74,12 @@ mod tests {
     }
 
     impl Capturer<RgbImage> for MockCapturer {
-        fn capture_rect(&self, rect: Rect<i32>) -> anyhow::Result<RgbImage> {
+        fn capture_rect(&self, rect: Rect<i32>) -> Result<RgbImage, CaptureError> {
             let mut count = self.capture_count.lock().unwrap();
             *count += 1;
 
             if self.should_fail {
-                anyhow::bail!("模拟捕获失败");
+                return Err(CaptureError::Backend("模拟捕获失败".to_string()));
             }
 
             // 模拟区域裁剪
@@ -98,14 +106,14 @@ mod tests {
             &self,
             rect: Rect<i32>,
             _base_pos: Pos<i32>,
-        ) -> anyhow::Result<RgbImage> {
+        ) -> Result<RgbImage, CaptureError> {
             // 调用capture_rect来避免重复代码
             self.capture_rect(rect)
         }
     }
 
     impl MockCapturer {
-        fn capture(&self) -> anyhow::Result<RgbImage> {
+        fn capture(&self) -> Result<RgbImage, CaptureError> {
             let full_rect =
                 Rect::new(0, 0, self.test_image.width() as i32, self.test_image.height() as i32);
             self.capture_rect(full_rect)
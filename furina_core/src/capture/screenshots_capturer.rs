@@ -2,7 +2,7 @@ use anyhow::Result;
 use image::buffer::ConvertBuffer;
 use image::{RgbImage, RgbaImage};
 
-use crate::capture::Capturer;
+use crate::capture::{CaptureError, Capturer};
 use crate::positioning::Rect;
 
 pub struct ScreenshotsCapturer {
@@ -16,15 +16,15 @@ impl ScreenshotsCapturer {
 }
 
 impl Capturer<RgbaImage> for ScreenshotsCapturer {
-    fn capture_rect(&self, rect: Rect<i32>) -> Result<RgbaImage> {
+    fn capture_rect(&self, rect: Rect<i32>) -> Result<RgbaImage, CaptureError> {
         let screen = &self.screens[0];
 
-        screen.capture_area(rect.left, rect.top, rect.width as u32, rect.height as u32)
+        Ok(screen.capture_area(rect.left, rect.top, rect.width as u32, rect.height as u32)?)
     }
 }
 
 impl Capturer<RgbImage> for ScreenshotsCapturer {
-    fn capture_rect(&self, rect: Rect<i32>) -> Result<RgbImage> {
+    fn capture_rect(&self, rect: Rect<i32>) -> Result<RgbImage, CaptureError> {
         let rgba_result: RgbaImage = self.capture_rect(rect)?;
         Ok(rgba_result.convert())
     }
@@ -0,0 +1,83 @@
+use image::RgbImage;
+
+use crate::capture::{CaptureError, Capturer};
+use crate::positioning::Rect;
+
+/// 从一张固定图像中截取区域的捕获器
+///
+/// 用于离线识别已保存的截图，以及在测试中代替真实的屏幕捕获。
+/// 坐标以图像左上角为原点，截取区域超出图像范围时返回错误
+pub struct StaticImageCapturer {
+    image: RgbImage,
+}
+
+impl StaticImageCapturer {
+    pub fn new(image: RgbImage) -> Self {
+        StaticImageCapturer { image }
+    }
+
+    pub fn image(&self) -> &RgbImage {
+        &self.image
+    }
+}
+
+impl Capturer<RgbImage> for StaticImageCapturer {
+    fn capture_rect(&self, rect: Rect<i32>) -> Result<RgbImage, CaptureError> {
+        CaptureError::check_bounds(rect, self.image.width(), self.image.height())?;
+
+        Ok(image::imageops::crop_imm(
+            &self.image,
+            rect.left as u32,
+            rect.top as u32,
+            rect.width as u32,
+            rect.height as u32,
+        )
+        .to_image())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::Rgb;
+
+    use super::*;
+    use crate::positioning::Pos;
+
+    fn gradient(width: u32, height: u32) -> RgbImage {
+        RgbImage::from_fn(width, height, |x, y| Rgb([x as u8, y as u8, 7]))
+    }
+
+    #[test]
+    fn test_capture_rect() {
+        let capturer = StaticImageCapturer::new(gradient(100, 80));
+        let image = capturer.capture_rect(Rect::new(10, 20, 30, 40)).unwrap();
+        assert_eq!(image.dimensions(), (30, 40));
+        assert_eq!(image.get_pixel(0, 0), &Rgb([10, 20, 7]));
+        assert_eq!(image.get_pixel(29, 39), &Rgb([39, 59, 7]));
+    }
+
+    #[test]
+    fn test_capture_color_and_relative() {
+        let capturer = StaticImageCapturer::new(gradient(100, 80));
+        assert_eq!(capturer.capture_color(Pos::new(42, 17)).unwrap(), Rgb([42, 17, 7]));
+
+        let image =
+            capturer.capture_relative_to(Rect::new(5, 5, 10, 10), Pos::new(20, 30)).unwrap();
+        assert_eq!(image.get_pixel(0, 0), &Rgb([25, 35, 7]));
+    }
+
+    #[test]
+    fn test_capture_out_of_bounds() {
+        let capturer = StaticImageCapturer::new(gradient(100, 80));
+        assert!(capturer.capture_rect(Rect::new(-1, 0, 10, 10)).is_err());
+        assert!(capturer.capture_rect(Rect::new(95, 0, 10, 10)).is_err());
+        assert!(capturer.capture_rect(Rect::new(0, 0, 0, 10)).is_err());
+        assert!(capturer.capture_rect(Rect::new(0, 0, 100, 80)).is_ok());
+
+        let error = capturer.capture_rect(Rect::new(95, 0, 10, 10)).unwrap_err();
+        assert_eq!(
+            error,
+            CaptureError::OutOfBounds { rect: Rect::new(95, 0, 10, 10), width: 100, height: 80 }
+        );
+    }
+}
@@ -6,7 +6,7 @@ use anyhow::{anyhow, Result};
 use image::{ImageBuffer, RgbImage};
 use windows_sys::Win32::Graphics::Gdi::*;
 
-use crate::capture::Capturer;
+use crate::capture::{CaptureError, Capturer};
 use crate::positioning::{Pos, Rect};
 
 // BGRA
@@ -92,7 +92,7 @@ impl WinapiCapturer {
 }
 
 impl Capturer<RgbImage> for WinapiCapturer {
-    fn capture_rect(&self, rect: Rect<i32>) -> Result<RgbImage> {
+    fn capture_rect(&self, rect: Rect<i32>) -> Result<RgbImage, CaptureError> {
         let raw: Vec<u8> = unsafe { unsafe_capture(rect)? };
 
         let height = rect.height as u32;
@@ -109,7 +109,7 @@ impl Capturer<RgbImage> for WinapiCapturer {
         Ok(img)
     }
 
-    fn capture_color(&self, pos: Pos<i32>) -> Result<image::Rgb<u8>> {
+    fn capture_color(&self, pos: Pos<i32>) -> Result<image::Rgb<u8>, CaptureError> {
         let raw: Vec<u8> =
             unsafe { unsafe_capture(Rect { left: pos.x, top: pos.y, width: 1, height: 1 })? };
         let r = raw[2];
@@ -1,7 +1,7 @@
 use anyhow::{anyhow, Result};
 use image::RgbImage;
 
-use crate::capture::{Capturer, ScreenshotsCapturer, WinapiCapturer};
+use crate::capture::{CaptureError, Capturer, ScreenshotsCapturer, WinapiCapturer};
 use crate::positioning::Rect;
 
 pub struct WindowsCapturer {
@@ -19,7 +19,7 @@ impl WindowsCapturer {
 }
 
 impl Capturer<RgbImage> for WindowsCapturer {
-    fn capture_rect(&self, rect: Rect<i32>) -> Result<RgbImage> {
+    fn capture_rect(&self, rect: Rect<i32>) -> Result<RgbImage, CaptureError> {
         {
             let result = self.windows_capturer.capture_rect(rect);
             if result.is_ok() {
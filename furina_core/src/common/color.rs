@@ -1,4 +1,6 @@
-#[derive(Debug, PartialEq, Default, Clone, Copy)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Default, Clone, Copy, Serialize, Deserialize)]
 pub struct Color(pub u8, pub u8, pub u8);
 
 impl Color {
@@ -15,3 +17,9 @@ impl Color {
         Color(r, g, b)
     }
 }
+
+impl From<image::Rgb<u8>> for Color {
+    fn from(rgb: image::Rgb<u8>) -> Self {
+        Color(rgb.0[0], rgb.0[1], rgb.0[2])
+    }
+}
@@ -3,6 +3,8 @@ use std::fmt;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+use log::info;
+
 /// 错误恢复策略
 #[derive(Debug, Clone, PartialEq)]
 pub enum RecoveryStrategy {
@@ -58,6 +60,8 @@ pub struct RecoveryConfig {
     pub adaptive_adjustment: bool,
     /// 错误阈值配置
     pub error_thresholds: ErrorThresholds,
+    /// 是否在每次重试前记录日志（错误类别、第几次尝试、等待时间），便于从用户反馈的日志中排查不稳定的识别和截图
+    pub log_attempts: bool,
 }
 
 /// 错误阈值配置
@@ -111,6 +115,7 @@ impl Default for RecoveryConfig {
                 error_count_per_window: 10,
                 time_window: Duration::from_secs(60),
             },
+            log_attempts: false,
         }
     }
 }
@@ -215,16 +220,74 @@ impl ErrorStatistics {
     }
 }
 
+/// 一次重试尝试的信息，在 [`RecoveryConfig::log_attempts`] 开启时于每次尝试前记录
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryAttempt {
+    pub category: ErrorCategory,
+    /// 第几次尝试，从 1 开始
+    pub attempt: usize,
+    pub max_attempts: usize,
+    /// 本次尝试前等待的时间
+    pub delay: Duration,
+}
+
+impl fmt::Display for RetryAttempt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "错误恢复重试: category={:?} attempt={}/{} delay={}ms",
+            self.category,
+            self.attempt,
+            self.max_attempts,
+            self.delay.as_millis()
+        )
+    }
+}
+
+type AttemptLogger = Arc<dyn Fn(&RetryAttempt) + Send + Sync>;
+
 /// 错误恢复管理器
 pub struct ErrorRecoveryManager {
     config: RecoveryConfig,
     statistics: Arc<Mutex<ErrorStatistics>>,
+    attempt_logger: Option<AttemptLogger>,
 }
 
 impl ErrorRecoveryManager {
     /// 创建新的错误恢复管理器
     pub fn new(config: RecoveryConfig) -> Self {
-        Self { config, statistics: Arc::new(Mutex::new(ErrorStatistics::default())) }
+        Self {
+            config,
+            statistics: Arc::new(Mutex::new(ErrorStatistics::default())),
+            attempt_logger: None,
+        }
+    }
+
+    /// 将重试日志交给 `logger` 处理而不是写入 `log`，仅在 [`RecoveryConfig::log_attempts`] 开启时调用
+    pub fn with_attempt_logger(
+        mut self,
+        logger: impl Fn(&RetryAttempt) + Send + Sync + 'static,
+    ) -> Self {
+        self.attempt_logger = Some(Arc::new(logger));
+        self
+    }
+
+    /// 记录第 `attempt` 次（从 0 开始）尝试
+    fn log_attempt(&self, category: &ErrorCategory, attempt: usize, delay: Duration) {
+        if !self.config.log_attempts {
+            return;
+        }
+
+        let attempt = RetryAttempt {
+            category: category.clone(),
+            attempt: attempt + 1,
+            max_attempts: self.config.max_retries,
+            delay,
+        };
+        match &self.attempt_logger {
+            Some(logger) => logger(&attempt),
+            None => info!("{attempt}"),
+        }
     }
 
     /// 创建默认配置的管理器
@@ -263,22 +326,87 @@ impl ErrorRecoveryManager {
             RecoveryStrategy::Skip => Err(RecoveryError::OperationSkipped),
             RecoveryStrategy::UseDefault => Err(RecoveryError::UseDefaultRequested),
             RecoveryStrategy::UseFallback => Err(RecoveryError::UseFallbackRequested),
-            RecoveryStrategy::ImmediateRetry => self.retry_with_strategy(operation, 0).await,
+            RecoveryStrategy::ImmediateRetry => {
+                self.retry_with_strategy(operation, &category, 0).await
+            },
             RecoveryStrategy::DelayedRetry(delay) => {
-                tokio::time::sleep(delay).await;
-                self.retry_with_strategy(operation, 0).await
+                self.retry_with_strategy(operation, &category, delay.as_millis() as u64).await
             },
             RecoveryStrategy::ExponentialBackoff { initial_delay, max_delay, multiplier } => {
-                self.retry_with_exponential_backoff(operation, initial_delay, max_delay, multiplier)
-                    .await
+                self.retry_with_exponential_backoff(
+                    operation,
+                    &category,
+                    initial_delay,
+                    max_delay,
+                    multiplier,
+                )
+                .await
             },
         }
     }
 
+    /// [`Self::attempt_recovery`] 的阻塞版本，用于不在异步运行时中的同步代码（例如扫描线程）
+    ///
+    /// 重试间隔由恢复策略决定：立即重试不等待，延迟重试每次等待固定时间，
+    /// 指数退避每次将等待时间乘以 `multiplier` 直到 `max_delay`
+    pub fn attempt_recovery_blocking<T, E, F>(
+        &self,
+        mut operation: F,
+        error: &E,
+    ) -> Result<T, RecoveryError<E>>
+    where
+        E: RecoverableError + Clone,
+        F: FnMut() -> Result<T, E>,
+    {
+        let category = error.error_category();
+        self.statistics.lock().unwrap().record_error(category.clone());
+
+        if !self.should_attempt_recovery(&category) {
+            return Err(RecoveryError::RecoveryAborted(error.clone()));
+        }
+
+        let (mut delay, multiplier, max_delay) = match self.get_recovery_strategy(&category) {
+            RecoveryStrategy::Fail => return Err(RecoveryError::RecoveryAborted(error.clone())),
+            RecoveryStrategy::Skip => return Err(RecoveryError::OperationSkipped),
+            RecoveryStrategy::UseDefault => return Err(RecoveryError::UseDefaultRequested),
+            RecoveryStrategy::UseFallback => return Err(RecoveryError::UseFallbackRequested),
+            RecoveryStrategy::ImmediateRetry => (Duration::ZERO, 1.0, Duration::ZERO),
+            RecoveryStrategy::DelayedRetry(delay) => (delay, 1.0, delay),
+            RecoveryStrategy::ExponentialBackoff { initial_delay, max_delay, multiplier } => {
+                (initial_delay, multiplier, max_delay)
+            },
+        };
+
+        for attempt in 0..self.config.max_retries {
+            self.log_attempt(&category, attempt, delay);
+            if !delay.is_zero() {
+                std::thread::sleep(delay);
+            }
+
+            match operation() {
+                Ok(result) => {
+                    self.statistics.lock().unwrap().record_successful_recovery();
+                    return Ok(result);
+                },
+                Err(e) => {
+                    if attempt == self.config.max_retries - 1 {
+                        self.statistics.lock().unwrap().record_failed_recovery();
+                        return Err(RecoveryError::MaxRetriesExceeded(e));
+                    }
+                    self.statistics.lock().unwrap().record_error(e.error_category());
+                    delay = delay.mul_f64(multiplier).min(max_delay);
+                },
+            }
+        }
+
+        Err(RecoveryError::RecoveryAborted(error.clone()))
+    }
+
     /// 使用指定策略重试
     async fn retry_with_strategy<T, E, F, Fut>(
         &self,
         operation: F,
+        category: &ErrorCategory,
         delay_ms: u64,
     ) -> Result<T, RecoveryError<E>>
     where
@@ -289,8 +417,10 @@ impl ErrorRecoveryManager {
         if delay_ms > 0 {
             tokio::time::sleep(Duration::from_millis(delay_ms)).await;
         }
+        let mut delay = Duration::from_millis(delay_ms);
 
         for attempt in 0..self.config.max_retries {
+            self.log_attempt(category, attempt, delay);
             match operation().await {
                 Ok(result) => {
                     // 记录成功恢复
@@ -318,7 +448,8 @@ impl ErrorRecoveryManager {
 
                     // 简单延迟后重试
                     if attempt < self.config.max_retries - 1 {
-                        tokio::time::sleep(Duration::from_millis(100 * (attempt + 1) as u64)).await;
+                        delay = Duration::from_millis(100 * (attempt + 1) as u64);
+                        tokio::time::sleep(delay).await;
                     }
                 },
             }
@@ -331,6 +462,7 @@ impl ErrorRecoveryManager {
     async fn retry_with_exponential_backoff<T, E, F, Fut>(
         &self,
         operation: F,
+        category: &ErrorCategory,
         initial_delay: Duration,
         max_delay: Duration,
         multiplier: f64,
@@ -344,10 +476,13 @@ impl ErrorRecoveryManager {
 
         for attempt in 0..self.config.max_retries {
             if attempt > 0 {
+                self.log_attempt(category, attempt, current_delay);
                 tokio::time::sleep(current_delay).await;
                 current_delay =
                     Duration::from_millis(((current_delay.as_millis() as f64) * multiplier) as u64)
                         .min(max_delay);
+            } else {
+                self.log_attempt(category, attempt, Duration::ZERO);
             }
 
             match operation().await {
@@ -677,6 +812,175 @@ mod tests {
         assert_eq!(stats.recent_errors.len(), 2);
     }
 
+    #[test]
+    fn test_blocking_retry_success() {
+        let manager = ErrorRecoveryManager::new_default();
+        let mut attempts = 0;
+        let error =
+            TestError { message: "初始错误".to_string(), category: ErrorCategory::Temporary };
+
+        let result = manager.attempt_recovery_blocking(
+            || {
+                attempts += 1;
+                if attempts < 2 {
+                    Err(error.clone())
+                } else {
+                    Ok(attempts)
+                }
+            },
+            &error,
+        );
+
+        assert_eq!(result.unwrap(), 2);
+        assert_eq!(manager.get_statistics().successful_recoveries, 1);
+    }
+
+    #[test]
+    fn test_blocking_non_retry_strategies() {
+        let manager = ErrorRecoveryManager::new_default();
+        let mut called = false;
+
+        let error = TestError {
+            message: "图像处理错误".to_string(),
+            category: ErrorCategory::ImageProcessing,
+        };
+        let result: Result<(), _> = manager.attempt_recovery_blocking(
+            || {
+                called = true;
+                Ok(())
+            },
+            &error,
+        );
+        assert!(matches!(result, Err(RecoveryError::UseFallbackRequested)));
+
+        let error = TestError {
+            message: "配置错误".to_string(),
+            category: ErrorCategory::Configuration,
+        };
+        let result: Result<(), _> = manager.attempt_recovery_blocking(|| Ok(()), &error);
+        assert!(matches!(result, Err(RecoveryError::RecoveryAborted(_))));
+        assert!(!called);
+    }
+
+    #[test]
+    fn test_blocking_max_retries_exceeded() {
+        let mut config = RecoveryConfig::default();
+        config.strategy_map.insert(ErrorCategory::Temporary, RecoveryStrategy::ImmediateRetry);
+        let manager = ErrorRecoveryManager::new(config);
+        let mut attempts = 0;
+        let error =
+            TestError { message: "持续错误".to_string(), category: ErrorCategory::Temporary };
+
+        let result: Result<(), _> = manager.attempt_recovery_blocking(
+            || {
+                attempts += 1;
+                Err(error.clone())
+            },
+            &error,
+        );
+
+        assert!(matches!(result, Err(RecoveryError::MaxRetriesExceeded(_))));
+        assert_eq!(attempts, 3);
+        assert_eq!(manager.get_statistics().failed_recoveries, 1);
+    }
+
+    /// 开启重试日志，并将日志收集到返回的列表中
+    fn fixture_logged_manager(
+        strategy: RecoveryStrategy,
+    ) -> (ErrorRecoveryManager, Arc<Mutex<Vec<RetryAttempt>>>) {
+        let mut config = RecoveryConfig { log_attempts: true, ..RecoveryConfig::default() };
+        config.strategy_map.insert(ErrorCategory::Temporary, strategy);
+        let attempts = Arc::new(Mutex::new(Vec::new()));
+        let manager = ErrorRecoveryManager::new(config).with_attempt_logger({
+            let attempts = attempts.clone();
+            move |attempt| attempts.lock().unwrap().push(attempt.clone())
+        });
+        (manager, attempts)
+    }
+
+    fn failing_error() -> TestError {
+        TestError { message: "持续错误".to_string(), category: ErrorCategory::Temporary }
+    }
+
+    fn logged_delays(attempts: &Mutex<Vec<RetryAttempt>>) -> Vec<(usize, u128)> {
+        attempts.lock().unwrap().iter().map(|a| (a.attempt, a.delay.as_millis())).collect()
+    }
+
+    #[test]
+    fn test_blocking_logs_each_fixed_delay_attempt() {
+        let (manager, attempts) =
+            fixture_logged_manager(RecoveryStrategy::DelayedRetry(Duration::from_millis(2)));
+        let error = failing_error();
+
+        let result: Result<(), _> =
+            manager.attempt_recovery_blocking(|| Err(error.clone()), &error);
+
+        assert!(matches!(result, Err(RecoveryError::MaxRetriesExceeded(_))));
+        assert_eq!(logged_delays(&attempts), vec![(1, 2), (2, 2), (3, 2)]);
+        let first = attempts.lock().unwrap()[0].clone();
+        assert_eq!(first.category, ErrorCategory::Temporary);
+        assert_eq!(first.max_attempts, 3);
+        assert_eq!(first.to_string(), "错误恢复重试: category=Temporary attempt=1/3 delay=2ms");
+    }
+
+    #[test]
+    fn test_blocking_logs_each_backoff_attempt() {
+        let (manager, attempts) = fixture_logged_manager(RecoveryStrategy::ExponentialBackoff {
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(3),
+            multiplier: 2.0,
+        });
+        let error = failing_error();
+        let mut calls = 0;
+
+        let result = manager.attempt_recovery_blocking(
+            || {
+                calls += 1;
+                if calls < 3 {
+                    Err(error.clone())
+                } else {
+                    Ok(calls)
+                }
+            },
+            &error,
+        );
+
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(logged_delays(&attempts), vec![(1, 1), (2, 2), (3, 3)]);
+    }
+
+    #[tokio::test]
+    async fn test_async_backoff_logs_each_attempt() {
+        let (manager, attempts) = fixture_logged_manager(RecoveryStrategy::ExponentialBackoff {
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(10),
+            multiplier: 2.0,
+        });
+        let error = failing_error();
+
+        let result: Result<(), _> =
+            manager.attempt_recovery(|| async { Err(failing_error()) }, &error).await;
+
+        assert!(matches!(result, Err(RecoveryError::MaxRetriesExceeded(_))));
+        assert_eq!(logged_delays(&attempts), vec![(1, 0), (2, 1), (3, 2)]);
+    }
+
+    #[test]
+    fn test_attempts_not_logged_by_default() {
+        let logged = Arc::new(AtomicUsize::new(0));
+        let manager = ErrorRecoveryManager::new_default().with_attempt_logger({
+            let logged = logged.clone();
+            move |_| {
+                logged.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+        let error = failing_error();
+
+        let _: Result<(), _> = manager.attempt_recovery_blocking(|| Err(error.clone()), &error);
+
+        assert_eq!(logged.load(Ordering::SeqCst), 0);
+    }
+
     #[test]
     fn test_error_count_in_window() {
         let mut stats = ErrorStatistics::default();
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fmt;
 
 use bytesize::ByteSize;
@@ -8,9 +9,34 @@ use crate::export::StatisticItem;
 pub struct ExportStatistics {
     pub exported_assets: Vec<StatisticItem>,
     pub failed_items: Vec<StatisticItem>,
+    /// 按套装名统计的导出物品数量
+    pub set_counts: BTreeMap<String, usize>,
 }
 
 impl ExportStatistics {
+    /// 汇总表中展示的套装数量上限
+    pub const TOP_SET_COUNT: usize = 5;
+
+    /// 按数量从多到少返回前 `n` 个套装，数量相同时按名称排序
+    pub fn top_sets(&self, n: usize) -> Vec<(&str, usize)> {
+        let mut sets: Vec<(&str, usize)> =
+            self.set_counts.iter().map(|(name, &count)| (name.as_str(), count)).collect();
+        sets.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        sets.truncate(n);
+        sets
+    }
+
+    pub fn get_set_table(&self) -> Table {
+        let mut table = Table::new();
+
+        table.add_row(row!["Set", "Count"]);
+        for (name, count) in self.top_sets(Self::TOP_SET_COUNT) {
+            table.add_row(row![name, count]);
+        }
+
+        table
+    }
+
     pub fn get_table(&self) -> Table {
         let mut table = Table::new();
 
@@ -31,7 +57,12 @@ impl ExportStatistics {
 impl fmt::Display for ExportStatistics {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let table = self.get_table();
-        write!(f, "{table}")
+        write!(f, "{table}")?;
+        if !self.set_counts.is_empty() {
+            let set_table = self.get_set_table();
+            write!(f, "{set_table}")?;
+        }
+        Ok(())
     }
 }
 
@@ -43,6 +74,37 @@ impl Default for ExportStatistics {
 
 impl ExportStatistics {
     pub fn new() -> Self {
-        ExportStatistics { exported_assets: Vec::new(), failed_items: Vec::new() }
+        ExportStatistics {
+            exported_assets: Vec::new(),
+            failed_items: Vec::new(),
+            set_counts: BTreeMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_top_sets_order_and_limit() {
+        let mut stat = ExportStatistics::new();
+        stat.set_counts.insert("A".to_string(), 2);
+        stat.set_counts.insert("B".to_string(), 7);
+        stat.set_counts.insert("C".to_string(), 2);
+
+        assert_eq!(stat.top_sets(2), vec![("B", 7), ("A", 2)]);
+        assert_eq!(stat.top_sets(10).len(), 3);
+    }
+
+    #[test]
+    fn test_display_includes_set_table() {
+        let mut stat = ExportStatistics::new();
+        assert!(!format!("{stat}").contains("Count"));
+
+        stat.set_counts.insert("EmblemOfSeveredFate".to_string(), 40);
+        let text = format!("{stat}");
+        assert!(text.contains("EmblemOfSeveredFate"));
+        assert!(text.lines().all(|line| !line.is_empty()));
     }
 }
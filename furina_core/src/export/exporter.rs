@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::Write;
 use std::path::PathBuf;
@@ -8,6 +9,10 @@ use crate::export::{ExportItem, ExportStatistics, StatisticItem};
 
 pub struct ExportAssets {
     pub assets: Vec<ExportItem>,
+    /// 按套装名统计的导出物品数量，会被复制到 [`ExportStatistics`] 中
+    pub set_counts: BTreeMap<String, usize>,
+    /// 生成内容时已失败、不会写入的导出项，会被复制到 [`ExportStatistics::failed_items`] 中
+    pub failed_items: Vec<StatisticItem>,
 }
 
 impl Default for ExportAssets {
@@ -18,7 +23,12 @@ impl Default for ExportAssets {
 
 impl ExportAssets {
     pub fn new() -> Self {
-        ExportAssets { assets: Vec::new() }
+        ExportAssets { assets: Vec::new(), set_counts: BTreeMap::new(), failed_items: Vec::new() }
+    }
+
+    /// 记录各套装的导出数量，重复记录时以最后一次为准
+    pub fn record_set_counts(&mut self, set_counts: BTreeMap<String, usize>) {
+        self.set_counts = set_counts;
     }
 
     pub fn add_asset(
@@ -31,28 +41,177 @@ impl ExportAssets {
         self.assets.push(ExportItem { contents, filename, name, description })
     }
 
-    pub fn save(&self) -> ExportStatistics {
+    /// 记录一个未能生成内容的导出项，不影响其他导出项的写入
+    pub fn add_failure(
+        &mut self,
+        name: Option<String>,
+        filename: PathBuf,
+        description: Option<String>,
+    ) {
+        self.failed_items.push(StatisticItem { size_in_bytes: 0, filename, name, description });
+    }
+
+    fn new_statistics(&self) -> ExportStatistics {
         let mut stat = ExportStatistics::new();
+        stat.set_counts = self.set_counts.clone();
+        stat.failed_items = self.failed_items.clone();
+        stat
+    }
+
+    /// 并发写入所有导出文件
+    ///
+    /// 每个文件路径一个线程，写入同一路径的导出内容在同一线程中按添加顺序写入，不会交错；
+    /// 统计结果仍按添加顺序排列
+    pub fn save(&self) -> ExportStatistics {
+        let mut stat = self.new_statistics();
+
+        let mut groups: Vec<(&PathBuf, Vec<usize>)> = Vec::new();
+        for (index, item) in self.assets.iter().enumerate() {
+            match groups.iter_mut().find(|(path, _)| **path == item.filename) {
+                Some((_, indices)) => indices.push(index),
+                None => groups.push((&item.filename, vec![index])),
+            }
+        }
 
-        for item in self.assets.iter() {
-            let mut file = match File::create(&item.filename) {
+        let mut outcomes: Vec<Option<Result<(), String>>> = vec![None; self.assets.len()];
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = groups
+                .iter()
+                .map(|(_, indices)| {
+                    scope.spawn(move || {
+                        indices
+                            .iter()
+                            .map(|&index| (index, Self::write_item(&self.assets[index])))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            for (handle, (_, indices)) in handles.into_iter().zip(&groups) {
+                match handle.join() {
+                    Ok(results) => {
+                        for (index, result) in results {
+                            outcomes[index] = Some(result);
+                        }
+                    },
+                    Err(_) => {
+                        for &index in indices {
+                            outcomes[index] = Some(Err("写入线程异常退出".to_string()));
+                        }
+                    },
+                }
+            }
+        });
+
+        for (item, outcome) in self.assets.iter().zip(outcomes) {
+            match outcome.unwrap_or_else(|| Err("未写入".to_string())) {
+                Ok(()) => stat.exported_assets.push(StatisticItem::from_export_item(item)),
                 Err(why) => {
+                    error!("{why}");
                     stat.failed_items.push(StatisticItem::from_export_item(item));
-                    error!("无法创建文件 {:?}: {}", &item.filename, why);
-                    continue;
                 },
-                Ok(file) => file,
-            };
-
-            if let Err(why) = file.write_all(&item.contents) {
-                stat.failed_items.push(StatisticItem::from_export_item(item));
-                error!("无法写入文件 {:?}: {}", &item.filename, why);
-                continue;
             }
+        }
+
+        stat
+    }
 
-            stat.exported_assets.push(StatisticItem::from_export_item(item));
+    fn write_item(item: &ExportItem) -> Result<(), String> {
+        if let Some(parent) = item.filename.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .map_err(|why| format!("无法创建目录 {parent:?}: {why}"))?;
         }
 
+        let mut file = File::create(&item.filename)
+            .map_err(|why| format!("无法创建文件 {:?}: {}", &item.filename, why))?;
+        file.write_all(&item.contents)
+            .map_err(|why| format!("无法写入文件 {:?}: {}", &item.filename, why))
+    }
+
+    /// 将唯一的导出内容写入 `writer`（例如标准输出），而不是写入文件
+    ///
+    /// 多个导出内容无法区分边界，此时返回错误且不写入任何内容
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> anyhow::Result<ExportStatistics> {
+        let item = match self.assets.as_slice() {
+            [item] => item,
+            assets => {
+                return Err(anyhow::anyhow!(
+                    "只能将单个导出内容写入输出流，当前共有 {} 个",
+                    assets.len()
+                ))
+            },
+        };
+
+        writer.write_all(&item.contents)?;
+        writer.flush()?;
+
+        let mut stat = self.new_statistics();
+        stat.exported_assets.push(StatisticItem::from_export_item(item));
+        Ok(stat)
+    }
+
+    /// 统计将要导出的文件，但不写入磁盘
+    pub fn dry_run(&self) -> ExportStatistics {
+        let mut stat = self.new_statistics();
+        stat.exported_assets = self.assets.iter().map(StatisticItem::from_export_item).collect();
         stat
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_to_captures_bytes() {
+        let mut assets = ExportAssets::new();
+        assets.add_asset(
+            Some("GOOD".to_string()),
+            PathBuf::from("good.json"),
+            br#"{"format":"GOOD"}"#.to_vec(),
+            None,
+        );
+
+        let mut output: Vec<u8> = Vec::new();
+        let stat = assets.write_to(&mut output).unwrap();
+        assert_eq!(output, br#"{"format":"GOOD"}"#);
+        assert_eq!(stat.exported_assets.len(), 1);
+        assert!(stat.failed_items.is_empty());
+    }
+
+    #[test]
+    fn test_write_to_rejects_multiple_assets() {
+        let mut assets = ExportAssets::new();
+        assets.add_asset(None, PathBuf::from("a.json"), b"a".to_vec(), None);
+        assets.add_asset(None, PathBuf::from("b.json"), b"b".to_vec(), None);
+
+        let mut output: Vec<u8> = Vec::new();
+        assert!(assets.write_to(&mut output).is_err());
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_save_writes_same_path_in_order() {
+        let dir = std::env::temp_dir().join(format!("furina_export_save_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let mut assets = ExportAssets::new();
+        assets.add_asset(Some("a".to_string()), dir.join("a.json"), b"first".to_vec(), None);
+        assets.add_asset(Some("b".to_string()), dir.join("b.json"), b"b".to_vec(), None);
+        assets.add_asset(Some("a2".to_string()), dir.join("a.json"), b"second".to_vec(), None);
+        assets.add_failure(Some("c".to_string()), dir.join("c.json"), None);
+
+        let stat = assets.save();
+
+        // 同一路径按添加顺序写入，后写入的内容生效
+        assert_eq!(std::fs::read(dir.join("a.json")).unwrap(), b"second");
+        assert_eq!(std::fs::read(dir.join("b.json")).unwrap(), b"b");
+        assert!(!dir.join("c.json").exists());
+        let exported: Vec<_> =
+            stat.exported_assets.iter().map(|i| i.name.clone().unwrap()).collect();
+        assert_eq!(exported, ["a", "b", "a2"]);
+        assert_eq!(stat.failed_items.len(), 1);
+        assert_eq!(stat.failed_items[0].name.as_deref(), Some("c"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
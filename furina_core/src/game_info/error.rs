@@ -0,0 +1,35 @@
+use std::fmt;
+
+/// 查找游戏窗口失败的原因
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameWindowError {
+    /// 没有标题匹配的窗口，`searched` 为查找过的全部窗口名称
+    NotFound { searched: Vec<String> },
+    /// 找到了窗口，但客户区尺寸为 0，通常是窗口被最小化
+    ZeroSize { title: String, width: i32, height: i32 },
+}
+
+impl GameWindowError {
+    /// 针对不同失败原因给出的处理建议
+    pub fn suggestion(&self) -> &'static str {
+        match self {
+            GameWindowError::NotFound { .. } => "请先启动游戏（或云·原神），并确认窗口标题未被修改",
+            GameWindowError::ZeroSize { .. } => "请将最小化的游戏窗口还原，并保持窗口可见",
+        }
+    }
+}
+
+impl fmt::Display for GameWindowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameWindowError::NotFound { searched } => {
+                write!(f, "未找到游戏窗口，已查找的窗口名称: {searched:?}")
+            },
+            GameWindowError::ZeroSize { title, width, height } => {
+                write!(f, "游戏窗口 '{title}' 的尺寸为 {width}x{height}，窗口可能已被最小化")
+            },
+        }
+    }
+}
+
+impl std::error::Error for GameWindowError {}
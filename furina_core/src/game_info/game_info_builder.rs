@@ -1,6 +1,7 @@
 use anyhow::Result;
 
 use super::game_info::GameInfo;
+use crate::game_info::GameWindowError;
 
 pub struct GameInfoBuilder {
     pub local_window_names: Vec<String>,
@@ -28,18 +29,110 @@ impl GameInfoBuilder {
         self
     }
 
+    /// 按查找顺序列出所有窗口名称，本地客户端在前，云游戏在后
+    pub fn window_names(&self) -> Vec<&str> {
+        self.local_window_names
+            .iter()
+            .chain(self.cloud_window_names.iter())
+            .map(|name| name.as_str())
+            .collect()
+    }
+
+    /// 从 `titles` 中筛选与窗口名称完全一致（忽略首尾空白）的窗口标题
+    ///
+    /// 没有任何匹配时返回 [`GameWindowError::NotFound`]，其中包含查找过的全部窗口名称
+    pub fn match_window_titles<'a>(
+        &self,
+        titles: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Vec<&'a str>, GameWindowError> {
+        match_window_titles(&self.window_names(), titles)
+    }
+
     pub fn build(&self) -> Result<GameInfo> {
         #[cfg(windows)]
         {
-            let mut window_names = Vec::new();
-            for name in self.local_window_names.iter() {
-                window_names.push(name.as_str());
-            }
-            for name in self.cloud_window_names.iter() {
-                window_names.push(name.as_str());
-            }
+            let window_names = self.window_names();
             crate::game_info::os::get_game_info(&window_names)
             // crate::game_info::os::get_game_info(&["原神", "Genshin Impact", "云·原神"])
         }
     }
 }
+
+/// 见 [`GameInfoBuilder::match_window_titles`]
+pub fn match_window_titles<'a>(
+    window_names: &[&str],
+    titles: impl IntoIterator<Item = &'a str>,
+) -> Result<Vec<&'a str>, GameWindowError> {
+    let matched: Vec<&str> = titles
+        .into_iter()
+        .map(|title| title.trim())
+        .filter(|title| window_names.contains(title))
+        .collect();
+
+    if matched.is_empty() {
+        return Err(GameWindowError::NotFound {
+            searched: window_names.iter().map(|name| name.to_string()).collect(),
+        });
+    }
+    Ok(matched)
+}
+
+/// 检查找到的窗口客户区是否有效，尺寸为 0 时返回 [`GameWindowError::ZeroSize`]
+pub fn check_window_size(title: &str, width: i32, height: i32) -> Result<(), GameWindowError> {
+    if width <= 0 || height <= 0 {
+        return Err(GameWindowError::ZeroSize { title: title.to_string(), width, height });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn genshin_builder() -> GameInfoBuilder {
+        let mut builder = GameInfoBuilder::new();
+        builder
+            .add_local_window_name("原神")
+            .add_local_window_name("Genshin Impact")
+            .add_cloud_window_name("云·原神");
+        builder
+    }
+
+    #[test]
+    fn test_window_names_order() {
+        assert_eq!(genshin_builder().window_names(), vec!["原神", "Genshin Impact", "云·原神"]);
+    }
+
+    #[test]
+    fn test_no_matching_window() {
+        let builder = genshin_builder();
+        let err = builder.match_window_titles(["记事本", "原神 - 攻略", ""]).unwrap_err();
+        assert_eq!(
+            err,
+            GameWindowError::NotFound {
+                searched: vec![
+                    "原神".to_string(),
+                    "Genshin Impact".to_string(),
+                    "云·原神".to_string()
+                ]
+            }
+        );
+        assert!(err.to_string().contains("Genshin Impact"));
+        assert!(err.suggestion().contains("启动游戏"));
+    }
+
+    #[test]
+    fn test_matching_window_titles() {
+        let builder = genshin_builder();
+        let matched = builder.match_window_titles(["记事本", " 原神 ", "云·原神"]).unwrap();
+        assert_eq!(matched, vec!["原神", "云·原神"]);
+    }
+
+    #[test]
+    fn test_zero_size_window() {
+        assert!(check_window_size("原神", 1920, 1080).is_ok());
+        let err = check_window_size("原神", 0, 0).unwrap_err();
+        assert!(matches!(err, GameWindowError::ZeroSize { .. }));
+        assert!(err.suggestion().contains("还原"));
+    }
+}
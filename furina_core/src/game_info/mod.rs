@@ -1,10 +1,12 @@
 #[allow(clippy::module_inception)]
+mod error;
 mod game_info;
 mod game_info_builder;
 mod os;
 mod resolution_family;
 mod ui;
 
+pub use error::GameWindowError;
 pub use game_info::GameInfo;
 pub use game_info_builder::GameInfoBuilder;
 pub use resolution_family::ResolutionFamily;
@@ -4,6 +4,7 @@ use anyhow::{anyhow, Result};
 use windows_sys::Win32::Foundation::HWND;
 use windows_sys::Win32::UI::WindowsAndMessaging::*;
 
+use crate::game_info::game_info_builder::{check_window_size, match_window_titles};
 use crate::game_info::{GameInfo, Platform, ResolutionFamily, UI};
 use crate::utils;
 
@@ -11,26 +12,23 @@ fn is_window_cloud(title: &str) -> bool {
     title.starts_with("云")
 }
 
-fn get_window(window_names: &[&str]) -> Result<(HWND, bool)> {
+fn get_window(window_names: &[&str]) -> Result<(HWND, bool, String)> {
     let handles = utils::iterate_window();
-    let mut viable_handles = Vec::new();
-    for hwnd in handles.iter() {
-        let title = utils::get_window_title(*hwnd);
-        if let Some(t) = title {
-            let trimmed = t.trim();
+    let titled: Vec<(HWND, String)> = handles
+        .iter()
+        .filter_map(|hwnd| utils::get_window_title(*hwnd).map(|title| (*hwnd, title)))
+        .collect();
 
-            for name in window_names.iter() {
-                if trimmed == *name {
-                    viable_handles.push((*hwnd, String::from(trimmed)));
-                }
-            }
-        }
-    }
+    let matched = match_window_titles(window_names, titled.iter().map(|(_, t)| t.as_str()))?;
+    let mut viable_handles: Vec<(HWND, String)> = titled
+        .iter()
+        .filter(|(_, title)| matched.contains(&title.trim()))
+        .map(|(hwnd, title)| (*hwnd, String::from(title.trim())))
+        .collect();
 
     if viable_handles.len() == 1 {
-        return Ok((viable_handles[0].0, is_window_cloud(&viable_handles[0].1)));
-    } else if viable_handles.is_empty() {
-        return Err(anyhow!("未找到游戏窗口，请确认{:?}已经开启", window_names));
+        let (hwnd, title) = viable_handles.swap_remove(0);
+        return Ok((hwnd, is_window_cloud(&title), title));
     }
 
     println!("找到多个符合名称的窗口，请手动选择窗口：");
@@ -42,8 +40,8 @@ fn get_window(window_names: &[&str]) -> Result<(HWND, bool)> {
 
     let idx = index.trim().parse::<usize>()?;
     if idx < viable_handles.len() {
-        let is_cloud = is_window_cloud(&viable_handles[idx].1);
-        Ok((viable_handles[idx].0, is_cloud))
+        let (hwnd, title) = viable_handles.swap_remove(idx);
+        Ok((hwnd, is_window_cloud(&title), title))
     } else {
         Err(anyhow!("索引{}超出范围", idx))
     }
@@ -52,7 +50,7 @@ fn get_window(window_names: &[&str]) -> Result<(HWND, bool)> {
 pub fn get_game_info(window_names: &[&str]) -> Result<GameInfo> {
     utils::set_dpi_awareness();
 
-    let (hwnd, is_cloud) = get_window(window_names)?;
+    let (hwnd, is_cloud, title) = get_window(window_names)?;
 
     unsafe {
         ShowWindow(hwnd, SW_RESTORE);
@@ -65,6 +63,7 @@ pub fn get_game_info(window_names: &[&str]) -> Result<GameInfo> {
     utils::sleep(1000);
 
     let rect = utils::get_client_rect(hwnd)?;
+    check_window_size(&title, rect.width, rect.height)?;
     let resolution_family = ResolutionFamily::new(rect.width as u32, rect.height as u32)?;
 
     Ok(GameInfo {
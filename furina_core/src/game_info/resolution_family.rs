@@ -5,7 +5,7 @@ use crate::positioning::Size;
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum ResolutionFamily {
     // 仅支持以下分辨率族
-    Windows16x9, // 2560×1440, 1920×1080, 1600×900
+    Windows16x9, // 2560×1440, 1920×1080, 1600×900，其他16:9分辨率通过缩放支持
 }
 
 impl ResolutionFamily {
@@ -16,12 +16,45 @@ impl ResolutionFamily {
             (1920, 1080) => Ok(ResolutionFamily::Windows16x9),
             (1600, 900) => Ok(ResolutionFamily::Windows16x9),
 
+            // 其他16:9分辨率，通过缩放最接近的同比例窗口信息获得
+            _ if width > 0 && width * 9 == height * 16 => Ok(ResolutionFamily::Windows16x9),
+
+            // 4:3 下背包与详情面板的布局与 16:9 不同，不能通过缩放得到，目前没有对应的窗口信息
+            _ if width > 0 && width * 3 == height * 4 => Err(anyhow::anyhow!(
+                "暂不支持4:3分辨率: {}×{}\n4:3下背包和圣遗物面板的位置与16:9不同，无法通过缩放适配，请将游戏切换为16:9分辨率（如1600×900）的窗口模式",
+                width,
+                height
+            )),
+
             // 不支持的分辨率
             _ => Err(anyhow::anyhow!(
-                "不支持的分辨率: {}×{}\n支持的分辨率:\n- 2560×1440\n- 1920×1080\n- 1600×900",
+                "不支持的分辨率: {}×{}\n支持的分辨率:\n- 2560×1440\n- 1920×1080\n- 1600×900\n- 其他16:9分辨率（自动缩放，精度可能下降）",
                 width,
                 height
             )),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolution_family() {
+        assert_eq!(ResolutionFamily::new(1920, 1080).unwrap(), ResolutionFamily::Windows16x9);
+        assert_eq!(ResolutionFamily::new(3840, 2160).unwrap(), ResolutionFamily::Windows16x9);
+        assert!(ResolutionFamily::new(1280, 1024).is_err());
+        assert!(ResolutionFamily::new(0, 0).is_err());
+    }
+
+    #[test]
+    fn test_four_by_three_has_specific_error() {
+        let error = ResolutionFamily::new(1280, 960).unwrap_err().to_string();
+        assert!(error.contains("4:3"));
+        assert!(error.contains("1280×960"));
+
+        let error = ResolutionFamily::new(1280, 1024).unwrap_err().to_string();
+        assert!(!error.contains("4:3"));
+    }
+}
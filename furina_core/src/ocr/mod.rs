@@ -171,13 +171,17 @@ mod tests {
 
 pub use traits::ImageToText;
 
+mod null_model;
 pub mod ocr_model;
+pub mod preprocessor;
 
 use std::time::Duration;
 
 use anyhow::Result;
 use image::{GrayImage, RgbImage};
+pub use null_model::NullOcrModel;
 pub use ocr_model::OcrModel;
+pub use preprocessor::DefaultPreprocessor;
 
 use crate::positioning::Rect;
 
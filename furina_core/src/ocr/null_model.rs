@@ -0,0 +1,21 @@
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::ocr::ImageToText;
+
+/// 不加载模型、总是返回空字符串的识别器
+///
+/// 用于在没有 OCR 模型时检查截图与翻页是否正常，识别结果没有任何意义
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullOcrModel;
+
+impl<ImageType> ImageToText<ImageType> for NullOcrModel {
+    fn image_to_text(&self, _image: &ImageType, _is_preprocessed: bool) -> Result<String> {
+        Ok(String::new())
+    }
+
+    fn get_average_inference_time(&self) -> Option<Duration> {
+        None
+    }
+}
@@ -0,0 +1,188 @@
+use anyhow::{anyhow, Result};
+use image::imageops::{self, FilterType};
+use image::{GrayImage, Luma, Rgb, RgbImage};
+
+use crate::ocr::ImagePreprocessor;
+
+/// 默认的图像预处理器
+///
+/// 用于暗色主题或低亮度画面：先拉伸对比度，再使用 Otsu 自适应阈值二值化，
+/// 使文字与背景在送入模型前明确分离
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultPreprocessor;
+
+impl DefaultPreprocessor {
+    pub fn new() -> Self {
+        DefaultPreprocessor
+    }
+
+    /// 使用 Otsu 方法计算使类间方差最大的阈值，图像为纯色时返回 `None`
+    pub fn otsu_threshold(image: &GrayImage) -> Option<u8> {
+        let mut histogram = [0u64; 256];
+        for pixel in image.pixels() {
+            histogram[pixel[0] as usize] += 1;
+        }
+
+        let total = image.pixels().len() as f64;
+        let sum_all: f64 = histogram.iter().enumerate().map(|(i, &c)| i as f64 * c as f64).sum();
+
+        let mut best: Option<(u8, f64)> = None;
+        let mut weight_bg = 0.0;
+        let mut sum_bg = 0.0;
+        for (t, &count) in histogram.iter().enumerate() {
+            weight_bg += count as f64;
+            sum_bg += t as f64 * count as f64;
+
+            let weight_fg = total - weight_bg;
+            if weight_bg == 0.0 || weight_fg == 0.0 {
+                continue;
+            }
+
+            let mean_bg = sum_bg / weight_bg;
+            let mean_fg = (sum_all - sum_bg) / weight_fg;
+            let variance = weight_bg * weight_fg * (mean_bg - mean_fg).powi(2);
+            if best.map_or(true, |(_, v)| variance > v) {
+                best = Some((t as u8, variance));
+            }
+        }
+
+        best.map(|(t, _)| t)
+    }
+
+    /// 完整的预处理流程：对比度增强、灰度化、二值化，结果以 RGB 图像返回以便直接送入模型
+    pub fn preprocess(&self, image: &RgbImage) -> Result<RgbImage> {
+        let enhanced = self.enhance_contrast(image)?;
+        let binary = self.binarize(&imageops::grayscale(&enhanced))?;
+        Ok(RgbImage::from_fn(binary.width(), binary.height(), |x, y| {
+            let v = binary.get_pixel(x, y)[0];
+            Rgb([v, v, v])
+        }))
+    }
+}
+
+impl ImagePreprocessor for DefaultPreprocessor {
+    type ImageType = RgbImage;
+
+    /// 按亮度的最小/最大值线性拉伸各通道，纯色图像原样返回
+    fn enhance_contrast(&self, image: &RgbImage) -> Result<RgbImage> {
+        let gray = imageops::grayscale(image);
+        let (min, max) =
+            gray.pixels().fold((u8::MAX, u8::MIN), |(lo, hi), p| (lo.min(p[0]), hi.max(p[0])));
+        if min >= max {
+            return Ok(image.clone());
+        }
+
+        let scale = 255.0 / (max - min) as f32;
+        let mut result = image.clone();
+        for pixel in result.pixels_mut() {
+            for c in pixel.0.iter_mut() {
+                *c = ((*c as f32 - min as f32) * scale).round().clamp(0.0, 255.0) as u8;
+            }
+        }
+        Ok(result)
+    }
+
+    /// 使用 Otsu 阈值二值化，高于阈值的像素为 255，其余为 0
+    fn binarize(&self, image: &GrayImage) -> Result<GrayImage> {
+        let threshold = match Self::otsu_threshold(image) {
+            Some(t) => t,
+            None => return Ok(image.clone()),
+        };
+
+        Ok(GrayImage::from_fn(image.width(), image.height(), |x, y| {
+            if image.get_pixel(x, y)[0] > threshold {
+                Luma([255])
+            } else {
+                Luma([0])
+            }
+        }))
+    }
+
+    /// 双线性插值缩放
+    fn resize(&self, image: &GrayImage, width: u32, height: u32) -> Result<GrayImage> {
+        if width == 0 || height == 0 {
+            return Err(anyhow!("目标尺寸无效: {width}x{height}"));
+        }
+        Ok(imageops::resize(image, width, height, FilterType::Triangle))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 左半部分为暗色渐变（背景），右半部分为亮色渐变（文字）
+    fn split_gradient(width: u32, height: u32) -> GrayImage {
+        GrayImage::from_fn(width, height, |x, _| {
+            let half = width / 2;
+            if x < half {
+                Luma([(x * 60 / half) as u8])
+            } else {
+                Luma([(180 + (x - half) * 75 / half) as u8])
+            }
+        })
+    }
+
+    #[test]
+    fn test_binarize_splits_foreground_and_background() {
+        let image = split_gradient(64, 8);
+        let threshold = DefaultPreprocessor::otsu_threshold(&image).unwrap();
+        assert!((58..180).contains(&threshold));
+
+        let binary = DefaultPreprocessor.binarize(&image).unwrap();
+        for (x, _, pixel) in binary.enumerate_pixels() {
+            let expected = if x < 32 { 0 } else { 255 };
+            assert_eq!(pixel[0], expected, "x = {x}");
+        }
+    }
+
+    #[test]
+    fn test_binarize_dim_gradient() {
+        // 低亮度画面：文字与背景都很暗，但仍然可以被分开
+        let image =
+            GrayImage::from_fn(40, 4, |x, _| Luma([if x < 30 { 10 + x as u8 / 10 } else { 45 }]));
+        let binary = DefaultPreprocessor.binarize(&image).unwrap();
+        assert_eq!(binary.get_pixel(0, 0)[0], 0);
+        assert_eq!(binary.get_pixel(29, 0)[0], 0);
+        assert_eq!(binary.get_pixel(30, 0)[0], 255);
+    }
+
+    #[test]
+    fn test_flat_image_is_unchanged() {
+        let image = GrayImage::from_pixel(10, 10, Luma([42]));
+        assert_eq!(DefaultPreprocessor::otsu_threshold(&image), None);
+        assert_eq!(DefaultPreprocessor.binarize(&image).unwrap(), image);
+    }
+
+    #[test]
+    fn test_enhance_contrast_stretches_range() {
+        let image = RgbImage::from_fn(16, 1, |x, _| {
+            let v = 40 + x as u8 * 4;
+            Rgb([v, v, v])
+        });
+        let enhanced = DefaultPreprocessor.enhance_contrast(&image).unwrap();
+        assert_eq!(enhanced.get_pixel(0, 0)[0], 0);
+        assert_eq!(enhanced.get_pixel(15, 0)[0], 255);
+    }
+
+    #[test]
+    fn test_resize() {
+        let image = split_gradient(64, 8);
+        let resized = DefaultPreprocessor.resize(&image, 32, 4).unwrap();
+        assert_eq!(resized.dimensions(), (32, 4));
+        assert!(DefaultPreprocessor.resize(&image, 0, 4).is_err());
+    }
+
+    #[test]
+    fn test_preprocess_outputs_binary_rgb() {
+        let image =
+            RgbImage::from_fn(
+                20,
+                4,
+                |x, _| if x < 10 { Rgb([20, 20, 30]) } else { Rgb([70, 70, 80]) },
+            );
+        let result = DefaultPreprocessor.preprocess(&image).unwrap();
+        assert_eq!(result.get_pixel(0, 0), &Rgb([0, 0, 0]));
+        assert_eq!(result.get_pixel(19, 0), &Rgb([255, 255, 255]));
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 use std::ops::{Add, Sub};
 
+use paste::paste;
 use serde::{Deserialize, Serialize};
 
 use crate::positioning::{Scalable, Size};
@@ -79,6 +80,27 @@ impl_int_pos!(i32);
 impl_int_pos!(usize);
 impl_int_pos!(u32);
 
+macro_rules! convert_pos_type {
+    ($t1:ty, $t2:ty) => {
+        impl Pos<$t1> {
+            paste! {
+                pub fn [<to_pos_ $t2>](&self) -> Pos<$t2> {
+                    Pos { x: self.x as $t2, y: self.y as $t2 }
+                }
+            }
+        }
+    };
+}
+
+convert_pos_type!(f64, i32);
+convert_pos_type!(f64, usize);
+convert_pos_type!(f64, u32);
+convert_pos_type!(u32, usize);
+convert_pos_type!(i32, usize);
+convert_pos_type!(i32, f64);
+convert_pos_type!(i32, u32);
+convert_pos_type!(usize, i32);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,6 +137,17 @@ mod tests {
         assert_eq!(result.y, 70);
     }
 
+    #[test]
+    fn test_pos_add_mixed_size() {
+        // 先累加偏移量再加到坐标上，与逐个相加结果一致
+        let margin = Pos::new(99.0, 101.0);
+        let offset = Size::new(0.0, 48.5);
+        let gap = Size::new(20.0, 20.0);
+        assert_eq!(margin + (offset + gap), margin + offset + gap);
+        assert_eq!(margin + offset + gap, Pos::new(119.0, 169.5));
+        assert_eq!(margin + offset - margin, Pos::new(0.0, 48.5));
+    }
+
     #[test]
     fn test_pos_sub() {
         let pos1 = Pos::new(10, 20);
@@ -145,6 +178,14 @@ mod tests {
         assert_eq!(scaled.y, 40.0);
     }
 
+    #[test]
+    fn test_pos_scale_fractional() {
+        let pos = Pos::new(10.0, 20.0);
+        let scaled = pos.scale(0.75);
+        assert_eq!(scaled.x, 7.5);
+        assert_eq!(scaled.y, 15.0);
+    }
+
     #[test]
     fn test_pos_scale_i32() {
         let pos = Pos::new(10_i32, 20_i32);
@@ -178,6 +219,41 @@ mod tests {
         assert_eq!(result.y, -10);
     }
 
+    #[test]
+    fn test_pos_convert_f64_to_i32() {
+        let converted = Pos::new(10.7, 20.3).to_pos_i32();
+        assert_eq!(converted.x, 10);
+        assert_eq!(converted.y, 20);
+    }
+
+    #[test]
+    fn test_pos_convert_f64_to_usize() {
+        let converted = Pos::new(10.7, 20.3).to_pos_usize();
+        assert_eq!(converted.x, 10);
+        assert_eq!(converted.y, 20);
+    }
+
+    #[test]
+    fn test_pos_convert_i32_to_f64() {
+        let converted = Pos::new(10_i32, 20_i32).to_pos_f64();
+        assert_eq!(converted.x, 10.0);
+        assert_eq!(converted.y, 20.0);
+    }
+
+    #[test]
+    fn test_pos_convert_u32_to_usize() {
+        let converted = Pos::new(10_u32, 20_u32).to_pos_usize();
+        assert_eq!(converted.x, 10_usize);
+        assert_eq!(converted.y, 20_usize);
+    }
+
+    #[test]
+    fn test_pos_convert_usize_to_i32() {
+        let converted = Pos::new(10_usize, 20_usize).to_pos_i32();
+        assert_eq!(converted.x, 10_i32);
+        assert_eq!(converted.y, 20_i32);
+    }
+
     #[test]
     fn test_pos_serde() {
         let pos = Pos::new(10, 20);
@@ -1,5 +1,5 @@
 use std::fmt::Display;
-use std::ops::Add;
+use std::ops::{Add, Mul, Sub};
 
 use paste::paste;
 use serde::{Deserialize, Serialize};
@@ -45,6 +45,73 @@ where
     }
 }
 
+impl<T> Rect<T>
+where
+    T: Add<T, Output = T> + PartialOrd + Copy,
+{
+    /// 点是否在矩形内，包含左、上边界，不包含右、下边界
+    pub fn contains(&self, pos: Pos<T>) -> bool {
+        pos.x >= self.left
+            && pos.y >= self.top
+            && pos.x < self.left + self.width
+            && pos.y < self.top + self.height
+    }
+}
+
+/// `rect + pos` 等价于 [`Rect::translate`]
+impl<T> Add<Pos<T>> for Rect<T>
+where
+    T: Add<T, Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Pos<T>) -> Self::Output {
+        self.translate(rhs)
+    }
+}
+
+/// `rect - pos` 将矩形反向平移，常用于把窗口坐标转换为相对某个区域左上角的坐标
+impl<T> Sub<Pos<T>> for Rect<T>
+where
+    T: Sub<T, Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Pos<T>) -> Self::Output {
+        Rect {
+            left: self.left - rhs.x,
+            top: self.top - rhs.y,
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Rect<T>
+where
+    T: Mul<T, Output = T> + Copy,
+{
+    /// 面积，整数类型在大尺寸下可能溢出，需要时请使用 `checked_area`
+    pub fn area(&self) -> T {
+        self.width * self.height
+    }
+}
+
+macro_rules! impl_int_checked_area {
+    ($t:ty) => {
+        impl Rect<$t> {
+            /// 提升为 u64 计算面积，尺寸为负或结果超出 u64 时返回 `None`
+            pub fn checked_area(&self) -> Option<u64> {
+                self.size().checked_area()
+            }
+        }
+    };
+}
+
+impl_int_checked_area!(i32);
+impl_int_checked_area!(usize);
+impl_int_checked_area!(u32);
+
 impl<T> Display for Rect<T>
 where
     T: Display + Copy,
@@ -129,6 +196,26 @@ mod tests {
         assert_eq!(size.height, 50);
     }
 
+    #[test]
+    fn test_rect_area() {
+        assert_eq!(Rect::new(10, 20, 100, 50).area(), 5000);
+        assert_eq!(Rect::new(0.0, 0.0, 1.5, 2.0).area(), 3.0);
+        assert_eq!(Rect::new(0_i32, 0, 3440, 1440).checked_area(), Some(4_953_600));
+        assert_eq!(Rect::new(0_u32, 0, 65_536, 65_536).checked_area(), Some(1 << 32));
+        assert_eq!(Rect::new(0_i32, 0, 100, -1).checked_area(), None);
+    }
+
+    #[test]
+    fn test_rect_contains() {
+        let rect = Rect::new(10.0, 20.0, 100.0, 50.0);
+        assert!(rect.contains(Pos::new(10.0, 20.0)));
+        assert!(rect.contains(Pos::new(60.0, 45.0)));
+        assert!(!rect.contains(Pos::new(110.0, 45.0)));
+        assert!(!rect.contains(Pos::new(60.0, 70.0)));
+        assert!(!rect.contains(Pos::new(9.9, 45.0)));
+        assert!(!Rect::new(0, 0, 0, 0).contains(Pos::new(0, 0)));
+    }
+
     #[test]
     fn test_rect_translate() {
         let rect = Rect::new(10, 20, 100, 50);
@@ -141,6 +228,22 @@ mod tests {
         assert_eq!(translated.height, 50);
     }
 
+    #[test]
+    fn test_rect_add_sub_pos() {
+        let rect = Rect::new(10, 20, 100, 50);
+        let pos = Pos::new(5, 15);
+
+        assert_eq!(rect + pos, rect.translate(pos));
+        assert_eq!(rect + pos - pos, rect);
+        assert_eq!(rect - rect.origin(), Rect::new(0, 0, 100, 50));
+
+        let rect = Rect::new(1090.0, 100.0, 410.0, 700.0);
+        assert_eq!(
+            rect - Pos::new(1090.0, 100.0) + Pos::new(0.5, 0.5),
+            Rect::new(0.5, 0.5, 410.0, 700.0)
+        );
+    }
+
     #[test]
     fn test_rect_display() {
         let rect = Rect::new(10, 20, 100, 50);
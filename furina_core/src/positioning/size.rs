@@ -1,6 +1,8 @@
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
+use std::ops::{Add, Mul};
 
+use paste::paste;
 use serde::{Deserialize, Serialize};
 
 use crate::positioning::Scalable;
@@ -17,6 +19,48 @@ impl<T> Size<T> {
     }
 }
 
+impl<T> Add<Size<T>> for Size<T>
+where
+    T: Add<T, Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Size<T>) -> Self::Output {
+        Size { width: self.width + rhs.width, height: self.height + rhs.height }
+    }
+}
+
+impl<T> Size<T>
+where
+    T: Mul<T, Output = T> + Copy,
+{
+    /// 面积，整数类型在大尺寸下可能溢出，需要时请使用 `checked_area`
+    pub fn area(&self) -> T {
+        self.width * self.height
+    }
+}
+
+impl<T> Size<T>
+where
+    T: PartialOrd + Copy,
+{
+    /// 逐分量取较小值
+    pub fn min(&self, other: Size<T>) -> Size<T> {
+        Size {
+            width: if other.width < self.width { other.width } else { self.width },
+            height: if other.height < self.height { other.height } else { self.height },
+        }
+    }
+
+    /// 逐分量取较大值
+    pub fn max(&self, other: Size<T>) -> Size<T> {
+        Size {
+            width: if other.width > self.width { other.width } else { self.width },
+            height: if other.height > self.height { other.height } else { self.height },
+        }
+    }
+}
+
 impl<T> Display for Size<T>
 where
     T: Display,
@@ -45,10 +89,46 @@ impl Scalable for Size<f64> {
     }
 }
 
+impl Size<f64> {
+    /// 宽高比（宽 / 高），高度为 0 时结果为无穷大或 NaN
+    pub fn aspect_ratio(&self) -> f64 {
+        self.width / self.height
+    }
+
+    /// 保持宽高比缩放到 `bounds` 以内的最大缩放系数
+    ///
+    /// 宽高比比 `bounds` 更宽时受宽度限制，否则受高度限制
+    pub fn scale_to_fit(&self, bounds: Size<f64>) -> f64 {
+        (bounds.width / self.width).min(bounds.height / self.height)
+    }
+}
+
+macro_rules! impl_int_area {
+    ($t:ty) => {
+        impl Size<$t> {
+            /// 宽高比（宽 / 高），高度为 0 时结果为无穷大或 NaN
+            pub fn aspect_ratio(&self) -> f64 {
+                self.width as f64 / self.height as f64
+            }
+
+            /// 提升为 u64 计算面积，避免大窗口下的溢出；尺寸为负或结果超出 u64 时返回 `None`
+            pub fn checked_area(&self) -> Option<u64> {
+                let width = u64::try_from(self.width).ok()?;
+                let height = u64::try_from(self.height).ok()?;
+                width.checked_mul(height)
+            }
+        }
+    };
+}
+
 impl_int_size!(i32);
 impl_int_size!(usize);
 impl_int_size!(u32);
 
+impl_int_area!(i32);
+impl_int_area!(usize);
+impl_int_area!(u32);
+
 macro_rules! impl_int_hash {
     ($t:ty) => {
         impl Hash for Size<$t> {
@@ -64,6 +144,27 @@ impl_int_hash!(i32);
 impl_int_hash!(usize);
 impl_int_hash!(u32);
 
+macro_rules! convert_size_type {
+    ($t1:ty, $t2:ty) => {
+        impl Size<$t1> {
+            paste! {
+                pub fn [<to_size_ $t2>](&self) -> Size<$t2> {
+                    Size { height: self.height as $t2, width: self.width as $t2 }
+                }
+            }
+        }
+    };
+}
+
+convert_size_type!(f64, i32);
+convert_size_type!(f64, usize);
+convert_size_type!(f64, u32);
+convert_size_type!(u32, usize);
+convert_size_type!(i32, usize);
+convert_size_type!(i32, f64);
+convert_size_type!(i32, u32);
+convert_size_type!(usize, i32);
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -105,6 +206,14 @@ mod tests {
         assert_eq!(scaled.height, 100.0);
     }
 
+    #[test]
+    fn test_size_scale_fractional() {
+        let size = Size::new(100.0, 50.0);
+        let scaled = size.scale(0.75);
+        assert_eq!(scaled.width, 75.0);
+        assert_eq!(scaled.height, 37.5);
+    }
+
     #[test]
     fn test_size_scale_i32() {
         let size = Size::new(100_i32, 50_i32);
@@ -129,6 +238,61 @@ mod tests {
         assert_eq!(scaled.height, 125);
     }
 
+    #[test]
+    fn test_size_add() {
+        assert_eq!(Size::new(100, 50) + Size::new(20, 10), Size::new(120, 60));
+        assert_eq!(Size::new(0.0, 48.5) + Size::new(1.5, 1.5), Size::new(1.5, 50.0));
+    }
+
+    #[test]
+    fn test_size_area_and_aspect_ratio() {
+        assert_eq!(Size::new(1920, 1080).area(), 2_073_600);
+        assert_eq!(Size::new(2.5, 4.0).area(), 10.0);
+        assert!((Size::new(1920_usize, 1080).aspect_ratio() - 16.0 / 9.0).abs() < 1e-12);
+        assert!((Size::new(3440_u32, 1440).aspect_ratio() - 43.0 / 18.0).abs() < 1e-12);
+        assert_eq!(Size::new(1600.0, 1000.0).aspect_ratio(), 1.6);
+    }
+
+    #[test]
+    fn test_size_min_max() {
+        let a = Size::new(1920, 900);
+        let b = Size::new(1600, 1080);
+        assert_eq!(a.min(b), Size::new(1600, 900));
+        assert_eq!(a.max(b), Size::new(1920, 1080));
+        assert_eq!(Size::new(1.5, 2.0).min(Size::new(2.0, 1.0)), Size::new(1.5, 1.0));
+    }
+
+    #[test]
+    fn test_size_scale_to_fit() {
+        let size = Size::new(1920.0, 1080.0);
+
+        // 21:9 的范围更宽，受高度限制
+        let factor = size.scale_to_fit(Size::new(3440.0, 1440.0));
+        assert!((factor - 1440.0 / 1080.0).abs() < 1e-12);
+        let fitted = size.scale(factor);
+        assert!((fitted.height - 1440.0).abs() < 1e-9);
+        assert!(fitted.width < 3440.0);
+
+        // 4:3 的范围更窄，受宽度限制
+        let factor = size.scale_to_fit(Size::new(1024.0, 768.0));
+        assert!((factor - 1024.0 / 1920.0).abs() < 1e-12);
+        let fitted = size.scale(factor);
+        assert!((fitted.width - 1024.0).abs() < 1e-9);
+        assert!(fitted.height < 768.0);
+    }
+
+    #[test]
+    fn test_size_checked_area() {
+        assert_eq!(Size::new(3440_i32, 1440_i32).checked_area(), Some(4_953_600));
+        assert_eq!(Size::new(3440_u32, 1440_u32).checked_area(), Some(4_953_600));
+
+        // 乘积超出 i32 / u32 的范围，提升为 u64 后仍能得到正确结果
+        assert_eq!(Size::new(50_000_i32, 50_000_i32).checked_area(), Some(2_500_000_000));
+        assert_eq!(Size::new(65_536_u32, 65_536_u32).checked_area(), Some(4_294_967_296));
+
+        assert_eq!(Size::new(-1920_i32, 1080_i32).checked_area(), None);
+    }
+
     #[test]
     fn test_size_hash_i32() {
         let size1 = Size::new(100_i32, 50_i32);
@@ -162,6 +326,41 @@ mod tests {
         assert!(map.contains_key(&size2));
     }
 
+    #[test]
+    fn test_size_convert_f64_to_i32() {
+        let converted = Size::new(100.9, 50.1).to_size_i32();
+        assert_eq!(converted.width, 100);
+        assert_eq!(converted.height, 50);
+    }
+
+    #[test]
+    fn test_size_convert_f64_to_usize() {
+        let converted = Size::new(100.9, 50.1).to_size_usize();
+        assert_eq!(converted.width, 100);
+        assert_eq!(converted.height, 50);
+    }
+
+    #[test]
+    fn test_size_convert_i32_to_f64() {
+        let converted = Size::new(100_i32, 50_i32).to_size_f64();
+        assert_eq!(converted.width, 100.0);
+        assert_eq!(converted.height, 50.0);
+    }
+
+    #[test]
+    fn test_size_convert_u32_to_usize() {
+        let converted = Size::new(100_u32, 50_u32).to_size_usize();
+        assert_eq!(converted.width, 100_usize);
+        assert_eq!(converted.height, 50_usize);
+    }
+
+    #[test]
+    fn test_size_convert_usize_to_i32() {
+        let converted = Size::new(100_usize, 50_usize).to_size_i32();
+        assert_eq!(converted.width, 100_i32);
+        assert_eq!(converted.height, 50_i32);
+    }
+
     #[test]
     fn test_size_serde() {
         let size = Size::new(100, 50);
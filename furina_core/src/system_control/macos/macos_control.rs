@@ -1,6 +1,6 @@
 use enigo::{Enigo, MouseControllable};
 
-use crate::system_control::system_control::SystemControl;
+use crate::system_control::SystemControlApi;
 use crate::utils;
 
 pub struct MacOSControl {
@@ -12,24 +12,6 @@ impl MacOSControl {
         MacOSControl { enigo: Enigo::new() }
     }
 
-    pub fn mouse_move_to(&mut self, x: i32, y: i32) -> anyhow::Result<()> {
-        self.enigo.mouse_move_to(x, y);
-
-        anyhow::Ok(())
-    }
-
-    pub fn mouse_click(&mut self) -> anyhow::Result<()> {
-        self.enigo.mouse_click(MouseButton::Left);
-
-        anyhow::Ok(())
-    }
-
-    pub fn mouse_scroll(&mut self, amount: i32) -> anyhow::Result<()> {
-        self.enigo.mouse_scroll_y(-amount);
-
-        anyhow::Ok(())
-    }
-
     pub fn mac_scroll(&mut self, length: i32, delta: i32, times: i32) {
         let enigo = &mut self.enigo;
 
@@ -61,3 +43,23 @@ impl MacOSControl {
         mac_scroll(length, 4, 5);
     }
 }
+
+impl SystemControlApi for MacOSControl {
+    fn mouse_move_to(&mut self, x: i32, y: i32) -> anyhow::Result<()> {
+        self.enigo.mouse_move_to(x, y);
+
+        anyhow::Ok(())
+    }
+
+    fn mouse_click(&mut self) -> anyhow::Result<()> {
+        self.enigo.mouse_click(MouseButton::Left);
+
+        anyhow::Ok(())
+    }
+
+    fn mouse_scroll(&mut self, amount: i32, _try_find: bool) -> anyhow::Result<()> {
+        self.enigo.mouse_scroll_y(-amount);
+
+        anyhow::Ok(())
+    }
+}
@@ -3,7 +3,12 @@ pub mod macos;
 #[cfg(target_os = "windows")]
 pub mod windows;
 
+mod null_system_control;
+mod system_control_api;
+
 #[cfg(target_os = "macos")]
 pub use macos::macos_control::MacOSControl as SystemControl;
+pub use null_system_control::{ControlCall, NullSystemControl};
+pub use system_control_api::SystemControlApi;
 #[cfg(target_os = "windows")]
 pub use windows::windows_control::WindowsSystemControl as SystemControl;
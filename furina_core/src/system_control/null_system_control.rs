@@ -0,0 +1,72 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::system_control::SystemControlApi;
+
+/// [`NullSystemControl`] 记录的一次鼠标操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlCall {
+    MoveTo { x: i32, y: i32 },
+    Click,
+    Scroll { amount: i32, try_find: bool },
+}
+
+/// 不操作鼠标、只记录调用的实现，用于测试和无界面运行
+///
+/// 克隆出的实例共享同一份记录，将其中一个交给控制器后仍可通过另一个查看调用
+#[derive(Debug, Clone, Default)]
+pub struct NullSystemControl {
+    calls: Rc<RefCell<Vec<ControlCall>>>,
+}
+
+impl NullSystemControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 按调用顺序返回已记录的操作
+    pub fn calls(&self) -> Vec<ControlCall> {
+        self.calls.borrow().clone()
+    }
+}
+
+impl SystemControlApi for NullSystemControl {
+    fn mouse_move_to(&mut self, x: i32, y: i32) -> anyhow::Result<()> {
+        self.calls.borrow_mut().push(ControlCall::MoveTo { x, y });
+        Ok(())
+    }
+
+    fn mouse_click(&mut self) -> anyhow::Result<()> {
+        self.calls.borrow_mut().push(ControlCall::Click);
+        Ok(())
+    }
+
+    fn mouse_scroll(&mut self, amount: i32, try_find: bool) -> anyhow::Result<()> {
+        self.calls.borrow_mut().push(ControlCall::Scroll { amount, try_find });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clones_share_recorded_calls() {
+        let control = NullSystemControl::new();
+        let mut boxed: Box<dyn SystemControlApi> = Box::new(control.clone());
+
+        boxed.mouse_move_to(10, 20).unwrap();
+        boxed.mouse_click().unwrap();
+        boxed.mouse_scroll(3, false).unwrap();
+
+        assert_eq!(
+            control.calls(),
+            vec![
+                ControlCall::MoveTo { x: 10, y: 20 },
+                ControlCall::Click,
+                ControlCall::Scroll { amount: 3, try_find: false },
+            ]
+        );
+    }
+}
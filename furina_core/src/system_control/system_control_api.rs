@@ -0,0 +1,14 @@
+/// 鼠标控制接口
+///
+/// 平台相关的实现负责真正移动鼠标，扫描控制器只依赖该接口，
+/// 测试或无界面运行时可以替换为 [`NullSystemControl`](super::NullSystemControl)
+pub trait SystemControlApi {
+    /// 将鼠标移动到屏幕坐标 (`x`, `y`)
+    fn mouse_move_to(&mut self, x: i32, y: i32) -> anyhow::Result<()>;
+
+    /// 在当前位置点击鼠标左键
+    fn mouse_click(&mut self) -> anyhow::Result<()>;
+
+    /// 滚动鼠标滚轮 `amount` 格，`try_find` 表示正在逐行检测滚动量
+    fn mouse_scroll(&mut self, amount: i32, try_find: bool) -> anyhow::Result<()>;
+}
@@ -1,5 +1,7 @@
 use enigo::{Enigo, MouseButton, MouseControllable};
 
+use crate::system_control::SystemControlApi;
+
 pub struct WindowsSystemControl {
     enigo: Enigo,
 }
@@ -14,20 +16,22 @@ impl WindowsSystemControl {
     pub fn new() -> WindowsSystemControl {
         WindowsSystemControl { enigo: Enigo::new() }
     }
+}
 
-    pub fn mouse_move_to(&mut self, x: i32, y: i32) -> anyhow::Result<()> {
+impl SystemControlApi for WindowsSystemControl {
+    fn mouse_move_to(&mut self, x: i32, y: i32) -> anyhow::Result<()> {
         self.enigo.mouse_move_to(x, y);
 
         anyhow::Ok(())
     }
 
-    pub fn mouse_click(&mut self) -> anyhow::Result<()> {
+    fn mouse_click(&mut self) -> anyhow::Result<()> {
         self.enigo.mouse_click(MouseButton::Left);
 
         anyhow::Ok(())
     }
 
-    pub fn mouse_scroll(&mut self, amount: i32, _try_find: bool) -> anyhow::Result<()> {
+    fn mouse_scroll(&mut self, amount: i32, _try_find: bool) -> anyhow::Result<()> {
         self.enigo.mouse_scroll_y(amount);
 
         anyhow::Ok(())
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+use anyhow::Result;
 use rand::distributions::{Alphanumeric, Distribution, Uniform};
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
@@ -197,10 +200,29 @@ impl FuzzDataGenerator {
     }
 }
 
+/// 失败回调，参数为触发失败的输入和测试结果
+type FailureHook = Box<dyn FnMut(&str, &FuzzTestResult)>;
+
+/// 停止条件，每记录一个结果后以当前统计调用，返回 true 时停止本轮测试
+type StopPredicate = Box<dyn FnMut(&FuzzTestResults) -> bool>;
+
+/// 读取语料目录中的所有文件作为测试输入，按文件名排序
+pub fn load_corpus(dir: impl AsRef<Path>) -> Result<Vec<String>> {
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    paths.retain(|path| path.is_file());
+    paths.sort();
+
+    paths.iter().map(|path| Ok(fs::read_to_string(path)?)).collect()
+}
+
 /// 模糊测试执行器
 pub struct FuzzTester {
     generator: FuzzDataGenerator,
     results: FuzzTestResults,
+    on_failure: Option<FailureHook>,
+    stop_when: Option<StopPredicate>,
 }
 
 /// 模糊测试结果统计
@@ -213,6 +235,10 @@ pub struct FuzzTestResults {
     pub timeouts: usize,
     pub unique_failures: HashMap<String, usize>,
     pub execution_times: Vec<Duration>,
+    /// 未通过的输入及其结果，按出现顺序排列
+    pub failing_inputs: Vec<(String, FuzzTestResult)>,
+    /// [`FuzzTester::shrink`] 得到的最小失败输入
+    pub shrunk_failures: Vec<ShrunkFailure>,
 }
 
 impl FuzzTestResults {
@@ -270,12 +296,25 @@ impl FuzzTestResults {
             }
         }
 
+        if !self.shrunk_failures.is_empty() {
+            report.push_str("\n=== 最小失败输入 ===\n");
+            for failure in &self.shrunk_failures {
+                report.push_str(&format!(
+                    "{:?} ({} 字符 → {} 字符): {:?}\n",
+                    failure.minimal,
+                    failure.original.chars().count(),
+                    failure.minimal.chars().count(),
+                    failure.result
+                ));
+            }
+        }
+
         report
     }
 }
 
 /// 单个模糊测试结果
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum FuzzTestResult {
     Passed,
     Failed(String),
@@ -283,35 +322,97 @@ pub enum FuzzTestResult {
     Timeout,
 }
 
+/// 失败输入及其最小化结果
+#[derive(Debug, Clone)]
+pub struct ShrunkFailure {
+    pub original: String,
+    pub minimal: String,
+    /// 最小输入的测试结果
+    pub result: FuzzTestResult,
+}
+
 impl FuzzTester {
     /// 创建新的模糊测试器
     pub fn new(config: FuzzConfig) -> Self {
-        Self { generator: FuzzDataGenerator::new(config), results: FuzzTestResults::default() }
+        Self {
+            generator: FuzzDataGenerator::new(config),
+            results: FuzzTestResults::default(),
+            on_failure: None,
+            stop_when: None,
+        }
     }
 
-    /// 执行字符串解析模糊测试
-    pub fn fuzz_string_parsing<F>(&mut self, test_fn: F)
-    where
-        F: Fn(&str) -> Result<(), String> + std::panic::UnwindSafe + std::panic::RefUnwindSafe,
-    {
-        for _ in 0..self.generator.config.iterations {
-            let test_string = self.generator.generate_random_string();
-            let start_time = std::time::Instant::now();
+    /// 每个未通过的输入（失败、崩溃或超时）都会立即调用 `hook`，便于CI第一时间记录出错的输入
+    pub fn on_failure(&mut self, hook: impl FnMut(&str, &FuzzTestResult) + 'static) -> &mut Self {
+        self.on_failure = Some(Box::new(hook));
+        self
+    }
 
-            let result = std::panic::catch_unwind(|| test_fn(&test_string));
+    /// 之后的 `fuzz_*` 在 `predicate` 返回 true 时提前结束，而不是跑满配置的迭代次数
+    ///
+    /// 例如 `run_until(|results| results.crashes > 0)` 在第一次崩溃时停止
+    pub fn run_until(
+        &mut self,
+        predicate: impl FnMut(&FuzzTestResults) -> bool + 'static,
+    ) -> &mut Self {
+        self.stop_when = Some(Box::new(predicate));
+        self
+    }
 
-            let execution_time = start_time.elapsed();
+    /// 执行一次测试，捕获 panic 并计时
+    fn execute<T, F>(test_fn: &F, input: &str) -> (FuzzTestResult, Duration)
+    where
+        F: Fn(&str) -> Result<T, String> + std::panic::RefUnwindSafe,
+    {
+        let start_time = std::time::Instant::now();
+        let result = std::panic::catch_unwind(|| test_fn(input));
+        let execution_time = start_time.elapsed();
+
+        let test_result = match result {
+            Ok(Ok(_)) => FuzzTestResult::Passed,
+            Ok(Err(error)) => FuzzTestResult::Failed(error),
+            Err(_) => FuzzTestResult::Crashed,
+        };
+        (test_result, execution_time)
+    }
 
-            let test_result = match result {
-                Ok(Ok(())) => FuzzTestResult::Passed,
-                Ok(Err(error)) => FuzzTestResult::Failed(error),
-                Err(_) => FuzzTestResult::Crashed,
-            };
+    /// 记录一个结果，未通过时先调用失败回调；返回是否应当停止
+    fn record(&mut self, input: &str, result: FuzzTestResult, execution_time: Duration) -> bool {
+        if !matches!(result, FuzzTestResult::Passed) {
+            if let Some(hook) = self.on_failure.as_mut() {
+                hook(input, &result);
+            }
+            self.results.failing_inputs.push((input.to_string(), result.clone()));
+        }
+        self.results.add_result(result, execution_time);
+        self.stop_when.as_mut().is_some_and(|stop| stop(&self.results))
+    }
 
-            self.results.add_result(test_result, execution_time);
+    /// 按配置的迭代次数生成输入并测试，满足停止条件时提前结束
+    fn run_iterations<T, F>(
+        &mut self,
+        mut next_input: impl FnMut(&mut FuzzDataGenerator) -> String,
+        test_fn: F,
+    ) where
+        F: Fn(&str) -> Result<T, String> + std::panic::RefUnwindSafe,
+    {
+        for _ in 0..self.generator.config.iterations {
+            let test_string = next_input(&mut self.generator);
+            let (test_result, execution_time) = Self::execute(&test_fn, &test_string);
+            if self.record(&test_string, test_result, execution_time) {
+                break;
+            }
         }
     }
 
+    /// 执行字符串解析模糊测试
+    pub fn fuzz_string_parsing<F>(&mut self, test_fn: F)
+    where
+        F: Fn(&str) -> Result<(), String> + std::panic::UnwindSafe + std::panic::RefUnwindSafe,
+    {
+        self.run_iterations(FuzzDataGenerator::generate_random_string, test_fn);
+    }
+
     /// 执行属性解析模糊测试
     pub fn fuzz_attribute_parsing<F>(&mut self, test_fn: F)
     where
@@ -319,27 +420,16 @@ impl FuzzTester {
             + std::panic::UnwindSafe
             + std::panic::RefUnwindSafe,
     {
-        for _ in 0..self.generator.config.iterations {
-            let test_string = if self.generator.rng.gen_bool(0.7) {
-                self.generator.generate_artifact_stat_string()
-            } else {
-                self.generator.generate_corrupted_stat_string()
-            };
-
-            let start_time = std::time::Instant::now();
-
-            let result = std::panic::catch_unwind(|| test_fn(&test_string));
-
-            let execution_time = start_time.elapsed();
-
-            let test_result = match result {
-                Ok(Ok(_)) => FuzzTestResult::Passed,
-                Ok(Err(error)) => FuzzTestResult::Failed(error),
-                Err(_) => FuzzTestResult::Crashed,
-            };
-
-            self.results.add_result(test_result, execution_time);
-        }
+        self.run_iterations(
+            |generator| {
+                if generator.rng.gen_bool(0.7) {
+                    generator.generate_artifact_stat_string()
+                } else {
+                    generator.generate_corrupted_stat_string()
+                }
+            },
+            test_fn,
+        );
     }
 
     /// 执行等级解析模糊测试
@@ -347,27 +437,104 @@ impl FuzzTester {
     where
         F: Fn(&str) -> Result<i32, String> + std::panic::UnwindSafe + std::panic::RefUnwindSafe,
     {
-        for _ in 0..self.generator.config.iterations {
-            let test_string = if self.generator.rng.gen_bool(0.8) {
-                self.generator.generate_level_string()
-            } else {
-                self.generator.generate_corrupted_level_string()
-            };
+        self.run_iterations(
+            |generator| {
+                if generator.rng.gen_bool(0.8) {
+                    generator.generate_level_string()
+                } else {
+                    generator.generate_corrupted_level_string()
+                }
+            },
+            test_fn,
+        );
+    }
 
-            let start_time = std::time::Instant::now();
+    /// 将失败输入最小化（delta debugging）
+    ///
+    /// 反复删除输入中的字符块并重新执行 `test_fn`，只要结果仍是同一类未通过
+    /// （失败、崩溃或超时）就保留删除，直到删除任意单个字符都会让测试通过。
+    /// 最小输入连同结果记录在 [`FuzzTestResults::shrunk_failures`] 中；
+    /// `failing_input` 本身能通过测试时原样返回，不做记录
+    pub fn shrink<T, F>(&mut self, failing_input: &str, test_fn: F) -> String
+    where
+        F: Fn(&str) -> Result<T, String> + std::panic::RefUnwindSafe,
+    {
+        let (original_result, _) = Self::execute(&test_fn, failing_input);
+        if matches!(original_result, FuzzTestResult::Passed) {
+            return failing_input.to_string();
+        }
+        let kind = std::mem::discriminant(&original_result);
+
+        let mut chars: Vec<char> = failing_input.chars().collect();
+        let mut result = original_result;
+        let mut granularity = 2;
+        while !chars.is_empty() {
+            let chunk_len = (chars.len() + granularity - 1) / granularity;
+            let mut reduced = false;
+
+            let mut start = 0;
+            while start < chars.len() {
+                let end = (start + chunk_len).min(chars.len());
+                let candidate: String = chars[..start].iter().chain(&chars[end..]).collect();
+                let (candidate_result, _) = Self::execute(&test_fn, &candidate);
+                if std::mem::discriminant(&candidate_result) == kind {
+                    chars = candidate.chars().collect();
+                    result = candidate_result;
+                    granularity = (granularity - 1).max(2);
+                    reduced = true;
+                    break;
+                }
+                start = end;
+            }
 
-            let result = std::panic::catch_unwind(|| test_fn(&test_string));
+            if !reduced {
+                if granularity >= chars.len() {
+                    break;
+                }
+                granularity = (granularity * 2).min(chars.len());
+            }
+        }
 
-            let execution_time = start_time.elapsed();
+        let minimal: String = chars.into_iter().collect();
+        self.results.shrunk_failures.push(ShrunkFailure {
+            original: failing_input.to_string(),
+            minimal: minimal.clone(),
+            result,
+        });
+        minimal
+    }
 
-            let test_result = match result {
-                Ok(Ok(_)) => FuzzTestResult::Passed,
-                Ok(Err(error)) => FuzzTestResult::Failed(error),
-                Err(_) => FuzzTestResult::Crashed,
-            };
+    /// 依次测试语料中的输入并返回每个输入的结果
+    ///
+    /// 结果同样计入统计并触发失败回调，用于在每次运行时回归之前保存的失败输入
+    pub fn replay_corpus<T, F>(&mut self, inputs: &[String], test_fn: F) -> Vec<FuzzTestResult>
+    where
+        F: Fn(&str) -> Result<T, String> + std::panic::RefUnwindSafe,
+    {
+        inputs
+            .iter()
+            .map(|input| {
+                let (test_result, execution_time) = Self::execute(&test_fn, input);
+                self.record(input, test_result.clone(), execution_time);
+                test_result
+            })
+            .collect()
+    }
 
-            self.results.add_result(test_result, execution_time);
+    /// 将未通过的输入写入目录，每个输入一个文件 `failure_{序号}.txt`，返回写入的路径
+    ///
+    /// 文件内容即原始输入，可提交到仓库作为语料，之后用 [`load_corpus`] 读取并回放
+    pub fn save_failures(&self, dir: impl AsRef<Path>) -> Result<Vec<PathBuf>> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        let mut paths = Vec::with_capacity(self.results.failing_inputs.len());
+        for (i, (input, _)) in self.results.failing_inputs.iter().enumerate() {
+            let path = dir.join(format!("failure_{i:04}.txt"));
+            fs::write(&path, input)?;
+            paths.push(path);
         }
+        Ok(paths)
     }
 
     /// 获取测试结果
@@ -557,6 +724,106 @@ mod tests {
         assert!(has_chinese, "应该包含中文字符: {unicode_str}");
     }
 
+    #[test]
+    fn test_on_failure_hook_fires_for_failing_inputs() {
+        let config = FuzzConfig { iterations: 200, ..FuzzConfig::default() };
+        let mut tester = FuzzTester::new(config);
+        let failures = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let recorded = failures.clone();
+        tester.on_failure(move |input, result| {
+            recorded.borrow_mut().push((input.to_string(), result.clone()));
+        });
+
+        tester.fuzz_string_parsing(|input| {
+            if input.chars().count() > 50 {
+                Err("输入过长".to_string())
+            } else {
+                Ok(())
+            }
+        });
+
+        let results = tester.get_results();
+        let failures = failures.borrow();
+        assert!(results.failed_tests > 0);
+        assert_eq!(failures.len(), results.failed_tests);
+        for (input, result) in failures.iter() {
+            assert!(input.chars().count() > 50);
+            assert!(matches!(result, FuzzTestResult::Failed(error) if error == "输入过长"));
+        }
+    }
+
+    #[test]
+    fn test_run_until_stops_on_first_failure() {
+        let config = FuzzConfig { iterations: 200, ..FuzzConfig::default() };
+        let mut tester = FuzzTester::new(config);
+        tester.run_until(|results| results.failed_tests + results.crashes > 0);
+
+        tester.fuzz_level_parsing(|input| {
+            input.trim_start_matches('+').parse::<i32>().map_err(|e| e.to_string())
+        });
+
+        let results = tester.get_results();
+        assert_eq!(results.failed_tests, 1);
+        assert!(results.total_tests < 200);
+    }
+
+    #[test]
+    fn test_shrink_reduces_to_bad_substring() {
+        let mut tester = FuzzTester::new(FuzzConfig::default());
+        let test_fn = |input: &str| -> Result<(String, f64, bool), String> {
+            if input.contains("暴击率+NaN%") {
+                Err("数值无法解析".to_string())
+            } else {
+                Ok((input.to_string(), 0.0, false))
+            }
+        };
+
+        let minimal = tester.shrink("xQ9!暴击率+12.5%，暴击率+NaN%@@##测试", test_fn);
+
+        assert_eq!(minimal, "暴击率+NaN%");
+        let shrunk = &tester.get_results().shrunk_failures;
+        assert_eq!(shrunk.len(), 1);
+        assert_eq!(shrunk[0].minimal, "暴击率+NaN%");
+        assert!(
+            matches!(&shrunk[0].result, FuzzTestResult::Failed(error) if error == "数值无法解析")
+        );
+
+        assert_eq!(tester.shrink("攻击力+19", test_fn), "攻击力+19");
+        assert_eq!(tester.get_results().shrunk_failures.len(), 1);
+    }
+
+    #[test]
+    fn test_save_failures_and_replay_corpus() {
+        let test_fn = |input: &str| -> Result<i32, String> {
+            let digits = input.trim_start_matches("Lv.").trim_start_matches('+');
+            digits.parse::<i32>().map_err(|_| format!("无法解析等级: {input}"))
+        };
+        let dir = std::env::temp_dir().join(format!("furina_fuzz_corpus_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let mut tester = FuzzTester::new(FuzzConfig { iterations: 100, ..FuzzConfig::default() });
+        tester.fuzz_level_parsing(test_fn);
+        let failing_inputs = tester.get_results().failing_inputs.clone();
+        assert!(!failing_inputs.is_empty());
+
+        let paths = tester.save_failures(&dir).unwrap();
+        assert_eq!(paths.len(), failing_inputs.len());
+
+        let corpus = load_corpus(&dir).unwrap();
+        let expected_inputs: Vec<String> =
+            failing_inputs.iter().map(|(input, _)| input.clone()).collect();
+        assert_eq!(corpus, expected_inputs);
+
+        let mut replay = FuzzTester::new(FuzzConfig::default());
+        let verdicts = replay.replay_corpus(&corpus, test_fn);
+        let expected_verdicts: Vec<FuzzTestResult> =
+            failing_inputs.into_iter().map(|(_, result)| result).collect();
+        assert_eq!(verdicts, expected_verdicts);
+        assert_eq!(replay.get_results().total_tests, corpus.len());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
     // 示例：使用模糊测试宏
     fuzz_test!(fuzz_simple_parsing, 100, |input: &str| -> anyhow::Result<()> {
         // 测试简单的字符串解析是否会崩溃
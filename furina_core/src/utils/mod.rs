@@ -1,6 +1,6 @@
 use std::fmt::Arguments;
 use std::io::stdin;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::{fs, process, thread};
 
@@ -57,3 +57,18 @@ pub fn ensure_dir(path: &str) {
         fs::create_dir_all(path).unwrap();
     }
 }
+
+/// 获取用户配置目录 `<系统配置目录>/FurinaOCR`
+///
+/// Windows 下为 `%APPDATA%\FurinaOCR`，其他平台优先使用 `$XDG_CONFIG_HOME/FurinaOCR`，
+/// 其次为 `$HOME/.config/FurinaOCR`；无法确定时返回 `None`
+pub fn user_config_dir() -> Option<PathBuf> {
+    let base = if cfg!(windows) {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+    };
+    base.map(|dir| dir.join("FurinaOCR"))
+}
@@ -4,6 +4,18 @@ use std::collections::HashMap;
 use lazy_static::lazy_static;
 use regex::Regex;
 
+/// 圣遗物等级上限，等级解析结果超出 `0..=MAX_ARTIFACT_LEVEL` 时视为识别错误
+pub const MAX_ARTIFACT_LEVEL: i32 = 20;
+
+/// 检查等级是否在合法范围内
+pub fn check_level_range(level: i32) -> anyhow::Result<i32> {
+    if (0..=MAX_ARTIFACT_LEVEL).contains(&level) {
+        Ok(level)
+    } else {
+        Err(anyhow::anyhow!("等级 {} 超出范围 0-{}", level, MAX_ARTIFACT_LEVEL))
+    }
+}
+
 /// 字符串处理性能优化工具集
 ///
 /// 提供优化的字符串操作，减少不必要的内存分配和提高解析性能
@@ -62,6 +74,11 @@ impl StringOptimizer {
             .parse::<f64>()
             .map_err(|e| anyhow::anyhow!("无法解析数值 '{}': {}", clean_value_str, e))?;
 
+        // f64 的解析接受 inf、NaN 以及溢出的数值，这些不可能是真实属性值
+        if !value.is_finite() {
+            return Err(anyhow::anyhow!("属性值不是有限数值: '{}'", clean_value_str));
+        }
+
         if is_percentage {
             value /= 100.0;
         }
@@ -81,19 +98,20 @@ impl StringOptimizer {
         }
     }
 
-    /// 高效的等级解析
+    /// 高效的等级解析，超出 `0..=MAX_ARTIFACT_LEVEL` 的等级返回错误
     pub fn parse_level_fast(&self, input: &str) -> anyhow::Result<i32> {
         // 快速路径：直接尝试解析整个字符串
         if let Ok(level) = input.parse::<i32>() {
-            return Ok(level);
+            return check_level_range(level);
         }
 
         // 查找'+'符号
         if let Some(pos) = input.find('+') {
             let level_str = &input[pos + 1..];
-            level_str
+            let level = level_str
                 .parse::<i32>()
-                .map_err(|e| anyhow::anyhow!("无法解析等级 '{}': {}", level_str, e))
+                .map_err(|e| anyhow::anyhow!("无法解析等级 '{}': {}", level_str, e))?;
+            check_level_range(level)
         } else {
             Err(anyhow::anyhow!("等级格式无效: '{}'", input))
         }
@@ -215,6 +233,21 @@ mod tests {
         assert!(is_percentage);
     }
 
+    #[test]
+    fn test_parse_attribute_value_rejects_non_finite() {
+        let mut optimizer = StringOptimizer::new();
+
+        assert!(optimizer.parse_attribute_value("攻击力+inf").is_err());
+        assert!(optimizer.parse_attribute_value("攻击力+NaN").is_err());
+        assert!(optimizer.parse_attribute_value("攻击力+-infinity%").is_err());
+        assert!(optimizer.parse_attribute_value("攻击力+1e400").is_err());
+
+        let (name, value, is_percentage) = optimizer.parse_attribute_value("攻击力+311").unwrap();
+        assert_eq!(name, "攻击力");
+        assert_eq!(value, 311.0);
+        assert!(!is_percentage);
+    }
+
     #[test]
     fn test_string_optimizer_performance() {
         let mut optimizer = StringOptimizer::new();
@@ -283,6 +316,12 @@ mod tests {
 
         // 测试错误情况
         assert!(optimizer.parse_level_fast("invalid").is_err());
+
+        // 测试超出范围的等级
+        assert!(optimizer.parse_level_fast("+200").is_err());
+        assert!(optimizer.parse_level_fast("-1").is_err());
+        assert!(optimizer.parse_level_fast("+99999999999").is_err());
+        assert_eq!(optimizer.parse_level_fast("0").unwrap(), 0);
     }
 
     #[test]
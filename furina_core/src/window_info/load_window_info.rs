@@ -1,9 +1,13 @@
 use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 
+use anyhow::{bail, Context};
+use log::warn;
 use serde::{Deserialize, Serialize};
 
 use crate::game_info::{Platform, UI};
-use crate::positioning::Size;
+use crate::positioning::{Rect, Size};
 use crate::window_info::{WindowInfoRepository, WindowInfoType};
 
 /// Which is a format, where the whole file are recorded under a certain resolution
@@ -16,6 +20,31 @@ pub struct WindowInfoTemplatePerSize {
 }
 
 impl WindowInfoTemplatePerSize {
+    /// 解析并校验窗口信息 JSON，`source` 为文件名，出错时会包含在错误信息中
+    pub fn from_json(s: &str, source: &str) -> anyhow::Result<Self> {
+        let template: Self =
+            serde_json::from_str(s).with_context(|| format!("窗口信息文件 {source} 格式错误"))?;
+        template.validate().with_context(|| format!("窗口信息文件 {source} 中的区域无效"))?;
+        Ok(template)
+    }
+
+    /// 检查所有矩形区域：宽高不能为负，且不能超出 `current_resolution` 的窗口范围
+    ///
+    /// 手动编辑的窗口信息中写反或写错的区域会在加载时报错，而不是在扫描时截出错误的图像
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let width = self.current_resolution.width as f64;
+        let height = self.current_resolution.height as f64;
+
+        let mut names: Vec<&String> = self.data.keys().collect();
+        names.sort();
+        for name in names {
+            if let WindowInfoType::Rect(rect) = self.data[name] {
+                validate_rect(name, rect, width, height)?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn inject_into_window_info_repo(&self, repo: &mut WindowInfoRepository) {
         for (name, value) in self.data.iter() {
             repo.add(name, self.current_resolution, self.ui, self.platform, *value);
@@ -23,6 +52,66 @@ impl WindowInfoTemplatePerSize {
     }
 }
 
+fn validate_rect(name: &str, rect: Rect<f64>, width: f64, height: f64) -> anyhow::Result<()> {
+    // NaN 与任何数比较都为 false，同样视为无效
+    let non_negative = |v: f64| v >= 0.0;
+    if !non_negative(rect.width) || !non_negative(rect.height) {
+        bail!("{name}: 宽高不能为负 (width = {}, height = {})", rect.width, rect.height);
+    }
+    if !non_negative(rect.left) || !non_negative(rect.top) {
+        bail!("{name}: 左上角 ({}, {}) 超出窗口范围", rect.left, rect.top);
+    }
+    if rect.left + rect.width > width || rect.top + rect.height > height {
+        bail!(
+            "{name}: 右下角 ({}, {}) 超出 {width}x{height} 的窗口范围",
+            rect.left + rect.width,
+            rect.top + rect.height
+        );
+    }
+    Ok(())
+}
+
+impl WindowInfoRepository {
+    /// 从用户目录加载 `*.json` 窗口信息文件，并合并到当前仓库中
+    ///
+    /// 合并优先级：对于相同的键、分辨率、UI 和平台，用户文件覆盖已有（内置）的数据；
+    /// 多个用户文件之间按文件名顺序加载，后加载的文件优先。
+    /// 目录不存在时返回空列表，无法解析或区域无效的文件会被跳过并输出警告。
+    ///
+    /// 返回成功加载的文件路径
+    pub fn load_user_overrides(&mut self, dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+        if !dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| {
+                path.is_file()
+                    && path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+            })
+            .collect();
+        paths.sort();
+
+        let mut loaded = Vec::new();
+        for path in paths {
+            let source = path.display().to_string();
+            let template = fs::read_to_string(&path)
+                .with_context(|| format!("无法读取窗口信息文件 {source}"))
+                .and_then(|s| WindowInfoTemplatePerSize::from_json(&s, &source));
+            match template {
+                Ok(template) => {
+                    template.inject_into_window_info_repo(self);
+                    loaded.push(path);
+                },
+                Err(e) => warn!("已跳过窗口信息文件: {e:#}"),
+            }
+        }
+
+        Ok(loaded)
+    }
+}
+
 #[macro_export]
 macro_rules! load_window_info_repo {
     ($($filename:literal),+ $(,)?) => {
@@ -39,3 +128,105 @@ macro_rules! load_window_info_repo {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("furina_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn template(rect: Rect<f64>) -> WindowInfoTemplatePerSize {
+        let mut data = HashMap::new();
+        data.insert(String::from("title_rect"), WindowInfoType::Rect(rect));
+        WindowInfoTemplatePerSize {
+            current_resolution: Size::new(1920, 1080),
+            platform: Platform::Windows,
+            ui: UI::Desktop,
+            data,
+        }
+    }
+
+    #[test]
+    fn test_user_override_takes_precedence() {
+        let mut repo = WindowInfoRepository::new();
+        template(Rect::new(1.0, 1.0, 1.0, 1.0)).inject_into_window_info_repo(&mut repo);
+
+        let dir = temp_dir("window_info_override");
+        let user = template(Rect::new(2.0, 2.0, 2.0, 2.0));
+        fs::write(dir.join("override.json"), serde_json::to_string(&user).unwrap()).unwrap();
+        fs::write(dir.join("broken.json"), "{ not json").unwrap();
+        fs::write(dir.join("readme.txt"), "ignored").unwrap();
+
+        let loaded = repo.load_user_overrides(&dir).unwrap();
+        assert_eq!(loaded, vec![dir.join("override.json")]);
+
+        let rect: Rect<f64> = repo
+            .get_exact("title_rect", Size::new(1920, 1080), UI::Desktop, Platform::Windows)
+            .unwrap();
+        assert_eq!(rect.left, 2.0);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_valid_rect() {
+        let json = serde_json::to_string(&template(Rect::new(10.0, 20.0, 300.0, 40.5))).unwrap();
+        assert!(WindowInfoTemplatePerSize::from_json(&json, "override.json").is_ok());
+
+        // 恰好贴着窗口右下角的区域同样有效
+        let full = template(Rect::new(0.0, 0.0, 1920.0, 1080.0));
+        assert!(full.validate().is_ok());
+    }
+
+    #[test]
+    fn test_negative_width_rect() {
+        let json = serde_json::to_string(&template(Rect::new(10.0, 20.0, -300.0, 40.0))).unwrap();
+
+        let error = WindowInfoTemplatePerSize::from_json(&json, "override.json")
+            .err()
+            .expect("negative width must be rejected");
+        let message = format!("{error:#}");
+        assert!(message.contains("override.json"), "{message}");
+        assert!(message.contains("title_rect"), "{message}");
+        assert!(message.contains("width = -300"), "{message}");
+    }
+
+    #[test]
+    fn test_out_of_window_rect() {
+        let error = template(Rect::new(1800.0, 20.0, 200.0, 40.0)).validate().unwrap_err();
+        assert!(error.to_string().contains("title_rect: 右下角 (2000, 60)"), "{error}");
+
+        let error = template(Rect::new(-1.0, 20.0, 200.0, 40.0)).validate().unwrap_err();
+        assert!(error.to_string().contains("左上角 (-1, 20)"), "{error}");
+    }
+
+    #[test]
+    fn test_invalid_override_is_skipped() {
+        let mut repo = WindowInfoRepository::new();
+        template(Rect::new(1.0, 1.0, 1.0, 1.0)).inject_into_window_info_repo(&mut repo);
+
+        let dir = temp_dir("window_info_invalid_override");
+        let user = template(Rect::new(2.0, 2.0, -2.0, 2.0));
+        fs::write(dir.join("override.json"), serde_json::to_string(&user).unwrap()).unwrap();
+
+        assert!(repo.load_user_overrides(&dir).unwrap().is_empty());
+        let rect: Rect<f64> = repo
+            .get_exact("title_rect", Size::new(1920, 1080), UI::Desktop, Platform::Windows)
+            .unwrap();
+        assert_eq!(rect.left, 1.0);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_dir_loads_nothing() {
+        let mut repo = WindowInfoRepository::new();
+        let dir = std::env::temp_dir().join("furina_window_info_does_not_exist");
+        assert!(repo.load_user_overrides(&dir).unwrap().is_empty());
+    }
+}
@@ -1,10 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 
 use crate::game_info::{Platform, UI};
 use crate::positioning::{Pos, Scalable, Size};
-use crate::window_info::WindowInfoType;
+use crate::window_info::{FromWindowInfoRepository, WindowInfoType};
 
 /// Maps a window-info-key to a list of entries
 /// where entries consist of a size where the value is recorded, and accordingly a value
@@ -90,7 +90,8 @@ impl WindowInfoRepository {
     }
 
     /// Get window info by name and size
-    /// if window size does not exists exactly, this function will search for the same resolution family and scale the result
+    /// if window size does not exists exactly, this function will search for the nearest size with the same
+    /// aspect ratio (see [`Self::nearest_template`]) and scale the result
     pub fn get_auto_scale<T>(
         &self,
         name: &str,
@@ -101,24 +102,298 @@ impl WindowInfoRepository {
     where
         WindowInfoType: TryInto<T>,
     {
-        if self.data.contains_key(name) {
-            if self.data[name].contains_key(&(window_size, ui, platform)) {
-                return self.data[name][&(window_size, ui, platform)].try_into().ok();
-            } else {
-                // 查找可以缩放的分辨率，优先选择最接近的尺寸以减少误差
-                for (k, value) in self.data[name].iter() {
-                    let size = &k.0;
-                    if size.width * window_size.height == size.height * window_size.width
-                        && k.1 == ui
-                        && k.2 == platform
-                    {
-                        let factor: f64 = window_size.width as f64 / size.width as f64;
-                        return value.scale(factor).try_into().ok();
+        let entries = self.data.get(name)?;
+        if let Some(value) = entries.get(&(window_size, ui, platform)) {
+            return (*value).try_into().ok();
+        }
+
+        // 查找可以缩放的分辨率，优先选择最接近的尺寸以减少误差
+        let sizes = entries.keys().filter(|k| k.1 == ui && k.2 == platform).map(|k| k.0);
+        let (size, factor) = nearest_same_aspect(sizes, window_size)?;
+        entries[&(size, ui, platform)].scale(factor).try_into().ok()
+    }
+
+    /// 已加载窗口信息的所有分辨率，按宽、高从大到小排列，不区分界面和平台
+    pub fn supported_sizes(&self) -> Vec<Size<usize>> {
+        let mut sizes: Vec<Size<usize>> =
+            self.data.values().flat_map(|entries| entries.keys()).map(|k| k.0).collect();
+        sizes.sort_by_key(|s| std::cmp::Reverse((s.width, s.height)));
+        sizes.dedup();
+        sizes
+    }
+
+    /// 对每个已记录的分辨率（及界面、平台）分别构造一次 `T`，返回各分辨率能否解析出 `T` 的全部字段
+    ///
+    /// 每个分辨率只使用该分辨率自己的条目，不会从其他分辨率缩放补齐，
+    /// 因此某个配置文件缺少字段或字段类型错误时会在对应的分辨率上报告错误。
+    /// 结果按宽、高从大到小排列
+    pub fn validate_all<T: FromWindowInfoRepository>(
+        &self,
+    ) -> Vec<(Size<usize>, Result<(), String>)> {
+        let keys: HashSet<(Size<usize>, UI, Platform)> =
+            self.data.values().flat_map(|entries| entries.keys().copied()).collect();
+        let mut keys: Vec<_> = keys.into_iter().collect();
+        keys.sort_by_key(|k| std::cmp::Reverse((k.0.width, k.0.height)));
+
+        keys.into_iter()
+            .map(|(size, ui, platform)| {
+                let mut single = WindowInfoRepository::new();
+                for (name, entries) in self.data.iter() {
+                    if let Some(value) = entries.get(&(size, ui, platform)) {
+                        single.add(name, size, ui, platform, *value);
                     }
                 }
-            }
+                let result = T::from_window_info_repository(size, ui, platform, &single)
+                    .map(|_| ())
+                    .map_err(|e| format!("{e:#}"));
+                (size, result)
+            })
+            .collect()
+    }
+
+    /// 窗口尺寸与所有模板都不完全一致时返回提示信息，一致时返回 `None`
+    ///
+    /// 尺寸只差几个像素通常是窗口带边框或标题栏造成的，此时缩放后的裁剪位置会略有偏差
+    pub fn size_mismatch_warning(
+        &self,
+        window_size: Size<usize>,
+        ui: UI,
+        platform: Platform,
+    ) -> Option<String> {
+        match self.nearest_template(window_size, ui, platform) {
+            Some((size, _)) if size == window_size => None,
+            Some((size, factor)) => Some(format!(
+                "窗口尺寸 {}×{} 与支持的分辨率不完全一致，将从 {}×{} 缩放 {factor:.3} 倍使用，识别区域可能略有偏差；\
+                 建议将游戏设置为无边框窗口或全屏（支持的分辨率: {}）",
+                window_size.width,
+                window_size.height,
+                size.width,
+                size.height,
+                self.supported_sizes_text()
+            )),
+            None => Some(format!(
+                "未找到与窗口尺寸 {}×{} 宽高比一致的窗口信息，建议将游戏设置为无边框窗口或全屏（支持的分辨率: {}）",
+                window_size.width,
+                window_size.height,
+                self.supported_sizes_text()
+            )),
         }
+    }
 
-        None
+    /// 以 `2560×1440, 1920×1080` 的形式列出所有已加载的分辨率，用于提示信息
+    pub fn supported_sizes_text(&self) -> String {
+        self.supported_sizes()
+            .iter()
+            .map(|size| format!("{}×{}", size.width, size.height))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// 在所有已记录的分辨率中查找与 `window_size` 最接近的模板分辨率
+    ///
+    /// 首先要求宽高比完全一致（例如 16:9 只会匹配 16:9），然后按像素距离选择最接近的尺寸；
+    /// 没有宽高比完全一致的尺寸时，接受宽高比相差 1% 以内的尺寸（如带边框的 1601×901）。
+    /// 返回模板分辨率以及从模板缩放到 `window_size` 所需的缩放系数
+    pub fn nearest_template(
+        &self,
+        window_size: Size<usize>,
+        ui: UI,
+        platform: Platform,
+    ) -> Option<(Size<usize>, f64)> {
+        let sizes = self
+            .data
+            .values()
+            .flat_map(|entries| entries.keys())
+            .filter(|k| k.1 == ui && k.2 == platform)
+            .map(|k| k.0);
+        nearest_same_aspect(sizes, window_size)
+    }
+}
+
+/// 宽高比的相对误差不超过该值时视为一致，窗口带边框等导致尺寸相差几个像素时仍能匹配模板
+const ASPECT_RATIO_TOLERANCE: f64 = 0.01;
+
+fn nearest_same_aspect(
+    sizes: impl Iterator<Item = Size<usize>>,
+    window_size: Size<usize>,
+) -> Option<(Size<usize>, f64)> {
+    let distance = |size: &Size<usize>| {
+        let dw = size.width.abs_diff(window_size.width);
+        let dh = size.height.abs_diff(window_size.height);
+        dw * dw + dh * dh
+    };
+    let aspect = |size: &Size<usize>| size.width as f64 / size.height as f64;
+
+    let sizes: Vec<Size<usize>> = sizes.filter(|size| size.width > 0 && size.height > 0).collect();
+    let nearest = |candidates: Vec<&Size<usize>>| {
+        candidates.into_iter().min_by_key(|size| (distance(size), size.width)).copied()
+    };
+
+    // 优先选择宽高比完全一致的尺寸，没有时再放宽到误差范围内
+    let exact = nearest(
+        sizes
+            .iter()
+            .filter(|size| size.width * window_size.height == size.height * window_size.width)
+            .collect(),
+    );
+    let size = exact.or_else(|| {
+        if window_size.height == 0 {
+            return None;
+        }
+        let target = aspect(&window_size);
+        nearest(
+            sizes
+                .iter()
+                .filter(|size| (aspect(size) - target).abs() / target <= ASPECT_RATIO_TOLERANCE)
+                .collect(),
+        )
+    })?;
+    Some((size, window_size.width as f64 / size.width as f64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::positioning::Rect;
+
+    fn repo_with(sizes: &[(usize, usize)]) -> WindowInfoRepository {
+        let mut repo = WindowInfoRepository::new();
+        for &(width, height) in sizes {
+            repo.add(
+                "title_rect",
+                Size::new(width, height),
+                UI::Desktop,
+                Platform::Windows,
+                WindowInfoType::Rect(Rect::new(
+                    width as f64 / 10.0,
+                    height as f64 / 10.0,
+                    100.0,
+                    50.0,
+                )),
+            );
+        }
+        repo
+    }
+
+    struct TitleOnly;
+
+    impl FromWindowInfoRepository for TitleOnly {
+        fn from_window_info_repository(
+            window_size: Size<usize>,
+            ui: UI,
+            platform: Platform,
+            repo: &WindowInfoRepository,
+        ) -> anyhow::Result<Self> {
+            let _: Rect<f64> = repo
+                .get_auto_scale("title_rect", window_size, ui, platform)
+                .ok_or_else(|| anyhow::anyhow!("cannot find window info key \"title_rect\""))?;
+            Ok(TitleOnly)
+        }
+    }
+
+    #[test]
+    fn test_validate_all_reports_each_size() {
+        let mut repo = repo_with(&[(1600, 900), (1920, 1080)]);
+        // 2560×1440 只有其他字段，虽然可以从其他分辨率缩放，仍应报告缺少字段
+        repo.add_pos(
+            "star_pos",
+            Size::new(2560, 1440),
+            UI::Desktop,
+            Platform::Windows,
+            Pos::new(1.0, 2.0),
+        );
+        // 字段类型错误
+        repo.add_pos(
+            "title_rect",
+            Size::new(1280, 720),
+            UI::Desktop,
+            Platform::Windows,
+            Pos::new(1.0, 2.0),
+        );
+
+        let results = repo.validate_all::<TitleOnly>();
+        let sizes: Vec<Size<usize>> = results.iter().map(|(size, _)| *size).collect();
+        assert_eq!(
+            sizes,
+            vec![
+                Size::new(2560, 1440),
+                Size::new(1920, 1080),
+                Size::new(1600, 900),
+                Size::new(1280, 720)
+            ]
+        );
+        assert!(results[0].1.as_ref().unwrap_err().contains("title_rect"));
+        assert!(results[1].1.is_ok());
+        assert!(results[2].1.is_ok());
+        assert!(results[3].1.is_err());
+        assert!(WindowInfoRepository::new().validate_all::<TitleOnly>().is_empty());
+    }
+
+    #[test]
+    fn test_supported_sizes() {
+        let repo = repo_with(&[(1600, 900), (2560, 1440), (1920, 1080), (1600, 900)]);
+        assert_eq!(
+            repo.supported_sizes(),
+            vec![Size::new(2560, 1440), Size::new(1920, 1080), Size::new(1600, 900)]
+        );
+        assert_eq!(repo.supported_sizes_text(), "2560×1440, 1920×1080, 1600×900");
+        assert!(WindowInfoRepository::new().supported_sizes().is_empty());
+    }
+
+    #[test]
+    fn test_nearest_template_matches_aspect_ratio_first() {
+        // 2560×1600 像素距离更近，但宽高比为 16:10，不应被选中
+        let repo = repo_with(&[(1600, 900), (2560, 1600)]);
+        let (size, factor) =
+            repo.nearest_template(Size::new(2560, 1440), UI::Desktop, Platform::Windows).unwrap();
+        assert_eq!(size, Size::new(1600, 900));
+        assert!((factor - 1.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_nearest_template_prefers_closest_size() {
+        let repo = repo_with(&[(1600, 900), (1920, 1080)]);
+        let (size, _) =
+            repo.nearest_template(Size::new(2560, 1440), UI::Desktop, Platform::Windows).unwrap();
+        assert_eq!(size, Size::new(1920, 1080));
+
+        assert!(repo
+            .nearest_template(Size::new(1280, 1024), UI::Desktop, Platform::Windows)
+            .is_none());
+    }
+
+    #[test]
+    fn test_off_by_one_size_uses_nearest_template() {
+        let repo = repo_with(&[(1600, 900), (1920, 1080)]);
+        let (size, factor) =
+            repo.nearest_template(Size::new(1601, 901), UI::Desktop, Platform::Windows).unwrap();
+        assert_eq!(size, Size::new(1600, 900));
+        assert!((factor - 1601.0 / 1600.0).abs() < 1e-9);
+
+        let rect: Option<Rect<f64>> =
+            repo.get_auto_scale("title_rect", Size::new(1601, 901), UI::Desktop, Platform::Windows);
+        assert!(rect.is_some());
+
+        let warning = repo
+            .size_mismatch_warning(Size::new(1601, 901), UI::Desktop, Platform::Windows)
+            .unwrap();
+        assert!(warning.contains("1600×900"));
+        assert!(warning.contains("无边框"));
+        assert_eq!(
+            repo.size_mismatch_warning(Size::new(1600, 900), UI::Desktop, Platform::Windows),
+            None
+        );
+    }
+
+    #[test]
+    fn test_auto_scale_2560x1440_from_1600x900() {
+        let repo = repo_with(&[(1600, 900), (2560, 1600)]);
+        let rect: Rect<f64> = repo
+            .get_auto_scale("title_rect", Size::new(2560, 1440), UI::Desktop, Platform::Windows)
+            .unwrap();
+        assert!((rect.left - 256.0).abs() < 1e-9);
+        assert!((rect.top - 144.0).abs() < 1e-9);
+        assert!((rect.width - 160.0).abs() < 1e-9);
+        assert!((rect.height - 80.0).abs() < 1e-9);
     }
 }
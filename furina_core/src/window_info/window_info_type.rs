@@ -1,6 +1,7 @@
 use anyhow::anyhow;
 use serde::{Deserialize, Serialize};
 
+use crate::common::color::Color;
 use crate::positioning::{Pos, Rect, Scalable, Size};
 
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
@@ -12,6 +13,8 @@ pub enum WindowInfoType {
     /// when window size scales, these amount will not scale
     InvariantInt(i32),
     InvariantFloat(f64),
+    /// colors will not scale either
+    Color(Color),
 }
 
 // due to orphan rule, we implement TryInto instead of TryFrom
@@ -71,6 +74,17 @@ impl TryInto<Size<f64>> for WindowInfoType {
     }
 }
 
+impl TryInto<Color> for WindowInfoType {
+    type Error = anyhow::Error;
+
+    fn try_into(self) -> Result<Color, Self::Error> {
+        match self {
+            WindowInfoType::Color(color) => Ok(color),
+            _ => Err(anyhow!(String::from("not a color type"))),
+        }
+    }
+}
+
 impl Scalable for WindowInfoType {
     fn scale(&self, factor: f64) -> Self {
         match *self {
@@ -80,6 +94,7 @@ impl Scalable for WindowInfoType {
             WindowInfoType::Float(v) => WindowInfoType::Float(v.scale(factor)),
             WindowInfoType::InvariantInt(v) => WindowInfoType::InvariantInt(v),
             WindowInfoType::InvariantFloat(v) => WindowInfoType::InvariantFloat(v),
+            WindowInfoType::Color(c) => WindowInfoType::Color(c),
         }
     }
 }
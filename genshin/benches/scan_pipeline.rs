@@ -0,0 +1,118 @@
+//! 裁剪与识别流水线的基准测试
+//!
+//! 使用 1600×900 的窗口配置和模拟OCR模型，不需要 GPU 或游戏客户端：
+//!
+//! ```text
+//! cargo bench -p genshin --bench scan_pipeline -- --save-baseline main
+//! cargo bench -p genshin --bench scan_pipeline -- --baseline main
+//! ```
+//!
+//! 预期基线（release，桌面级 CPU，仅供参考）：
+//! - `crop_optimized/9_fields`: 约 50–150 µs，耗时与字段区域的总像素数成正比
+//! - `scan_item_image_optimized/stub_model`: 约 0.1–0.5 ms，主要为裁剪和祝圣之霜检测，
+//!   模拟模型本身几乎不耗时
+//!
+//! 相对保存的基线变慢超过 20% 时应排查 `crop_optimized` 或批量识别路径的改动
+
+use std::hint::black_box;
+use std::time::Duration;
+
+use anyhow::Result;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use furina_core::game_info::{Platform, UI};
+use furina_core::ocr::ImageToText;
+use furina_core::positioning::{Rect, Size};
+use furina_core::window_info::{
+    FromWindowInfoRepository, WindowInfoRepository, WindowInfoTemplatePerSize,
+};
+use genshin::scanner::{
+    ArtifactScannerWindowInfo, ArtifactScannerWorker, GenshinArtifactScannerConfig,
+    OptimizedImageProcessor, SendItem,
+};
+use image::{Rgb, RgbImage};
+
+/// 返回固定文本的模拟模型，只衡量流水线本身的开销
+struct StubModel;
+
+impl ImageToText<RgbImage> for StubModel {
+    fn image_to_text(&self, _image: &RgbImage, _is_preprocessed: bool) -> Result<String> {
+        Ok("暴击率+3.9%".to_string())
+    }
+
+    fn get_average_inference_time(&self) -> Option<Duration> {
+        None
+    }
+}
+
+fn window_info_1600x900() -> ArtifactScannerWindowInfo {
+    let mut repo = WindowInfoRepository::new();
+    let template: WindowInfoTemplatePerSize =
+        serde_json::from_str(include_str!("../window_info/windows1600x900.json")).unwrap();
+    template.inject_into_window_info_repo(&mut repo);
+    ArtifactScannerWindowInfo::from_window_info_repository(
+        Size::new(1600, 900),
+        UI::Desktop,
+        Platform::Windows,
+        &repo,
+    )
+    .unwrap()
+}
+
+fn field_rects(info: &ArtifactScannerWindowInfo) -> [Rect<f64>; 9] {
+    [
+        info.title_rect,
+        info.main_stat_name_rect,
+        info.main_stat_value_rect,
+        info.level_rect,
+        info.item_equip_rect,
+        info.sub_stat_1,
+        info.sub_stat_2,
+        info.sub_stat_3,
+        info.sub_stat_4,
+    ]
+}
+
+fn noise_image(width: u32, height: u32) -> RgbImage {
+    RgbImage::from_fn(width, height, |x, y| {
+        Rgb([(x % 251) as u8, (y % 241) as u8, ((x * 7 + y * 3) % 127) as u8])
+    })
+}
+
+fn bench_crop(c: &mut Criterion) {
+    let info = window_info_1600x900();
+    let rects = field_rects(&info);
+    let image = noise_image(1600, 900);
+
+    c.bench_function("crop_optimized/9_fields", |b| {
+        b.iter(|| {
+            for rect in rects.iter() {
+                black_box(OptimizedImageProcessor::crop_optimized(&image, black_box(rect)));
+            }
+        })
+    });
+}
+
+fn bench_scan_item(c: &mut Criterion) {
+    let info = window_info_1600x900();
+    let panel = noise_image(info.panel_rect.width as u32, info.panel_rect.height as u32);
+    // 不启用超时，避免每个字段额外创建线程的开销掩盖流水线本身的变化
+    let config = GenshinArtifactScannerConfig { ocr_timeout_ms: 0, ..Default::default() };
+    let mut worker =
+        ArtifactScannerWorker::with_model(Box::new(StubModel), info, config, (1600, 900));
+
+    c.bench_function("scan_item_image_optimized/stub_model", |b| {
+        b.iter_batched(
+            || SendItem {
+                panel_image: Some(panel.clone()),
+                star: 5,
+                list_image: None,
+                position: None,
+            },
+            |item| black_box(worker.scan_item_image_optimized(item, false).unwrap()),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(benches, bench_crop, bench_scan_item);
+criterion_main!(benches);
@@ -1,16 +1,61 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
 use anyhow::Result;
-use clap::{command, ArgMatches, Args};
+use clap::{command, ArgMatches, Args, FromArgMatches};
+use furina_core::capture::{CaptureError, Capturer, GenericCapturer};
 use furina_core::export::{AssetEmitter, ExportAssets};
-use furina_core::game_info::{GameInfo, GameInfoBuilder};
-use furina_core::window_info::{WindowInfoRepository, WindowInfoTemplatePerSize};
+use furina_core::game_info::{GameInfo, GameInfoBuilder, GameWindowError, Platform, UI};
+use furina_core::positioning::{Rect, Size};
+use furina_core::window_info::{
+    FromWindowInfoRepository, WindowInfoRepository, WindowInfoTemplatePerSize,
+};
+use image::{Rgb, RgbImage};
 use log::{error, info, warn};
 
-use crate::artifact::GenshinArtifact;
-use crate::export::artifact::{ExportArtifactConfig, GenshinArtifactExporter};
+use crate::application::{
+    GameModeConfig, InteractionConfig, LoggerConfig, SelfTestConfig, SelfTestReport,
+};
+use crate::artifact::{
+    group_by_equip, is_complete_set, ArtifactConversionError, EquipGroup, GenshinArtifact,
+};
+use crate::character::is_recommended_main_stat;
+use crate::export::artifact::{
+    ConversionFailure, ExportArtifactConfig, ExportedArtifactSet, FailureDumpWriter,
+    GenshinArtifactExportFormat, GenshinArtifactExporter, DEFAULT_FAILURE_DUMP_LIMIT,
+};
+use crate::locale::Locale;
 use crate::scanner::{
-    get_error_suggestion, ArtifactScanError, GenshinArtifactScanner, GenshinArtifactScannerConfig,
+    get_error_suggestion, write_metrics_file, ArtifactScanError, ArtifactScannerWindowInfo,
+    GenshinArtifactScanResult, GenshinArtifactScanner, GenshinArtifactScannerConfig,
+    HEALTHY_SCAN_SCORE,
+};
+use crate::scanner_controller::repository_layout::{
+    GenshinRepositoryScanControllerWindowInfo, GenshinRepositoryScannerLogicConfig,
 };
-use crate::scanner_controller::repository_layout::GenshinRepositoryScannerLogicConfig;
+
+/// 扫描需要的全部窗口信息，用于按分辨率检查配置是否完整
+struct ScanWindowInfo;
+
+impl FromWindowInfoRepository for ScanWindowInfo {
+    fn from_window_info_repository(
+        window_size: Size<usize>,
+        ui: UI,
+        platform: Platform,
+        repo: &WindowInfoRepository,
+    ) -> Result<Self> {
+        ArtifactScannerWindowInfo::from_window_info_repository(window_size, ui, platform, repo)?;
+        GenshinRepositoryScanControllerWindowInfo::from_window_info_repository(
+            window_size,
+            ui,
+            platform,
+            repo,
+        )?;
+        Ok(ScanWindowInfo)
+    }
+}
 
 pub struct ArtifactScannerApplication {
     arg_matches: ArgMatches,
@@ -26,34 +71,380 @@ impl ArtifactScannerApplication {
         cmd = <ExportArtifactConfig as Args>::augment_args_for_update(cmd);
         cmd = <GenshinArtifactScannerConfig as Args>::augment_args_for_update(cmd);
         cmd = <GenshinRepositoryScannerLogicConfig as Args>::augment_args_for_update(cmd);
+        cmd = <LoggerConfig as Args>::augment_args_for_update(cmd);
+        cmd = <SelfTestConfig as Args>::augment_args_for_update(cmd);
+        cmd = <InteractionConfig as Args>::augment_args_for_update(cmd);
+        cmd = <GameModeConfig as Args>::augment_args_for_update(cmd);
         cmd
     }
 
-    fn get_window_info_repository() -> WindowInfoRepository {
+    /// 内置的窗口信息，不包含用户目录中的覆盖配置
+    pub fn builtin_window_info_repository() -> WindowInfoRepository {
         let mut repo = WindowInfoRepository::new();
 
         // 仅加载支持的3种分辨率配置文件
         let configs = [
-            include_str!("../../window_info/windows2560x1440.json"),
-            include_str!("../../window_info/windows1920x1080.json"),
-            include_str!("../../window_info/windows1600x900.json"),
+            ("windows2560x1440.json", include_str!("../../window_info/windows2560x1440.json")),
+            ("windows1920x1080.json", include_str!("../../window_info/windows1920x1080.json")),
+            ("windows1600x900.json", include_str!("../../window_info/windows1600x900.json")),
         ];
 
-        for content in &configs {
-            let template: WindowInfoTemplatePerSize =
-                serde_json::from_str(content).expect("配置文件格式错误");
+        for (name, content) in configs {
+            let template = WindowInfoTemplatePerSize::from_json(content, name)
+                .unwrap_or_else(|e| panic!("配置文件格式错误: {e:#}"));
             template.inject_into_window_info_repo(&mut repo);
         }
 
         repo
     }
 
+    /// 检查窗口信息中的每个分辨率能否单独解析出扫描所需的全部字段，见 [`WindowInfoRepository::validate_all`]
+    pub fn validate_window_info(
+        repo: &WindowInfoRepository,
+    ) -> Vec<(Size<usize>, std::result::Result<(), String>)> {
+        repo.validate_all::<ScanWindowInfo>()
+    }
+
+    /// 加载内置窗口信息以及用户目录中的覆盖配置
+    pub fn get_window_info_repository() -> WindowInfoRepository {
+        let mut repo = Self::builtin_window_info_repository();
+
+        // 用户目录中的窗口信息会覆盖内置的同分辨率配置
+        if let Some(dir) = furina_core::utils::user_config_dir().map(|d| d.join("window_info")) {
+            match repo.load_user_overrides(&dir) {
+                Ok(loaded) if loaded.is_empty() => {},
+                Ok(loaded) => {
+                    for path in loaded {
+                        info!("已加载用户窗口信息: {}", path.display());
+                    }
+                },
+                Err(e) => warn!("读取用户窗口信息目录 {} 失败: {e}", dir.display()),
+            }
+        }
+
+        repo
+    }
+
     // fn init() {
     //     env_logger::Builder::new()
     //         .filter_level(log::LevelFilter::Info)
     //         .init();
     // }
 
+    /// 按套装和部位输出圣遗物数量统计
+    fn log_artifact_counts(artifacts: &[GenshinArtifact]) {
+        let mut set_counts: HashMap<String, usize> = HashMap::new();
+        let mut slot_counts: HashMap<&'static str, usize> = HashMap::new();
+        for artifact in artifacts {
+            *set_counts.entry(artifact.set_name.to_string()).or_insert(0) += 1;
+            *slot_counts.entry(artifact.slot.to_zh_cn()).or_insert(0) += 1;
+        }
+
+        let mut set_counts: Vec<_> = set_counts.into_iter().collect();
+        set_counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        info!("套装统计:");
+        for (set_name, count) in &set_counts {
+            info!("- {set_name}: {count} 件");
+        }
+
+        let mut slot_counts: Vec<_> = slot_counts.into_iter().collect();
+        slot_counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        info!("部位统计:");
+        for (slot, count) in &slot_counts {
+            info!("- {slot}: {count} 件");
+        }
+    }
+
+    /// 按装备角色输出每个角色身上的圣遗物，便于核对配装
+    fn log_equip_groups(artifacts: &[GenshinArtifact]) {
+        info!("按角色分组:");
+        for (group, pieces) in group_by_equip(artifacts) {
+            let title = match &group {
+                EquipGroup::Character(name) => format!("{name} ({}/5)", pieces.len()),
+                EquipGroup::Unknown(name) => format!("{name}（未知角色） ({}/5)", pieces.len()),
+                EquipGroup::Unequipped => format!("未装备: {} 件", pieces.len()),
+            };
+            info!("- {title}");
+            if group == EquipGroup::Unequipped {
+                continue;
+            }
+            for artifact in &pieces {
+                let recommended = match &group {
+                    EquipGroup::Character(name) => {
+                        is_recommended_main_stat(name, &artifact.slot, &artifact.main_stat.name)
+                    },
+                    _ => None,
+                };
+                let note = match recommended {
+                    Some(true) => "（推荐）",
+                    Some(false) => "（非推荐主属性）",
+                    None => "",
+                };
+                info!(
+                    "    {} {} +{} 主属性: {}{note}",
+                    artifact.slot.to_zh_cn(),
+                    artifact.set_name,
+                    artifact.level,
+                    artifact.main_stat.name
+                );
+            }
+
+            let mut sets: Vec<_> = pieces.iter().map(|artifact| &artifact.set_name).collect();
+            sets.sort();
+            sets.dedup();
+            for set in sets {
+                if is_complete_set(pieces.iter().copied(), set) {
+                    info!("    已集齐 {set} {}件套", set.full_set_piece_count());
+                }
+            }
+        }
+    }
+
+    /// 解析是否导出的回答，直接回车视为同意
+    fn parse_confirm_answer(answer: &str) -> bool {
+        !matches!(answer.trim().to_ascii_lowercase().as_str(), "n" | "no" | "否")
+    }
+
+    /// 扫描被中断时询问是否导出已识别的部分结果，读取输入失败时默认导出以免丢失数据
+    fn confirm_partial_export(count: usize) -> bool {
+        // 提示输出到标准错误，使用 --stdout 时标准输出只包含导出内容
+        eprint!("扫描已中断，是否导出已识别的 {count} 个圣遗物？[Y/n] ");
+        let _ = io::stderr().flush();
+
+        let mut answer = String::new();
+        match io::stdin().read_line(&mut answer) {
+            Ok(_) => Self::parse_confirm_answer(&answer),
+            Err(_) => true,
+        }
+    }
+
+    /// 将转换后圣遗物的数值校验结果记为扫描结果的解析错误，只作提示，不影响导出
+    fn add_validation_errors(
+        scan_result: &mut GenshinArtifactScanResult,
+        artifact: &GenshinArtifact,
+    ) {
+        if !artifact.validate_main_stat() {
+            let expected = artifact.expected_main_stat_value().unwrap_or_default();
+            scan_result.add_error(&ArtifactScanError::ArtifactParsingFailed {
+                field: "主属性数值".to_string(),
+                value: scan_result.main_stat_value.clone(),
+                expected_format: format!("约 {expected:.3}（{}级）", artifact.level),
+            });
+        }
+
+        if let Some(range) = artifact.expected_sub_stat_count() {
+            if !range.contains(&artifact.sub_stat_count()) {
+                scan_result.add_error(&ArtifactScanError::ArtifactParsingFailed {
+                    field: "副属性数量".to_string(),
+                    value: artifact.sub_stat_count().to_string(),
+                    expected_format: format!(
+                        "{}-{} 条（{}星{}级）",
+                        range.start(),
+                        range.end(),
+                        artifact.star,
+                        artifact.level
+                    ),
+                });
+            }
+        }
+    }
+
+    /// 详细诊断扫描结果转换为圣遗物失败的原因，`error` 为转换时返回的错误
+    fn conversion_failure_reasons(
+        scan_result: &GenshinArtifactScanResult,
+        error: &ArtifactConversionError,
+    ) -> Vec<String> {
+        let mut failure_reasons = error.reasons();
+
+        // 检查是否为明显的OCR识别错误
+        if scan_result.name.len() <= 3 || scan_result.name.chars().any(|c| !c.is_alphabetic()) {
+            failure_reasons.push("疑似OCR识别错误：圣遗物名称过短或包含异常字符".to_string());
+        }
+
+        failure_reasons
+    }
+
+    /// 转换失败是否由OCR识别错误（名称过短、套装无法识别）导致，`--retry-failed` 只重新扫描这类物品
+    fn is_ocr_failure(reasons: &[String]) -> bool {
+        reasons.iter().any(|reason| {
+            reason.starts_with("疑似OCR识别错误") || reason.starts_with("套装名称无法识别")
+        })
+    }
+
+    /// 将重新扫描后转换成功的物品加入 `artifacts`，返回这些物品在背包中的序号
+    fn merge_retried(
+        artifacts: &mut Vec<GenshinArtifact>,
+        retried: &[GenshinArtifactScanResult],
+        locale: Locale,
+        fuzzy_equip: bool,
+    ) -> HashSet<usize> {
+        let mut recovered = HashSet::new();
+        for scan_result in retried {
+            let (Some(position), Ok(artifact)) = (
+                scan_result.position,
+                GenshinArtifact::try_from_locale(scan_result, locale, fuzzy_equip),
+            ) else {
+                continue;
+            };
+            if recovered.insert(position.index) {
+                info!("第{}个物品重新扫描成功: {}", position.index + 1, scan_result.name);
+                artifacts.push(artifact);
+            }
+        }
+        recovered
+    }
+
+    /// 将转换失败的物品及原因写入 `--dump-failures` 指定的文件，超过上限的物品只计数
+    fn dump_conversion_failures(
+        path: &Path,
+        failures: &[(usize, &GenshinArtifactScanResult, Vec<String>)],
+    ) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let file = BufWriter::new(File::create(path)?);
+        let mut writer = FailureDumpWriter::new(file, DEFAULT_FAILURE_DUMP_LIMIT)?;
+        for (index, item, reasons) in failures {
+            writer.write(&ConversionFailure { index: *index, reasons, item })?;
+        }
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// 将转换前的原始识别结果写入 `--export-raw` 指定的文件
+    fn export_raw_results(path: &Path, results: &[GenshinArtifactScanResult]) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(&mut file, results)?;
+        file.flush()?;
+        Ok(())
+    }
+
+    /// `--self-test`：逐项检查运行环境并输出结果，某一项失败不影响其余检查
+    ///
+    /// OCR识别使用程序生成的空白样例图，只检查模型推理能否正常完成，不检查识别准确率
+    fn run_self_test() -> Result<()> {
+        let mut report = SelfTestReport::new();
+
+        let model = report.check(
+            "OCR模型",
+            GenshinArtifactScanner::get_image_to_text(&GenshinArtifactScannerConfig::default()),
+            |_| "模型加载成功".to_string(),
+        );
+
+        let repo = Self::get_window_info_repository();
+        let supported = repo.supported_sizes_text();
+        let sizes = if supported.is_empty() {
+            Err(anyhow::anyhow!("没有可用的窗口信息"))
+        } else {
+            Ok(supported)
+        };
+        report.check("支持的分辨率", sizes, |sizes| sizes.clone());
+
+        let results = Self::validate_window_info(&Self::builtin_window_info_repository());
+        let invalid: Vec<String> = results
+            .iter()
+            .filter_map(|(size, result)| {
+                result.as_ref().err().map(|e| format!("{}x{}: {e}", size.width, size.height))
+            })
+            .collect();
+        let validated = if invalid.is_empty() {
+            Ok(results.len())
+        } else {
+            Err(anyhow::anyhow!("{}", invalid.join("；")))
+        };
+        report.check("内置窗口信息", validated, |count| {
+            format!("{count} 种分辨率均可解析全部字段")
+        });
+
+        report.check("游戏窗口", Self::get_game_info(), |game_info| {
+            let window_size = game_info.window.size().to_size_usize();
+            let matched = match repo.nearest_template(window_size, game_info.ui, game_info.platform)
+            {
+                Some((size, _)) if size == window_size => "有内置窗口信息".to_string(),
+                Some((size, _)) => format!("将从 {}x{} 缩放使用", size.width, size.height),
+                None => "没有宽高比一致的窗口信息".to_string(),
+            };
+            format!(
+                "{}x{}，{:?}，{}",
+                game_info.window.width, game_info.window.height, game_info.ui, matched
+            )
+        });
+
+        match model {
+            Some(model) => {
+                let sample = RgbImage::from_pixel(256, 32, Rgb([255, 255, 255]));
+                let start = std::time::Instant::now();
+                report.check("OCR识别", model.image_to_text(&sample, false), |text| {
+                    format!("识别完成，耗时 {:?}，结果: '{text}'", start.elapsed())
+                });
+            },
+            None => report.fail("OCR识别", "模型未加载，跳过"),
+        }
+
+        info!("=== 自检结果 ===");
+        for (line, step) in report.lines().iter().zip(report.steps()) {
+            if step.passed {
+                info!("{line}");
+            } else {
+                error!("{line}");
+            }
+        }
+
+        if report.passed() {
+            info!("🎉 所有检查均已通过");
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("自检未通过：{} 项检查失败", report.failed_count()))
+        }
+    }
+
+    /// `--ocr-rect`：截取游戏窗口内的一个区域识别一次并输出结果，不进入扫描流程
+    ///
+    /// 模型只输出文字，没有可用的置信度，因此只输出识别结果和耗时
+    fn run_ocr_rect(
+        rect: Rect<i32>,
+        game_info: &GameInfo,
+        config: &GenshinArtifactScannerConfig,
+    ) -> Result<()> {
+        let window = game_info.window;
+        CaptureError::check_bounds(rect, window.width as u32, window.height as u32)
+            .map_err(|e| anyhow::anyhow!("--ocr-rect 区域超出游戏窗口: {e}"))?;
+
+        let model = GenshinArtifactScanner::get_image_to_text(config)?;
+        let capturer = GenericCapturer::new()?;
+        let image = capturer.capture_relative_to(rect, window.origin())?;
+
+        let start = std::time::Instant::now();
+        let text = model.image_to_text(&image, false)?;
+        info!(
+            "区域 {},{},{},{} 的识别结果: '{text}'（耗时 {:?}）",
+            rect.left,
+            rect.top,
+            rect.width,
+            rect.height,
+            start.elapsed()
+        );
+        println!("{text}");
+        Ok(())
+    }
+
+    /// `--print-window-info`：输出当前游戏窗口使用的窗口信息，不进入扫描流程
+    fn print_window_info(repo: &WindowInfoRepository, game_info: &GameInfo) -> Result<()> {
+        let window_info = ArtifactScannerWindowInfo::from_window_info_repository(
+            game_info.window.to_rect_usize().size(),
+            game_info.ui,
+            game_info.platform,
+            repo,
+        )?;
+        for line in window_info.dump() {
+            println!("{line}");
+        }
+        Ok(())
+    }
+
     fn get_game_info() -> Result<GameInfo> {
         let game_info = GameInfoBuilder::new()
             .add_local_window_name("原神")
@@ -68,9 +459,30 @@ impl ArtifactScannerApplication {
 impl ArtifactScannerApplication {
     pub fn run(&self) -> Result<()> {
         let arg_matches = &self.arg_matches;
+
+        if SelfTestConfig::from_arg_matches(arg_matches)?.self_test {
+            return Self::run_self_test();
+        }
+
+        // 在开始扫描前检查导出参数，避免扫描完成后才发现无法导出
+        let export_config = ExportArtifactConfig::from_arg_matches(arg_matches)?;
+        let non_interactive = InteractionConfig::from_arg_matches(arg_matches)?.enabled();
+        if export_config.stdout && export_config.format == GenshinArtifactExportFormat::All {
+            return Err(anyhow::anyhow!(
+                "--stdout 只支持单一导出格式，不能与 --format all 同时使用"
+            ));
+        }
+
         let window_info_repository = Self::get_window_info_repository();
 
-        let game_info = Self::get_game_info().map_err(|e| {
+        let mut game_info = Self::get_game_info().map_err(|e| {
+            // 找不到窗口和窗口被最小化需要用户做不同的处理，分别给出建议
+            if let Some(window_error) = e.downcast_ref::<GameWindowError>() {
+                error!("游戏窗口检测失败: {window_error}");
+                error!("建议: {}", window_error.suggestion());
+                return e;
+            }
+
             let error = ArtifactScanError::WindowInfoFailed {
                 error_msg: format!("游戏窗口检测失败: {e}"),
             };
@@ -78,12 +490,26 @@ impl ArtifactScannerApplication {
             error!("建议: {}", get_error_suggestion(&error));
             anyhow::anyhow!(error)
         })?;
+        GameModeConfig::from_arg_matches(arg_matches)?.apply(&mut game_info);
 
         info!("游戏窗口: {}x{}", game_info.window.width, game_info.window.height);
         info!("游戏界面: {:?}", game_info.ui);
         info!("云游戏: {}", if game_info.is_cloud { "是" } else { "否" });
         info!("分辨率族: {:?}", game_info.resolution_family);
 
+        // 窗口尺寸与内置窗口信息不一致时的提示由扫描器在获取窗口信息时输出
+
+        let scanner_config = GenshinArtifactScannerConfig::from_arg_matches(arg_matches)?;
+        if scanner_config.print_window_info {
+            return Self::print_window_info(&window_info_repository, &game_info);
+        }
+        if !scanner_config.locale.is_supported() {
+            return Err(anyhow::anyhow!(
+                "暂不支持{}客户端，目前仅支持简体中文，请将游戏语言切换为简体中文后重试",
+                scanner_config.locale
+            ));
+        }
+
         #[cfg(target_os = "windows")]
         {
             // assure admin
@@ -97,6 +523,20 @@ impl ArtifactScannerApplication {
             }
         }
 
+        if let Some(rect) = scanner_config.ocr_rect {
+            return Self::run_ocr_rect(rect, &game_info, &scanner_config);
+        }
+
+        // 在扫描前读取，记录文件损坏时不必等到扫描结束才报错
+        let mut exported_set = match &export_config.only_new {
+            Some(path) => {
+                let set = ExportedArtifactSet::load(path)?;
+                info!("已读取导出记录 {}，共 {} 件已导出的圣遗物", path.display(), set.len());
+                Some(set)
+            },
+            None => None,
+        };
+
         info!("🔧 开始初始化扫描器...");
         let mut scanner = GenshinArtifactScanner::from_arg_matches(
             &window_info_repository,
@@ -115,9 +555,8 @@ impl ArtifactScannerApplication {
         })?;
 
         info!("✅ 扫描器初始化成功！开始扫描圣遗物...");
-        let scan_start_time = std::time::Instant::now();
 
-        let result = scanner.scan().map_err(|e| {
+        let mut report = scanner.scan().map_err(|e| {
             error!("扫描过程发生错误: {e}");
             if e.to_string().contains("图像捕获失败") {
                 error!("图像捕获相关问题的解决方案:");
@@ -135,14 +574,55 @@ impl ArtifactScannerApplication {
             e
         })?;
 
-        let scan_duration = scan_start_time.elapsed();
+        let scan_duration = report.elapsed;
+        let ocr_timing = report.ocr_timing;
         info!("扫描完成，耗时: {scan_duration:?}");
 
+        if report.interrupted {
+            warn!("⏸️ 扫描被用户中断，已识别 {} 个圣遗物", report.len());
+            // 非交互模式下无法询问，与 --export-on-interrupt 一样直接导出
+            let export_partial = export_config.export_on_interrupt
+                || non_interactive
+                || Self::confirm_partial_export(report.len());
+            if !export_partial {
+                info!("已放弃导出本次扫描的部分结果");
+                return Ok(());
+            }
+            info!("将导出中断前已识别的部分结果");
+        }
+        if let Some(reason) = report.abort_reason() {
+            warn!("⚠️ 扫描提前停止（{reason}），将导出已识别的 {} 个圣遗物", report.len());
+        }
+
+        // 根据前几个圣遗物名称确定客户端语言，之后的转换都使用该语言
+        let locale = Locale::detect(
+            report.artifacts.iter().map(|item| item.name.as_str()),
+            scanner_config.locale,
+        );
+
+        // 校验主属性数值、副属性数量是否与星级、等级相符，不符时记为解析错误
+        for scan_result in report.artifacts.iter_mut() {
+            let Ok(artifact) =
+                GenshinArtifact::try_from_locale(scan_result, locale, scanner_config.fuzzy_equip)
+            else {
+                continue;
+            };
+            Self::add_validation_errors(scan_result, &artifact);
+        }
+
         // 详细的扫描结果分析
-        let total_scanned = result.len();
-        let error_items = result.iter().filter(|r| r.has_errors()).count();
-        let low_confidence_items = result.iter().filter(|r| !r.is_reliable(0.8)).count();
-        let high_quality_items = result.iter().filter(|r| r.star >= 4).count();
+        let total_scanned = report.len();
+        let error_items = report.error_item_count();
+        let low_confidence_items = report.low_confidence_count();
+        let high_quality_items = report.high_quality_count();
+        let result = &report.artifacts;
+
+        if let Some(path) = &export_config.export_raw {
+            match Self::export_raw_results(path, result) {
+                Ok(()) => info!("已将 {} 个原始识别结果写入 {}", result.len(), path.display()),
+                Err(e) => warn!("写入原始识别结果到 {} 失败: {e}", path.display()),
+            }
+        }
 
         info!("扫描结果统计:");
         info!("- 总计扫描: {total_scanned} 个圣遗物");
@@ -184,42 +664,40 @@ impl ArtifactScannerApplication {
         let mut conversion_failed_items = Vec::new();
 
         for (index, scan_result) in result.iter().enumerate() {
-            match GenshinArtifact::try_from(scan_result) {
+            match GenshinArtifact::try_from_locale(scan_result, locale, scanner_config.fuzzy_equip)
+            {
                 Ok(artifact) => artifacts.push(artifact),
-                Err(_) => {
-                    // 详细诊断转换失败的原因
-                    let mut failure_reasons = Vec::new();
-
-                    // 检查套装识别
-                    if crate::artifact::ArtifactSetName::from_zh_cn(&scan_result.name).is_none() {
-                        failure_reasons.push(format!("套装名称无法识别: '{}'", scan_result.name));
-                    }
-
-                    // 检查部位识别
-                    if crate::artifact::ArtifactSlot::from_zh_cn(&scan_result.name).is_none() {
-                        failure_reasons.push(format!("部位无法识别: '{}'", scan_result.name));
-                    }
-
-                    // 检查主属性解析
-                    let main_stat_raw =
-                        format!("{}+{}", scan_result.main_stat_name, scan_result.main_stat_value);
-                    if crate::artifact::ArtifactStat::from_zh_cn_raw(&main_stat_raw).is_none() {
-                        failure_reasons.push(format!("主属性解析失败: '{main_stat_raw}'"));
-                    }
-
-                    // 检查是否为明显的OCR识别错误
-                    if scan_result.name.len() <= 3
-                        || scan_result.name.chars().any(|c| !c.is_alphabetic())
-                    {
-                        failure_reasons
-                            .push("疑似OCR识别错误：圣遗物名称过短或包含异常字符".to_string());
-                    }
-
+                Err(error) => {
+                    let failure_reasons = Self::conversion_failure_reasons(scan_result, &error);
                     conversion_failed_items.push((index + 1, scan_result, failure_reasons));
                 },
             }
         }
 
+        if scanner_config.retry_failed {
+            let failed: Vec<GenshinArtifactScanResult> = conversion_failed_items
+                .iter()
+                .filter(|(_, _, reasons)| Self::is_ocr_failure(reasons))
+                .map(|(_, item, _)| (*item).clone())
+                .collect();
+            if !failed.is_empty() {
+                let retried = scanner.rescan(&failed);
+                let recovered = Self::merge_retried(
+                    &mut artifacts,
+                    &retried,
+                    locale,
+                    scanner_config.fuzzy_equip,
+                );
+                conversion_failed_items.retain(|(_, item, _)| {
+                    !item.position.is_some_and(|position| recovered.contains(&position.index))
+                });
+                info!("重新扫描后 {}/{} 个物品转换成功", recovered.len(), failed.len());
+            }
+        }
+
+        // 在按导出选项过滤之前计算，过滤掉的圣遗物同样算作转换成功
+        let converted_count = artifacts.len();
+        let health_score = report.health_score(converted_count);
         let conversion_errors = conversion_failed_items.len();
         if conversion_errors > 0 {
             warn!("数据转换过程中丢失了 {conversion_errors} 个物品");
@@ -261,6 +739,35 @@ impl ArtifactScannerApplication {
             }
         }
 
+        if let Some(path) = &export_config.dump_failures {
+            match Self::dump_conversion_failures(path, &conversion_failed_items) {
+                Ok(()) => info!("已将 {conversion_errors} 个转换失败的物品写入 {}", path.display()),
+                Err(e) => warn!("写入转换失败的物品到 {} 失败: {e}", path.display()),
+            }
+        }
+
+        if export_config.group_by_character {
+            Self::log_equip_groups(&artifacts);
+        }
+
+        let before_filter = artifacts.len();
+        export_config.filter_artifacts(&mut artifacts);
+        if artifacts.len() < before_filter {
+            info!("按导出选项排除了 {} 件圣遗物", before_filter - artifacts.len());
+        }
+
+        // 只记录实际导出的圣遗物，被导出选项排除的圣遗物在之后放宽选项时仍可导出
+        let exported_artifacts = exported_set.as_ref().map(|_| artifacts.clone());
+        if let Some(set) = &exported_set {
+            let skipped = set.retain_new(&mut artifacts);
+            info!("跳过 {skipped} 件之前已导出的圣遗物，本次新增 {} 件", artifacts.len());
+        }
+
+        if export_config.dry_run {
+            info!("🧪 试运行模式：仅输出统计信息，不会写入任何文件");
+            Self::log_artifact_counts(&artifacts);
+        }
+
         // 导出结果
         let exporter = GenshinArtifactExporter::new(arg_matches, &artifacts).map_err(|e| {
             error!("导出器初始化失败: {e}");
@@ -273,7 +780,33 @@ impl ArtifactScannerApplication {
         let mut export_assets = ExportAssets::new();
         exporter.emit(&mut export_assets);
 
-        let stats = export_assets.save();
+        let stats = if export_config.dry_run {
+            info!("将要导出的文件（试运行，未写入）：");
+            export_assets.dry_run()
+        } else if export_config.stdout {
+            info!("导出内容已写入标准输出");
+            export_assets.write_to(&mut io::stdout().lock())?
+        } else {
+            export_assets.save()
+        };
+
+        if let (Some(path), Some(set), Some(exported)) =
+            (&export_config.only_new, &mut exported_set, &exported_artifacts)
+        {
+            if export_config.dry_run {
+                info!("试运行模式，不更新导出记录 {}", path.display());
+            } else if !stats.failed_items.is_empty() {
+                warn!("部分文件导出失败，未更新导出记录 {}", path.display());
+            } else {
+                for artifact in exported {
+                    set.insert(artifact);
+                }
+                match set.save(path) {
+                    Ok(()) => info!("已更新导出记录 {}，共 {} 件", path.display(), set.len()),
+                    Err(e) => warn!("写入导出记录 {} 失败: {e}", path.display()),
+                }
+            }
+        }
         info!("导出结果：");
         let table = format!("{stats}");
         // print multiline
@@ -284,8 +817,21 @@ impl ArtifactScannerApplication {
         // 最终总结
         info!("=== 扫描完成总结 ===");
         info!("✅ 成功识别 {total_scanned} 件圣遗物");
-        info!("✅ 成功导出 {} 件圣遗物", artifacts.len());
+        if export_config.dry_run {
+            info!("✅ 可导出 {} 件圣遗物（试运行，未写入文件）", artifacts.len());
+        } else {
+            info!("✅ 成功导出 {} 件圣遗物", artifacts.len());
+        }
         info!("⏱️  总耗时: {scan_duration:?}");
+        if let Some(average) = ocr_timing.average() {
+            info!(
+                "⏱️  识别耗时: {:?}（{} 个字段，平均 {average:?}）",
+                ocr_timing.total, ocr_timing.field_count
+            );
+        }
+        if let Some(model_average) = ocr_timing.model_average {
+            info!("⏱️  模型平均推理耗时: {model_average:?}");
+        }
 
         // 综合判断是否有任何问题
         let has_any_issues = error_items > 0 || low_confidence_items > 0 || conversion_errors > 0;
@@ -305,6 +851,374 @@ impl ArtifactScannerApplication {
             warn!("💡 建议检查游戏设置和环境，以提高识别准确率");
         }
 
+        if health_score >= HEALTHY_SCAN_SCORE {
+            info!("🩺 扫描健康度: {health_score:.0}/100");
+        } else {
+            warn!("🩺 扫描健康度: {health_score:.0}/100，结果可能不可靠，建议检查上方的警告后重新扫描");
+        }
+
+        if let Some(path) = &export_config.metrics_file {
+            match write_metrics_file(path, &report, converted_count) {
+                Ok(()) => info!("已将扫描指标写入 {}", path.display()),
+                Err(e) => warn!("写入扫描指标到 {} 失败: {e}", path.display()),
+            }
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use furina_core::game_info::ResolutionFamily;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_confirm_answer() {
+        assert!(ArtifactScannerApplication::parse_confirm_answer("\n"));
+        assert!(ArtifactScannerApplication::parse_confirm_answer("y\n"));
+        assert!(ArtifactScannerApplication::parse_confirm_answer("Yes"));
+        assert!(!ArtifactScannerApplication::parse_confirm_answer("n\n"));
+        assert!(!ArtifactScannerApplication::parse_confirm_answer(" NO "));
+        assert!(!ArtifactScannerApplication::parse_confirm_answer("否"));
+    }
+
+    #[test]
+    fn test_builtin_supported_sizes() {
+        let repo = ArtifactScannerApplication::builtin_window_info_repository();
+        assert_eq!(
+            repo.supported_sizes(),
+            vec![Size::new(2560, 1440), Size::new(1920, 1080), Size::new(1600, 900)]
+        );
+    }
+
+    #[test]
+    fn test_builtin_window_info_is_valid() {
+        let repo = ArtifactScannerApplication::builtin_window_info_repository();
+        let results = ArtifactScannerApplication::validate_window_info(&repo);
+
+        assert_eq!(results.len(), repo.supported_sizes().len());
+        for (size, result) in results {
+            assert!(result.is_ok(), "{}x{}: {}", size.width, size.height, result.unwrap_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_window_info_reports_missing_field() {
+        let mut repo = ArtifactScannerApplication::builtin_window_info_repository();
+        repo.data.get_mut("genshin_artifact_star_sample_rect").unwrap().remove(&(
+            Size::new(1600, 900),
+            UI::Desktop,
+            Platform::Windows,
+        ));
+
+        let results = ArtifactScannerApplication::validate_window_info(&repo);
+        let (size, result) = results.last().unwrap();
+        assert_eq!(*size, Size::new(1600, 900));
+        assert!(result.as_ref().unwrap_err().contains("genshin_artifact_star_sample_rect"));
+        assert!(results[..2].iter().all(|(_, result)| result.is_ok()));
+    }
+
+    #[test]
+    fn test_dump_conversion_failures() {
+        let item = GenshinArtifactScanResult::new(
+            "??".to_string(),
+            "生命值".to_string(),
+            "abc".to_string(),
+            Default::default(),
+            String::new(),
+            0,
+            5,
+            false,
+        );
+        let error = GenshinArtifact::from_scan_result(&item, false).unwrap_err();
+        let reasons = ArtifactScannerApplication::conversion_failure_reasons(&item, &error);
+        assert!(reasons.iter().any(|r| r.starts_with("套装名称无法识别")));
+        assert!(reasons.iter().any(|r| r.starts_with("主属性解析失败")));
+
+        let path = std::env::temp_dir()
+            .join(format!("furina-dump-failures-{}", std::process::id()))
+            .join("failures.json");
+        let failures = vec![(2, &item, reasons.clone()), (5, &item, reasons)];
+        ArtifactScannerApplication::dump_conversion_failures(&path, &failures).unwrap();
+
+        let value: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        assert_eq!(value["total"], 2);
+        assert_eq!(value["failures"][1]["index"], 5);
+        assert_eq!(value["failures"][0]["item"]["name"], "??");
+    }
+
+    #[test]
+    fn test_merge_retried_ocr_failures() {
+        use crate::scanner_controller::repository_layout::ItemPosition;
+
+        let scan_result = |name: &str, index: Option<usize>| {
+            let mut item = GenshinArtifactScanResult::new(
+                name.to_string(),
+                "攻击力".to_string(),
+                "46.6%".to_string(),
+                Default::default(),
+                String::new(),
+                20,
+                5,
+                false,
+            );
+            item.position =
+                index.map(|index| ItemPosition { index, scrolled_rows: 0, row: 0, col: index });
+            item
+        };
+
+        let truncated_item = scan_result("金铜", None);
+        let error = GenshinArtifact::from_scan_result(&truncated_item, false).unwrap_err();
+        let truncated =
+            ArtifactScannerApplication::conversion_failure_reasons(&truncated_item, &error);
+        assert!(ArtifactScannerApplication::is_ocr_failure(&truncated));
+        let bad_main_stat = ["主属性解析失败: '攻击力+abc'".to_string()];
+        assert!(!ArtifactScannerApplication::is_ocr_failure(&bad_main_stat));
+
+        // 仍然失败或没有位置信息的结果不计为恢复
+        let retried = [
+            scan_result("金铜时晷", Some(3)),
+            scan_result("金铜", Some(5)),
+            scan_result("金铜时晷", None),
+        ];
+        let mut artifacts = Vec::new();
+        let recovered = ArtifactScannerApplication::merge_retried(
+            &mut artifacts,
+            &retried,
+            Locale::ZhCn,
+            false,
+        );
+        assert_eq!(recovered, HashSet::from([3]));
+        assert_eq!(artifacts.len(), 1);
+    }
+
+    #[test]
+    fn test_export_raw_results() {
+        let mut item = GenshinArtifactScanResult::new(
+            "魔女的炎之花".to_string(),
+            "生命值".to_string(),
+            "4,78".to_string(),
+            Default::default(),
+            String::new(),
+            20,
+            5,
+            true,
+        );
+        item.add_error(&ArtifactScanError::ArtifactParsingFailed {
+            field: "主属性数值".to_string(),
+            value: "4,78".to_string(),
+            expected_format: "约 4780".to_string(),
+        });
+
+        let path = std::env::temp_dir()
+            .join(format!("furina-export-raw-{}", std::process::id()))
+            .join("raw.json");
+        ArtifactScannerApplication::export_raw_results(&path, &[item.clone()]).unwrap();
+
+        let restored: Vec<GenshinArtifactScanResult> =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        assert_eq!(restored, vec![item.clone()]);
+        assert_eq!(restored[0].scan_errors, item.scan_errors);
+        assert_eq!(restored[0].confidence_score, item.confidence_score);
+    }
+
+    #[test]
+    fn test_sub_stat_count_validation() {
+        let scan_result = |level: i32, sub_stats: [&str; 4]| {
+            GenshinArtifactScanResult::new(
+                "魔女的炎之花".to_string(),
+                "生命值".to_string(),
+                "4,780".to_string(),
+                sub_stats.map(String::from),
+                String::new(),
+                level,
+                5,
+                false,
+            )
+        };
+
+        // 0级4条副属性是合理的
+        let mut valid =
+            scan_result(0, ["暴击率+3.9%", "暴击伤害+7.8%", "攻击力+5.8%", "元素精通+23"]);
+        valid.main_stat_value = "717".to_string();
+        let artifact = GenshinArtifact::from_scan_result(&valid, false).unwrap();
+        ArtifactScannerApplication::add_validation_errors(&mut valid, &artifact);
+        assert!(!valid.has_errors());
+
+        // 20级只有2条副属性，记为解析错误但仍可转换
+        let mut warned = scan_result(20, ["暴击率+3.9%", "暴击伤害+7.8%", "", ""]);
+        let artifact = GenshinArtifact::from_scan_result(&warned, false).unwrap();
+        ArtifactScannerApplication::add_validation_errors(&mut warned, &artifact);
+        assert!(warned.has_errors());
+        assert!(GenshinArtifact::from_scan_result(&warned, false).is_ok());
+    }
+
+    #[test]
+    fn test_game_mode_override_flips_is_cloud() {
+        let mode = |args: &[&str]| {
+            let matches = ArtifactScannerApplication::build_command()
+                .try_get_matches_from(std::iter::once("furinaocr").chain(args.iter().copied()))
+                .unwrap();
+            GameModeConfig::from_arg_matches(&matches).unwrap()
+        };
+        let mut game_info = GameInfo {
+            window: Rect::new(0, 0, 1920, 1080),
+            resolution_family: ResolutionFamily::Windows16x9,
+            is_cloud: false,
+            ui: UI::Desktop,
+            platform: Platform::Windows,
+        };
+
+        mode(&[]).apply(&mut game_info);
+        assert!(!game_info.is_cloud);
+        mode(&["--cloud"]).apply(&mut game_info);
+        assert!(game_info.is_cloud);
+        mode(&["--local"]).apply(&mut game_info);
+        assert!(!game_info.is_cloud);
+
+        assert!(ArtifactScannerApplication::build_command()
+            .try_get_matches_from(["furinaocr", "--cloud", "--local"])
+            .is_err());
+    }
+
+    #[test]
+    fn test_self_test_flag() {
+        let matches = ArtifactScannerApplication::build_command()
+            .try_get_matches_from(["furinaocr", "--self-test"])
+            .unwrap();
+        assert!(SelfTestConfig::from_arg_matches(&matches).unwrap().self_test);
+    }
+
+    #[test]
+    fn test_ocr_rect_arg() {
+        let matches = ArtifactScannerApplication::build_command()
+            .try_get_matches_from(["furinaocr", "--ocr-rect", "1308,120,492,840"])
+            .unwrap();
+        let config = GenshinArtifactScannerConfig::from_arg_matches(&matches).unwrap();
+        assert_eq!(config.ocr_rect, Some(Rect::new(1308, 120, 492, 840)));
+
+        assert!(ArtifactScannerApplication::build_command()
+            .try_get_matches_from(["furinaocr", "--ocr-rect", "1308,120,492"])
+            .is_err());
+    }
+
+    #[test]
+    fn test_locale_arg() {
+        let matches = ArtifactScannerApplication::build_command()
+            .try_get_matches_from(["furinaocr", "--locale", "en"])
+            .unwrap();
+        let config = GenshinArtifactScannerConfig::from_arg_matches(&matches).unwrap();
+        assert_eq!(config.locale, Locale::En);
+
+        let matches = ArtifactScannerApplication::build_command()
+            .try_get_matches_from(["furinaocr"])
+            .unwrap();
+        let config = GenshinArtifactScannerConfig::from_arg_matches(&matches).unwrap();
+        assert_eq!(config.locale, Locale::ZhCn);
+    }
+
+    #[test]
+    fn test_export_on_interrupt_flag() {
+        let matches = ArtifactScannerApplication::build_command()
+            .try_get_matches_from(["furinaocr", "--export-on-interrupt"])
+            .unwrap();
+        let config = ExportArtifactConfig::from_arg_matches(&matches).unwrap();
+        assert!(config.export_on_interrupt);
+    }
+
+    #[test]
+    fn test_exclude_equipped_filter() {
+        use crate::artifact::{ArtifactSetName, ArtifactSlot, ArtifactStat, ArtifactStatName};
+
+        let artifact = |slot, equip: Option<&str>| GenshinArtifact {
+            set_name: ArtifactSetName::EmblemOfSeveredFate,
+            slot,
+            star: 5,
+            lock: false,
+            level: 20,
+            main_stat: ArtifactStat { name: ArtifactStatName::Hp, value: 4780.0 },
+            sub_stat_1: None,
+            sub_stat_2: None,
+            sub_stat_3: None,
+            sub_stat_4: None,
+            equip: equip.map(str::to_string),
+        };
+        let artifacts = vec![
+            artifact(ArtifactSlot::Flower, Some("雷电将军")),
+            artifact(ArtifactSlot::Feather, None),
+            artifact(ArtifactSlot::Sand, Some("行秋")),
+            artifact(ArtifactSlot::Goblet, None),
+        ];
+
+        let config_from = |args: &[&str]| {
+            let matches = ArtifactScannerApplication::build_command()
+                .try_get_matches_from(std::iter::once("furinaocr").chain(args.iter().copied()))
+                .unwrap();
+            ExportArtifactConfig::from_arg_matches(&matches).unwrap()
+        };
+
+        let mut kept = artifacts.clone();
+        config_from(&[]).filter_artifacts(&mut kept);
+        assert_eq!(kept.len(), 4);
+
+        let mut kept = artifacts.clone();
+        config_from(&["--exclude-equipped"]).filter_artifacts(&mut kept);
+        let slots: Vec<_> = kept.iter().map(|a| a.slot.clone()).collect();
+        assert_eq!(slots, vec![ArtifactSlot::Feather, ArtifactSlot::Goblet]);
+        assert!(kept.iter().all(|a| a.equip.is_none()));
+    }
+
+    #[test]
+    fn test_min_crit_value_filter() {
+        use crate::artifact::{ArtifactSetName, ArtifactSlot, ArtifactStat, ArtifactStatName};
+
+        let artifact = |slot, sub_stats: [(ArtifactStatName, f64); 2], equip: Option<&str>| {
+            let [sub_1, sub_2] = sub_stats.map(|(name, value)| Some(ArtifactStat { name, value }));
+            GenshinArtifact {
+                set_name: ArtifactSetName::EmblemOfSeveredFate,
+                slot,
+                star: 5,
+                lock: false,
+                level: 20,
+                main_stat: ArtifactStat { name: ArtifactStatName::Hp, value: 4780.0 },
+                sub_stat_1: sub_1,
+                sub_stat_2: sub_2,
+                sub_stat_3: None,
+                sub_stat_4: None,
+                equip: equip.map(str::to_string),
+            }
+        };
+        // 双暴分 2×10.1 + 21.8 = 42
+        let double_crit =
+            [(ArtifactStatName::Critical, 0.101), (ArtifactStatName::CriticalDamage, 0.218)];
+        let no_crit = [(ArtifactStatName::Recharge, 0.065), (ArtifactStatName::Atk, 19.0)];
+        let artifacts = vec![
+            artifact(ArtifactSlot::Flower, double_crit.clone(), None),
+            artifact(ArtifactSlot::Feather, no_crit, None),
+            artifact(ArtifactSlot::Sand, double_crit, Some("雷电将军")),
+        ];
+
+        let config_from = |args: &[&str]| {
+            let matches = ArtifactScannerApplication::build_command()
+                .try_get_matches_from(std::iter::once("furinaocr").chain(args.iter().copied()))
+                .unwrap();
+            ExportArtifactConfig::from_arg_matches(&matches).unwrap()
+        };
+
+        let mut kept = artifacts.clone();
+        config_from(&["--min-crit-value", "42"]).filter_artifacts(&mut kept);
+        let slots: Vec<_> = kept.iter().map(|a| a.slot.clone()).collect();
+        assert_eq!(slots, vec![ArtifactSlot::Flower, ArtifactSlot::Sand]);
+
+        // 与其他导出过滤条件同时生效
+        let mut kept = artifacts.clone();
+        config_from(&["--min-crit-value", "20", "--exclude-equipped"]).filter_artifacts(&mut kept);
+        let slots: Vec<_> = kept.iter().map(|a| a.slot.clone()).collect();
+        assert_eq!(slots, vec![ArtifactSlot::Flower]);
+    }
+}
@@ -0,0 +1,46 @@
+use furina_core::game_info::GameInfo;
+use log::info;
+
+#[derive(Clone, Default, clap::Args)]
+pub struct GameModeConfig {
+    /// Treat the game as cloud gaming regardless of the window title
+    #[arg(
+        id = "cloud",
+        long = "cloud",
+        conflicts_with = "local",
+        help = "强制按云原神处理（使用云游戏的切换等待时间），适用于窗口标题不是“云·原神”的启动器"
+    )]
+    pub cloud: bool,
+
+    /// Treat the game as a local client regardless of the window title
+    #[arg(id = "local", long = "local", help = "强制按本地客户端处理，忽略窗口标题的云游戏检测")]
+    pub local: bool,
+}
+
+impl GameModeConfig {
+    /// 命令行指定的游戏模式，`Some(true)` 为云游戏，未指定时为 `None`
+    pub fn is_cloud_override(&self) -> Option<bool> {
+        if self.cloud {
+            Some(true)
+        } else if self.local {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// 用命令行指定的模式覆盖根据窗口标题检测到的 `is_cloud`
+    pub fn apply(&self, game_info: &mut GameInfo) {
+        let Some(is_cloud) = self.is_cloud_override() else {
+            return;
+        };
+        if game_info.is_cloud != is_cloud {
+            info!(
+                "根据命令行参数将游戏模式从{}改为{}",
+                if game_info.is_cloud { "云游戏" } else { "本地客户端" },
+                if is_cloud { "云游戏" } else { "本地客户端" }
+            );
+        }
+        game_info.is_cloud = is_cloud;
+    }
+}
@@ -0,0 +1,41 @@
+/// 设置为非空且不为 `0`/`false` 时等同于 `--non-interactive`，便于在计划任务中统一配置
+pub const NON_INTERACTIVE_ENV: &str = "FURINA_NONINTERACTIVE";
+
+#[derive(Clone, Default, clap::Args)]
+pub struct InteractionConfig {
+    /// Never prompt: use the default config without arguments, skip all confirmations and export on interrupt
+    #[arg(
+        id = "non-interactive",
+        long = "non-interactive",
+        help = "非交互模式：没有其他参数时直接使用默认配置，并跳过所有确认和按键等待（扫描中断时直接导出），适用于脚本和计划任务（也可设置环境变量 FURINA_NONINTERACTIVE=1）"
+    )]
+    pub non_interactive: bool,
+}
+
+impl InteractionConfig {
+    /// 命令行参数或环境变量是否启用了非交互模式
+    pub fn enabled(&self) -> bool {
+        self.non_interactive
+            || std::env::var(NON_INTERACTIVE_ENV).is_ok_and(|v| env_enables_non_interactive(&v))
+    }
+}
+
+/// 环境变量的值是否表示启用非交互模式
+pub fn env_enables_non_interactive(value: &str) -> bool {
+    !matches!(value.trim().to_ascii_lowercase().as_str(), "" | "0" | "false")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_enables_non_interactive() {
+        for value in ["1", "true", "yes", " TRUE "] {
+            assert!(env_enables_non_interactive(value), "{value:?}");
+        }
+        for value in ["", "0", "false", "False"] {
+            assert!(!env_enables_non_interactive(value), "{value:?}");
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use log::LevelFilter;
+
+/// 日志输出格式
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum LogFormat {
+    /// 带颜色的文本格式
+    Text,
+    /// 每行一个 JSON 对象，便于其他工具解析
+    Json,
+}
+
+/// 解析 `--log-level` 参数，仅接受 error/warn/info/debug/trace（不区分大小写）
+pub fn parse_log_level(s: &str) -> Result<LevelFilter, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "error" => Ok(LevelFilter::Error),
+        "warn" => Ok(LevelFilter::Warn),
+        "info" => Ok(LevelFilter::Info),
+        "debug" => Ok(LevelFilter::Debug),
+        "trace" => Ok(LevelFilter::Trace),
+        _ => Err(format!("无效的日志等级 '{s}'，可选值: error, warn, info, debug, trace")),
+    }
+}
+
+#[derive(Clone, clap::Args)]
+pub struct LoggerConfig {
+    #[arg(id = "log-format", long = "log-format", value_enum, default_value_t = LogFormat::Text, help = "日志输出格式")]
+    pub log_format: LogFormat,
+
+    /// Log level, takes precedence over RUST_LOG
+    #[arg(
+        id = "log-level",
+        long = "log-level",
+        value_name = "LEVEL",
+        value_parser = parse_log_level,
+        help = "日志等级 (error/warn/info/debug/trace)，优先于 RUST_LOG 环境变量"
+    )]
+    pub log_level: Option<LevelFilter>,
+}
+
+impl Default for LoggerConfig {
+    fn default() -> Self {
+        LoggerConfig { log_format: LogFormat::Text, log_level: None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::FromArgMatches;
+
+    use super::*;
+    use crate::application::ArtifactScannerApplication;
+
+    #[test]
+    fn test_parse_log_level() {
+        assert_eq!(parse_log_level("debug"), Ok(LevelFilter::Debug));
+        assert_eq!(parse_log_level("TRACE"), Ok(LevelFilter::Trace));
+        assert!(parse_log_level("verbose").is_err());
+    }
+
+    #[test]
+    fn test_log_level_from_command_line() {
+        let matches = ArtifactScannerApplication::build_command()
+            .try_get_matches_from(["furinaocr", "--log-level", "debug"])
+            .unwrap();
+        let config = LoggerConfig::from_arg_matches(&matches).unwrap();
+        assert_eq!(config.log_level, Some(LevelFilter::Debug));
+
+        let matches = ArtifactScannerApplication::build_command()
+            .try_get_matches_from(["furinaocr"])
+            .unwrap();
+        let config = LoggerConfig::from_arg_matches(&matches).unwrap();
+        assert_eq!(config.log_level, None);
+        assert_eq!(config.log_format, LogFormat::Text);
+    }
+}
@@ -1,3 +1,11 @@
 pub use artifact_scanner::ArtifactScannerApplication;
+pub use game_mode_config::GameModeConfig;
+pub use interaction_config::{env_enables_non_interactive, InteractionConfig, NON_INTERACTIVE_ENV};
+pub use logger_config::{parse_log_level, LogFormat, LoggerConfig};
+pub use self_test::{SelfTestConfig, SelfTestReport, SelfTestStep};
 
 mod artifact_scanner;
+mod game_mode_config;
+mod interaction_config;
+mod logger_config;
+mod self_test;
@@ -0,0 +1,124 @@
+use anyhow::Result;
+
+#[derive(Clone, Default, clap::Args)]
+pub struct SelfTestConfig {
+    /// Check the OCR model, window info and game window, then exit without scanning
+    #[arg(
+        id = "self-test",
+        long = "self-test",
+        help = "自检模式：依次检查OCR模型、支持的分辨率、内置窗口信息、游戏窗口和一次OCR识别，输出每项是否通过后退出，反馈问题时请附上输出"
+    )]
+    pub self_test: bool,
+}
+
+/// 自检中单个步骤的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelfTestStep {
+    pub name: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+/// 自检报告
+///
+/// 每个步骤独立记录结果，某一步失败不会中断后续步骤；
+/// 依赖前一步结果的步骤在前一步失败时应通过 [`SelfTestReport::fail`] 记为失败
+#[derive(Debug, Default)]
+pub struct SelfTestReport {
+    steps: Vec<SelfTestStep>,
+}
+
+impl SelfTestReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 记录一个步骤的结果，成功时返回步骤产出的值供后续步骤使用
+    pub fn check<T>(
+        &mut self,
+        name: &str,
+        result: Result<T>,
+        describe: impl FnOnce(&T) -> String,
+    ) -> Option<T> {
+        match result {
+            Ok(value) => {
+                self.push(name, true, describe(&value));
+                Some(value)
+            },
+            Err(e) => {
+                self.push(name, false, format!("{e:#}"));
+                None
+            },
+        }
+    }
+
+    pub fn fail(&mut self, name: &str, detail: &str) {
+        self.push(name, false, detail.to_string());
+    }
+
+    fn push(&mut self, name: &str, passed: bool, detail: String) {
+        self.steps.push(SelfTestStep { name: name.to_string(), passed, detail });
+    }
+
+    pub fn steps(&self) -> &[SelfTestStep] {
+        &self.steps
+    }
+
+    pub fn failed_count(&self) -> usize {
+        self.steps.iter().filter(|step| !step.passed).count()
+    }
+
+    pub fn passed(&self) -> bool {
+        self.failed_count() == 0
+    }
+
+    /// 每个步骤一行的文字报告，例如 `✅ [1/4] OCR模型: 加载成功`
+    pub fn lines(&self) -> Vec<String> {
+        let total = self.steps.len();
+        self.steps
+            .iter()
+            .enumerate()
+            .map(|(i, step)| {
+                let mark = if step.passed { "✅" } else { "❌" };
+                format!("{mark} [{}/{total}] {}: {}", i + 1, step.name, step.detail)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::*;
+
+    #[test]
+    fn test_failed_step_does_not_stop_later_steps() {
+        let mut report = SelfTestReport::new();
+        let model =
+            report.check("OCR模型", Err::<u32, _>(anyhow!("找不到模型")), |_| unreachable!());
+        report.check("窗口信息", Ok(3), |count| format!("{count} 种分辨率"));
+        if model.is_none() {
+            report.fail("OCR识别", "模型未加载，跳过");
+        }
+
+        assert!(!report.passed());
+        assert_eq!(report.failed_count(), 2);
+        assert_eq!(
+            report.lines(),
+            vec![
+                "❌ [1/3] OCR模型: 找不到模型",
+                "✅ [2/3] 窗口信息: 3 种分辨率",
+                "❌ [3/3] OCR识别: 模型未加载，跳过",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_all_steps_passed() {
+        let mut report = SelfTestReport::new();
+        assert_eq!(report.check("OCR模型", Ok("model"), |_| "加载成功".to_string()), Some("model"));
+        assert!(report.passed());
+        assert_eq!(report.steps()[0].detail, "加载成功");
+    }
+}
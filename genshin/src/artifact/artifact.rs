@@ -1,15 +1,29 @@
 use std::hash::{Hash, Hasher};
 
 use furina_core::utils::string_optimizer::parse_stat_optimized;
-use log::error;
+use log::{error, info};
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 
-use crate::character::CHARACTER_NAMES;
+use crate::artifact::{ArtifactConversionError, ArtifactField};
+use crate::character::match_character_name;
+use crate::locale::Locale;
 use crate::scanner::GenshinArtifactScanResult;
 
 /// 圣遗物属性名称枚举
-#[derive(Debug, Hash, Clone, PartialEq, Eq, strum_macros::Display)]
+#[derive(
+    Debug,
+    Hash,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    strum_macros::Display,
+    strum_macros::EnumIter,
+)]
 #[strum(serialize_all = "PascalCase")]
+#[serde(rename_all = "PascalCase")]
 pub enum ArtifactStatName {
     HealingBonus,     // 治疗加成
     CriticalDamage,   // 暴击伤害
@@ -33,8 +47,19 @@ pub enum ArtifactStatName {
 }
 
 /// 圣遗物部位枚举
-#[derive(Debug, Hash, Clone, PartialEq, Eq, strum_macros::Display)]
+#[derive(
+    Debug,
+    Hash,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    strum_macros::Display,
+    strum_macros::EnumIter,
+)]
 #[strum(serialize_all = "PascalCase")]
+#[serde(rename_all = "PascalCase")]
 pub enum ArtifactSlot {
     Flower,  // 生之花
     Feather, // 死之羽
@@ -44,8 +69,22 @@ pub enum ArtifactSlot {
 }
 
 /// 圣遗物套装名称枚举
-#[derive(Debug, Hash, Clone, PartialEq, Eq, strum_macros::Display)]
+#[derive(
+    Debug,
+    Hash,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    strum_macros::Display,
+    strum_macros::EnumString,
+    strum_macros::EnumIter,
+)]
 #[strum(serialize_all = "PascalCase")]
+#[serde(rename_all = "PascalCase")]
 pub enum ArtifactSetName {
     ArchaicPetra,                       // 磐陀裂生之岩
     HeartOfDepth,                       // 沉沦之心
@@ -105,14 +144,14 @@ pub enum ArtifactSetName {
 }
 
 /// 圣遗物属性结构体
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArtifactStat {
     pub name: ArtifactStatName, // 属性名称
     pub value: f64,             // 属性数值（百分比已转换为小数）
 }
 
 /// 原神圣遗物完整信息结构体
-#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+#[derive(Debug, Hash, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GenshinArtifact {
     pub set_name: ArtifactSetName,        // 套装名称
     pub slot: ArtifactSlot,               // 部位
@@ -190,6 +229,60 @@ impl ArtifactStatName {
             _ => None,
         }
     }
+
+    /// 韩语客户端的属性名称，规则同 [`Self::from_zh_cn`]
+    pub fn from_ko(name: &str, is_percentage: bool) -> Option<ArtifactStatName> {
+        match name {
+            "치유 보너스" => Some(ArtifactStatName::HealingBonus),
+            "치명타 피해" => Some(ArtifactStatName::CriticalDamage),
+            "치명타 확률" => Some(ArtifactStatName::Critical),
+            "공격력" => {
+                if is_percentage {
+                    Some(ArtifactStatName::AtkPercentage)
+                } else {
+                    Some(ArtifactStatName::Atk)
+                }
+            },
+            "원소 마스터리" => Some(ArtifactStatName::ElementalMastery),
+            "원소 충전 효율" => Some(ArtifactStatName::Recharge),
+            "HP" => {
+                if is_percentage {
+                    Some(ArtifactStatName::HpPercentage)
+                } else {
+                    Some(ArtifactStatName::Hp)
+                }
+            },
+            "방어력" => {
+                if is_percentage {
+                    Some(ArtifactStatName::DefPercentage)
+                } else {
+                    Some(ArtifactStatName::Def)
+                }
+            },
+            "번개 원소 피해 보너스" => Some(ArtifactStatName::ElectroBonus),
+            "불 원소 피해 보너스" => Some(ArtifactStatName::PyroBonus),
+            "물 원소 피해 보너스" => Some(ArtifactStatName::HydroBonus),
+            "얼음 원소 피해 보너스" => Some(ArtifactStatName::CryoBonus),
+            "바람 원소 피해 보너스" => Some(ArtifactStatName::AnemoBonus),
+            "바위 원소 피해 보너스" => Some(ArtifactStatName::GeoBonus),
+            "풀 원소 피해 보너스" => Some(ArtifactStatName::DendroBonus),
+            "물리 피해 보너스" => Some(ArtifactStatName::PhysicalBonus),
+            _ => None,
+        }
+    }
+
+    /// 按游戏客户端语言查找属性名称，没有该语言的名称表时返回 `None`
+    pub fn from_locale(
+        name: &str,
+        is_percentage: bool,
+        locale: Locale,
+    ) -> Option<ArtifactStatName> {
+        match locale {
+            Locale::ZhCn => Self::from_zh_cn(name, is_percentage),
+            Locale::Ko => Self::from_ko(name, is_percentage),
+            Locale::En | Locale::Ja => None,
+        }
+    }
 }
 
 impl ArtifactStat {
@@ -226,8 +319,8 @@ impl ArtifactStat {
         // 移除百分号和逗号，然后解析数值
         let re = Regex::new("[%,]").unwrap();
         let mut value = match re.replace_all(temp[1], "").parse::<f64>() {
-            Ok(v) => v,
-            Err(_) => {
+            Ok(v) if v.is_finite() => v,
+            _ => {
                 error!("属性解析失败: `{s}`");
                 return None;
             },
@@ -243,42 +336,79 @@ impl ArtifactStat {
 }
 
 impl TryFrom<&GenshinArtifactScanResult> for GenshinArtifact {
-    type Error = ();
+    type Error = ArtifactConversionError;
 
     fn try_from(value: &GenshinArtifactScanResult) -> Result<Self, Self::Error> {
-        // 识别套装名称
-        let set_name = ArtifactSetName::from_zh_cn(&value.name).ok_or(())?;
-        // 识别圣遗物部位
-        let slot = ArtifactSlot::from_zh_cn(&value.name).ok_or(())?;
+        GenshinArtifact::from_scan_result(value, false)
+    }
+}
+
+impl GenshinArtifact {
+    /// 按游戏客户端语言从扫描结果转换，目前只支持简体中文，
+    /// 其他语言返回 [`ArtifactConversionError::UnsupportedLocale`]
+    ///
+    /// 韩语已有全部套装和属性的名称表（见 [`ArtifactSetName::from_locale`]），
+    /// 但属性数值和装备角色的解析仍只支持中文，因此暂不转换
+    pub fn try_from_locale(
+        value: &GenshinArtifactScanResult,
+        locale: Locale,
+        fuzzy_equip: bool,
+    ) -> Result<Self, ArtifactConversionError> {
+        match locale {
+            Locale::ZhCn => Self::from_scan_result(value, fuzzy_equip),
+            Locale::En | Locale::Ja | Locale::Ko => {
+                Err(ArtifactConversionError::UnsupportedLocale(locale))
+            },
+        }
+    }
+
+    /// 从扫描结果转换，`fuzzy_equip` 为 true 时对装备角色名称进行模糊纠正
+    ///
+    /// 套装名称、部位或主属性无法解析时返回 [`ArtifactConversionError::Unrecognized`]，
+    /// 其中列出全部无法解析的字段
+    pub fn from_scan_result(
+        value: &GenshinArtifactScanResult,
+        fuzzy_equip: bool,
+    ) -> Result<Self, ArtifactConversionError> {
+        // 识别套装名称、圣遗物部位并解析主属性，记录所有无法解析的字段
+        let set_name = ArtifactSetName::from_zh_cn(&value.name);
+        let slot = ArtifactSlot::from_zh_cn(&value.name);
+        let main_stat_raw = value.main_stat_name.clone() + "+" + value.main_stat_value.as_str();
+        let main_stat = ArtifactStat::from_zh_cn_raw(&main_stat_raw);
+
+        let mut unrecognized = Vec::new();
+        if set_name.is_none() {
+            unrecognized.push((ArtifactField::SetName, value.name.clone()));
+        }
+        if slot.is_none() {
+            unrecognized.push((ArtifactField::Slot, value.name.clone()));
+        }
+        if main_stat.is_none() {
+            unrecognized.push((ArtifactField::MainStat, main_stat_raw));
+        }
+        let (Some(set_name), Some(slot), Some(main_stat)) = (set_name, slot, main_stat) else {
+            return Err(ArtifactConversionError::Unrecognized(unrecognized));
+        };
         let star = value.star;
         let lock = value.lock;
 
-        // 解析主属性
-        let main_stat = ArtifactStat::from_zh_cn_raw(
-            (value.main_stat_name.clone() + "+" + value.main_stat_value.as_str()).as_str(),
-        )
-        .ok_or(())?;
-
         // 解析副属性（可能为空）
         let sub1 = ArtifactStat::from_zh_cn_raw(&value.sub_stat[0]);
         let sub2 = ArtifactStat::from_zh_cn_raw(&value.sub_stat[1]);
         let sub3 = ArtifactStat::from_zh_cn_raw(&value.sub_stat[2]);
         let sub4 = ArtifactStat::from_zh_cn_raw(&value.sub_stat[3]);
 
-        // 解析装备角色信息
-        let equip = if value.equip.ends_with("已装备") {
-            let chars = value.equip.chars().collect::<Vec<_>>();
-            let equip_name = chars[..chars.len() - 3].iter().collect::<String>();
-
-            // 验证角色名称是否在有效角色列表中
-            if CHARACTER_NAMES.contains(equip_name.as_str()) {
-                Some(equip_name)
-            } else {
-                None
+        // 解析装备角色信息，去掉“已装备”后缀得到角色名称，并验证是否在有效角色列表中
+        let equip = value.equip.strip_suffix("已装备").and_then(|equip_name| {
+            match match_character_name(equip_name, fuzzy_equip) {
+                Some(name) if name != equip_name => {
+                    info!("🔧 装备角色修正: {equip_name} -> {name}");
+                    Some(name.to_string())
+                },
+                Some(name) => Some(name.to_string()),
+                None => None,
             }
-        } else {
-            None
-        };
+        });
 
         Ok(GenshinArtifact {
             set_name,
@@ -506,6 +636,15 @@ impl ArtifactSetName {
             _ => None,
         }
     }
+
+    /// 按游戏客户端语言查找圣遗物名称对应的套装，没有该语言的名称表时返回 `None`
+    pub fn from_locale(s: &str, locale: Locale) -> Option<ArtifactSetName> {
+        match locale {
+            Locale::ZhCn => Self::from_zh_cn(s),
+            Locale::Ko => Self::from_ko(s),
+            Locale::En | Locale::Ja => None,
+        }
+    }
 }
 
 impl ArtifactSlot {
@@ -787,12 +926,53 @@ impl ArtifactSlot {
             _ => None,
         }
     }
+
+    /// 按游戏客户端语言查找圣遗物名称对应的部位，没有该语言的名称表时返回 `None`
+    pub fn from_locale(s: &str, locale: Locale) -> Option<ArtifactSlot> {
+        match locale {
+            Locale::ZhCn => Self::from_zh_cn(s),
+            Locale::Ko => Self::from_ko(s),
+            Locale::En | Locale::Ja => None,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_artifact_json_round_trip() {
+        let artifact = GenshinArtifact {
+            set_name: ArtifactSetName::NighttimeWhispersInTheEchoingWoods,
+            slot: ArtifactSlot::Goblet,
+            star: 5,
+            lock: true,
+            level: 20,
+            main_stat: ArtifactStat { name: ArtifactStatName::GeoBonus, value: 0.466 },
+            sub_stat_1: Some(ArtifactStat { name: ArtifactStatName::Critical, value: 0.1 + 0.2 }),
+            sub_stat_2: Some(ArtifactStat {
+                name: ArtifactStatName::CriticalDamage,
+                value: 1.0 / 3.0,
+            }),
+            sub_stat_3: Some(ArtifactStat { name: ArtifactStatName::Atk, value: 19.0 }),
+            sub_stat_4: None,
+            equip: Some("娜维娅".to_string()),
+        };
+
+        let json = serde_json::to_string(&artifact).unwrap();
+        // 枚举的序列化与 strum 的 Display 保持一致
+        assert!(json.contains(&format!("\"{}\"", artifact.set_name)));
+        assert!(json.contains("\"Goblet\""));
+        assert!(json.contains("\"GeoBonus\""));
+
+        let decoded: GenshinArtifact = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, artifact);
+        // f64 数值不丢失精度
+        assert_eq!(decoded.sub_stat_1.unwrap().value, 0.1 + 0.2);
+        assert_eq!(decoded.sub_stat_2.unwrap().value, 1.0 / 3.0);
+    }
+
     #[test]
     fn test_artifact_stat_name_from_zh_cn() {
         // 测试百分比属性
@@ -874,6 +1054,16 @@ mod tests {
         assert!(ArtifactStat::from_zh_cn_raw("攻击力").is_none());
     }
 
+    #[test]
+    fn test_artifact_stat_from_zh_cn_raw_rejects_non_finite() {
+        assert!(ArtifactStat::from_zh_cn_raw("攻击力+inf").is_none());
+        assert!(ArtifactStat::from_zh_cn_raw("攻击力+NaN").is_none());
+        assert!(ArtifactStat::from_zh_cn_raw("暴击率+inf%").is_none());
+
+        let stat = ArtifactStat::from_zh_cn_raw("攻击力+19").unwrap();
+        assert_eq!(stat, ArtifactStat { name: ArtifactStatName::Atk, value: 19.0 });
+    }
+
     #[test]
     fn test_artifact_stat_equality() {
         let stat1 = ArtifactStat { name: ArtifactStatName::Critical, value: 0.062 };
@@ -947,6 +1137,55 @@ mod tests {
         assert_eq!(ArtifactSlot::from_zh_cn("无效部位"), None);
     }
 
+    #[test]
+    fn test_artifact_stat_name_from_ko() {
+        assert_eq!(
+            ArtifactStatName::from_ko("치명타 확률", true),
+            Some(ArtifactStatName::Critical)
+        );
+        assert_eq!(
+            ArtifactStatName::from_ko("치명타 피해", true),
+            Some(ArtifactStatName::CriticalDamage)
+        );
+
+        // 测试百分比和固定值属性
+        assert_eq!(
+            ArtifactStatName::from_ko("공격력", true),
+            Some(ArtifactStatName::AtkPercentage)
+        );
+        assert_eq!(ArtifactStatName::from_ko("공격력", false), Some(ArtifactStatName::Atk));
+        assert_eq!(ArtifactStatName::from_ko("HP", true), Some(ArtifactStatName::HpPercentage));
+        assert_eq!(ArtifactStatName::from_ko("HP", false), Some(ArtifactStatName::Hp));
+
+        assert_eq!(
+            ArtifactStatName::from_ko("불 원소 피해 보너스", false),
+            Some(ArtifactStatName::PyroBonus)
+        );
+        assert_eq!(ArtifactStatName::from_ko("暴击率", false), None);
+    }
+
+    #[test]
+    fn test_from_locale() {
+        assert_eq!(
+            ArtifactSetName::from_locale("검투사의 향수", Locale::Ko),
+            Some(ArtifactSetName::GladiatorFinale)
+        );
+        assert_eq!(
+            ArtifactSetName::from_locale("魔女的炎之花", Locale::ZhCn),
+            Some(ArtifactSetName::CrimsonWitch)
+        );
+        assert_eq!(ArtifactSetName::from_locale("검투사의 향수", Locale::ZhCn), None);
+        assert_eq!(ArtifactSlot::from_locale("魔女的炎之花", Locale::En), None);
+        assert_eq!(
+            ArtifactStatName::from_locale("치명타 확률", false, Locale::Ko),
+            Some(ArtifactStatName::Critical)
+        );
+        assert_eq!(
+            ArtifactStatName::from_locale("暴击伤害", false, Locale::ZhCn),
+            Some(ArtifactStatName::CriticalDamage)
+        );
+    }
+
     #[test]
     fn test_artifact_stat_display() {
         let stat = ArtifactStat { name: ArtifactStatName::Critical, value: 0.062 };
@@ -964,6 +1203,130 @@ mod tests {
         assert_eq!(format!("{}", ArtifactSlot::Head), "Head");
     }
 
+    #[test]
+    fn test_from_scan_result_fuzzy_equip() {
+        let scan_result = |equip: &str| {
+            GenshinArtifactScanResult::new(
+                "魔女的炎之花".to_string(),
+                "生命值".to_string(),
+                "4780".to_string(),
+                Default::default(),
+                equip.to_string(),
+                20,
+                5,
+                false,
+            )
+        };
+
+        let exact = GenshinArtifact::from_scan_result(&scan_result("胡桃已装备"), true).unwrap();
+        assert_eq!(exact.equip, Some("胡桃".to_string()));
+
+        let corrected =
+            GenshinArtifact::from_scan_result(&scan_result("胡林已装备"), true).unwrap();
+        assert_eq!(corrected.equip, Some("胡桃".to_string()));
+
+        let strict = GenshinArtifact::from_scan_result(&scan_result("胡林已装备"), false).unwrap();
+        assert_eq!(strict.equip, None);
+
+        let distant =
+            GenshinArtifact::from_scan_result(&scan_result("随便写的已装备"), true).unwrap();
+        assert_eq!(distant.equip, None);
+    }
+
+    #[test]
+    fn test_from_scan_result_equip_suffix() {
+        let equip_of = |equip: &str| {
+            let scan_result = GenshinArtifactScanResult::new(
+                "魔女的炎之花".to_string(),
+                "生命值".to_string(),
+                "4780".to_string(),
+                Default::default(),
+                equip.to_string(),
+                20,
+                5,
+                false,
+            );
+            GenshinArtifact::from_scan_result(&scan_result, true).unwrap().equip
+        };
+
+        assert_eq!(equip_of("纳西妲已装备"), Some("纳西妲".to_string()));
+        // 只识别出后缀时没有角色名称，不会因截取越界而 panic
+        assert_eq!(equip_of("已装备"), None);
+        assert_eq!(equip_of("装备"), None);
+        // 未装备的圣遗物
+        assert_eq!(equip_of(""), None);
+        assert_eq!(equip_of("纳西妲"), None);
+    }
+
+    #[test]
+    fn test_from_scan_result_unrecognized_fields() {
+        let scan_result = |name: &str, main_stat_value: &str| {
+            GenshinArtifactScanResult::new(
+                name.to_string(),
+                "生命值".to_string(),
+                main_stat_value.to_string(),
+                Default::default(),
+                String::new(),
+                20,
+                5,
+                false,
+            )
+        };
+
+        // 名称无法识别时套装和部位都无法解析，主属性仍单独检查
+        let error =
+            GenshinArtifact::from_scan_result(&scan_result("金铜", "abc"), false).unwrap_err();
+        assert_eq!(
+            error,
+            ArtifactConversionError::Unrecognized(vec![
+                (ArtifactField::SetName, "金铜".to_string()),
+                (ArtifactField::Slot, "金铜".to_string()),
+                (ArtifactField::MainStat, "生命值+abc".to_string()),
+            ])
+        );
+        assert_eq!(
+            error.reasons(),
+            vec![
+                "套装名称无法识别: '金铜'",
+                "部位无法识别: '金铜'",
+                "主属性解析失败: '生命值+abc'"
+            ]
+        );
+
+        let error = GenshinArtifact::from_scan_result(&scan_result("魔女的炎之花", "abc"), false)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            ArtifactConversionError::Unrecognized(vec![(
+                ArtifactField::MainStat,
+                "生命值+abc".to_string()
+            )])
+        );
+    }
+
+    #[test]
+    fn test_try_from_locale() {
+        let scan_result = GenshinArtifactScanResult::new(
+            "魔女的炎之花".to_string(),
+            "生命值".to_string(),
+            "4780".to_string(),
+            Default::default(),
+            String::new(),
+            20,
+            5,
+            false,
+        );
+
+        assert_eq!(
+            GenshinArtifact::try_from_locale(&scan_result, Locale::ZhCn, false),
+            GenshinArtifact::from_scan_result(&scan_result, false)
+        );
+        assert_eq!(
+            GenshinArtifact::try_from_locale(&scan_result, Locale::En, false),
+            Err(ArtifactConversionError::UnsupportedLocale(Locale::En))
+        );
+    }
+
     #[test]
     fn test_artifact_set_name_display() {
         assert_eq!(format!("{}", ArtifactSetName::CrimsonWitch), "CrimsonWitch");
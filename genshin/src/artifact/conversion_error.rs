@@ -0,0 +1,50 @@
+use std::fmt;
+
+use crate::locale::Locale;
+
+/// 扫描结果中参与转换的字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArtifactField {
+    /// 套装名称，取自圣遗物名称
+    SetName,
+    /// 部位，取自圣遗物名称
+    Slot,
+    /// 主属性，`名称+数值` 形式
+    MainStat,
+}
+
+/// 扫描结果转换为圣遗物失败的原因
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArtifactConversionError {
+    /// 该语言还不支持转换
+    UnsupportedLocale(Locale),
+    /// 无法解析的字段及其原始文本，按套装名称、部位、主属性的顺序排列，至少包含一项
+    Unrecognized(Vec<(ArtifactField, String)>),
+}
+
+impl ArtifactConversionError {
+    /// 每个无法解析的字段各一条说明，例如 `套装名称无法识别: '金铜'`
+    pub fn reasons(&self) -> Vec<String> {
+        match self {
+            ArtifactConversionError::UnsupportedLocale(locale) => {
+                vec![format!("暂不支持转换{locale}的识别结果")]
+            },
+            ArtifactConversionError::Unrecognized(fields) => fields
+                .iter()
+                .map(|(field, raw)| match field {
+                    ArtifactField::SetName => format!("套装名称无法识别: '{raw}'"),
+                    ArtifactField::Slot => format!("部位无法识别: '{raw}'"),
+                    ArtifactField::MainStat => format!("主属性解析失败: '{raw}'"),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for ArtifactConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.reasons().join("; "))
+    }
+}
+
+impl std::error::Error for ArtifactConversionError {}
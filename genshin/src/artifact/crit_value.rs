@@ -0,0 +1,71 @@
+use crate::artifact::{ArtifactStatName, GenshinArtifact};
+
+impl GenshinArtifact {
+    /// 双暴分（crit value）：副属性中 `2 × 暴击率 + 暴击伤害`，以游戏内显示的百分数计
+    ///
+    /// 只统计副属性，暴击头的主属性不计入；结果四舍五入到 0.1，与游戏内显示精度一致，
+    /// 例如暴击率 3.9% + 暴击伤害 7.8% 的双暴分为 15.6
+    pub fn crit_value(&self) -> f64 {
+        let value: f64 = [&self.sub_stat_1, &self.sub_stat_2, &self.sub_stat_3, &self.sub_stat_4]
+            .into_iter()
+            .flatten()
+            .map(|stat| match stat.name {
+                ArtifactStatName::Critical => stat.value * 200.0,
+                ArtifactStatName::CriticalDamage => stat.value * 100.0,
+                _ => 0.0,
+            })
+            .sum();
+        (value * 10.0).round() / 10.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact::{ArtifactSetName, ArtifactSlot, ArtifactStat};
+
+    fn artifact(
+        main_stat: ArtifactStatName,
+        sub_stats: &[(ArtifactStatName, f64)],
+    ) -> GenshinArtifact {
+        let mut sub_stats = sub_stats
+            .iter()
+            .map(|(name, value)| Some(ArtifactStat { name: name.clone(), value: *value }));
+        GenshinArtifact {
+            set_name: ArtifactSetName::EmblemOfSeveredFate,
+            slot: ArtifactSlot::Head,
+            star: 5,
+            lock: false,
+            level: 20,
+            main_stat: ArtifactStat { name: main_stat, value: 0.311 },
+            sub_stat_1: sub_stats.next().flatten(),
+            sub_stat_2: sub_stats.next().flatten(),
+            sub_stat_3: sub_stats.next().flatten(),
+            sub_stat_4: sub_stats.next().flatten(),
+            equip: None,
+        }
+    }
+
+    #[test]
+    fn test_crit_value() {
+        let double_crit = artifact(
+            ArtifactStatName::AtkPercentage,
+            &[
+                (ArtifactStatName::Critical, 0.101),
+                (ArtifactStatName::CriticalDamage, 0.218),
+                (ArtifactStatName::Atk, 19.0),
+            ],
+        );
+        assert_eq!(double_crit.crit_value(), 42.0);
+
+        let no_crit = artifact(ArtifactStatName::Hp, &[(ArtifactStatName::Recharge, 0.065)]);
+        assert_eq!(no_crit.crit_value(), 0.0);
+    }
+
+    #[test]
+    fn test_crit_value_ignores_main_stat() {
+        let crit_circlet =
+            artifact(ArtifactStatName::Critical, &[(ArtifactStatName::CriticalDamage, 0.078)]);
+        assert_eq!(crit_circlet.crit_value(), 7.8);
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+
+use crate::artifact::GenshinArtifact;
+use crate::character::CHARACTER_NAMES;
+
+/// 按装备角色分组时的分组键，排序为：已知角色（按名称）、未知角色、未装备
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EquipGroup {
+    /// 装备在 `CHARACTER_NAMES` 中的角色身上
+    Character(String),
+    /// 装备在不在 `CHARACTER_NAMES` 中的角色身上，例如从旧版本导出文件中读取的新角色
+    Unknown(String),
+    /// 未装备
+    Unequipped,
+}
+
+impl EquipGroup {
+    pub fn of(artifact: &GenshinArtifact) -> EquipGroup {
+        match &artifact.equip {
+            Some(name) if CHARACTER_NAMES.contains(name.as_str()) => {
+                EquipGroup::Character(name.clone())
+            },
+            Some(name) => EquipGroup::Unknown(name.clone()),
+            None => EquipGroup::Unequipped,
+        }
+    }
+}
+
+/// 按装备角色对圣遗物分组，每组内按部位（花、羽、沙、杯、冠）排序
+pub fn group_by_equip(
+    artifacts: &[GenshinArtifact],
+) -> BTreeMap<EquipGroup, Vec<&GenshinArtifact>> {
+    let mut groups: BTreeMap<EquipGroup, Vec<&GenshinArtifact>> = BTreeMap::new();
+    for artifact in artifacts {
+        groups.entry(EquipGroup::of(artifact)).or_default().push(artifact);
+    }
+    for group in groups.values_mut() {
+        group.sort_by_key(|artifact| artifact.slot.clone() as u8);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact::{ArtifactSetName, ArtifactSlot, ArtifactStat, ArtifactStatName};
+
+    fn artifact(slot: ArtifactSlot, equip: Option<&str>) -> GenshinArtifact {
+        GenshinArtifact {
+            set_name: ArtifactSetName::ArchaicPetra,
+            slot,
+            star: 5,
+            lock: false,
+            level: 20,
+            main_stat: ArtifactStat { name: ArtifactStatName::Hp, value: 4780.0 },
+            sub_stat_1: None,
+            sub_stat_2: None,
+            sub_stat_3: None,
+            sub_stat_4: None,
+            equip: equip.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_group_by_equip() {
+        let artifacts = vec![
+            artifact(ArtifactSlot::Head, Some("胡桃")),
+            artifact(ArtifactSlot::Flower, None),
+            artifact(ArtifactSlot::Flower, Some("胡桃")),
+            artifact(ArtifactSlot::Goblet, Some("不存在的角色")),
+            artifact(ArtifactSlot::Sand, Some("胡桃")),
+            artifact(ArtifactSlot::Feather, None),
+        ];
+
+        let groups = group_by_equip(&artifacts);
+        let keys: Vec<_> = groups.keys().cloned().collect();
+        assert_eq!(
+            keys,
+            vec![
+                EquipGroup::Character("胡桃".to_string()),
+                EquipGroup::Unknown("不存在的角色".to_string()),
+                EquipGroup::Unequipped,
+            ]
+        );
+
+        let hu_tao: Vec<_> = groups[&EquipGroup::Character("胡桃".to_string())]
+            .iter()
+            .map(|a| a.slot.clone())
+            .collect();
+        assert_eq!(hu_tao, vec![ArtifactSlot::Flower, ArtifactSlot::Sand, ArtifactSlot::Head]);
+
+        assert_eq!(groups[&EquipGroup::Unknown("不存在的角色".to_string())].len(), 1);
+        assert_eq!(groups[&EquipGroup::Unequipped].len(), 2);
+    }
+
+    #[test]
+    fn test_group_by_equip_empty() {
+        assert!(group_by_equip(&[]).is_empty());
+    }
+}
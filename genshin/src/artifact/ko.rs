@@ -0,0 +1,475 @@
+use crate::artifact::{ArtifactSetName, ArtifactSlot};
+
+/// 韩语客户端的圣遗物名称，每个套装按生之花、死之羽、时之沙、空之杯、理之冠的顺序排列
+///
+/// 套装和部位的查找共用这张表，分组与 [`ArtifactSetName::from_zh_cn`] 一致
+const KO_SET_PIECES: [(ArtifactSetName, [&str; 5]); 51] = [
+    // 四星套装
+    (
+        ArtifactSetName::Berserker,
+        [
+            "전투광의 장미",
+            "전투광의 깃털",
+            "전투광의 시계",
+            "전투광의 해골잔",
+            "전투광의 귀신 가면",
+        ],
+    ),
+    (
+        ArtifactSetName::BraveHeart,
+        ["용사의 훈장", "용사의 기대", "용사의 강인함", "용사의 출정", "용사의 왕관"],
+    ),
+    (
+        ArtifactSetName::DefenderWill,
+        ["수호의 꽃", "수호의 휘장", "수호의 탁상시계", "수호의 그릇", "수호의 머리띠"],
+    ),
+    (
+        ArtifactSetName::Exile,
+        ["망명자의 꽃", "망명자의 깃털", "망명자의 회중시계", "망명자의 잔", "망명자의 왕관"],
+    ),
+    (
+        ArtifactSetName::Gambler,
+        [
+            "도박꾼의 브로치",
+            "도박꾼의 깃털 장식",
+            "도박꾼의 회중시계",
+            "도박꾼의 주사위 컵",
+            "도박꾼의 귀걸이",
+        ],
+    ),
+    (
+        ArtifactSetName::Instructor,
+        ["교관의 브로치", "교관의 깃털 장식", "교관의 회중시계", "교관의 찻잔", "교관의 모자"],
+    ),
+    (
+        ArtifactSetName::MartialArtist,
+        ["무인의 붉은 꽃", "무인의 깃털 장식", "무인의 물시계", "무인의 술잔", "무인의 두건"],
+    ),
+    (
+        ArtifactSetName::ResolutionOfSojourner,
+        ["옛 친구의 마음", "귀향의 깃털", "빛을 좇는 돌", "이국의 잔", "이별의 모자"],
+    ),
+    (
+        ArtifactSetName::Scholar,
+        ["학자의 책갈피", "학자의 깃펜", "학자의 시계", "학자의 먹물잔", "학자의 렌즈"],
+    ),
+    (
+        ArtifactSetName::TinyMiracle,
+        ["기적의 꽃", "기적의 깃털", "기적의 모래", "기적의 잔", "기적의 귀걸이"],
+    ),
+    // 三星套装
+    (
+        ArtifactSetName::Adventurer,
+        [
+            "모험가의 꽃",
+            "모험가의 꼬리 깃털",
+            "모험가의 회중시계",
+            "모험가의 금잔",
+            "모험가의 머리띠",
+        ],
+    ),
+    (
+        ArtifactSetName::LuckyDog,
+        [
+            "행운아의 녹색 꽃",
+            "행운아의 매 깃털",
+            "행운아의 모래시계",
+            "행운아의 잔",
+            "행운아의 은관",
+        ],
+    ),
+    (
+        ArtifactSetName::TravelingDoctor,
+        [
+            "떠돌이 의사의 은련",
+            "떠돌이 의사의 올빼미 깃털",
+            "떠돌이 의사의 회중시계",
+            "떠돌이 의사의 약 주전자",
+            "떠돌이 의사의 손수건",
+        ],
+    ),
+    // 1.0版本套装
+    (
+        ArtifactSetName::GladiatorFinale,
+        ["검투사의 향수", "검투사의 귀착", "검투사의 희망", "검투사의 취기", "검투사의 개선"],
+    ),
+    (
+        ArtifactSetName::WandererTroupe,
+        ["악단의 새벽빛", "악사의 화살 깃털", "끝막의 시계", "음유시인의 물병", "지휘자의 중절모"],
+    ),
+    (
+        ArtifactSetName::ViridescentVenerer,
+        [
+            "들꽃 기억의 들판",
+            "사냥꾼의 청록색 화살 깃털",
+            "청록색 사냥꾼의 결심",
+            "청록색 사냥꾼의 그릇",
+            "청록색 사냥꾼의 관",
+        ],
+    ),
+    (
+        ArtifactSetName::MaidenBeloved,
+        [
+            "먼 곳의 소녀의 마음",
+            "흔들리는 소녀의 그리움",
+            "소녀의 짧은 좋은 시절",
+            "소녀의 잠깐의 여유",
+            "소녀의 사라지는 미모",
+        ],
+    ),
+    (
+        ArtifactSetName::CrimsonWitch,
+        [
+            "마녀의 화염 꽃",
+            "마녀의 타오르는 깃털",
+            "마녀의 파멸의 시간",
+            "마녀의 마음의 불꽃",
+            "불타는 마녀의 모자",
+        ],
+    ),
+    (
+        ArtifactSetName::ThunderingFury,
+        ["뇌조의 연민", "뇌재의 생존자", "천둥의 시계", "낙뢰의 흉조", "번개 소환사의 왕관"],
+    ),
+    (
+        ArtifactSetName::ThunderSmoother,
+        ["평뢰의 마음", "평뢰의 깃털", "평뢰의 시간", "평뢰의 그릇", "평뢰의 관"],
+    ),
+    (
+        ArtifactSetName::LavaWalker,
+        [
+            "불 건너는 자의 결단",
+            "불 건너는 자의 해탈",
+            "불 건너는 자의 고통",
+            "불 건너는 자의 각성",
+            "불 건너는 자의 지혜",
+        ],
+    ),
+    (
+        ArtifactSetName::BloodstainedChivalry,
+        [
+            "피에 물든 강철 심장",
+            "피에 물든 검은 깃털",
+            "피에 물든 기사의 시간",
+            "피에 물든 기사의 잔",
+            "피에 물든 철가면",
+        ],
+    ),
+    (
+        ArtifactSetName::NoblesseOblige,
+        ["왕실의 꽃", "왕실의 깃털", "왕실의 시계", "왕실의 은 항아리", "왕실의 가면"],
+    ),
+    (
+        ArtifactSetName::ArchaicPetra,
+        [
+            "반석을 가르는 꽃",
+            "험준한 봉우리의 날개",
+            "별자리 옥의 해시계",
+            "조각된 암석의 술잔",
+            "움직이지 않는 현석의 얼굴",
+        ],
+    ),
+    (
+        ArtifactSetName::RetracingBolide,
+        [
+            "여름 축제의 꽃",
+            "여름 축제의 끝",
+            "여름 축제의 시간",
+            "여름 축제의 물방울",
+            "여름 축제의 가면",
+        ],
+    ),
+    // 1.2版本套装
+    (
+        ArtifactSetName::BlizzardStrayer,
+        [
+            "눈보라 속의 그리움",
+            "얼음을 깨고 나아가는 집념",
+            "빙설 고향의 종말",
+            "서리 맺힌 오만함",
+            "얼음을 깨는 메아리",
+        ],
+    ),
+    (
+        ArtifactSetName::HeartOfDepth,
+        [
+            "금장식 코르사주",
+            "추억의 바람",
+            "견고한 구리 나침반",
+            "침몰한 배의 잔",
+            "술에 젖은 선장 모자",
+        ],
+    ),
+    // 2.0版本套装
+    (
+        ArtifactSetName::PaleFlame,
+        ["무구의 꽃", "현의의 깃털", "멈춘 시간", "초월의 잔", "조소의 가면"],
+    ),
+    (
+        ArtifactSetName::TenacityOfTheMillelith,
+        ["훈장의 꽃", "소무의 깃털", "금동 해시계", "맹세의 금잔", "장수의 투구"],
+    ),
+    (
+        ArtifactSetName::EmblemOfSeveredFate,
+        ["명위의 코등이", "베어진 깃털", "뇌운의 함", "비화의 항아리", "화려한 투구"],
+    ),
+    (
+        ArtifactSetName::ShimenawaReminiscence,
+        ["인연의 꽃", "추억의 화살", "아침 이슬의 시간", "소망의 마음", "무상의 가면"],
+    ),
+    // 2.1版本套装
+    (
+        ArtifactSetName::HuskOfOpulentDreams,
+        ["꽃피는 시절", "화관의 깃털", "중생의 노래", "꿈에서 깬 표주박", "형해의 삿갓"],
+    ),
+    (
+        ArtifactSetName::OceanHuedClam,
+        ["바다에 물든 꽃", "심해 궁전의 깃털", "이별의 조개", "진주 바구니", "와타츠미의 관"],
+    ),
+    // 2.6版本套装
+    (
+        ArtifactSetName::VermillionHereafter,
+        ["생령의 꽃", "숨은 빛의 깃털", "양비의 유물", "결계의 시간", "훼뢰의 자태"],
+    ),
+    (
+        ArtifactSetName::EchoesOfAnOffering,
+        ["혼향의 꽃", "수옥의 잎", "축사의 의지", "용천의 잔", "부소의 패옥"],
+    ),
+    // 3.0版本套装
+    (
+        ArtifactSetName::DeepwoodMemories,
+        ["미궁의 나그네", "덩굴의 현자", "현자의 정기", "길 잃은 자의 등불", "월계수 왕관"],
+    ),
+    (
+        ArtifactSetName::GildedDreams,
+        [
+            "꿈속의 철꽃",
+            "재단된 깃털",
+            "가라앉은 금의 세월",
+            "꿀 같은 마지막 연회",
+            "사막왕의 투영",
+        ],
+    ),
+    // 3.2版本套装
+    (
+        ArtifactSetName::FlowerOfParadiseLost,
+        ["월녀의 화려함", "떨어진 연회", "응결된 시간", "비밀을 지키는 마법병", "자수정 화관"],
+    ),
+    (
+        ArtifactSetName::DesertPavilionChronicle,
+        [
+            "뭇 왕들의 도시의 시작",
+            "황금 왕국의 결말",
+            "잃어버린 길의 무브먼트",
+            "긴 꿈의 수호",
+            "유사 귀족의 유물",
+        ],
+    ),
+    // 3.6版本套装
+    (
+        ArtifactSetName::NymphsDream,
+        [
+            "여정 중의 꽃",
+            "나쁜 마법사의 깃털 지팡이",
+            "나르키소스의 시시각각",
+            "용사들의 다과회",
+            "악룡의 외눈 안경",
+        ],
+    ),
+    (
+        ArtifactSetName::VourukashasGlow,
+        [
+            "영광의 근원의 꽃술",
+            "기이한 색의 깃털",
+            "오래전 꽃이 진 시간",
+            "끝없는 즐거움의 연회",
+            "영광으로 빛나는 마음",
+        ],
+    ),
+    // 4.0版本套装
+    (
+        ArtifactSetName::MarechausseeHunter,
+        ["사냥꾼의 브로치", "걸작의 서곡", "심판의 시간", "잊힌 그릇", "노병의 용모"],
+    ),
+    (
+        ArtifactSetName::GoldenTroupe,
+        [
+            "황금 악곡의 변주",
+            "황금 새의 깃털",
+            "황금시대의 전조",
+            "황금 밤의 떠들썩함",
+            "황금 극단의 포상",
+        ],
+    ),
+    // 4.3版本套装
+    (
+        ArtifactSetName::SongOfDaysPast,
+        [
+            "옛날에 잃어버린 맹세",
+            "옛날의 떠오르는 생각",
+            "옛날에 되비친 소리",
+            "옛날에 약속된 꿈",
+            "옛날에 전해진 시",
+        ],
+    ),
+    (
+        ArtifactSetName::NighttimeWhispersInTheEchoingWoods,
+        [
+            "사심 없는 장식 꽃",
+            "성실한 펜",
+            "충실한 모래시계",
+            "관대한 잉크병",
+            "자애로운 숙녀 모자",
+        ],
+    ),
+    // 4.6版本套装
+    (
+        ArtifactSetName::FragmentOfHarmonicWhimsy,
+        [
+            "해율 교향의 서곡",
+            "고해의 밤 상념",
+            "운명 윤회의 해학",
+            "영로를 쏟는 광시",
+            "공상이 흩어진 원무",
+        ],
+    ),
+    (
+        ArtifactSetName::UnfinishedReverie,
+        ["숨겨진 밝은 꽃", "빛바랜 비취 꼬리", "과업의 시간", "계략의 술잔", "잃어버린 왕관"],
+    ),
+    // 5.0版本套装
+    (
+        ArtifactSetName::ScrollOfTheHeroOfCinderCity,
+        [
+            "조련사의 부적",
+            "산 순찰자의 신호기",
+            "비술가의 금접시",
+            "유학자의 발톱 잔",
+            "마전사의 깃털 가면",
+        ],
+    ),
+    (
+        ArtifactSetName::ObsidianCodex,
+        ["이종의 기대", "영수의 뿌리", "밤의 영역의 미혹", "분쟁의 전야 연회", "성인들의 예관"],
+    ),
+    // 5.5版本套装
+    (
+        ArtifactSetName::LongNightsOath,
+        [
+            "등불지기의 맹세",
+            "밤꾀꼬리의 꼬리깃",
+            "불사자의 애도 종",
+            "울리지 않은 호각",
+            "물든 투구",
+        ],
+    ),
+    (
+        ArtifactSetName::FinaleOfTheDeepGalleries,
+        [
+            "깊은 회랑의 회주곡",
+            "깊은 회랑의 먼 약속",
+            "깊은 회랑의 침몰한 시간",
+            "깊은 회랑의 하사받은 연회",
+            "깊은 회랑의 잃어버린 왕관",
+        ],
+    ),
+];
+
+/// 祭礼系列套装只有理之冠
+const KO_PRAYERS_HEADS: [(ArtifactSetName, &str); 4] = [
+    (ArtifactSetName::PrayersForWisdom, "제뢰의 관"),
+    (ArtifactSetName::PrayersToSpringtime, "제빙의 관"),
+    (ArtifactSetName::PrayersForIllumination, "제화의 관"),
+    (ArtifactSetName::PrayersForDestiny, "제수의 관"),
+];
+
+/// 表中每件圣遗物的部位，与 [`KO_SET_PIECES`] 中名称的顺序对应
+const KO_PIECE_SLOTS: [ArtifactSlot; 5] = [
+    ArtifactSlot::Flower,
+    ArtifactSlot::Feather,
+    ArtifactSlot::Sand,
+    ArtifactSlot::Goblet,
+    ArtifactSlot::Head,
+];
+
+/// 在韩语名称表中查找圣遗物名称，返回所属套装和部位
+fn find_ko(s: &str) -> Option<(ArtifactSetName, ArtifactSlot)> {
+    for (set_name, pieces) in KO_SET_PIECES.iter() {
+        if let Some(index) = pieces.iter().position(|&piece| piece == s) {
+            return Some((set_name.clone(), KO_PIECE_SLOTS[index].clone()));
+        }
+    }
+    KO_PRAYERS_HEADS
+        .iter()
+        .find(|(_, head)| *head == s)
+        .map(|(set_name, _)| (set_name.clone(), ArtifactSlot::Head))
+}
+
+impl ArtifactSetName {
+    /// 韩语客户端的圣遗物名称对应的套装，未收录的名称返回 `None`
+    pub fn from_ko(s: &str) -> Option<ArtifactSetName> {
+        find_ko(s).map(|(set_name, _)| set_name)
+    }
+}
+
+impl ArtifactSlot {
+    /// 韩语客户端的圣遗物名称对应的部位，与 [`ArtifactSetName::from_ko`] 使用同一张名称表
+    pub fn from_ko(s: &str) -> Option<ArtifactSlot> {
+        find_ko(s).map(|(_, slot)| slot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn test_from_ko() {
+        assert_eq!(
+            ArtifactSetName::from_ko("검투사의 향수"),
+            Some(ArtifactSetName::GladiatorFinale)
+        );
+        assert_eq!(ArtifactSlot::from_ko("검투사의 향수"), Some(ArtifactSlot::Flower));
+        assert_eq!(
+            ArtifactSetName::from_ko("명위의 코등이"),
+            Some(ArtifactSetName::EmblemOfSeveredFate)
+        );
+        assert_eq!(ArtifactSlot::from_ko("무상의 가면"), Some(ArtifactSlot::Head));
+        assert_eq!(ArtifactSetName::from_ko("제수의 관"), Some(ArtifactSetName::PrayersForDestiny));
+        assert_eq!(ArtifactSlot::from_ko("제수의 관"), Some(ArtifactSlot::Head));
+
+        // 测试无效输入
+        assert_eq!(ArtifactSetName::from_ko("魔女的炎之花"), None);
+        assert_eq!(ArtifactSlot::from_ko("알 수 없음"), None);
+    }
+
+    #[test]
+    fn test_ko_table_covers_every_set() {
+        // 与中文名称表一样收录全部套装，每件圣遗物的名称互不相同
+        let covered: HashSet<ArtifactSetName> = KO_SET_PIECES
+            .iter()
+            .map(|(set_name, _)| set_name.clone())
+            .chain(KO_PRAYERS_HEADS.iter().map(|(set_name, _)| set_name.clone()))
+            .collect();
+        for set_name in ArtifactSetName::iter() {
+            assert!(covered.contains(&set_name), "缺少套装 {set_name}");
+        }
+
+        let mut names = HashSet::new();
+        for (set_name, pieces) in KO_SET_PIECES.iter() {
+            for (piece, slot) in pieces.iter().zip(KO_PIECE_SLOTS.iter()) {
+                assert!(names.insert(*piece), "重复的名称 {piece}");
+                assert_eq!(ArtifactSetName::from_ko(piece).as_ref(), Some(set_name));
+                assert_eq!(ArtifactSlot::from_ko(piece).as_ref(), Some(slot));
+            }
+        }
+        for (set_name, head) in KO_PRAYERS_HEADS.iter() {
+            assert!(names.insert(*head), "重复的名称 {head}");
+            assert_eq!(ArtifactSetName::from_ko(head).as_ref(), Some(set_name));
+        }
+    }
+}
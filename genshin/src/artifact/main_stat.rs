@@ -0,0 +1,119 @@
+use crate::artifact::{ArtifactStatName, GenshinArtifact};
+
+/// 5星圣遗物主属性在 0 级和 20 级时的数值（百分比属性以小数表示）
+///
+/// 主属性随等级线性增长，中间等级的数值由这两个端点插值得到，
+/// 与游戏内显示的数值误差在显示精度以内
+fn main_stat_range_5_star(name: &ArtifactStatName) -> (f64, f64) {
+    match name {
+        ArtifactStatName::Hp => (717.0, 4780.0),
+        ArtifactStatName::Atk => (47.0, 311.0),
+        ArtifactStatName::HpPercentage | ArtifactStatName::AtkPercentage => (0.070, 0.466),
+        ArtifactStatName::DefPercentage | ArtifactStatName::PhysicalBonus => (0.087, 0.583),
+        ArtifactStatName::ElementalMastery => (28.0, 186.5),
+        ArtifactStatName::Recharge => (0.078, 0.518),
+        ArtifactStatName::Critical => (0.047, 0.311),
+        ArtifactStatName::CriticalDamage => (0.093, 0.622),
+        ArtifactStatName::HealingBonus => (0.054, 0.359),
+        ArtifactStatName::PyroBonus
+        | ArtifactStatName::HydroBonus
+        | ArtifactStatName::ElectroBonus
+        | ArtifactStatName::CryoBonus
+        | ArtifactStatName::AnemoBonus
+        | ArtifactStatName::GeoBonus
+        | ArtifactStatName::DendroBonus => (0.070, 0.466),
+        // 防御力固定值不会作为主属性出现
+        ArtifactStatName::Def => (0.0, 0.0),
+    }
+}
+
+/// 允许的误差：百分比属性 0.3 个百分点，数值属性为期望值的 1% 加 1
+fn main_stat_tolerance(name: &ArtifactStatName, expected: f64) -> f64 {
+    match name {
+        ArtifactStatName::Hp | ArtifactStatName::Atk | ArtifactStatName::ElementalMastery => {
+            expected * 0.01 + 1.0
+        },
+        _ => 0.003,
+    }
+}
+
+impl GenshinArtifact {
+    /// 根据星级和等级计算主属性的期望数值，目前仅支持5星圣遗物
+    pub fn expected_main_stat_value(&self) -> Option<f64> {
+        if self.star != 5 || !(0..=20).contains(&self.level) {
+            return None;
+        }
+
+        let (base, max) = main_stat_range_5_star(&self.main_stat.name);
+        if max == 0.0 {
+            return None;
+        }
+        Some(base + (max - base) * self.level as f64 / 20.0)
+    }
+
+    /// 检查主属性数值是否与星级、等级对应的标准数值一致
+    ///
+    /// 没有对应数值表（非5星、等级越界或不可能的主属性）时不做判断，返回 true
+    pub fn validate_main_stat(&self) -> bool {
+        match self.expected_main_stat_value() {
+            None => true,
+            Some(expected) => {
+                let tolerance = main_stat_tolerance(&self.main_stat.name, expected);
+                (self.main_stat.value - expected).abs() <= tolerance
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact::{ArtifactSetName, ArtifactSlot, ArtifactStat};
+
+    fn artifact(name: ArtifactStatName, value: f64, level: i32, star: i32) -> GenshinArtifact {
+        GenshinArtifact {
+            set_name: ArtifactSetName::EmblemOfSeveredFate,
+            slot: ArtifactSlot::Sand,
+            star,
+            lock: false,
+            level,
+            main_stat: ArtifactStat { name, value },
+            sub_stat_1: None,
+            sub_stat_2: None,
+            sub_stat_3: None,
+            sub_stat_4: None,
+            equip: None,
+        }
+    }
+
+    #[test]
+    fn test_correct_values_pass() {
+        assert!(artifact(ArtifactStatName::AtkPercentage, 0.466, 20, 5).validate_main_stat());
+        assert!(artifact(ArtifactStatName::AtkPercentage, 0.070, 0, 5).validate_main_stat());
+        assert!(artifact(ArtifactStatName::HpPercentage, 0.228, 8, 5).validate_main_stat());
+        assert!(artifact(ArtifactStatName::Recharge, 0.518, 20, 5).validate_main_stat());
+        assert!(artifact(ArtifactStatName::ElementalMastery, 187.0, 20, 5).validate_main_stat());
+        assert!(artifact(ArtifactStatName::Critical, 0.311, 20, 5).validate_main_stat());
+        assert!(artifact(ArtifactStatName::CriticalDamage, 0.622, 20, 5).validate_main_stat());
+        assert!(artifact(ArtifactStatName::Hp, 4780.0, 20, 5).validate_main_stat());
+    }
+
+    #[test]
+    fn test_off_by_ten_percent_fails() {
+        assert!(!artifact(ArtifactStatName::AtkPercentage, 0.466 * 0.9, 20, 5).validate_main_stat());
+        assert!(!artifact(ArtifactStatName::AtkPercentage, 0.40, 20, 5).validate_main_stat());
+        assert!(
+            !artifact(ArtifactStatName::ElementalMastery, 187.0 * 0.9, 20, 5).validate_main_stat()
+        );
+    }
+
+    #[test]
+    fn test_unknown_tables_are_not_judged() {
+        // 4星圣遗物暂无数值表
+        assert!(artifact(ArtifactStatName::AtkPercentage, 0.1, 20, 4).validate_main_stat());
+        assert_eq!(
+            artifact(ArtifactStatName::AtkPercentage, 0.1, 20, 4).expected_main_stat_value(),
+            None
+        );
+    }
+}
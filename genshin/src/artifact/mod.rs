@@ -1,7 +1,23 @@
 pub use artifact::{
     ArtifactSetName, ArtifactSlot, ArtifactStat, ArtifactStatName, GenshinArtifact,
 };
+pub use conversion_error::{ArtifactConversionError, ArtifactField};
+pub use equip_group::{group_by_equip, EquipGroup};
+pub use parse_panel::parse_panel;
+pub use set_bonus::{count_set_pieces, is_complete_set};
+pub use stat_name_matcher::{
+    snap_stat_name, snap_stat_text, MAX_STAT_NAME_DISTANCE, ZH_CN_STAT_NAMES,
+};
 
 #[allow(clippy::module_inception)]
 mod artifact;
+mod conversion_error;
+mod crit_value;
+mod equip_group;
+mod ko;
+mod main_stat;
+mod parse_panel;
+mod set_bonus;
+mod stat_name_matcher;
+mod sub_stat_count;
 mod zh_cn;
@@ -0,0 +1,52 @@
+use anyhow::{anyhow, Context, Result};
+use furina_core::game_info::{Platform, UI};
+use furina_core::ocr::ImageToText;
+use furina_core::positioning::Size;
+use furina_core::window_info::FromWindowInfoRepository;
+use image::RgbImage;
+
+use crate::application::ArtifactScannerApplication;
+use crate::artifact::GenshinArtifact;
+use crate::locale::Locale;
+use crate::scanner::{
+    ArtifactScannerWindowInfo, ArtifactScannerWorker, GenshinArtifactScannerConfig,
+};
+
+/// 从一张圣遗物面板截图（PNG 等 `image` 支持的格式）识别圣遗物，不需要游戏窗口，适合服务端或嵌入使用
+///
+/// - `window_size`: 截图时的游戏窗口大小，用于选择内置窗口信息（不加载用户目录中的覆盖配置），
+///   面板截图的尺寸需与该窗口大小下的面板区域一致
+/// - `model`: 识别使用的OCR模型
+///
+/// 识别在当前线程中进行，不会为超时额外创建线程；单张截图中没有背包列表，锁定状态视为未锁定
+pub fn parse_panel<M>(
+    panel_png: &[u8],
+    window_size: (u32, u32),
+    locale: Locale,
+    model: M,
+) -> Result<GenshinArtifact>
+where
+    M: ImageToText<RgbImage> + Send + 'static,
+{
+    if !locale.is_supported() {
+        return Err(anyhow!("暂不支持{locale}客户端，目前仅支持简体中文"));
+    }
+
+    let panel_image = image::load_from_memory(panel_png).context("面板截图解码失败")?.to_rgb8();
+
+    let repo = ArtifactScannerApplication::builtin_window_info_repository();
+    let window_info = ArtifactScannerWindowInfo::from_window_info_repository(
+        Size::new(window_size.0 as usize, window_size.1 as usize),
+        UI::Desktop,
+        Platform::Windows,
+        &repo,
+    )?;
+
+    let config = GenshinArtifactScannerConfig { locale, ocr_timeout_ms: 0, ..Default::default() };
+    let mut worker =
+        ArtifactScannerWorker::with_model(Box::new(model), window_info, config, window_size);
+    let result = worker.scan_panel_image(panel_image)?;
+
+    GenshinArtifact::try_from_locale(&result, locale, false)
+        .map_err(|error| anyhow!("无法将识别结果转换为圣遗物: {error}"))
+}
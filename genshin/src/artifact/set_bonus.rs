@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+
+use crate::artifact::{ArtifactSetName, GenshinArtifact};
+
+impl ArtifactSetName {
+    /// 套装效果表，每项为（所需件数，效果描述），按件数升序排列
+    ///
+    /// 目前只收录常用套装，未收录的套装返回空表，后续逐步补充
+    pub fn piece_count_bonuses(&self) -> &'static [(u8, &'static str)] {
+        match self {
+            ArtifactSetName::EmblemOfSeveredFate => &[
+                (2, "元素充能效率提高20%"),
+                (4, "基于元素充能效率的25%，提高元素爆发造成的伤害，至多通过这种方式获得75%提升"),
+            ],
+            ArtifactSetName::GildedDreams => &[
+                (2, "元素精通提高80点"),
+                (
+                    4,
+                    "触发元素反应后的8秒内，队伍中每有一名元素类型与装备者相同的角色，攻击力提高14%；\
+                     每有一名元素类型不同的角色，元素精通提高50点。上述效果各至多计入3名角色，\
+                     每8秒至多触发一次，装备者处于队伍后台时也能触发",
+                ),
+            ],
+            ArtifactSetName::CrimsonWitch => &[
+                (2, "获得15%火元素伤害加成"),
+                (
+                    4,
+                    "超载、燃烧、烈绽放反应造成的伤害提升40%，蒸发、融化反应的加成系数提高15%。\
+                     施放元素战技后的10秒内，2件套的效果提高50%，该效果最多叠加3次",
+                ),
+            ],
+            ArtifactSetName::ViridescentVenerer => &[
+                (2, "获得15%风元素伤害加成"),
+                (
+                    4,
+                    "扩散反应造成的伤害提升60%。根据扩散的元素类型，降低受到影响的敌人40%的对应元素抗性，持续10秒",
+                ),
+            ],
+            ArtifactSetName::NoblesseOblige => &[
+                (2, "元素爆发造成的伤害提升20%"),
+                (4, "施放元素爆发后，队伍中所有角色攻击力提升20%，持续12秒，该效果不可叠加"),
+            ],
+            ArtifactSetName::BlizzardStrayer => &[
+                (2, "获得15%冰元素伤害加成"),
+                (4, "攻击处于冰元素影响下的敌人时，暴击率提高20%；若敌人处于冻结状态，则暴击率额外提高20%"),
+            ],
+            ArtifactSetName::DeepwoodMemories => &[
+                (2, "获得15%草元素伤害加成"),
+                (
+                    4,
+                    "元素战技或元素爆发命中敌人后，使命中目标的草元素抗性降低30%，持续8秒，装备者处于队伍后台时也能触发",
+                ),
+            ],
+            ArtifactSetName::TenacityOfTheMillelith => &[
+                (2, "生命值提升20%"),
+                (
+                    4,
+                    "元素战技命中敌人后，使队伍中附近的所有角色攻击力提升20%，护盾强效提升30%，持续3秒，\
+                     该效果每0.5秒至多触发一次，装备者处于队伍后台时也能触发",
+                ),
+            ],
+            ArtifactSetName::GladiatorFinale => &[
+                (2, "攻击力提高18%"),
+                (4, "装备该圣遗物套装的角色为单手剑、双手剑、长柄武器角色时，角色普通攻击造成的伤害提高35%"),
+            ],
+            ArtifactSetName::ShimenawaReminiscence => &[
+                (2, "攻击力提高18%"),
+                (
+                    4,
+                    "施放元素战技时，如果角色的元素能量高于或等于15点，则会流失15点元素能量，\
+                     使接下来的10秒内，普通攻击、重击及下落攻击造成的伤害提高50%，持续期间内该效果不会再次触发",
+                ),
+            ],
+            ArtifactSetName::MarechausseeHunter => &[
+                (2, "普通攻击与重击造成的伤害提高15%"),
+                (4, "当前生命值提升或降低时，暴击率提升12%，持续5秒，该效果至多叠加3次"),
+            ],
+            ArtifactSetName::GoldenTroupe => &[
+                (2, "元素战技造成的伤害提升20%"),
+                (
+                    4,
+                    "元素战技造成的伤害提升25%；此外，装备者处于队伍后台时，元素战技造成的伤害还会额外提升25%，\
+                     登场后2秒移除该效果",
+                ),
+            ],
+            _ => &[],
+        }
+    }
+
+    /// 触发全部套装效果所需的件数，未收录效果的套装按 4 件计算
+    pub fn full_set_piece_count(&self) -> usize {
+        self.piece_count_bonuses().last().map_or(4, |(count, _)| *count as usize)
+    }
+}
+
+/// 统计属于套装 `set` 的圣遗物件数，同一部位只计一次
+pub fn count_set_pieces<'a>(
+    artifacts: impl IntoIterator<Item = &'a GenshinArtifact>,
+    set: &ArtifactSetName,
+) -> usize {
+    artifacts
+        .into_iter()
+        .filter(|artifact| &artifact.set_name == set)
+        .map(|artifact| &artifact.slot)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// 是否集齐了触发套装全部效果所需的件数
+pub fn is_complete_set<'a>(
+    artifacts: impl IntoIterator<Item = &'a GenshinArtifact>,
+    set: &ArtifactSetName,
+) -> bool {
+    count_set_pieces(artifacts, set) >= set.full_set_piece_count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact::{ArtifactSlot, ArtifactStat, ArtifactStatName};
+
+    fn artifact(set_name: ArtifactSetName, slot: ArtifactSlot) -> GenshinArtifact {
+        GenshinArtifact {
+            set_name,
+            slot,
+            star: 5,
+            lock: false,
+            level: 20,
+            main_stat: ArtifactStat { name: ArtifactStatName::Hp, value: 4780.0 },
+            sub_stat_1: None,
+            sub_stat_2: None,
+            sub_stat_3: None,
+            sub_stat_4: None,
+            equip: Some("雷电将军".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_piece_count_bonuses() {
+        let emblem = ArtifactSetName::EmblemOfSeveredFate.piece_count_bonuses();
+        assert_eq!(emblem.iter().map(|(count, _)| *count).collect::<Vec<_>>(), vec![2, 4]);
+        assert_eq!(emblem[0].1, "元素充能效率提高20%");
+        assert!(emblem[1].1.contains("元素爆发"));
+
+        let gilded = ArtifactSetName::GildedDreams.piece_count_bonuses();
+        assert_eq!(gilded.iter().map(|(count, _)| *count).collect::<Vec<_>>(), vec![2, 4]);
+        assert_eq!(gilded[0].1, "元素精通提高80点");
+        assert!(gilded[1].1.contains("元素精通提高50点"));
+
+        assert!(ArtifactSetName::Adventurer.piece_count_bonuses().is_empty());
+        assert_eq!(ArtifactSetName::Adventurer.full_set_piece_count(), 4);
+    }
+
+    #[test]
+    fn test_count_set_pieces() {
+        let emblem = ArtifactSetName::EmblemOfSeveredFate;
+        let artifacts = vec![
+            artifact(emblem.clone(), ArtifactSlot::Flower),
+            artifact(emblem.clone(), ArtifactSlot::Feather),
+            artifact(emblem.clone(), ArtifactSlot::Sand),
+            artifact(ArtifactSetName::GildedDreams, ArtifactSlot::Goblet),
+            artifact(emblem.clone(), ArtifactSlot::Head),
+        ];
+        assert_eq!(count_set_pieces(&artifacts, &emblem), 4);
+        assert_eq!(count_set_pieces(&artifacts, &ArtifactSetName::GildedDreams), 1);
+        assert!(is_complete_set(&artifacts, &emblem));
+        assert!(!is_complete_set(&artifacts, &ArtifactSetName::GildedDreams));
+
+        // 同一部位的重复圣遗物只计一次
+        let duplicated = [
+            artifact(emblem.clone(), ArtifactSlot::Flower),
+            artifact(emblem.clone(), ArtifactSlot::Flower),
+            artifact(emblem.clone(), ArtifactSlot::Feather),
+            artifact(emblem.clone(), ArtifactSlot::Sand),
+        ];
+        assert_eq!(count_set_pieces(&duplicated, &emblem), 3);
+        assert!(!is_complete_set(&duplicated, &emblem));
+    }
+}
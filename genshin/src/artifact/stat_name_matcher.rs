@@ -0,0 +1,121 @@
+use log::info;
+
+use crate::artifact::ArtifactStatName;
+use crate::character::edit_distance;
+
+/// 属性名称纠正允许的最大编辑距离
+pub const MAX_STAT_NAME_DISTANCE: usize = 1;
+
+/// 属性名称纠正的候选，即 [`ArtifactStatName::from_zh_cn`] 能识别的完整属性名称
+pub const ZH_CN_STAT_NAMES: [&str; 16] = [
+    "治疗加成",
+    "暴击伤害",
+    "暴击率",
+    "攻击力",
+    "元素精通",
+    "元素充能效率",
+    "生命值",
+    "防御力",
+    "雷元素伤害加成",
+    "火元素伤害加成",
+    "水元素伤害加成",
+    "冰元素伤害加成",
+    "风元素伤害加成",
+    "岩元素伤害加成",
+    "草元素伤害加成",
+    "物理伤害加成",
+];
+
+/// 将识别出的属性名称纠正为 [`ZH_CN_STAT_NAMES`] 中最接近的名称
+///
+/// 已能识别的名称返回 `None`（无需纠正）；否则返回编辑距离不超过 [`MAX_STAT_NAME_DISTANCE`]
+/// 的最接近名称。若有多个名称同样接近（例如无法确定是哪种元素的伤害加成），视为无法确定并返回 `None`
+pub fn snap_stat_name(name: &str) -> Option<&'static str> {
+    if name.is_empty() || ArtifactStatName::from_zh_cn(name, false).is_some() {
+        return None;
+    }
+
+    let mut best: Option<(&'static str, usize)> = None;
+    let mut ambiguous = false;
+    for &candidate in ZH_CN_STAT_NAMES.iter() {
+        let distance = edit_distance(name, candidate);
+        if distance > MAX_STAT_NAME_DISTANCE {
+            continue;
+        }
+        match best {
+            Some((_, best_distance)) if distance > best_distance => {},
+            Some((_, best_distance)) if distance == best_distance => ambiguous = true,
+            _ => {
+                best = Some((candidate, distance));
+                ambiguous = false;
+            },
+        }
+    }
+
+    match best {
+        Some((candidate, _)) if !ambiguous => Some(candidate),
+        _ => None,
+    }
+}
+
+/// 对一条属性文本（主属性名称，或 `暴击率+3.9%` 形式的副属性）中的属性名称进行纠正
+///
+/// 只替换 `+` 之前的名称部分，数值保持不变；无法纠正时原样返回
+pub fn snap_stat_text(text: &str) -> String {
+    let (name, rest) = match text.find('+') {
+        Some(pos) => text.split_at(pos),
+        None => (text, ""),
+    };
+
+    match snap_stat_name(name.trim()) {
+        Some(snapped) => {
+            let fixed = format!("{snapped}{rest}");
+            info!("🔧 属性名称修正: {text} -> {fixed}");
+            fixed
+        },
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact::ArtifactStat;
+
+    #[test]
+    fn test_one_char_off_snaps() {
+        assert_eq!(snap_stat_name("暴击宰"), Some("暴击率"));
+        assert_eq!(snap_stat_name("元素充能效"), Some("元素充能效率"));
+        assert_eq!(snap_stat_name("物理伤害加戍"), Some("物理伤害加成"));
+
+        let fixed = snap_stat_text("暴击伤窖+7.8%");
+        assert_eq!(fixed, "暴击伤害+7.8%");
+        assert_eq!(
+            ArtifactStat::from_zh_cn_raw(&fixed),
+            Some(ArtifactStat { name: ArtifactStatName::CriticalDamage, value: 0.078 })
+        );
+    }
+
+    #[test]
+    fn test_known_and_distant_names_unchanged() {
+        // 已能识别的名称不需要纠正
+        assert_eq!(snap_stat_name("暴击率"), None);
+        assert_eq!(snap_stat_name("暴击伤"), None);
+        assert_eq!(snap_stat_text("攻击力+5.8%"), "攻击力+5.8%");
+
+        // 相差过多的文本仍然丢弃
+        assert_eq!(snap_stat_name("未识别"), None);
+        assert_eq!(snap_stat_name("暴伤宰"), None);
+        assert_eq!(snap_stat_text("乱码文本+3.9%"), "乱码文本+3.9%");
+        assert_eq!(ArtifactStat::from_zh_cn_raw(&snap_stat_text("乱码文本+3.9%")), None);
+        assert_eq!(snap_stat_text(""), "");
+    }
+
+    #[test]
+    fn test_ambiguous_element_not_snapped() {
+        // 元素名称识别错误时无法确定是哪种元素
+        assert_eq!(snap_stat_name("X元素伤害加成"), None);
+        // 元素名称正确、其他字识别错误时可以纠正
+        assert_eq!(snap_stat_name("火元素伤害加戍"), Some("火元素伤害加成"));
+    }
+}
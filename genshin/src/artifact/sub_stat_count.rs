@@ -0,0 +1,96 @@
+use std::ops::RangeInclusive;
+
+use crate::artifact::GenshinArtifact;
+
+/// 每强化多少级获得一次副属性强化（词条不足4条时为新增词条）
+const UPGRADE_INTERVAL: i32 = 4;
+
+impl GenshinArtifact {
+    /// 已识别出的副属性数量
+    pub fn sub_stat_count(&self) -> usize {
+        [&self.sub_stat_1, &self.sub_stat_2, &self.sub_stat_3, &self.sub_stat_4]
+            .into_iter()
+            .filter(|stat| stat.is_some())
+            .count()
+    }
+
+    /// 根据星级和等级计算副属性数量的合理范围
+    ///
+    /// 3-5星圣遗物初始有 星级-2 或 星级-1 条副属性，每 4 级新增一条直至 4 条；
+    /// 其他星级或等级越界时返回 `None`
+    pub fn expected_sub_stat_count(&self) -> Option<RangeInclusive<usize>> {
+        if !(3..=5).contains(&self.star) || !(0..=20).contains(&self.level) {
+            return None;
+        }
+
+        let upgrades = self.level / UPGRADE_INTERVAL;
+        let min = (self.star - 2 + upgrades).min(4) as usize;
+        let max = (self.star - 1 + upgrades).min(4) as usize;
+        Some(min..=max)
+    }
+
+    /// 检查副属性数量是否与星级、等级相符
+    ///
+    /// 没有对应范围时不做判断，返回 true
+    pub fn validate_sub_stat_count(&self) -> bool {
+        self.expected_sub_stat_count().map_or(true, |range| range.contains(&self.sub_stat_count()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::artifact::{
+        ArtifactSetName, ArtifactSlot, ArtifactStat, ArtifactStatName, GenshinArtifact,
+    };
+
+    fn artifact(star: i32, level: i32, sub_stat_count: usize) -> GenshinArtifact {
+        let stat = || Some(ArtifactStat { name: ArtifactStatName::Critical, value: 0.039 });
+        let mut sub_stats = [None, None, None, None];
+        for sub_stat in sub_stats.iter_mut().take(sub_stat_count) {
+            *sub_stat = stat();
+        }
+        let [sub_stat_1, sub_stat_2, sub_stat_3, sub_stat_4] = sub_stats;
+        GenshinArtifact {
+            set_name: ArtifactSetName::GildedDreams,
+            slot: ArtifactSlot::Flower,
+            star,
+            lock: false,
+            level,
+            main_stat: ArtifactStat { name: ArtifactStatName::Hp, value: 717.0 },
+            sub_stat_1,
+            sub_stat_2,
+            sub_stat_3,
+            sub_stat_4,
+            equip: None,
+        }
+    }
+
+    #[test]
+    fn test_level_0_five_star() {
+        assert!(artifact(5, 0, 4).validate_sub_stat_count());
+        assert!(artifact(5, 0, 3).validate_sub_stat_count());
+        assert!(!artifact(5, 0, 2).validate_sub_stat_count());
+        assert_eq!(artifact(5, 0, 4).expected_sub_stat_count(), Some(3..=4));
+    }
+
+    #[test]
+    fn test_level_20_with_two_sub_stats_is_invalid() {
+        assert!(!artifact(5, 20, 2).validate_sub_stat_count());
+        assert!(artifact(5, 20, 4).validate_sub_stat_count());
+        assert_eq!(artifact(5, 20, 2).expected_sub_stat_count(), Some(4..=4));
+    }
+
+    #[test]
+    fn test_four_star_upgrades() {
+        assert_eq!(artifact(4, 0, 2).expected_sub_stat_count(), Some(2..=3));
+        assert_eq!(artifact(4, 7, 2).expected_sub_stat_count(), Some(3..=4));
+        assert!(!artifact(4, 8, 3).validate_sub_stat_count());
+        assert!(artifact(4, 16, 4).validate_sub_stat_count());
+    }
+
+    #[test]
+    fn test_unknown_stars_are_not_judged() {
+        assert_eq!(artifact(2, 4, 0).expected_sub_stat_count(), None);
+        assert!(artifact(1, 0, 4).validate_sub_stat_count());
+    }
+}
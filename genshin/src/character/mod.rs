@@ -1,3 +1,9 @@
 pub use character_names::CHARACTER_NAMES;
+pub use name_matcher::{edit_distance, match_character_name, MAX_FUZZY_DISTANCE};
+pub use recommended_main_stats::{
+    is_recommended_main_stat, RecommendedMainStats, RECOMMENDED_MAIN_STATS,
+};
 
 mod character_names;
+mod name_matcher;
+mod recommended_main_stats;
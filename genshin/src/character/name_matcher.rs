@@ -0,0 +1,87 @@
+use crate::character::CHARACTER_NAMES;
+
+/// 模糊匹配允许的最大编辑距离
+pub const MAX_FUZZY_DISTANCE: usize = 1;
+
+/// 按字符（而非字节）计算两个字符串的编辑距离
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        cur[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
+
+/// 将识别出的角色名称匹配到 `CHARACTER_NAMES` 中的有效名称
+///
+/// 精确匹配时直接返回；开启 `fuzzy` 时，返回编辑距离不超过 [`MAX_FUZZY_DISTANCE`] 的最接近名称。
+/// 若有多个名称同样接近（例如单字名称），视为无法确定并返回 `None`
+pub fn match_character_name(name: &str, fuzzy: bool) -> Option<&'static str> {
+    if let Some(&exact) = CHARACTER_NAMES.get(name) {
+        return Some(exact);
+    }
+    if !fuzzy || name.is_empty() {
+        return None;
+    }
+
+    let mut best: Option<&'static str> = None;
+    let mut ambiguous = false;
+    for &candidate in CHARACTER_NAMES.iter() {
+        if edit_distance(name, candidate) > MAX_FUZZY_DISTANCE {
+            continue;
+        }
+        if best.is_some() {
+            ambiguous = true;
+        }
+        best = Some(candidate);
+    }
+
+    if ambiguous {
+        None
+    } else {
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("胡桃", "胡桃"), 0);
+        assert_eq!(edit_distance("胡林", "胡桃"), 1);
+        assert_eq!(edit_distance("枫原万", "枫原万叶"), 1);
+        assert_eq!(edit_distance("", "琴"), 1);
+        assert_eq!(edit_distance("甘雨", "钟离"), 2);
+    }
+
+    #[test]
+    fn test_exact_match() {
+        assert_eq!(match_character_name("胡桃", false), Some("胡桃"));
+        assert_eq!(match_character_name("胡桃", true), Some("胡桃"));
+    }
+
+    #[test]
+    fn test_one_edit_correction() {
+        assert_eq!(match_character_name("胡林", true), Some("胡桃"));
+        assert_eq!(match_character_name("枫原万业", true), Some("枫原万叶"));
+        // 未开启模糊匹配时保持原有行为
+        assert_eq!(match_character_name("胡林", false), None);
+    }
+
+    #[test]
+    fn test_too_distant_stays_none() {
+        assert_eq!(match_character_name("随便写的", true), None);
+        assert_eq!(match_character_name("", true), None);
+    }
+}
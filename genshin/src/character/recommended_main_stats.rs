@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+
+use crate::artifact::ArtifactStatName::*;
+use crate::artifact::{ArtifactSlot, ArtifactStatName};
+
+/// 角色在时之沙、空之杯、理之冠上常用的主属性
+pub struct RecommendedMainStats {
+    pub sands: &'static [ArtifactStatName],
+    pub goblet: &'static [ArtifactStatName],
+    pub circlet: &'static [ArtifactStatName],
+}
+
+impl RecommendedMainStats {
+    /// 部位的推荐主属性，生之花和死之羽主属性固定，返回 `None`
+    pub fn for_slot(&self, slot: &ArtifactSlot) -> Option<&'static [ArtifactStatName]> {
+        match slot {
+            ArtifactSlot::Sand => Some(self.sands),
+            ArtifactSlot::Goblet => Some(self.goblet),
+            ArtifactSlot::Head => Some(self.circlet),
+            ArtifactSlot::Flower | ArtifactSlot::Feather => None,
+        }
+    }
+}
+
+const CRIT: &[ArtifactStatName] = &[Critical, CriticalDamage];
+
+// 常见角色的推荐主属性，仅用于按角色分组输出时提示明显不合适的配装，不在表中的角色不做标注
+lazy_static! {
+    pub static ref RECOMMENDED_MAIN_STATS: HashMap<&'static str, RecommendedMainStats> = {
+        let mut map = HashMap::new();
+        map.insert(
+            "胡桃",
+            RecommendedMainStats {
+                sands: &[HpPercentage, ElementalMastery],
+                goblet: &[PyroBonus],
+                circlet: CRIT,
+            },
+        );
+        map.insert(
+            "神里绫华",
+            RecommendedMainStats {
+                sands: &[AtkPercentage],
+                goblet: &[CryoBonus],
+                circlet: &[CriticalDamage],
+            },
+        );
+        map.insert(
+            "甘雨",
+            RecommendedMainStats {
+                sands: &[AtkPercentage, ElementalMastery],
+                goblet: &[CryoBonus],
+                circlet: CRIT,
+            },
+        );
+        map.insert(
+            "宵宫",
+            RecommendedMainStats {
+                sands: &[AtkPercentage, ElementalMastery],
+                goblet: &[PyroBonus],
+                circlet: CRIT,
+            },
+        );
+        map.insert(
+            "魈",
+            RecommendedMainStats { sands: &[AtkPercentage], goblet: &[AnemoBonus], circlet: CRIT },
+        );
+        map.insert(
+            "香菱",
+            RecommendedMainStats {
+                sands: &[Recharge, ElementalMastery, AtkPercentage],
+                goblet: &[PyroBonus, ElementalMastery],
+                circlet: &[Critical, CriticalDamage, ElementalMastery],
+            },
+        );
+        map.insert(
+            "行秋",
+            RecommendedMainStats {
+                sands: &[AtkPercentage, Recharge],
+                goblet: &[HydroBonus],
+                circlet: CRIT,
+            },
+        );
+        map.insert(
+            "夜兰",
+            RecommendedMainStats {
+                sands: &[HpPercentage, Recharge],
+                goblet: &[HydroBonus, HpPercentage],
+                circlet: CRIT,
+            },
+        );
+        map.insert(
+            "那维莱特",
+            RecommendedMainStats {
+                sands: &[HpPercentage],
+                goblet: &[HydroBonus, HpPercentage],
+                circlet: CRIT,
+            },
+        );
+        map.insert(
+            "芙宁娜",
+            RecommendedMainStats {
+                sands: &[HpPercentage, Recharge],
+                goblet: &[HpPercentage, HydroBonus],
+                circlet: CRIT,
+            },
+        );
+        map.insert(
+            "钟离",
+            RecommendedMainStats {
+                sands: &[HpPercentage],
+                goblet: &[HpPercentage, GeoBonus],
+                circlet: &[HpPercentage, Critical, CriticalDamage],
+            },
+        );
+        map.insert(
+            "纳西妲",
+            RecommendedMainStats {
+                sands: &[ElementalMastery],
+                goblet: &[ElementalMastery, DendroBonus],
+                circlet: &[ElementalMastery, Critical, CriticalDamage],
+            },
+        );
+        map.insert(
+            "枫原万叶",
+            RecommendedMainStats {
+                sands: &[ElementalMastery, Recharge],
+                goblet: &[ElementalMastery],
+                circlet: &[ElementalMastery],
+            },
+        );
+        map.insert(
+            "班尼特",
+            RecommendedMainStats {
+                sands: &[Recharge, HpPercentage],
+                goblet: &[HpPercentage],
+                circlet: &[HealingBonus, HpPercentage],
+            },
+        );
+        map
+    };
+}
+
+/// 主属性是否为角色在该部位的推荐主属性
+///
+/// 角色不在 [`RECOMMENDED_MAIN_STATS`] 中，或部位为主属性固定的生之花、死之羽时返回 `None`
+pub fn is_recommended_main_stat(
+    character: &str,
+    slot: &ArtifactSlot,
+    main_stat: &ArtifactStatName,
+) -> Option<bool> {
+    let stats = RECOMMENDED_MAIN_STATS.get(character)?.for_slot(slot)?;
+    Some(stats.contains(main_stat))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::character::CHARACTER_NAMES;
+
+    #[test]
+    fn test_dps_sands() {
+        assert_eq!(
+            is_recommended_main_stat("甘雨", &ArtifactSlot::Sand, &AtkPercentage),
+            Some(true)
+        );
+        assert_eq!(
+            is_recommended_main_stat("甘雨", &ArtifactSlot::Sand, &HpPercentage),
+            Some(false)
+        );
+        assert_eq!(is_recommended_main_stat("甘雨", &ArtifactSlot::Goblet, &CryoBonus), Some(true));
+    }
+
+    #[test]
+    fn test_no_annotation() {
+        assert_eq!(is_recommended_main_stat("甘雨", &ArtifactSlot::Flower, &Hp), None);
+        assert_eq!(is_recommended_main_stat("甘雨", &ArtifactSlot::Feather, &Atk), None);
+        assert_eq!(is_recommended_main_stat("安柏", &ArtifactSlot::Sand, &AtkPercentage), None);
+        assert_eq!(is_recommended_main_stat("不存在的角色", &ArtifactSlot::Head, &Critical), None);
+    }
+
+    #[test]
+    fn test_table_uses_known_character_names() {
+        for name in RECOMMENDED_MAIN_STATS.keys() {
+            assert!(CHARACTER_NAMES.contains(name), "{name} 不在 CHARACTER_NAMES 中");
+        }
+    }
+}
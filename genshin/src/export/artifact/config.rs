@@ -1,3 +1,6 @@
+use std::path::PathBuf;
+
+use crate::artifact::GenshinArtifact;
 use crate::export::artifact::GenshinArtifactExportFormat;
 
 #[derive(clap::Args)]
@@ -8,4 +11,122 @@ pub struct ExportArtifactConfig {
 
     #[arg(id = "output-dir", long = "output-dir", short, default_value_t = String::from("."), help = "输出目录")]
     pub output_dir: String,
+
+    #[arg(
+        id = "output-name",
+        long = "output-name",
+        value_name = "TEMPLATE",
+        value_parser = parse_output_name,
+        help = "输出文件名模板（不含扩展名），支持 {format}、{date}、{count} 占位符"
+    )]
+    pub output_name: Option<String>,
+
+    #[arg(id = "dry-run", long = "dry-run", help = "仅扫描并输出统计信息，不写入任何文件")]
+    pub dry_run: bool,
+
+    #[arg(
+        id = "group-by-character",
+        long = "group-by-character",
+        help = "扫描完成后按装备角色分组输出每个角色身上的圣遗物"
+    )]
+    pub group_by_character: bool,
+
+    #[arg(
+        id = "exclude-equipped",
+        long = "exclude-equipped",
+        help = "导出时排除已装备在角色身上的圣遗物"
+    )]
+    pub exclude_equipped: bool,
+
+    #[arg(
+        id = "min-crit-value",
+        long = "min-crit-value",
+        value_name = "CV",
+        help = "只导出双暴分不低于该值的圣遗物（双暴分 = 2×暴击率 + 暴击伤害，按副属性的百分数计算，如 3.9% 暴击率 + 7.8% 暴击伤害为 15.6）"
+    )]
+    pub min_crit_value: Option<f64>,
+
+    #[arg(
+        id = "export-on-interrupt",
+        long = "export-on-interrupt",
+        help = "扫描被中断时不再询问，直接导出已扫描的部分结果"
+    )]
+    pub export_on_interrupt: bool,
+
+    #[arg(
+        id = "stdout",
+        long = "stdout",
+        help = "将导出内容写入标准输出而不是文件（不支持 all 格式），日志仍输出到标准错误"
+    )]
+    pub stdout: bool,
+
+    #[arg(
+        id = "dump-failures",
+        long = "dump-failures",
+        value_name = "PATH",
+        help = "将转换失败的物品及失败原因写入指定的 JSON 文件，便于反馈问题"
+    )]
+    pub dump_failures: Option<PathBuf>,
+
+    #[arg(
+        id = "export-raw",
+        long = "export-raw",
+        value_name = "PATH",
+        help = "将转换前的原始识别结果（含识别错误和置信度）写入指定的 JSON 文件，便于排查问题或离线重新解析"
+    )]
+    pub export_raw: Option<PathBuf>,
+
+    #[arg(
+        id = "metrics-file",
+        long = "metrics-file",
+        value_name = "PATH",
+        help = "将扫描指标（扫描数量、各类错误数、耗时、平均识别耗时、健康度）以 Prometheus 文本格式写入指定文件，可由 node_exporter 的 textfile collector 采集"
+    )]
+    pub metrics_file: Option<PathBuf>,
+
+    #[arg(
+        id = "only-new",
+        long = "only-new",
+        value_name = "PATH",
+        help = "只导出之前未导出过的圣遗物：读取指定文件中记录的已导出圣遗物，导出成功后将本次扫描到的圣遗物追加到该文件（文件不存在时会自动创建）"
+    )]
+    pub only_new: Option<PathBuf>,
+}
+
+impl ExportArtifactConfig {
+    /// 按导出选项过滤转换后的圣遗物，只影响导出内容，不影响扫描与分组报告
+    pub fn filter_artifacts(&self, artifacts: &mut Vec<GenshinArtifact>) {
+        if self.exclude_equipped {
+            artifacts.retain(|artifact| artifact.equip.is_none());
+        }
+        if let Some(min_crit_value) = self.min_crit_value {
+            artifacts.retain(|artifact| artifact.crit_value() >= min_crit_value);
+        }
+    }
+}
+
+/// 校验输出文件名模板：不能为空，且不能包含路径分隔符、盘符或 `..`，防止写到输出目录之外
+pub fn parse_output_name(s: &str) -> Result<String, String> {
+    if s.trim().is_empty() {
+        return Err("输出文件名模板不能为空".to_string());
+    }
+    if s.contains('/') || s.contains('\\') || s.contains("..") || s.contains(':') {
+        return Err(format!("输出文件名模板 '{s}' 不能包含路径分隔符、盘符或 '..'"));
+    }
+    Ok(s.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_output_name() {
+        assert_eq!(parse_output_name("{format}-{date}"), Ok("{format}-{date}".to_string()));
+        assert!(parse_output_name("").is_err());
+        assert!(parse_output_name("../good").is_err());
+        assert!(parse_output_name("sub/good").is_err());
+        assert!(parse_output_name("sub\\good").is_err());
+        assert!(parse_output_name("C:good").is_err());
+    }
 }
@@ -1,6 +1,7 @@
 use serde::{Serialize, Serializer};
 
 use crate::artifact::GenshinArtifact;
+use crate::export::artifact::serializer::ArtifactSerializer;
 
 pub struct GenshinArtifactCSVFormat<'a> {
     artifacts: &'a [GenshinArtifact],
@@ -76,3 +77,32 @@ impl<'a> Serialize for GenshinArtifactCSVFormat<'a> {
         serializer.serialize_str(&s)
     }
 }
+
+/// CSV格式
+pub struct CSVSerializer;
+
+impl ArtifactSerializer for CSVSerializer {
+    fn format_name(&self) -> &'static str {
+        "csv"
+    }
+
+    fn default_file_name(&self) -> &'static str {
+        "artifacts"
+    }
+
+    fn extension(&self) -> &'static str {
+        "csv"
+    }
+
+    fn asset_name(&self) -> &'static str {
+        "csv"
+    }
+
+    fn description(&self) -> &'static str {
+        "CSV格式圣遗物"
+    }
+
+    fn serialize(&self, artifacts: &[GenshinArtifact]) -> anyhow::Result<String> {
+        Ok(GenshinArtifactCSVFormat::new(artifacts).to_csv_string())
+    }
+}
@@ -0,0 +1,54 @@
+use strum::IntoEnumIterator;
+
+use crate::artifact::{ArtifactStat, ArtifactStatName};
+
+/// 通过导出时使用的编码函数反查枚举值，保证导入与导出使用同一份映射
+pub fn decode_by<T, K, F>(key: &str, encode: F) -> Option<T>
+where
+    T: IntoEnumIterator,
+    K: AsRef<str>,
+    F: Fn(&T) -> K,
+{
+    T::iter().find(|item| encode(item).as_ref() == key)
+}
+
+/// 与 `decode_by` 相同，但找不到时返回带字段名的错误
+pub fn decode_required<T, K, F>(field: &str, key: &str, encode: F) -> anyhow::Result<T>
+where
+    T: IntoEnumIterator,
+    K: AsRef<str>,
+    F: Fn(&T) -> K,
+{
+    decode_by(key, encode).ok_or_else(|| anyhow::anyhow!("无法识别的{field}: '{key}'"))
+}
+
+/// 该属性是否以百分比形式存储（内部以小数表示）
+pub fn is_percentage_stat(name: &ArtifactStatName) -> bool {
+    !matches!(
+        name,
+        ArtifactStatName::Atk
+            | ArtifactStatName::ElementalMastery
+            | ArtifactStatName::Hp
+            | ArtifactStatName::Def
+    )
+}
+
+/// 将导出时乘以 `percent_scale` 的数值还原为内部数值
+///
+/// 百分比属性除以 `percent_scale` 后四舍五入到 1e-6，避免浮点误差影响 `ArtifactStat` 的相等比较
+pub fn decode_stat_value(name: &ArtifactStatName, raw: f64, percent_scale: f64) -> f64 {
+    if is_percentage_stat(name) {
+        (raw / percent_scale * 1e6).round() / 1e6
+    } else {
+        raw
+    }
+}
+
+/// 将按顺序排列的副属性填充到四个副属性槽位，多余的副属性会被丢弃
+pub fn fill_sub_stats(sub_stats: Vec<ArtifactStat>) -> [Option<ArtifactStat>; 4] {
+    let mut slots: [Option<ArtifactStat>; 4] = Default::default();
+    for (slot, stat) in slots.iter_mut().zip(sub_stats) {
+        *slot = Some(stat);
+    }
+    slots
+}
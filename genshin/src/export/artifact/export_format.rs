@@ -1,11 +1,19 @@
 use clap::ValueEnum;
 
+use crate::export::artifact::csv::CSVSerializer;
+use crate::export::artifact::good::GOODSerializer;
+use crate::export::artifact::mingyu_lab::MingyuLabSerializer;
+use crate::export::artifact::mona_uranai::MonaSerializer;
+use crate::export::artifact::seelie::SeelieSerializer;
+use crate::export::artifact::serializer::ArtifactSerializer;
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
 pub enum GenshinArtifactExportFormat {
     Mona,
     MingyuLab,
     Good,
     CSV,
+    Seelie,
     /// Export all formats
     All,
 }
@@ -15,3 +23,23 @@ impl Default for GenshinArtifactExportFormat {
         Self::Mona
     }
 }
+
+impl GenshinArtifactExportFormat {
+    /// 该选项需要输出的所有格式，`All` 按莫娜、原魔计算器、GOOD、CSV、Seelie 的顺序返回
+    pub fn serializers(&self) -> Vec<Box<dyn ArtifactSerializer>> {
+        match self {
+            Self::Mona => vec![Box::new(MonaSerializer)],
+            Self::MingyuLab => vec![Box::new(MingyuLabSerializer)],
+            Self::Good => vec![Box::new(GOODSerializer)],
+            Self::CSV => vec![Box::new(CSVSerializer)],
+            Self::Seelie => vec![Box::new(SeelieSerializer)],
+            Self::All => vec![
+                Box::new(MonaSerializer),
+                Box::new(MingyuLabSerializer),
+                Box::new(GOODSerializer),
+                Box::new(CSVSerializer),
+                Box::new(SeelieSerializer),
+            ],
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::artifact::{ArtifactStat, GenshinArtifact};
+
+/// 已导出记录文件的格式版本，哈希算法或格式不兼容时递增
+///
+/// 版本 2 起改为对显式编码的字段计算哈希，版本 1 的记录无法继续使用
+pub const EXPORTED_SET_VERSION: u32 = 2;
+
+/// 64 位 FNV-1a
+///
+/// 哈希值需要写入文件并在之后的运行中比较，不能使用不保证跨版本稳定的 `DefaultHasher`
+struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Fnv1aHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1aHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    /// 写入字符串，以 `0xff`（不会出现在 UTF-8 中）结尾，避免相邻字段拼接后产生歧义
+    fn write_str(&mut self, s: &str) {
+        self.write(s.as_bytes());
+        self.write(&[0xff]);
+    }
+
+    fn write_i32(&mut self, value: i32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    /// 写入可能为空的属性：先写入是否存在，存在时再写入属性名称和放大 1000 倍取整后的数值
+    fn write_stat(&mut self, stat: Option<&ArtifactStat>) {
+        match stat {
+            Some(stat) => {
+                self.write(&[1]);
+                self.write_str(&stat.name.to_string());
+                self.write_i32((stat.value * 1000.0) as i32);
+            },
+            None => self.write(&[0]),
+        }
+    }
+}
+
+/// 圣遗物的持久化哈希
+///
+/// 依次对套装和部位的名称、星级、等级、锁定状态、主属性、四条副属性和装备角色的显式编码计算 FNV-1a，
+/// 不依赖枚举的声明顺序和标准库 `Hash` 的编码方式，因此新增套装或升级编译器后哈希值保持不变。
+/// 属性数值的比较方式与 `GenshinArtifact` 的 `PartialEq` 一致，
+/// 锁定状态或装备角色发生变化的圣遗物视为新的圣遗物
+pub fn artifact_hash(artifact: &GenshinArtifact) -> u64 {
+    let mut hasher = Fnv1aHasher::default();
+    hasher.write_str(&artifact.set_name.to_string());
+    hasher.write_str(&artifact.slot.to_string());
+    hasher.write_i32(artifact.star);
+    hasher.write_i32(artifact.level);
+    hasher.write(&[artifact.lock as u8]);
+    hasher.write_stat(Some(&artifact.main_stat));
+    for stat in
+        [&artifact.sub_stat_1, &artifact.sub_stat_2, &artifact.sub_stat_3, &artifact.sub_stat_4]
+    {
+        hasher.write_stat(stat.as_ref());
+    }
+    match &artifact.equip {
+        Some(equip) => {
+            hasher.write(&[1]);
+            hasher.write_str(equip);
+        },
+        None => hasher.write(&[0]),
+    }
+    hasher.0
+}
+
+/// 之前的运行中已导出的圣遗物，用于 `--only-new` 增量导出
+///
+/// 文件为 JSON：`{"version": 2, "hashes": ["0123456789abcdef", ...]}`，
+/// `hashes` 为 [`artifact_hash`] 的 16 位小写十六进制字符串并按字典序排列。
+/// 使用字符串而不是数字，避免其他工具按双精度浮点数读取时丢失精度
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedArtifactSet {
+    version: u32,
+    hashes: BTreeSet<String>,
+}
+
+impl Default for ExportedArtifactSet {
+    fn default() -> Self {
+        ExportedArtifactSet { version: EXPORTED_SET_VERSION, hashes: BTreeSet::new() }
+    }
+}
+
+impl ExportedArtifactSet {
+    /// 读取记录文件，文件不存在时视为首次运行，返回空记录
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content =
+            fs::read(path).map_err(|e| anyhow!("无法读取导出记录文件 {}: {e}", path.display()))?;
+        let set: ExportedArtifactSet = serde_json::from_slice(&content)
+            .map_err(|e| anyhow!("导出记录文件 {} 格式错误: {e}", path.display()))?;
+        if set.version != EXPORTED_SET_VERSION {
+            return Err(anyhow!(
+                "导出记录文件版本 {} 与当前版本 {EXPORTED_SET_VERSION} 不兼容，请删除该文件后重新导出",
+                set.version
+            ));
+        }
+        Ok(set)
+    }
+
+    /// 写入记录文件，先写入临时文件再重命名，避免写入途中退出时留下损坏的记录
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec(self)?)?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.hashes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hashes.is_empty()
+    }
+
+    pub fn contains(&self, artifact: &GenshinArtifact) -> bool {
+        self.hashes.contains(&format!("{:016x}", artifact_hash(artifact)))
+    }
+
+    /// 记录一件圣遗物，返回之前是否未记录
+    pub fn insert(&mut self, artifact: &GenshinArtifact) -> bool {
+        self.hashes.insert(format!("{:016x}", artifact_hash(artifact)))
+    }
+
+    /// 只保留未记录的圣遗物，返回移除的数量
+    pub fn retain_new(&self, artifacts: &mut Vec<GenshinArtifact>) -> usize {
+        let before = artifacts.len();
+        artifacts.retain(|artifact| !self.contains(artifact));
+        before - artifacts.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact::{ArtifactSetName, ArtifactSlot, ArtifactStat, ArtifactStatName};
+
+    fn artifact(level: i32, lock: bool) -> GenshinArtifact {
+        GenshinArtifact {
+            set_name: ArtifactSetName::GildedDreams,
+            slot: ArtifactSlot::Sand,
+            star: 5,
+            lock,
+            level,
+            main_stat: ArtifactStat { name: ArtifactStatName::ElementalMastery, value: 187.0 },
+            sub_stat_1: Some(ArtifactStat { name: ArtifactStatName::Critical, value: 0.035 }),
+            sub_stat_2: None,
+            sub_stat_3: None,
+            sub_stat_4: None,
+            equip: None,
+        }
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir()
+            .join(format!("furina_exported_set_{}", std::process::id()))
+            .join(format!("{name}.json"))
+    }
+
+    /// 模拟一次 `--only-new` 导出：过滤已导出的圣遗物，再记录本次的全部圣遗物
+    fn export_new(path: &Path, artifacts: &[GenshinArtifact]) -> Vec<GenshinArtifact> {
+        let mut set = ExportedArtifactSet::load(path).unwrap();
+        let mut new = artifacts.to_vec();
+        set.retain_new(&mut new);
+        for artifact in artifacts {
+            set.insert(artifact);
+        }
+        set.save(path).unwrap();
+        new
+    }
+
+    #[test]
+    fn test_second_run_emits_nothing_new() {
+        let path = temp_path("second_run");
+        let _ = fs::remove_file(&path);
+        let artifacts = vec![artifact(20, true), artifact(16, false), artifact(0, false)];
+
+        assert_eq!(export_new(&path, &artifacts), artifacts);
+        assert!(export_new(&path, &artifacts).is_empty());
+
+        // 强化后的圣遗物视为新的圣遗物
+        let upgraded = vec![artifact(20, true), artifact(20, false)];
+        assert_eq!(export_new(&path, &upgraded), vec![artifact(20, false)]);
+        assert_eq!(ExportedArtifactSet::load(&path).unwrap().len(), 4);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_file_format() {
+        let path = temp_path("format");
+        let mut set = ExportedArtifactSet::default();
+        set.insert(&artifact(20, true));
+        set.save(&path).unwrap();
+
+        let value: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(value["version"], EXPORTED_SET_VERSION);
+        let hash = value["hashes"][0].as_str().unwrap();
+        assert_eq!(hash, format!("{:016x}", artifact_hash(&artifact(20, true))));
+        assert_eq!(hash.len(), 16);
+
+        fs::write(&path, r#"{"version":99,"hashes":[]}"#).unwrap();
+        assert!(ExportedArtifactSet::load(&path).is_err());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_fnv1a_is_stable() {
+        // FNV-1a 64 的标准测试向量，哈希算法变化会使已有的记录文件失效
+        let mut hasher = Fnv1aHasher::default();
+        hasher.write(b"a");
+        assert_eq!(hasher.0, 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn test_artifact_hash_is_pinned() {
+        // 编码方式变化会使已有的记录文件失效，此时需要同时递增 EXPORTED_SET_VERSION
+        assert_eq!(artifact_hash(&artifact(20, false)), 0x873b_e18a_8de9_0315);
+
+        // 每个字段都参与哈希
+        let base = artifact_hash(&artifact(20, false));
+        assert_ne!(artifact_hash(&artifact(16, false)), base);
+        assert_ne!(artifact_hash(&artifact(20, true)), base);
+        let mut equipped = artifact(20, false);
+        equipped.equip = Some("纳西妲".to_string());
+        assert_ne!(artifact_hash(&equipped), base);
+        let mut moved = artifact(20, false);
+        moved.sub_stat_2 = moved.sub_stat_1.take();
+        assert_ne!(artifact_hash(&moved), base);
+    }
+}
@@ -1,20 +1,22 @@
 use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use clap::FromArgMatches;
 use furina_core::export::{AssetEmitter, ExportAssets};
+use log::error;
 
 use crate::artifact::GenshinArtifact;
-use crate::export::artifact::csv::GenshinArtifactCSVFormat;
-use crate::export::artifact::good::GOODFormat;
-use crate::export::artifact::mingyu_lab::MingyuLabFormat;
-use crate::export::artifact::mona_uranai::MonaFormat;
+use crate::export::artifact::serializer::ArtifactSerializer;
+use crate::export::artifact::statistics::ArtifactSetCounts;
 use crate::export::artifact::{ExportArtifactConfig, GenshinArtifactExportFormat};
 
 pub struct GenshinArtifactExporter<'a> {
     pub format: GenshinArtifactExportFormat,
     pub results: Option<&'a [GenshinArtifact]>,
     pub output_dir: PathBuf,
+    /// 输出文件名模板，为 `None` 时使用各格式的默认文件名
+    pub output_name: Option<String>,
 }
 
 impl<'a> GenshinArtifactExporter<'a> {
@@ -24,8 +26,47 @@ impl<'a> GenshinArtifactExporter<'a> {
             format: config.format,
             results: Some(results),
             output_dir: PathBuf::from(&config.output_dir),
+            output_name: config.output_name,
         })
     }
+
+    /// 计算某个格式的输出路径
+    ///
+    /// 设置了文件名模板时，`{format}` 替换为格式名，`{date}` 替换为 UTC 时间戳，
+    /// `{count}` 替换为导出的圣遗物数量
+    pub fn output_path(&self, format: &str, default_name: &str, extension: &str) -> PathBuf {
+        let filename = match &self.output_name {
+            None => format!("{default_name}.{extension}"),
+            Some(template) => {
+                let count = self.results.map_or(0, |r| r.len());
+                let name = template
+                    .replace("{format}", format)
+                    .replace("{date}", &format_timestamp(SystemTime::now()))
+                    .replace("{count}", &count.to_string());
+                format!("{name}.{extension}")
+            },
+        };
+        self.output_dir.join(filename)
+    }
+}
+
+/// 将时间格式化为 `YYYYMMDD-HHMMSS`（UTC）
+fn format_timestamp(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (days, rem) = (secs / 86400, secs % 86400);
+
+    // 将自 1970-01-01 起的天数转换为公历日期
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{year:04}{month:02}{day:02}-{:02}{:02}{:02}", rem / 3600, rem % 3600 / 60, rem % 60)
 }
 
 impl<'a> AssetEmitter for GenshinArtifactExporter<'a> {
@@ -35,108 +76,182 @@ impl<'a> AssetEmitter for GenshinArtifactExporter<'a> {
         }
 
         let results = self.results.unwrap();
+        export_assets.record_set_counts(
+            results.set_counts().into_iter().map(|(k, v)| (k.to_string(), v)).collect(),
+        );
 
-        match self.format {
-            GenshinArtifactExportFormat::Mona => {
-                let path = self.output_dir.join("mona.json");
-                let value = MonaFormat::new(results);
-                let contents = serde_json::to_string(&value).unwrap();
-
-                export_assets.add_asset(
-                    Some(String::from("artifacts")),
-                    path,
-                    contents.into_bytes(),
-                    Some(String::from("莫娜圣遗物格式")),
-                );
-            },
-            GenshinArtifactExportFormat::MingyuLab => {
-                let path = self.output_dir.join("mingyulab.json");
-                let value = MingyuLabFormat::new(results);
-                let contents = serde_json::to_string(&value).unwrap();
-
-                export_assets.add_asset(
-                    Some(String::from("artifacts")),
-                    path,
-                    contents.into_bytes(),
-                    Some(String::from("原魔计算器圣遗物格式")),
-                );
-            },
-            GenshinArtifactExportFormat::Good => {
-                let path = self.output_dir.join("good.json");
-                let value = GOODFormat::new(results);
-                let contents = serde_json::to_string(&value).unwrap();
-
-                export_assets.add_asset(
-                    Some(String::from("artifacts")),
-                    path,
-                    contents.into_bytes(),
-                    Some(String::from("GOOD圣遗物格式")),
-                );
-            },
-            GenshinArtifactExportFormat::CSV => {
-                let path = self.output_dir.join("artifacts.csv");
-                let value = GenshinArtifactCSVFormat::new(results);
-                let contents = value.to_csv_string();
-                export_assets.add_asset(
-                    Some(String::from("artifacts csv format")),
-                    path,
-                    contents.into_bytes(),
-                    Some(String::from("CSV格式圣遗物")),
-                );
-            },
-            GenshinArtifactExportFormat::All => {
-                // mona
-                {
-                    let path = self.output_dir.join("mona.json");
-                    let value = MonaFormat::new(results);
-                    let contents = serde_json::to_string(&value).unwrap();
-
-                    export_assets.add_asset(
-                        Some(String::from("mona")),
-                        path,
-                        contents.into_bytes(),
-                        Some(String::from("莫娜圣遗物格式")),
-                    );
-                }
-                // mingyulab
-                {
-                    let path = self.output_dir.join("mingyulab.json");
-                    let value = MingyuLabFormat::new(results);
-                    let contents = serde_json::to_string(&value).unwrap();
-
-                    export_assets.add_asset(
-                        Some(String::from("mingyulab")),
-                        path,
-                        contents.into_bytes(),
-                        Some(String::from("原魔计算器圣遗物格式")),
-                    );
-                }
-                // good
-                {
-                    let path = self.output_dir.join("good.json");
-                    let value = GOODFormat::new(results);
-                    let contents = serde_json::to_string(&value).unwrap();
-
-                    export_assets.add_asset(
-                        Some(String::from("GOOD")),
-                        path,
-                        contents.into_bytes(),
-                        Some(String::from("GOOD圣遗物格式")),
-                    );
-                }
-                // csv
-                {
-                    let path = self.output_dir.join("artifacts.csv");
-                    let value = GenshinArtifactCSVFormat::new(results);
-                    let contents = value.to_csv_string();
-                    export_assets.add_asset(
-                        Some(String::from("csv")),
-                        path,
-                        contents.into_bytes(),
-                        Some(String::from("CSV格式圣遗物")),
-                    );
-                }
-            },
+        let is_all = self.format == GenshinArtifactExportFormat::All;
+        let serializers = self.format.serializers();
+        for (serializer, contents) in serializers.iter().zip(serialize_all(&serializers, results)) {
+            let path = self.output_path(
+                serializer.format_name(),
+                serializer.default_file_name(),
+                serializer.extension(),
+            );
+            // 单独导出某个格式时统一显示为 artifacts
+            let name =
+                Some(String::from(if is_all { serializer.asset_name() } else { "artifacts" }));
+            let description = Some(String::from(serializer.description()));
+
+            match contents {
+                Ok(contents) => {
+                    export_assets.add_asset(name, path, contents.into_bytes(), description)
+                },
+                Err(e) => {
+                    // 某个格式失败不影响其他格式，失败项会出现在导出统计中
+                    error!("{}导出失败: {e}", serializer.description());
+                    export_assets.add_failure(name, path, description);
+                },
+            }
+        }
+    }
+}
+
+/// 序列化所有格式，结果顺序与 `serializers` 一致
+///
+/// 多个格式时每个格式一个线程，单个格式时直接在当前线程中序列化
+fn serialize_all(
+    serializers: &[Box<dyn ArtifactSerializer>],
+    artifacts: &[GenshinArtifact],
+) -> Vec<Result<String>> {
+    if serializers.len() <= 1 {
+        return serializers.iter().map(|s| s.serialize(artifacts)).collect();
+    }
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = serializers
+            .iter()
+            .map(|serializer| scope.spawn(move || serializer.serialize(artifacts)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap_or_else(|_| Err(anyhow!("序列化线程异常退出"))))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::artifact::{
+        ArtifactSetName, ArtifactSlot, ArtifactStat, ArtifactStatName, GenshinArtifact,
+    };
+
+    fn artifact(slot: ArtifactSlot) -> GenshinArtifact {
+        GenshinArtifact {
+            set_name: ArtifactSetName::GildedDreams,
+            slot,
+            star: 5,
+            lock: false,
+            level: 20,
+            main_stat: ArtifactStat { name: ArtifactStatName::Atk, value: 311.0 },
+            sub_stat_1: None,
+            sub_stat_2: None,
+            sub_stat_3: None,
+            sub_stat_4: None,
+            equip: None,
+        }
+    }
+
+    #[test]
+    fn test_output_name_template() {
+        let artifacts = vec![
+            artifact(ArtifactSlot::Flower),
+            artifact(ArtifactSlot::Feather),
+            artifact(ArtifactSlot::Head),
+        ];
+        let exporter = GenshinArtifactExporter {
+            format: GenshinArtifactExportFormat::Good,
+            results: Some(&artifacts),
+            output_dir: PathBuf::from("exports"),
+            output_name: Some("{format}-{count}".to_string()),
+        };
+
+        let mut export_assets = ExportAssets::new();
+        exporter.emit(&mut export_assets);
+        assert_eq!(export_assets.assets.len(), 1);
+        assert_eq!(export_assets.assets[0].filename, PathBuf::from("exports").join("good-3.json"));
+    }
+
+    #[test]
+    fn test_default_output_name() {
+        let artifacts = vec![artifact(ArtifactSlot::Flower)];
+        let exporter = GenshinArtifactExporter {
+            format: GenshinArtifactExportFormat::CSV,
+            results: Some(&artifacts),
+            output_dir: PathBuf::from("."),
+            output_name: None,
+        };
+        assert_eq!(
+            exporter.output_path("csv", "artifacts", "csv"),
+            PathBuf::from("./artifacts.csv")
+        );
+    }
+
+    #[test]
+    fn test_export_all_formats() {
+        let artifacts = vec![artifact(ArtifactSlot::Flower)];
+        let exporter = GenshinArtifactExporter {
+            format: GenshinArtifactExportFormat::All,
+            results: Some(&artifacts),
+            output_dir: PathBuf::from("."),
+            output_name: None,
+        };
+
+        let mut export_assets = ExportAssets::new();
+        exporter.emit(&mut export_assets);
+        let files: Vec<_> = export_assets.assets.iter().map(|a| a.filename.clone()).collect();
+        assert_eq!(
+            files,
+            ["./mona.json", "./mingyulab.json", "./good.json", "./artifacts.csv", "./seelie.json"]
+                .map(PathBuf::from)
+        );
+        let names: Vec<_> = export_assets.assets.iter().map(|a| a.name.clone().unwrap()).collect();
+        assert_eq!(names, ["mona", "mingyulab", "GOOD", "csv", "seelie"]);
+    }
+
+    #[test]
+    fn test_export_all_matches_sequential() {
+        let artifacts = vec![
+            artifact(ArtifactSlot::Flower),
+            artifact(ArtifactSlot::Feather),
+            artifact(ArtifactSlot::Head),
+        ];
+        let dir = std::env::temp_dir().join(format!("furina_export_all_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let exporter = GenshinArtifactExporter {
+            format: GenshinArtifactExportFormat::All,
+            results: Some(&artifacts),
+            output_dir: dir.clone(),
+            output_name: None,
         };
+
+        let mut export_assets = ExportAssets::new();
+        exporter.emit(&mut export_assets);
+        let stat = export_assets.save();
+
+        let serializers = GenshinArtifactExportFormat::All.serializers();
+        assert_eq!(stat.exported_assets.len(), serializers.len());
+        assert!(stat.failed_items.is_empty());
+        for serializer in serializers {
+            let path = exporter.output_path(
+                serializer.format_name(),
+                serializer.default_file_name(),
+                serializer.extension(),
+            );
+            let expected = serializer.serialize(&artifacts).unwrap();
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), expected, "{path:?}");
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(UNIX_EPOCH), "19700101-000000");
+        let time = UNIX_EPOCH + Duration::from_secs(1_709_210_096);
+        assert_eq!(format_timestamp(time), "20240229-123456");
     }
 }
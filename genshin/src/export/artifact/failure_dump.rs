@@ -0,0 +1,130 @@
+use std::io::Write;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::scanner::GenshinArtifactScanResult;
+
+/// 默认最多写入的失败物品数量，超出部分只计数
+pub const DEFAULT_FAILURE_DUMP_LIMIT: usize = 1000;
+
+/// 一个转换失败的物品及其失败原因
+#[derive(Serialize)]
+pub struct ConversionFailure<'a> {
+    /// 物品在扫描结果中的序号（从 1 开始）
+    pub index: usize,
+    pub reasons: &'a [String],
+    pub item: &'a GenshinArtifactScanResult,
+}
+
+/// 将转换失败的物品逐个写入 JSON 文件，便于用户附在问题反馈中
+///
+/// 每个物品在写入时立即序列化，不会在内存中拼接整个文件；超过 `limit` 的物品不再写入，
+/// 只在文件末尾的 `omitted` 中记录数量。输出格式为
+/// `{"failures":[...],"total":N,"omitted":M}`
+pub struct FailureDumpWriter<W: Write> {
+    writer: W,
+    limit: usize,
+    written: usize,
+    omitted: usize,
+}
+
+impl<W: Write> FailureDumpWriter<W> {
+    pub fn new(mut writer: W, limit: usize) -> Result<Self> {
+        writer.write_all(br#"{"failures":["#)?;
+        Ok(FailureDumpWriter { writer, limit, written: 0, omitted: 0 })
+    }
+
+    pub fn write(&mut self, failure: &ConversionFailure) -> Result<()> {
+        if self.written >= self.limit {
+            self.omitted += 1;
+            return Ok(());
+        }
+
+        if self.written > 0 {
+            self.writer.write_all(b",")?;
+        }
+        serde_json::to_writer(&mut self.writer, failure)?;
+        self.written += 1;
+        Ok(())
+    }
+
+    /// 写入文件末尾并返回内部的 writer
+    pub fn finish(mut self) -> Result<W> {
+        write!(
+            self.writer,
+            r#"],"total":{},"omitted":{}}}"#,
+            self.written + self.omitted,
+            self.omitted
+        )?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn synthetic_item(name: &str) -> GenshinArtifactScanResult {
+        GenshinArtifactScanResult::new(
+            name.to_string(),
+            "生命值".to_string(),
+            "4,780".to_string(),
+            Default::default(),
+            String::new(),
+            20,
+            5,
+            false,
+        )
+    }
+
+    fn dump(limit: usize) -> serde_json::Value {
+        let first = synthetic_item("明威之");
+        let second = synthetic_item("??");
+        let first_reasons = vec!["套装名称无法识别: '明威之'".to_string()];
+        let second_reasons = vec![
+            "部位无法识别: '??'".to_string(),
+            "疑似OCR识别错误：圣遗物名称过短或包含异常字符".to_string(),
+        ];
+
+        let mut writer = FailureDumpWriter::new(Vec::new(), limit).unwrap();
+        writer
+            .write(&ConversionFailure { index: 3, reasons: &first_reasons, item: &first })
+            .unwrap();
+        writer
+            .write(&ConversionFailure { index: 7, reasons: &second_reasons, item: &second })
+            .unwrap();
+        serde_json::from_slice(&writer.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_dump_failures() {
+        let value = dump(DEFAULT_FAILURE_DUMP_LIMIT);
+        assert_eq!(value["total"], 2);
+        assert_eq!(value["omitted"], 0);
+
+        let failures = value["failures"].as_array().unwrap();
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0]["index"], 3);
+        assert_eq!(failures[0]["item"]["name"], "明威之");
+        assert_eq!(failures[0]["item"]["main_stat_value"], "4,780");
+        assert_eq!(failures[1]["reasons"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_dump_failures_limit() {
+        let value = dump(1);
+        assert_eq!(value["total"], 2);
+        assert_eq!(value["omitted"], 1);
+        assert_eq!(value["failures"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_dump_without_failures_is_valid_json() {
+        let writer = FailureDumpWriter::new(Vec::new(), 10).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&writer.finish().unwrap()).unwrap();
+        assert_eq!(value["failures"].as_array().unwrap().len(), 0);
+        assert_eq!(value["total"], 0);
+    }
+}
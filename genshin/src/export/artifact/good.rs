@@ -4,6 +4,7 @@ use serde::Serialize;
 use crate::artifact::{
     ArtifactSetName, ArtifactSlot, ArtifactStat, ArtifactStatName, GenshinArtifact,
 };
+use crate::export::artifact::serializer::ArtifactSerializer;
 
 /// GOOD格式圣遗物导出模块
 /// 用于将圣遗物数据转换为GOOD格式的JSON输出，支持与其他原神工具的数据交换
@@ -72,7 +73,7 @@ impl<'a> Serialize for GOODArtifact<'a> {
 /// 表示GOOD格式中的一个属性，包含属性键名和数值。
 /// 与内部格式不同，GOOD格式使用英文键名，并且百分比属性需要转换回百分比形式。
 #[derive(Serialize)]
-struct GOODStat<'a> {
+pub(crate) struct GOODStat<'a> {
     key: &'a str, // 属性键名（英文）
     value: f64,   // 属性数值
 }
@@ -89,7 +90,7 @@ impl<'a> GOODStat<'a> {
     ///
     /// # 返回值
     /// 返回GOOD格式的属性结构体
-    fn new(stat: &ArtifactStat) -> GOODStat {
+    pub(crate) fn new(stat: &ArtifactStat) -> GOODStat {
         GOODStat {
             key: stat.name.to_good(),
             value: match stat.name {
@@ -255,7 +256,7 @@ impl ArtifactSetName {
 /// - 使用PascalCase命名风格
 /// - 保持与游戏官方英文名称的一致性
 /// - 对于复合名称，去除空格和特殊字符
-fn equip_from_zh_cn(equip: Option<&str>) -> &'static str {
+pub(crate) fn equip_from_zh_cn(equip: Option<&str>) -> &'static str {
     match equip {
         // 火元素角色
         Some("迪卢克") => "Diluc",
@@ -430,3 +431,32 @@ impl<'a> GOODFormat<'a> {
         GOODFormat { format: "GOOD", version: 1, source: "furina", artifacts }
     }
 }
+
+/// GOOD格式
+pub struct GOODSerializer;
+
+impl ArtifactSerializer for GOODSerializer {
+    fn format_name(&self) -> &'static str {
+        "good"
+    }
+
+    fn default_file_name(&self) -> &'static str {
+        "good"
+    }
+
+    fn extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn asset_name(&self) -> &'static str {
+        "GOOD"
+    }
+
+    fn description(&self) -> &'static str {
+        "GOOD圣遗物格式"
+    }
+
+    fn serialize(&self, artifacts: &[GenshinArtifact]) -> anyhow::Result<String> {
+        Ok(serde_json::to_string(&GOODFormat::new(artifacts))?)
+    }
+}
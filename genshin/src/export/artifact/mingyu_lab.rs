@@ -1,8 +1,16 @@
 use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde::Deserialize;
 
 use crate::artifact::{
     ArtifactSetName, ArtifactSlot, ArtifactStat, ArtifactStatName, GenshinArtifact,
 };
+use crate::export::artifact::decode::{
+    decode_required, decode_stat_value, fill_sub_stats, is_percentage_stat,
+};
+use crate::export::artifact::serializer::ArtifactSerializer;
+
+/// 原魔计算器中百分比属性的放大倍数
+const PERCENT_SCALE: f64 = 100.0;
 
 struct MingyuLabArtifact<'a> {
     artifact: &'a GenshinArtifact,
@@ -20,13 +28,8 @@ impl<'a> Serialize for MingyuLabArtifact<'a> {
 
         let extract_stat_value = |maybe_stat: &Option<ArtifactStat>| match maybe_stat {
             None => 0.0,
-            Some(stat) => match stat.name {
-                ArtifactStatName::Atk
-                | ArtifactStatName::ElementalMastery
-                | ArtifactStatName::Hp
-                | ArtifactStatName::Def => stat.value,
-                _ => stat.value * 100.0,
-            },
+            Some(stat) if is_percentage_stat(&stat.name) => stat.value * PERCENT_SCALE,
+            Some(stat) => stat.value,
         };
 
         let artifact = &self.artifact;
@@ -172,3 +175,168 @@ impl Serialize for MingyuLabFormat<'_> {
         self.artifacts.serialize(serializer)
     }
 }
+
+/// 原魔计算器格式
+pub struct MingyuLabSerializer;
+
+impl ArtifactSerializer for MingyuLabSerializer {
+    fn format_name(&self) -> &'static str {
+        "mingyulab"
+    }
+
+    fn default_file_name(&self) -> &'static str {
+        "mingyulab"
+    }
+
+    fn extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn asset_name(&self) -> &'static str {
+        "mingyulab"
+    }
+
+    fn description(&self) -> &'static str {
+        "原魔计算器圣遗物格式"
+    }
+
+    fn serialize(&self, artifacts: &[GenshinArtifact]) -> anyhow::Result<String> {
+        Ok(serde_json::to_string(&MingyuLabFormat::new(artifacts))?)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MingyuLabArtifactData {
+    as_key: String,
+    rarity: i32,
+    slot: String,
+    level: i32,
+    main_stat: String,
+    sub_stat1_type: String,
+    sub_stat1_value: f64,
+    sub_stat2_type: String,
+    sub_stat2_value: f64,
+    sub_stat3_type: String,
+    sub_stat3_value: f64,
+    sub_stat4_type: String,
+    sub_stat4_value: f64,
+}
+
+impl MingyuLabArtifactData {
+    fn into_artifact(self) -> anyhow::Result<GenshinArtifact> {
+        let decode_stat = |name: &str| {
+            decode_required("属性名称", name, |s: &ArtifactStatName| s.to_mingyu_lab())
+        };
+
+        let mut sub_stats = Vec::new();
+        for (name, value) in [
+            (&self.sub_stat1_type, self.sub_stat1_value),
+            (&self.sub_stat2_type, self.sub_stat2_value),
+            (&self.sub_stat3_type, self.sub_stat3_value),
+            (&self.sub_stat4_type, self.sub_stat4_value),
+        ] {
+            // 导出时空副属性以数值 0 占位
+            if value == 0.0 {
+                continue;
+            }
+            let name = decode_stat(name)?;
+            let value = decode_stat_value(&name, value, PERCENT_SCALE);
+            sub_stats.push(ArtifactStat { name, value });
+        }
+        let [sub_stat_1, sub_stat_2, sub_stat_3, sub_stat_4] = fill_sub_stats(sub_stats);
+
+        Ok(GenshinArtifact {
+            set_name: decode_required("套装", &self.as_key, |s: &ArtifactSetName| {
+                s.to_mingyu_lab()
+            })?,
+            slot: decode_required("部位", &self.slot, |s: &ArtifactSlot| s.to_mingyu_lab())?,
+            star: self.rarity,
+            lock: false,
+            level: self.level,
+            main_stat: ArtifactStat { name: decode_stat(&self.main_stat)?, value: 0.0 },
+            sub_stat_1,
+            sub_stat_2,
+            sub_stat_3,
+            sub_stat_4,
+            equip: None,
+        })
+    }
+}
+
+/// 从原魔计算器格式的 JSON 导入圣遗物
+///
+/// 该格式不包含主属性数值、锁定状态和装备信息，导入后主属性数值为 0，
+/// 锁定状态为 `false`，装备角色为 `None`
+pub fn import(json: &str) -> anyhow::Result<Vec<GenshinArtifact>> {
+    let data: Vec<MingyuLabArtifactData> = serde_json::from_str(json)?;
+    data.into_iter().map(MingyuLabArtifactData::into_artifact).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let artifacts = vec![
+            GenshinArtifact {
+                set_name: ArtifactSetName::EmblemOfSeveredFate,
+                slot: ArtifactSlot::Sand,
+                star: 5,
+                lock: true,
+                level: 20,
+                main_stat: ArtifactStat { name: ArtifactStatName::Recharge, value: 0.518 },
+                sub_stat_1: Some(ArtifactStat { name: ArtifactStatName::Critical, value: 0.062 }),
+                sub_stat_2: Some(ArtifactStat {
+                    name: ArtifactStatName::CriticalDamage,
+                    value: 0.132,
+                }),
+                sub_stat_3: Some(ArtifactStat { name: ArtifactStatName::Atk, value: 33.0 }),
+                sub_stat_4: None,
+                equip: Some("雷电将军".to_string()),
+            },
+            GenshinArtifact {
+                set_name: ArtifactSetName::FinaleOfTheDeepGalleries,
+                slot: ArtifactSlot::Flower,
+                star: 4,
+                lock: false,
+                level: 0,
+                main_stat: ArtifactStat { name: ArtifactStatName::Hp, value: 645.0 },
+                sub_stat_1: Some(ArtifactStat {
+                    name: ArtifactStatName::ElementalMastery,
+                    value: 16.0,
+                }),
+                sub_stat_2: None,
+                sub_stat_3: None,
+                sub_stat_4: None,
+                equip: None,
+            },
+        ];
+
+        let json = serde_json::to_string(&MingyuLabFormat::new(&artifacts)).unwrap();
+        let imported = import(&json).unwrap();
+
+        assert_eq!(imported.len(), artifacts.len());
+        for (original, imported) in artifacts.iter().zip(&imported) {
+            assert_eq!(imported.set_name, original.set_name);
+            assert_eq!(imported.slot, original.slot);
+            assert_eq!(imported.star, original.star);
+            assert_eq!(imported.level, original.level);
+            assert_eq!(imported.main_stat.name, original.main_stat.name);
+            assert_eq!(imported.sub_stat_1, original.sub_stat_1);
+            assert_eq!(imported.sub_stat_2, original.sub_stat_2);
+            assert_eq!(imported.sub_stat_3, original.sub_stat_3);
+            assert_eq!(imported.sub_stat_4, original.sub_stat_4);
+        }
+    }
+
+    #[test]
+    fn test_import_unknown_set() {
+        let json = r#"[{"asKey":"unknown","rarity":5,"slot":"flower","level":0,
+            "mainStat":"flatHP","subStat1Type":"flatATK","subStat1Value":0,
+            "subStat2Type":"flatATK","subStat2Value":0,"subStat3Type":"flatATK",
+            "subStat3Value":0,"subStat4Type":"flatATK","subStat4Value":0}]"#;
+        assert!(import(json).is_err());
+    }
+}
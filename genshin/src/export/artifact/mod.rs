@@ -1,11 +1,21 @@
 pub use config::ExportArtifactConfig;
 pub use export_format::GenshinArtifactExportFormat;
+pub use exported_set::{artifact_hash, ExportedArtifactSet, EXPORTED_SET_VERSION};
 pub use exporter::GenshinArtifactExporter;
+pub use failure_dump::{ConversionFailure, FailureDumpWriter, DEFAULT_FAILURE_DUMP_LIMIT};
+pub use serializer::ArtifactSerializer;
+pub use statistics::{ArtifactSetCounts, ArtifactSetStatistics};
 
 mod config;
 mod csv;
+mod decode;
 mod export_format;
+mod exported_set;
 mod exporter;
+mod failure_dump;
 pub mod good;
-mod mingyu_lab;
-mod mona_uranai;
+pub mod mingyu_lab;
+pub mod mona_uranai;
+pub mod seelie;
+mod serializer;
+mod statistics;
@@ -1,12 +1,19 @@
 use std::convert::From;
 
 use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde::Deserialize;
 
 use crate::artifact::{
     ArtifactSetName, ArtifactSlot, ArtifactStat, ArtifactStatName, GenshinArtifact,
 };
+use crate::export::artifact::decode::{decode_required, decode_stat_value, fill_sub_stats};
+use crate::export::artifact::serializer::ArtifactSerializer;
 
-type MonaArtifact = GenshinArtifact;
+/// 按莫娜占卜铺的键名序列化的圣遗物
+struct MonaArtifact<'a>(&'a GenshinArtifact);
+
+/// 按莫娜占卜铺的键名序列化的属性
+struct MonaStat<'a>(&'a ArtifactStat);
 
 impl ArtifactStatName {
     pub fn to_mona(&self) -> String {
@@ -95,41 +102,42 @@ impl ArtifactSlot {
     }
 }
 
-impl Serialize for ArtifactStat {
+impl Serialize for MonaStat<'_> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         let mut root = serializer.serialize_map(Some(2))?;
-        root.serialize_entry("name", &self.name.to_mona()).unwrap();
-        root.serialize_entry("value", &self.value).unwrap();
+        root.serialize_entry("name", &self.0.name.to_mona()).unwrap();
+        root.serialize_entry("value", &self.0.value).unwrap();
         root.end()
     }
 }
 
-impl Serialize for MonaArtifact {
+impl Serialize for MonaArtifact<'_> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
+        let artifact = self.0;
         let mut root = serializer.serialize_map(Some(7))?;
 
-        root.serialize_entry("setName", &self.set_name.to_mona()).unwrap();
-        root.serialize_entry("position", &self.slot.to_mona()).unwrap();
-        root.serialize_entry("mainTag", &self.main_stat).unwrap();
+        root.serialize_entry("setName", &artifact.set_name.to_mona()).unwrap();
+        root.serialize_entry("position", &artifact.slot.to_mona()).unwrap();
+        root.serialize_entry("mainTag", &MonaStat(&artifact.main_stat)).unwrap();
 
-        let mut sub_stats: Vec<&ArtifactStat> = vec![];
-        if let Some(ref s) = self.sub_stat_1 {
-            sub_stats.push(s);
+        let mut sub_stats: Vec<MonaStat> = vec![];
+        if let Some(ref s) = artifact.sub_stat_1 {
+            sub_stats.push(MonaStat(s));
         }
-        if let Some(ref s) = self.sub_stat_2 {
-            sub_stats.push(s);
+        if let Some(ref s) = artifact.sub_stat_2 {
+            sub_stats.push(MonaStat(s));
         }
-        if let Some(ref s) = self.sub_stat_3 {
-            sub_stats.push(s);
+        if let Some(ref s) = artifact.sub_stat_3 {
+            sub_stats.push(MonaStat(s));
         }
-        if let Some(ref s) = self.sub_stat_4 {
-            sub_stats.push(s);
+        if let Some(ref s) = artifact.sub_stat_4 {
+            sub_stats.push(MonaStat(s));
         }
         // let mut subs = serializer.serialize_seq(Some(sub_stats.len()))?;
         //
@@ -141,9 +149,9 @@ impl Serialize for MonaArtifact {
 
         root.serialize_entry("normalTags", &sub_stats)?;
         root.serialize_entry("omit", &false)?;
-        root.serialize_entry("level", &self.level)?;
-        root.serialize_entry("star", &self.star)?;
-        root.serialize_entry("equip", &self.equip)?;
+        root.serialize_entry("level", &artifact.level)?;
+        root.serialize_entry("star", &artifact.star)?;
+        root.serialize_entry("equip", &artifact.equip)?;
         // let random_id = thread_rng().gen::<u64>();
         // root.serialize_entry("id", &random_id);
 
@@ -153,11 +161,11 @@ impl Serialize for MonaArtifact {
 
 pub struct MonaFormat<'a> {
     version: String,
-    flower: Vec<&'a MonaArtifact>,
-    feather: Vec<&'a MonaArtifact>,
-    cup: Vec<&'a MonaArtifact>,
-    sand: Vec<&'a MonaArtifact>,
-    head: Vec<&'a MonaArtifact>,
+    flower: Vec<MonaArtifact<'a>>,
+    feather: Vec<MonaArtifact<'a>>,
+    cup: Vec<MonaArtifact<'a>>,
+    sand: Vec<MonaArtifact<'a>>,
+    head: Vec<MonaArtifact<'a>>,
 }
 
 impl<'a> Serialize for MonaFormat<'a> {
@@ -178,22 +186,185 @@ impl<'a> Serialize for MonaFormat<'a> {
 
 impl<'a> MonaFormat<'a> {
     pub fn new(results: &[GenshinArtifact]) -> MonaFormat {
-        let mut flower: Vec<&MonaArtifact> = Vec::new();
-        let mut feather: Vec<&MonaArtifact> = Vec::new();
-        let mut cup: Vec<&MonaArtifact> = Vec::new();
-        let mut sand: Vec<&MonaArtifact> = Vec::new();
-        let mut head: Vec<&MonaArtifact> = Vec::new();
+        let mut flower: Vec<MonaArtifact> = Vec::new();
+        let mut feather: Vec<MonaArtifact> = Vec::new();
+        let mut cup: Vec<MonaArtifact> = Vec::new();
+        let mut sand: Vec<MonaArtifact> = Vec::new();
+        let mut head: Vec<MonaArtifact> = Vec::new();
 
         for art in results.iter() {
+            let mona = MonaArtifact(art);
             match art.slot {
-                ArtifactSlot::Flower => flower.push(art),
-                ArtifactSlot::Feather => feather.push(art),
-                ArtifactSlot::Sand => sand.push(art),
-                ArtifactSlot::Goblet => cup.push(art),
-                ArtifactSlot::Head => head.push(art),
+                ArtifactSlot::Flower => flower.push(mona),
+                ArtifactSlot::Feather => feather.push(mona),
+                ArtifactSlot::Sand => sand.push(mona),
+                ArtifactSlot::Goblet => cup.push(mona),
+                ArtifactSlot::Head => head.push(mona),
             }
         }
 
         MonaFormat { flower, feather, cup, sand, head, version: String::from("1") }
     }
 }
+
+/// 莫娜占卜铺格式
+pub struct MonaSerializer;
+
+impl ArtifactSerializer for MonaSerializer {
+    fn format_name(&self) -> &'static str {
+        "mona"
+    }
+
+    fn default_file_name(&self) -> &'static str {
+        "mona"
+    }
+
+    fn extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn asset_name(&self) -> &'static str {
+        "mona"
+    }
+
+    fn description(&self) -> &'static str {
+        "莫娜圣遗物格式"
+    }
+
+    fn serialize(&self, artifacts: &[GenshinArtifact]) -> anyhow::Result<String> {
+        Ok(serde_json::to_string(&MonaFormat::new(artifacts))?)
+    }
+}
+
+#[derive(Deserialize)]
+struct MonaStatData {
+    name: String,
+    value: f64,
+}
+
+impl MonaStatData {
+    fn into_stat(self) -> anyhow::Result<ArtifactStat> {
+        let name = decode_required("属性名称", &self.name, ArtifactStatName::to_mona)?;
+        // 莫娜格式与内部一致，百分比属性以小数存储
+        let value = decode_stat_value(&name, self.value, 1.0);
+        Ok(ArtifactStat { name, value })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MonaArtifactData {
+    set_name: String,
+    position: String,
+    main_tag: MonaStatData,
+    normal_tags: Vec<MonaStatData>,
+    level: i32,
+    star: i32,
+    #[serde(default)]
+    equip: Option<String>,
+}
+
+impl MonaArtifactData {
+    fn into_artifact(self) -> anyhow::Result<GenshinArtifact> {
+        let sub_stats =
+            self.normal_tags.into_iter().map(MonaStatData::into_stat).collect::<Result<_, _>>()?;
+        let [sub_stat_1, sub_stat_2, sub_stat_3, sub_stat_4] = fill_sub_stats(sub_stats);
+
+        Ok(GenshinArtifact {
+            set_name: decode_required("套装", &self.set_name, ArtifactSetName::to_mona)?,
+            slot: decode_required("部位", &self.position, ArtifactSlot::to_mona)?,
+            star: self.star,
+            lock: false,
+            level: self.level,
+            main_stat: self.main_tag.into_stat()?,
+            sub_stat_1,
+            sub_stat_2,
+            sub_stat_3,
+            sub_stat_4,
+            equip: self.equip,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct MonaFormatData {
+    #[serde(default)]
+    flower: Vec<MonaArtifactData>,
+    #[serde(default)]
+    feather: Vec<MonaArtifactData>,
+    #[serde(default)]
+    sand: Vec<MonaArtifactData>,
+    #[serde(default)]
+    cup: Vec<MonaArtifactData>,
+    #[serde(default)]
+    head: Vec<MonaArtifactData>,
+}
+
+/// 从莫娜占卜铺格式的 JSON 导入圣遗物
+///
+/// 该格式不包含锁定状态，导入后锁定状态为 `false`；结果按生之花、死之羽、时之沙、空之杯、理之冠的顺序排列
+pub fn import(json: &str) -> anyhow::Result<Vec<GenshinArtifact>> {
+    let data: MonaFormatData = serde_json::from_str(json)?;
+    [data.flower, data.feather, data.sand, data.cup, data.head]
+        .into_iter()
+        .flatten()
+        .map(MonaArtifactData::into_artifact)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let artifacts = vec![
+            GenshinArtifact {
+                set_name: ArtifactSetName::CrimsonWitch,
+                slot: ArtifactSlot::Flower,
+                star: 5,
+                lock: false,
+                level: 20,
+                main_stat: ArtifactStat { name: ArtifactStatName::Hp, value: 4780.0 },
+                sub_stat_1: Some(ArtifactStat { name: ArtifactStatName::Critical, value: 0.101 }),
+                sub_stat_2: Some(ArtifactStat {
+                    name: ArtifactStatName::CriticalDamage,
+                    value: 0.202,
+                }),
+                sub_stat_3: Some(ArtifactStat { name: ArtifactStatName::Def, value: 23.0 }),
+                sub_stat_4: Some(ArtifactStat {
+                    name: ArtifactStatName::AtkPercentage,
+                    value: 0.053,
+                }),
+                equip: Some("胡桃".to_string()),
+            },
+            GenshinArtifact {
+                // 未在 to_mona 中显式映射、使用默认名称的套装
+                set_name: ArtifactSetName::ObsidianCodex,
+                slot: ArtifactSlot::Goblet,
+                star: 5,
+                lock: false,
+                level: 4,
+                main_stat: ArtifactStat { name: ArtifactStatName::PyroBonus, value: 0.466 },
+                sub_stat_1: Some(ArtifactStat { name: ArtifactStatName::Recharge, value: 0.065 }),
+                sub_stat_2: None,
+                sub_stat_3: None,
+                sub_stat_4: None,
+                equip: None,
+            },
+        ];
+
+        let json = serde_json::to_string(&MonaFormat::new(&artifacts)).unwrap();
+        let imported = import(&json).unwrap();
+
+        assert_eq!(imported, artifacts);
+    }
+
+    #[test]
+    fn test_import_unknown_stat() {
+        let json = r#"{"version":"1","flower":[{"setName":"crimsonWitch","position":"flower",
+            "mainTag":{"name":"unknown","value":1.0},"normalTags":[],"omit":false,
+            "level":0,"star":5,"equip":null}]}"#;
+        assert!(import(json).is_err());
+    }
+}
@@ -0,0 +1,173 @@
+use serde::Serialize;
+
+use crate::artifact::GenshinArtifact;
+use crate::export::artifact::good::{equip_from_zh_cn, GOODStat};
+use crate::export::artifact::serializer::ArtifactSerializer;
+
+/// Seelie.me 圣遗物导出格式
+///
+/// Seelie.me 的圣遗物导入沿用 GOOD 的结构和套装、部位、属性键名，与 GOOD 格式的区别：
+/// - `location` 使用 Seelie 的角色 ID：GOOD 角色键的小写下划线形式，如 `HuTao` → `hu_tao`，
+///   `KamisatoAyaka` → `kamisato_ayaka`
+/// - 未装备或无法识别装备角色时 `location` 为 `null`，GOOD 格式为空字符串
+/// - `source` 为 `furina-seelie`，便于区分导出来源
+#[derive(Serialize)]
+pub struct SeelieFormat<'a> {
+    format: &'static str,
+    version: u32,
+    source: &'static str,
+    artifacts: Vec<SeelieArtifact<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SeelieArtifact<'a> {
+    set_key: &'static str,
+    slot_key: &'static str,
+    level: i32,
+    rarity: i32,
+    main_stat_key: &'static str,
+    location: Option<String>,
+    lock: bool,
+    substats: Vec<GOODStat<'a>>,
+}
+
+impl<'a> SeelieArtifact<'a> {
+    fn new(artifact: &'a GenshinArtifact) -> Self {
+        let substats = [
+            &artifact.sub_stat_1,
+            &artifact.sub_stat_2,
+            &artifact.sub_stat_3,
+            &artifact.sub_stat_4,
+        ]
+        .into_iter()
+        .flatten()
+        .map(GOODStat::new)
+        .collect();
+
+        SeelieArtifact {
+            set_key: artifact.set_name.to_good(),
+            slot_key: artifact.slot.to_good(),
+            level: artifact.level,
+            rarity: artifact.star,
+            main_stat_key: artifact.main_stat.name.to_good(),
+            location: seelie_character_id(artifact.equip.as_deref()),
+            lock: artifact.lock,
+            substats,
+        }
+    }
+}
+
+impl<'a> SeelieFormat<'a> {
+    pub fn new(results: &'a [GenshinArtifact]) -> SeelieFormat<'a> {
+        SeelieFormat {
+            format: "GOOD",
+            version: 1,
+            source: "furina-seelie",
+            artifacts: results.iter().map(SeelieArtifact::new).collect(),
+        }
+    }
+}
+
+/// 将中文角色名转换为 Seelie 的角色 ID，未装备或无法识别时返回 `None`
+fn seelie_character_id(equip: Option<&str>) -> Option<String> {
+    let good_key = equip_from_zh_cn(equip);
+    if good_key.is_empty() {
+        return None;
+    }
+
+    let mut id = String::with_capacity(good_key.len() + 4);
+    for (i, c) in good_key.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            id.push('_');
+        }
+        id.push(c.to_ascii_lowercase());
+    }
+    Some(id)
+}
+
+/// Seelie.me 格式
+pub struct SeelieSerializer;
+
+impl ArtifactSerializer for SeelieSerializer {
+    fn format_name(&self) -> &'static str {
+        "seelie"
+    }
+
+    fn default_file_name(&self) -> &'static str {
+        "seelie"
+    }
+
+    fn extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn asset_name(&self) -> &'static str {
+        "seelie"
+    }
+
+    fn description(&self) -> &'static str {
+        "Seelie圣遗物格式"
+    }
+
+    fn serialize(&self, artifacts: &[GenshinArtifact]) -> anyhow::Result<String> {
+        Ok(serde_json::to_string(&SeelieFormat::new(artifacts))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact::{ArtifactSetName, ArtifactSlot, ArtifactStat, ArtifactStatName};
+
+    #[test]
+    fn test_seelie_character_id() {
+        assert_eq!(seelie_character_id(Some("胡桃")).as_deref(), Some("hu_tao"));
+        assert_eq!(seelie_character_id(Some("梦见月瑞希")).as_deref(), Some("yumemizuki_mizuki"));
+        assert_eq!(seelie_character_id(Some("魈")).as_deref(), Some("xiao"));
+        assert_eq!(seelie_character_id(Some("不存在的角色")), None);
+        assert_eq!(seelie_character_id(None), None);
+    }
+
+    #[test]
+    fn test_serialize_fixture() {
+        let artifacts = vec![
+            GenshinArtifact {
+                set_name: ArtifactSetName::EmblemOfSeveredFate,
+                slot: ArtifactSlot::Sand,
+                star: 5,
+                lock: true,
+                level: 20,
+                main_stat: ArtifactStat { name: ArtifactStatName::Recharge, value: 0.5 },
+                sub_stat_1: Some(ArtifactStat { name: ArtifactStatName::Critical, value: 0.125 }),
+                sub_stat_2: Some(ArtifactStat { name: ArtifactStatName::Atk, value: 19.0 }),
+                sub_stat_3: None,
+                sub_stat_4: None,
+                equip: Some("雷电将军".to_string()),
+            },
+            GenshinArtifact {
+                set_name: ArtifactSetName::GildedDreams,
+                slot: ArtifactSlot::Feather,
+                star: 4,
+                lock: false,
+                level: 0,
+                main_stat: ArtifactStat { name: ArtifactStatName::Atk, value: 42.0 },
+                sub_stat_1: None,
+                sub_stat_2: None,
+                sub_stat_3: None,
+                sub_stat_4: None,
+                equip: None,
+            },
+        ];
+
+        let expected = concat!(
+            r#"{"format":"GOOD","version":1,"source":"furina-seelie","artifacts":["#,
+            r#"{"setKey":"EmblemOfSeveredFate","slotKey":"sands","level":20,"rarity":5,"#,
+            r#""mainStatKey":"enerRech_","location":"raiden_shogun","lock":true,"substats":["#,
+            r#"{"key":"critRate_","value":12.5},{"key":"atk","value":19.0}]},"#,
+            r#"{"setKey":"GildedDreams","slotKey":"plume","level":0,"rarity":4,"#,
+            r#""mainStatKey":"atk","location":null,"lock":false,"substats":[]}]}"#,
+        );
+        assert_eq!(SeelieSerializer.serialize(&artifacts).unwrap(), expected);
+    }
+}
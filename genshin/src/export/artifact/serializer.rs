@@ -0,0 +1,99 @@
+use anyhow::Result;
+
+use crate::artifact::GenshinArtifact;
+
+/// 将圣遗物列表转换为某种导出格式的文本
+///
+/// 每种导出格式实现一次，`GenshinArtifactExporter` 通过该 trait 统一输出，
+/// 新增格式时只需实现该 trait 并在 `GenshinArtifactExportFormat::serializers` 中注册。
+/// 导出所有格式时各格式在不同线程中序列化，因此要求 `Send + Sync`
+pub trait ArtifactSerializer: Send + Sync {
+    /// 格式名，用于替换文件名模板中的 `{format}`
+    fn format_name(&self) -> &'static str;
+
+    /// 未设置文件名模板时使用的文件名（不含扩展名）
+    fn default_file_name(&self) -> &'static str;
+
+    fn extension(&self) -> &'static str;
+
+    /// 同时导出所有格式时，导出统计中显示的名称
+    fn asset_name(&self) -> &'static str;
+
+    /// 导出统计中显示的格式说明
+    fn description(&self) -> &'static str;
+
+    fn serialize(&self, artifacts: &[GenshinArtifact]) -> Result<String>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact::{ArtifactSetName, ArtifactSlot, ArtifactStat, ArtifactStatName};
+    use crate::export::artifact::csv::CSVSerializer;
+    use crate::export::artifact::good::GOODSerializer;
+    use crate::export::artifact::mingyu_lab::MingyuLabSerializer;
+    use crate::export::artifact::mona_uranai::MonaSerializer;
+
+    /// 数值都能被二进制浮点数精确表示，避免百分比换算的舍入影响输出
+    fn fixture_artifacts() -> Vec<GenshinArtifact> {
+        vec![GenshinArtifact {
+            set_name: ArtifactSetName::CrimsonWitch,
+            slot: ArtifactSlot::Flower,
+            star: 5,
+            lock: true,
+            level: 20,
+            main_stat: ArtifactStat { name: ArtifactStatName::Hp, value: 4780.0 },
+            sub_stat_1: Some(ArtifactStat { name: ArtifactStatName::Critical, value: 0.125 }),
+            sub_stat_2: Some(ArtifactStat { name: ArtifactStatName::CriticalDamage, value: 0.25 }),
+            sub_stat_3: Some(ArtifactStat { name: ArtifactStatName::Atk, value: 19.0 }),
+            sub_stat_4: None,
+            equip: Some("胡桃".to_string()),
+        }]
+    }
+
+    #[test]
+    fn test_mona_golden() {
+        let expected = concat!(
+            r#"{"version":"1","flower":[{"setName":"crimsonWitch","position":"flower","#,
+            r#""mainTag":{"name":"lifeStatic","value":4780.0},"normalTags":["#,
+            r#"{"name":"critical","value":0.125},{"name":"criticalDamage","value":0.25},"#,
+            r#"{"name":"attackStatic","value":19.0}],"omit":false,"level":20,"star":5,"#,
+            r#""equip":"胡桃"}],"feather":[],"sand":[],"cup":[],"head":[]}"#,
+        );
+        assert_eq!(MonaSerializer.serialize(&fixture_artifacts()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_mingyu_lab_golden() {
+        let expected = concat!(
+            r#"[{"asKey":"crimson_witch_of_flames","rarity":5,"slot":"flower","level":20,"#,
+            r#""mainStat":"flatHP","subStat1Type":"critRate","subStat1Value":12.5,"#,
+            r#""subStat2Type":"critDamage","subStat2Value":25.0,"#,
+            r#""subStat3Type":"flatATK","subStat3Value":19.0,"#,
+            r#""subStat4Type":"flatATK","subStat4Value":0.0}]"#,
+        );
+        assert_eq!(MingyuLabSerializer.serialize(&fixture_artifacts()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_good_golden() {
+        let expected = concat!(
+            r#"{"format":"GOOD","version":1,"source":"furina","artifacts":["#,
+            r#"{"setKey":"CrimsonWitchOfFlames","slotKey":"flower","level":20,"rarity":5,"#,
+            r#""mainStatKey":"hp","location":"HuTao","lock":true,"substats":["#,
+            r#"{"key":"critRate_","value":12.5},{"key":"critDMG_","value":25.0},"#,
+            r#"{"key":"atk","value":19.0}]}]}"#,
+        );
+        assert_eq!(GOODSerializer.serialize(&fixture_artifacts()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_csv_golden() {
+        let expected = concat!(
+            "套装,部位,星级,等级,主词条名,主词条值,副词条名1,副词条值1,副词条名2,副词条值2,",
+            "副词条名3,副词条值3,副词条名4,副词条值4,装备\n",
+            "CrimsonWitch,Flower,5,20,Hp,4780,Critical,0.125,CriticalDamage,0.25,Atk,19,,,胡桃\n",
+        );
+        assert_eq!(CSVSerializer.serialize(&fixture_artifacts()).unwrap(), expected);
+    }
+}
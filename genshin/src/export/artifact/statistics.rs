@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use furina_core::export::ExportStatistics;
+use log::warn;
+
+use crate::artifact::{ArtifactSetName, GenshinArtifact};
+
+/// 统计圣遗物列表中各套装的数量
+pub trait ArtifactSetCounts {
+    fn set_counts(&self) -> BTreeMap<ArtifactSetName, usize>;
+}
+
+impl ArtifactSetCounts for [GenshinArtifact] {
+    fn set_counts(&self) -> BTreeMap<ArtifactSetName, usize> {
+        let mut counts = BTreeMap::new();
+        for artifact in self {
+            *counts.entry(artifact.set_name.clone()).or_insert(0) += 1;
+        }
+        counts
+    }
+}
+
+/// 从导出统计中读取按套装分类的数量
+///
+/// `ExportStatistics` 位于 furina_core，不能依赖本 crate 的 `ArtifactSetName`，
+/// 因此以扩展 trait 的形式提供，按套装名称字符串转换回枚举
+pub trait ArtifactSetStatistics {
+    fn by_set(&self) -> BTreeMap<ArtifactSetName, usize>;
+}
+
+impl ArtifactSetStatistics for ExportStatistics {
+    /// 无法识别的套装名称不计入结果，并记录警告，避免各套装数量之和少于导出数量却无从察觉
+    fn by_set(&self) -> BTreeMap<ArtifactSetName, usize> {
+        let mut counts = BTreeMap::new();
+        for (name, &count) in &self.set_counts {
+            match ArtifactSetName::from_str(name) {
+                Ok(set_name) => *counts.entry(set_name).or_insert(0) += count,
+                Err(_) => warn!(
+                    "导出统计中的套装名称 `{name}` 无法识别，其 {count} 件圣遗物未计入套装统计"
+                ),
+            }
+        }
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use furina_core::export::{AssetEmitter, ExportAssets};
+
+    use super::*;
+    use crate::artifact::{ArtifactSlot, ArtifactStat, ArtifactStatName};
+    use crate::export::artifact::{GenshinArtifactExportFormat, GenshinArtifactExporter};
+
+    fn artifact(set_name: ArtifactSetName, slot: ArtifactSlot) -> GenshinArtifact {
+        GenshinArtifact {
+            set_name,
+            slot,
+            star: 5,
+            lock: false,
+            level: 20,
+            main_stat: ArtifactStat { name: ArtifactStatName::Hp, value: 4780.0 },
+            sub_stat_1: None,
+            sub_stat_2: None,
+            sub_stat_3: None,
+            sub_stat_4: None,
+            equip: None,
+        }
+    }
+
+    #[test]
+    fn test_by_set_counts_fixture() {
+        let artifacts = vec![
+            artifact(ArtifactSetName::EmblemOfSeveredFate, ArtifactSlot::Flower),
+            artifact(ArtifactSetName::EmblemOfSeveredFate, ArtifactSlot::Feather),
+            artifact(ArtifactSetName::CrimsonWitch, ArtifactSlot::Flower),
+            artifact(ArtifactSetName::EmblemOfSeveredFate, ArtifactSlot::Head),
+        ];
+
+        let exporter = GenshinArtifactExporter {
+            format: GenshinArtifactExportFormat::All,
+            results: Some(&artifacts),
+            output_dir: std::path::PathBuf::from("."),
+            output_name: None,
+        };
+        let mut export_assets = ExportAssets::new();
+        exporter.emit(&mut export_assets);
+        let stats = export_assets.dry_run();
+
+        let by_set = stats.by_set();
+        assert_eq!(by_set.len(), 2);
+        assert_eq!(by_set[&ArtifactSetName::EmblemOfSeveredFate], 3);
+        assert_eq!(by_set[&ArtifactSetName::CrimsonWitch], 1);
+        assert_eq!(stats.top_sets(1), vec![("EmblemOfSeveredFate", 3)]);
+    }
+
+    #[test]
+    fn test_by_set_skips_unknown_names() {
+        let mut stats = ExportStatistics::default();
+        stats.set_counts.insert("CrimsonWitch".to_string(), 2);
+        stats.set_counts.insert("NotASet".to_string(), 5);
+
+        let by_set = stats.by_set();
+        assert_eq!(by_set.len(), 1);
+        assert_eq!(by_set[&ArtifactSetName::CrimsonWitch], 2);
+    }
+}
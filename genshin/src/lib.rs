@@ -10,5 +10,9 @@ pub mod application;
 pub mod artifact;
 pub mod character;
 pub mod export;
+pub mod locale;
 pub mod scanner;
 pub mod scanner_controller;
+pub mod weapon;
+
+pub use locale::Locale;
@@ -0,0 +1,98 @@
+use std::fmt;
+
+use clap::ValueEnum;
+use log::{info, warn};
+
+use crate::artifact::ArtifactSetName;
+
+/// 自动检测语言时最多查看的圣遗物名称数量
+pub const LOCALE_SNIFF_LIMIT: usize = 5;
+
+/// 游戏客户端的语言
+///
+/// 目前OCR模型和角色名称表只有简体中文，其他语言暂不支持识别；扫描时根据圣遗物名称自动检测（见 [`Locale::detect`]），
+/// `--locale` 指定的语言只在无法检测时使用。
+/// 韩语已有全部套装和属性的名称表，但没有对应的OCR模型。预先定义以便各语言的数据表逐步加入时不必再修改接口
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, clap::ValueEnum)]
+pub enum Locale {
+    /// 简体中文
+    #[default]
+    ZhCn,
+    /// 英语
+    En,
+    /// 日语
+    Ja,
+    /// 韩语
+    Ko,
+}
+
+impl Locale {
+    /// 是否已有该语言的识别模型和名称表
+    pub fn is_supported(&self) -> bool {
+        matches!(self, Locale::ZhCn)
+    }
+
+    /// 根据识别出的圣遗物名称检测游戏客户端语言
+    ///
+    /// 依次查看前 [`LOCALE_SNIFF_LIMIT`] 个非空的名称，用每种语言的名称表（见 [`ArtifactSetName::from_locale`]）
+    /// 查找，第一个能查到的语言即为检测结果，之后的名称不再查看。
+    /// 这些名称都无法识别时返回 `fallback`（即 `--locale` 指定的语言）并输出警告
+    pub fn detect<'a>(names: impl IntoIterator<Item = &'a str>, fallback: Locale) -> Locale {
+        let mut checked = 0;
+        for name in names.into_iter().filter(|name| !name.is_empty()).take(LOCALE_SNIFF_LIMIT) {
+            checked += 1;
+            let detected = Locale::value_variants()
+                .iter()
+                .copied()
+                .find(|&locale| ArtifactSetName::from_locale(name, locale).is_some());
+            if let Some(locale) = detected {
+                info!("根据圣遗物名称 '{name}' 检测到游戏客户端语言: {locale}");
+                return locale;
+            }
+        }
+
+        if checked > 0 {
+            warn!(
+                "前 {checked} 个圣遗物名称均无法判断游戏客户端语言，使用 --locale 指定的{fallback}"
+            );
+        }
+        fallback
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Locale::ZhCn => "简体中文",
+            Locale::En => "英语",
+            Locale::Ja => "日语",
+            Locale::Ko => "韩语",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_locale() {
+        assert_eq!(Locale::detect(["魔女的炎之花"], Locale::ZhCn), Locale::ZhCn);
+        // 无法识别的名称被跳过，第一个能识别的名称决定语言
+        assert_eq!(
+            Locale::detect(["???", "검투사의 향수", "魔女的炎之花"], Locale::ZhCn),
+            Locale::Ko
+        );
+        // 未识别出名称的物品不计入查看数量
+        let mut names = vec![""; LOCALE_SNIFF_LIMIT];
+        names.push("검투사의 향수");
+        assert_eq!(Locale::detect(names, Locale::ZhCn), Locale::Ko);
+
+        // 前几个名称都无法识别时使用指定的语言，之后的名称不再查看
+        let mut names = vec!["???"; LOCALE_SNIFF_LIMIT];
+        names.push("검투사의 향수");
+        assert_eq!(Locale::detect(names, Locale::ZhCn), Locale::ZhCn);
+        assert_eq!(Locale::detect([], Locale::Ja), Locale::Ja);
+    }
+}
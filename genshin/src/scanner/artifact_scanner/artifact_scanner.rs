@@ -1,55 +1,240 @@
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::ops::{Coroutine, CoroutineState};
 use std::pin::Pin;
 use std::rc::Rc;
-use std::sync::mpsc::{self, Sender};
-use std::time::SystemTime;
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
+use std::time::{Duration, Instant, SystemTime};
 
 use anyhow::Result;
 use clap::FromArgMatches;
-use furina_core::capture::{Capturer, GenericCapturer};
+use furina_core::capture::{is_blank_capture, Capturer, GenericCapturer, BLANK_CAPTURE_TOLERANCE};
+use furina_core::common::color::Color;
+use furina_core::error_recovery::{
+    ErrorCategory, ErrorRecoveryManager, RecoveryConfig, RecoveryStrategy,
+};
 use furina_core::game_info::GameInfo;
-use furina_core::ocr::{ImageToText, OcrModel};
+use furina_core::ocr::{ImageToText, NullOcrModel, OcrModel};
 use furina_core::ocr_model;
-use furina_core::positioning::Pos;
 use furina_core::window_info::{FromWindowInfoRepository, WindowInfoRepository};
-use image::RgbImage;
+use image::{Rgb, RgbImage};
 use log::{error, info, warn};
 
-use crate::scanner::artifact_scanner::artifact_scanner_config::GenshinArtifactScannerConfig;
+use crate::scanner::artifact_scanner::artifact_scanner_config::{
+    FilterDecision, GenshinArtifactScannerConfig,
+};
 use crate::scanner::artifact_scanner::artifact_scanner_worker::ArtifactScannerWorker;
+use crate::scanner::artifact_scanner::checkpoint::{CheckpointRecorder, ScanCheckpoint};
 use crate::scanner::artifact_scanner::error::{get_error_suggestion, ArtifactScanError};
 use crate::scanner::artifact_scanner::message_items::SendItem;
+use crate::scanner::artifact_scanner::performance_optimizations::{
+    ColorMetric, OptimizedImageProcessor, OptimizedOCRRecognizer,
+};
+use crate::scanner::artifact_scanner::scan_report::{ScanReport, ScanStopReason};
 use crate::scanner::artifact_scanner::scan_result::GenshinArtifactScanResult;
 use crate::scanner::artifact_scanner::ArtifactScannerWindowInfo;
 use crate::scanner_controller::repository_layout::{
-    GenshinRepositoryScanController, GenshinRepositoryScannerLogicConfig,
+    GenshinRepositoryScanController, GenshinRepositoryScannerLogicConfig, ItemPosition,
     ReturnResult as GenshinRepositoryControllerReturnResult,
 };
 
-fn color_distance(c1: &image::Rgb<u8>, c2: &image::Rgb<u8>) -> usize {
-    let x = c1.0[0] as i32 - c2.0[0] as i32;
-    let y = c1.0[1] as i32 - c2.0[1] as i32;
-    let z = c1.0[2] as i32 - c2.0[2] as i32;
-    (x * x + y * y + z * z) as usize
+/// 截图线程向识别线程发送物品的有界队列
+///
+/// 每个物品都包含一张完整的面板截图，识别跟不上截图时队列满后截图线程会阻塞等待，
+/// 避免大背包下积压的截图占满内存
+fn item_channel(capacity: usize) -> (SyncSender<Option<SendItem>>, Receiver<Option<SendItem>>) {
+    mpsc::sync_channel(capacity)
+}
+
+/// 根据采样颜色判断星级，返回星级（1-5）以及与最接近的星级颜色的距离平方
+pub fn classify_star(color: Color, star_colors: &[Color; 5], metric: ColorMetric) -> (usize, u32) {
+    let sampled = Rgb([color.0, color.1, color.2]);
+    let mut min_dis: u32 = u32::MAX;
+    let mut ret: usize = 1;
+    for (i, match_color) in star_colors.iter().enumerate() {
+        let target = Rgb([match_color.0, match_color.1, match_color.2]);
+        let dis2 = OptimizedImageProcessor::color_distance(&sampled, &target, metric);
+        if dis2 < min_dis {
+            min_dis = dis2;
+            ret = i + 1;
+        }
+    }
+    (ret, min_dis)
+}
+
+/// 星级采样区域每个方向上的采样点数
+pub const STAR_SAMPLE_GRID: u32 = 3;
+
+/// 将星级采样区域的截图等分为 [`STAR_SAMPLE_GRID`]×[`STAR_SAMPLE_GRID`] 个格子，取每格中心像素的颜色
+///
+/// 截图为空时返回空列表
+pub fn star_sample_colors(image: &RgbImage) -> Vec<Color> {
+    let (width, height) = image.dimensions();
+    if width == 0 || height == 0 {
+        return Vec::new();
+    }
+
+    let mut colors = Vec::with_capacity((STAR_SAMPLE_GRID * STAR_SAMPLE_GRID) as usize);
+    for row in 0..STAR_SAMPLE_GRID {
+        for col in 0..STAR_SAMPLE_GRID {
+            let x = (2 * col + 1) * width / (2 * STAR_SAMPLE_GRID);
+            let y = (2 * row + 1) * height / (2 * STAR_SAMPLE_GRID);
+            colors.push((*image.get_pixel(x, y)).into());
+        }
+    }
+    colors
+}
+
+/// 对多个采样颜色分别按 [`classify_star`] 判断星级，取票数最多的星级，票数相同时取距离更近的
+///
+/// 返回星级以及投给该星级的采样点中最小的距离平方。单个像素可能落在图标边缘的抗锯齿或高光上，
+/// 多点投票可以避免这类误判；没有采样颜色时与空颜色表一样返回 `(1, u32::MAX)`
+pub fn classify_star_samples(
+    colors: &[Color],
+    star_colors: &[Color; 5],
+    metric: ColorMetric,
+) -> (usize, u32) {
+    if colors.is_empty() {
+        return (1, u32::MAX);
+    }
+
+    // 每个星级的票数和最小距离
+    let mut votes = [(0usize, u32::MAX); 5];
+    for &color in colors {
+        let (star, dis2) = classify_star(color, star_colors, metric);
+        let vote = &mut votes[star - 1];
+        vote.0 += 1;
+        vote.1 = vote.1.min(dis2);
+    }
+
+    let (index, (_, min_dis)) = votes
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.0.cmp(&b.0).then(b.1.cmp(&a.1)))
+        .unwrap();
+    (index + 1, *min_dis)
+}
+
+/// 截图失败时按恢复策略重试，重试后仍然失败则记录错误并返回 `None`
+///
+/// 调用方应跳过当前物品，而不是中止整个扫描
+pub fn capture_with_recovery<T, E: Into<anyhow::Error>>(
+    recovery_manager: &ErrorRecoveryManager,
+    region: &str,
+    mut capture: impl FnMut() -> Result<T, E>,
+) -> Option<T> {
+    let mut capture = || {
+        capture().map_err(|e| match e.into().downcast::<ArtifactScanError>() {
+            Ok(error) => error,
+            Err(e) => ArtifactScanError::ImageCaptureFailed {
+                region: region.to_string(),
+                error_msg: e.to_string(),
+            },
+        })
+    };
+
+    let error = match capture() {
+        Ok(v) => return Some(v),
+        Err(e) => e,
+    };
+
+    match recovery_manager.attempt_recovery_blocking(&mut capture, &error) {
+        Ok(v) => {
+            info!("{region}重新截图成功");
+            Some(v)
+        },
+        Err(e) => {
+            error!("{region}截图失败，跳过当前物品: {e}");
+            error!("建议: {}", get_error_suggestion(&error));
+            None
+        },
+    }
+}
+
+/// 连续截图失败的物品计数，用于区分偶发的截图失败和已经关闭的游戏窗口
+///
+/// 单个物品的截图失败由 [`capture_with_recovery`] 重试后跳过；连续 `limit` 个物品都截图失败时，
+/// 游戏很可能已经关闭或最小化，继续扫描只会不断重试，此时应停止扫描并保留已识别的结果
+#[derive(Debug, Clone)]
+pub struct CaptureFailureTracker {
+    limit: usize,
+    consecutive: usize,
+}
+
+impl CaptureFailureTracker {
+    pub fn new(limit: usize) -> Self {
+        CaptureFailureTracker { limit, consecutive: 0 }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// 当前连续截图失败的物品数量
+    pub fn consecutive(&self) -> usize {
+        self.consecutive
+    }
+
+    /// 记录一个物品的截图结果，成功时清零计数；连续失败达到上限时返回中断扫描的错误
+    pub fn record<T>(
+        &mut self,
+        captured: &Option<T>,
+        scanned_count: usize,
+    ) -> Result<(), ArtifactScanError> {
+        if captured.is_some() {
+            self.consecutive = 0;
+            return Ok(());
+        }
+
+        self.consecutive += 1;
+        if self.consecutive >= self.limit {
+            return Err(ArtifactScanError::ScanInterrupted {
+                reason: "capture lost".to_string(),
+                scanned_count,
+            });
+        }
+        Ok(())
+    }
 }
 
 pub struct GenshinArtifactScanner {
     scanner_config: GenshinArtifactScannerConfig,
     window_info: ArtifactScannerWindowInfo,
     game_info: GameInfo,
-    image_to_text: Box<dyn ImageToText<RgbImage> + Send>,
+    // 与识别线程共享同一个模型
+    ocr_recognizer: OptimizedOCRRecognizer,
     controller: Rc<RefCell<GenshinRepositoryScanController>>,
     capturer: Rc<dyn Capturer<RgbImage>>,
+    recovery_manager: ErrorRecoveryManager,
 }
 
 impl GenshinArtifactScanner {
-    pub const MAX_COUNT: usize = 2100;
+    /// 扫描时截图使用的错误恢复管理器
+    ///
+    /// 截图没有备用方法，默认的 `UseFallback` 在这里只能是重新截图，
+    /// 因此改为短暂等待后重试，以跳过窗口重绘等瞬时故障。
+    /// 截图重试很少发生，开启重试日志以便从用户反馈的日志中看出重试了几次
+    pub fn capture_recovery_manager() -> ErrorRecoveryManager {
+        let mut config = RecoveryConfig { log_attempts: true, ..RecoveryConfig::default() };
+        config.strategy_map.insert(
+            ErrorCategory::ImageProcessing,
+            RecoveryStrategy::DelayedRetry(Duration::from_millis(100)),
+        );
+        ErrorRecoveryManager::new(config)
+    }
 }
 
 // constructor
 impl GenshinArtifactScanner {
-    fn get_image_to_text() -> Result<Box<dyn ImageToText<RgbImage> + Send>> {
+    /// 加载OCR模型，`--no-ocr` 时使用总是返回空字符串的 [`NullOcrModel`]
+    pub fn get_image_to_text(
+        config: &GenshinArtifactScannerConfig,
+    ) -> Result<Box<dyn ImageToText<RgbImage> + Send>> {
+        if config.no_ocr {
+            warn!("[no-ocr] 未加载OCR模型，识别结果均为空，本次扫描仅用于检查截图与翻页");
+            return Ok(Box::new(NullOcrModel));
+        }
+
         let model: Box<dyn ImageToText<RgbImage> + Send> = Box::new(
             ocr_model!("./models/model_training.onnx", "./models/index_2_word.json").map_err(
                 |e| {
@@ -78,38 +263,79 @@ impl GenshinArtifactScanner {
         })?))
     }
 
-    pub fn new(
+    /// 获取当前窗口尺寸对应的窗口信息，尺寸与模板不完全一致时提示缩放使用的模板
+    fn resolve_window_info(
         window_info_repo: &WindowInfoRepository,
-        config: GenshinArtifactScannerConfig,
-        controller_config: GenshinRepositoryScannerLogicConfig,
-        game_info: GameInfo,
-    ) -> Result<Self> {
-        let window_info = ArtifactScannerWindowInfo::from_window_info_repository(
-            game_info.window.to_rect_usize().size(),
+        game_info: &GameInfo,
+    ) -> Result<ArtifactScannerWindowInfo> {
+        let window_size = game_info.window.to_rect_usize().size();
+        if let Some(warning) =
+            window_info_repo.size_mismatch_warning(window_size, game_info.ui, game_info.platform)
+        {
+            warn!("{warning}");
+        }
+
+        ArtifactScannerWindowInfo::from_window_info_repository(
+            window_size,
             game_info.ui,
             game_info.platform,
             window_info_repo,
         )
         .map_err(|e| {
-            let error = ArtifactScanError::WindowInfoFailed { error_msg: e.to_string() };
+            let error = ArtifactScanError::WindowInfoFailed {
+                error_msg: format!(
+                    "{e}（支持的分辨率: {}）",
+                    window_info_repo.supported_sizes_text()
+                ),
+            };
             error!("窗口信息获取失败: {error}");
             error!("建议: {}", get_error_suggestion(&error));
             anyhow::anyhow!(error)
-        })?;
+        })
+    }
+
+    pub fn new(
+        window_info_repo: &WindowInfoRepository,
+        config: GenshinArtifactScannerConfig,
+        controller_config: GenshinRepositoryScannerLogicConfig,
+        game_info: GameInfo,
+    ) -> Result<Self> {
+        let model = Self::get_image_to_text(&config)?;
+        Self::with_model(window_info_repo, config, controller_config, game_info, model)
+    }
+
+    /// 使用已加载的OCR模型创建扫描器，模型同时用于识别物品数量和识别线程，不会再从磁盘加载
+    ///
+    /// 创建时会先对模型做一次预热推理，见 [`OptimizedOCRRecognizer::warm_up`]
+    pub fn with_model(
+        window_info_repo: &WindowInfoRepository,
+        config: GenshinArtifactScannerConfig,
+        controller_config: GenshinRepositoryScannerLogicConfig,
+        game_info: GameInfo,
+        model: Box<dyn ImageToText<RgbImage> + Send>,
+    ) -> Result<Self> {
+        let window_info = Self::resolve_window_info(window_info_repo, &game_info)?;
 
-        Ok(Self {
-            scanner_config: config,
+        let controller = GenshinRepositoryScanController::new(
+            window_info_repo,
+            controller_config,
+            game_info.clone(),
+            true,
+        )?;
+
+        let warm_up = !config.no_ocr;
+        let scanner = Self::with_backends(
+            config,
             window_info,
-            controller: Rc::new(RefCell::new(GenshinRepositoryScanController::new(
-                window_info_repo,
-                controller_config,
-                game_info.clone(),
-                true,
-            )?)),
             game_info,
-            image_to_text: Self::get_image_to_text()?,
-            capturer: Self::get_capturer()?,
-        })
+            controller,
+            model,
+            Self::get_capturer()?,
+        );
+        if warm_up {
+            scanner.ocr_recognizer.warm_up();
+        }
+        Ok(scanner)
     }
 
     pub fn from_arg_matches(
@@ -117,32 +343,32 @@ impl GenshinArtifactScanner {
         arg_matches: &clap::ArgMatches,
         game_info: GameInfo,
     ) -> Result<Self> {
-        let window_info = ArtifactScannerWindowInfo::from_window_info_repository(
-            game_info.window.to_rect_usize().size(),
-            game_info.ui,
-            game_info.platform,
+        Self::new(
             window_info_repo,
+            GenshinArtifactScannerConfig::from_arg_matches(arg_matches)?,
+            GenshinRepositoryScannerLogicConfig::from_arg_matches(arg_matches)?,
+            game_info,
         )
-        .map_err(|e| {
-            let error = ArtifactScanError::WindowInfoFailed { error_msg: e.to_string() };
-            error!("窗口信息获取失败: {error}");
-            error!("建议: {}", get_error_suggestion(&error));
-            anyhow::anyhow!(error)
-        })?;
+    }
 
-        Ok(GenshinArtifactScanner {
-            scanner_config: GenshinArtifactScannerConfig::from_arg_matches(arg_matches)?,
+    /// 使用指定的控制器、识别模型与截图实现创建扫描器，用于测试或无界面运行
+    pub fn with_backends(
+        scanner_config: GenshinArtifactScannerConfig,
+        window_info: ArtifactScannerWindowInfo,
+        game_info: GameInfo,
+        controller: GenshinRepositoryScanController,
+        image_to_text: Box<dyn ImageToText<RgbImage> + Send>,
+        capturer: Rc<dyn Capturer<RgbImage>>,
+    ) -> Self {
+        GenshinArtifactScanner {
+            scanner_config,
             window_info,
-            controller: Rc::new(RefCell::new(GenshinRepositoryScanController::from_arg_matches(
-                window_info_repo,
-                arg_matches,
-                game_info.clone(),
-                true,
-            )?)),
             game_info,
-            image_to_text: Self::get_image_to_text()?,
-            capturer: Self::get_capturer()?,
-        })
+            ocr_recognizer: OptimizedOCRRecognizer::from_model(image_to_text),
+            controller: Rc::new(RefCell::new(controller)),
+            capturer,
+            recovery_manager: Self::capture_recovery_manager(),
+        }
     }
 }
 
@@ -164,44 +390,55 @@ impl GenshinArtifactScanner {
             })
     }
 
-    pub fn get_star(&self) -> Result<usize> {
-        let pos: Pos<i32> = Pos {
-            x: self.game_info.window.left + self.window_info.star_pos.x as i32,
-            y: self.game_info.window.top + self.window_info.star_pos.y as i32,
+    /// 扫描前检查截图是否为纯色画面
+    ///
+    /// 截图静默返回全黑画面时后续识别全部失败，错误信息难以理解，提前给出明确的提示
+    pub fn check_capture_not_blank(&self) -> Result<()> {
+        let panel = self.capture_panel()?;
+        if !is_blank_capture(&panel, BLANK_CAPTURE_TOLERANCE) {
+            return Ok(());
+        }
+
+        let error = ArtifactScanError::ImageCaptureFailed {
+            region: "圣遗物面板".to_string(),
+            error_msg: "截图结果为纯色画面（如全黑），截图可能没有获取到游戏内容".to_string(),
         };
-        let color = self.capturer.capture_color(pos).map_err(|e| {
-            let error = ArtifactScanError::ImageCaptureFailed {
-                region: "星级颜色采样".to_string(),
-                error_msg: e.to_string(),
-            };
-            warn!("星级颜色采样失败: {error}");
-            warn!("建议: {}", get_error_suggestion(&error));
-            anyhow::anyhow!(error)
-        })?;
+        error!("{error}");
+        error!("建议: 请以管理员身份运行程序，并尝试关闭 Windows 的 HDR 和游戏的画面保护");
+        Err(anyhow::anyhow!(error))
+    }
 
-        let match_colors = [
-            image::Rgb([113, 119, 139]), // 1星
-            image::Rgb([42, 143, 114]),  // 2星
-            image::Rgb([81, 127, 203]),  // 3星
-            image::Rgb([161, 86, 224]),  // 4星
-            image::Rgb([188, 105, 50]),  // 5星
-        ];
-
-        let mut min_dis: usize = 0xdeadbeef;
-        let mut ret: usize = 1;
-        for (i, match_color) in match_colors.iter().enumerate() {
-            let dis2 = color_distance(match_color, &color);
-            if dis2 < min_dis {
-                min_dis = dis2;
-                ret = i + 1;
-            }
-        }
+    /// 在星级采样区域内多点采样判断星级，见 [`classify_star_samples`]
+    pub fn get_star(&self) -> Result<usize> {
+        let image = self
+            .capturer
+            .capture_relative_to(
+                self.window_info.star_sample_rect.to_rect_i32(),
+                self.game_info.window.origin(),
+            )
+            .map_err(|e| {
+                let error = ArtifactScanError::ImageCaptureFailed {
+                    region: "星级颜色采样".to_string(),
+                    error_msg: e.to_string(),
+                };
+                warn!("星级颜色采样失败: {error}");
+                warn!("建议: {}", get_error_suggestion(&error));
+                anyhow::anyhow!(error)
+            })?;
+        let colors = star_sample_colors(&image);
+
+        let metric = self.scanner_config.color_metric;
+        let (ret, min_dis) =
+            classify_star_samples(&colors, &self.window_info.star_colors(), metric);
+        // 阈值按欧氏距离标定，换算到同一尺度后再比较
+        let min_dis = (min_dis as f64 / metric.threshold_scale()) as u32;
 
         // 检查识别置信度
-        if min_dis > 10000 {
-            // 颜色差距过大，可能识别错误
+        if min_dis as f64 > self.window_info.star_color_threshold {
+            // 颜色差距过大，可能识别错误，报告采样区域中心的颜色
+            let color = colors.get(colors.len() / 2).copied().unwrap_or_default();
             let error = ArtifactScanError::StarRecognitionFailed {
-                detected_color: format!("RGB({}, {}, {})", color.0[0], color.0[1], color.0[2]),
+                detected_color: format!("RGB({}, {}, {})", color.0, color.1, color.2),
                 confidence: 1.0 - (min_dis as f64 / 50000.0).min(1.0),
             };
             warn!("星级识别置信度较低: {error}");
@@ -211,11 +448,16 @@ impl GenshinArtifactScanner {
         anyhow::Ok(ret)
     }
 
+    /// 最多扫描的物品数量，见 `--max-items`
+    fn max_item_count(&self) -> i32 {
+        self.scanner_config.max_items.min(i32::MAX as usize) as i32
+    }
+
     pub fn get_item_count(&self) -> Result<i32> {
         let count = self.scanner_config.number;
         let item_name = "圣遗物";
 
-        let max_count = Self::MAX_COUNT as i32;
+        let max_count = self.max_item_count();
         if count > 0 {
             return Ok(max_count.min(count));
         }
@@ -236,7 +478,7 @@ impl GenshinArtifactScanner {
                 anyhow::anyhow!(error)
             })?;
 
-        let s = self.image_to_text.image_to_text(&im, false).map_err(|e| {
+        let s = self.ocr_recognizer.recognize(&im).map_err(|e| {
             let error = ArtifactScanError::OcrRecognitionFailed {
                 field: "物品数量".to_string(),
                 raw_text: "".to_string(),
@@ -271,27 +513,98 @@ impl GenshinArtifactScanner {
         }
     }
 
-    pub fn scan(&mut self) -> Result<Vec<GenshinArtifactScanResult>> {
+    /// 只返回识别结果的 [`Self::scan`]
+    #[deprecated(note = "使用 scan，返回包含错误统计和耗时的 ScanReport")]
+    pub fn scan_artifacts(&mut self) -> Result<Vec<GenshinArtifactScanResult>> {
+        Ok(self.scan()?.into_artifacts())
+    }
+
+    pub fn scan(&mut self) -> Result<ScanReport> {
+        self.scan_with(None)
+    }
+
+    /// 边扫描边获取识别结果的 [`Self::scan`]
+    ///
+    /// 识别线程每得到一个最终结果（已通过星级、等级、锁定过滤和去重）就发送到 `results`，
+    /// 从检查点恢复时先发送检查点中的结果，扫描结束后返回的 [`ScanReport`] 与 `scan` 相同。
+    ///
+    /// 截图和翻页依赖鼠标操作和非 `Send` 的控制器，只能在调用线程中进行，因此本方法同样会阻塞到扫描结束，
+    /// 需要实时显示结果时应在其他线程中消费对应的 `Receiver`。发送端由识别线程持有，
+    /// 识别线程结束时释放，`Receiver` 的迭代随之结束；`Receiver` 被提前丢弃不影响扫描
+    pub fn scan_streaming(
+        &mut self,
+        results: Sender<GenshinArtifactScanResult>,
+    ) -> Result<ScanReport> {
+        self.scan_with(Some(results))
+    }
+
+    fn scan_with(
+        &mut self,
+        result_sender: Option<Sender<GenshinArtifactScanResult>>,
+    ) -> Result<ScanReport> {
         info!("开始扫描，使用鼠标右键中断扫描");
 
+        self.check_capture_not_blank()?;
+
         let now = SystemTime::now();
-        let (tx, rx) = mpsc::channel::<Option<SendItem>>();
+        let (tx, rx) = item_channel(self.scanner_config.queue_capacity);
 
         let count = self.get_item_count().unwrap_or_else(|e| {
             error!("获取物品数量失败: {e}, 使用默认值");
-            Self::MAX_COUNT as i32
+            self.max_item_count()
         });
 
+        // 从检查点恢复时沿用已测得的滚动量，并跳过已扫描的整页
+        let checkpoint = match &self.scanner_config.resume {
+            Some(path) => {
+                let checkpoint = ScanCheckpoint::load(path)?;
+                checkpoint.check_item_count(count as usize)?;
+                info!(
+                    "从检查点恢复扫描: 已扫描 {}/{} 个物品，已识别 {} 个圣遗物",
+                    checkpoint.scanned_count,
+                    checkpoint.item_count,
+                    checkpoint.results.len()
+                );
+                self.controller.borrow_mut().restore_scroll(checkpoint.scroll);
+                Some(checkpoint)
+            },
+            None => None,
+        };
+        let skip_count = checkpoint.as_ref().map_or(0, |c| c.scanned_count);
+
         let window_size = (self.game_info.window.width as u32, self.game_info.window.height as u32);
-        let worker = ArtifactScannerWorker::new(
+        let mut worker = ArtifactScannerWorker::with_recognizer(
+            self.ocr_recognizer.clone(),
             self.window_info.clone(),
             self.scanner_config.clone(),
             window_size,
-        )?;
+        );
 
-        let join_handle = worker.run(rx);
+        let page_size = (self.window_info.row * self.window_info.col) as usize;
+        let recorder = self
+            .scanner_config
+            .checkpoint_path()
+            .map(|path| CheckpointRecorder::new(path.to_path_buf(), count as usize, page_size));
+        if let Some(recorder) = &recorder {
+            worker = worker.with_checkpoint(recorder.clone());
+        }
+        if let Some(checkpoint) = checkpoint {
+            worker = worker.resume_from(checkpoint);
+        }
+        if let Some(sender) = result_sender {
+            worker = worker.with_result_sender(sender);
+        }
+
+        let join_handle = worker.run_with_stats(rx);
 
-        self.send(&tx, count);
+        let capture_stop_reason = self.send(&tx, count, skip_count, recorder.as_ref());
+        let interrupted = capture_stop_reason == ScanStopReason::Interrupted;
+        if interrupted {
+            if let Some(recorder) = &recorder {
+                let path = recorder.path().display();
+                info!("扫描进度已按页保存到 {path}，可使用 --resume {path} 继续扫描");
+            }
+        }
 
         match tx.send(None) {
             Ok(_) => info!("扫描结束，等待识别线程结束，请勿关闭程序"),
@@ -299,18 +612,34 @@ impl GenshinArtifactScanner {
         }
 
         match join_handle.join() {
-            Ok(v) => {
-                info!("识别耗时: {:?}", now.elapsed()?);
+            Ok((v, error_stats, ocr_timing)) => {
+                let elapsed = now.elapsed()?;
+                info!("识别耗时: {elapsed:?}");
 
-                // filter min level
-                let min_level = self.scanner_config.min_level;
-                let filtered_results: Vec<GenshinArtifactScanResult> =
-                    v.iter().filter(|a| a.level >= min_level).cloned().collect();
+                // filter level range
+                let artifacts: Vec<GenshinArtifactScanResult> = v
+                    .into_iter()
+                    .filter(|a| {
+                        self.scanner_config.level_decision(a.level) == FilterDecision::Accept
+                    })
+                    .collect();
+                // 识别线程主动停止时截图线程随之结束，以识别线程的原因为准
+                let stop_reason = error_stats.stop_reason.unwrap_or(capture_stop_reason);
+                let scanned_count = error_stats.scanned_items;
+                let report = ScanReport {
+                    artifacts,
+                    error_stats,
+                    elapsed,
+                    ocr_timing,
+                    interrupted,
+                    item_count: count.max(0) as usize,
+                    scanned_count,
+                    stop_reason,
+                };
 
                 // 统计有错误的物品
-                let error_count = filtered_results.iter().filter(|r| r.has_errors()).count();
-                let low_confidence_count =
-                    filtered_results.iter().filter(|r| !r.is_reliable(0.8)).count();
+                let error_count = report.error_item_count();
+                let low_confidence_count = report.low_confidence_count();
 
                 if error_count > 0 {
                     warn!("扫描完成，但有 {error_count} 个圣遗物存在识别错误");
@@ -319,9 +648,13 @@ impl GenshinArtifactScanner {
                     warn!("扫描完成，但有 {low_confidence_count} 个圣遗物置信度较低（<80%）");
                 }
 
-                info!("最终结果: 成功识别 {} 个圣遗物", filtered_results.len());
+                if let Some(warning) = report.shortfall_warning() {
+                    warn!("⚠️ 扫描不完整: {warning}");
+                }
+
+                info!("最终结果: 成功识别 {} 个圣遗物", report.len());
 
-                Ok(filtered_results)
+                Ok(report)
             },
             Err(_) => {
                 let error = ArtifactScanError::ScanInterrupted {
@@ -335,6 +668,84 @@ impl GenshinArtifactScanner {
         }
     }
 
+    /// 截取当前物品的面板并采样星级，重试后仍然失败时返回 `None`
+    fn capture_item(&self) -> Option<(RgbImage, usize)> {
+        let image = capture_with_recovery(&self.recovery_manager, "圣遗物面板", || {
+            self.capture_panel()
+        });
+        // 面板截取失败时不再采样星级
+        let star = image.as_ref().and_then(|_| {
+            capture_with_recovery(&self.recovery_manager, "星级颜色采样", || self.get_star())
+        });
+        image.zip(star)
+    }
+
+    /// `--retry-failed`：回到背包顶部，按第一次扫描记录的位置重新定位并识别 `items` 中的物品
+    ///
+    /// 没有位置信息的物品（如从旧版本检查点恢复的结果）会被跳过，锁定状态沿用第一次扫描的结果。
+    /// 返回的结果通过 `position` 与原来的物品对应，重新识别仍然失败的物品也会返回
+    pub fn rescan(
+        &mut self,
+        items: &[GenshinArtifactScanResult],
+    ) -> Vec<GenshinArtifactScanResult> {
+        let locks: HashMap<usize, bool> =
+            items.iter().filter_map(|item| Some((item.position?.index, item.lock))).collect();
+        let positions: Vec<ItemPosition> = items.iter().filter_map(|item| item.position).collect();
+        if positions.len() < items.len() {
+            warn!("{} 个物品没有记录背包位置，无法重新扫描", items.len() - positions.len());
+        }
+        if positions.is_empty() {
+            return Vec::new();
+        }
+        info!("开始重新扫描 {} 个识别失败的物品，使用鼠标右键中断", positions.len());
+
+        let window_size = (self.game_info.window.width as u32, self.game_info.window.height as u32);
+        let mut worker = ArtifactScannerWorker::with_recognizer(
+            self.ocr_recognizer.clone(),
+            self.window_info.clone(),
+            self.scanner_config.clone(),
+            window_size,
+        );
+        let mut generator = GenshinRepositoryScanController::get_retry_generator(
+            self.controller.clone(),
+            positions,
+        );
+        let mut results = Vec::new();
+
+        loop {
+            match Pin::new(&mut generator).resume(()) {
+                CoroutineState::Yielded(position) => {
+                    let Some((panel_image, star)) = self.capture_item() else {
+                        continue;
+                    };
+                    let lock = locks.get(&position.index).copied().unwrap_or(false);
+                    let item = SendItem {
+                        panel_image: Some(panel_image),
+                        star,
+                        list_image: None,
+                        position: Some(position),
+                    };
+                    match worker.scan_item_image_optimized(item, lock) {
+                        Ok(result) => results.push(result),
+                        Err(e) => warn!("第{}个物品重新识别失败: {e}", position.index + 1),
+                    }
+                },
+                CoroutineState::Complete(result) => {
+                    match result {
+                        Err(e) => error!("重新扫描发生错误：{e}"),
+                        Ok(GenshinRepositoryControllerReturnResult::Interrupted) => {
+                            info!("用户中断重新扫描");
+                        },
+                        Ok(GenshinRepositoryControllerReturnResult::Finished) => (),
+                    }
+                    break;
+                },
+            }
+        }
+
+        results
+    }
+
     fn is_page_first_artifact(&self, cur_index: i32) -> bool {
         let col = self.window_info.col;
         let row = self.window_info.row;
@@ -361,17 +772,61 @@ impl GenshinArtifactScanner {
         }
     }
 
-    fn send(&mut self, tx: &Sender<Option<SendItem>>, count: i32) {
-        let mut generator =
-            GenshinRepositoryScanController::get_generator(self.controller.clone(), count as usize);
-        let mut artifact_index: i32 = 0;
+    /// 从第 `skip_count` 个物品起逐个截取物品并发送给识别线程，返回截图结束的原因
+    ///
+    /// 识别线程提前停止导致发送失败时返回 [`ScanStopReason::Finished`]，实际原因由识别线程记录
+    fn send(
+        &mut self,
+        tx: &SyncSender<Option<SendItem>>,
+        count: i32,
+        skip_count: usize,
+        recorder: Option<&CheckpointRecorder>,
+    ) -> ScanStopReason {
+        let mut generator = GenshinRepositoryScanController::get_generator_from(
+            self.controller.clone(),
+            count as usize,
+            skip_count,
+        );
+        let mut artifact_index = skip_count as i32;
+        let mut stop_reason = ScanStopReason::Finished;
+        let mut capture_failures =
+            CaptureFailureTracker::new(self.scanner_config.consecutive_capture_failure_limit);
+        let start = Instant::now();
 
         loop {
             let pinned_generator = Pin::new(&mut generator);
             match pinned_generator.resume(()) {
                 CoroutineState::Yielded(_) => {
-                    let image = self.capture_panel().unwrap();
-                    let star = self.get_star().unwrap();
+                    if let Some(recorder) = recorder {
+                        recorder.update_scroll(self.controller.borrow().scroll_checkpoint());
+                    }
+
+                    if self.scanner_config.is_scan_timed_out(start.elapsed()) {
+                        let error = ArtifactScanError::ScanInterrupted {
+                            reason: "timeout".to_string(),
+                            scanned_count: artifact_index as usize,
+                        };
+                        warn!("超过最长扫描时间，停止扫描并保留已识别的结果: {error}");
+                        stop_reason = ScanStopReason::Timeout;
+                        break;
+                    }
+
+                    let position = self.controller.borrow().current_position();
+                    let captured = self.capture_item();
+                    if let Err(error) = capture_failures.record(&captured, artifact_index as usize)
+                    {
+                        error!(
+                            "连续 {} 个物品截图失败，游戏窗口可能已关闭，停止扫描并保留已识别的结果: {error}",
+                            capture_failures.consecutive()
+                        );
+                        error!("建议: 请确认游戏仍在运行且窗口没有被最小化，然后重新扫描");
+                        stop_reason = ScanStopReason::CaptureLost;
+                        break;
+                    }
+                    let (panel_image, star) = match captured {
+                        Some((image, star)) => (Some(image), star),
+                        None => (None, 0),
+                    };
 
                     let list_image = if self.is_page_first_artifact(artifact_index) {
                         let origin = self.game_info.window;
@@ -392,40 +847,52 @@ impl GenshinArtifactScanner {
                                 * self.get_start_row(count, artifact_index) as f64)
                             as i32;
 
-                        let game_image = self
-                            .capturer
-                            .capture_rect(furina_core::positioning::Rect {
-                                left,
-                                top,
-                                width,
-                                height,
-                            })
-                            .unwrap();
-                        Some(game_image)
+                        match self.capturer.capture_rect(furina_core::positioning::Rect {
+                            left,
+                            top,
+                            width,
+                            height,
+                        }) {
+                            Ok(game_image) => Some(game_image),
+                            Err(e) => {
+                                warn!("背包列表截图失败，本页物品的锁定状态可能不准确: {e}");
+                                None
+                            },
+                        }
                     } else {
                         None
                     };
 
                     artifact_index += 1;
 
-                    if (star as i32) < self.scanner_config.min_star {
+                    // 仅在需要停止时于此处退出；需要跳过或截图失败的物品依然发送给识别线程，
+                    // 以保证列表截图与锁定状态的索引对齐，由识别线程负责跳过
+                    if panel_image.is_some()
+                        && self.scanner_config.star_decision(star as i32) == FilterDecision::Stop
+                    {
                         info!(
                             "找到满足最低星级要求 {} 的物品，准备退出……",
                             self.scanner_config.min_star
                         );
+                        stop_reason = ScanStopReason::MinStar;
                         break;
                     }
 
-                    if tx.send(Some(SendItem { panel_image: image, star, list_image })).is_err() {
+                    if tx.send(Some(SendItem { panel_image, star, list_image, position })).is_err()
+                    {
                         break;
                     }
                 },
                 CoroutineState::Complete(result) => {
                     match result {
-                        Err(e) => error!("扫描发生错误：{e}"),
+                        Err(e) => {
+                            error!("扫描发生错误：{e}");
+                            stop_reason = ScanStopReason::ControllerError;
+                        },
                         Ok(value) => match value {
                             GenshinRepositoryControllerReturnResult::Interrupted => {
-                                info!("用户中断")
+                                info!("用户中断");
+                                stop_reason = ScanStopReason::Interrupted;
                             },
                             GenshinRepositoryControllerReturnResult::Finished => (),
                         },
@@ -435,5 +902,394 @@ impl GenshinArtifactScanner {
                 },
             }
         }
+
+        stop_reason
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::scanner::artifact_scanner::artifact_scanner_config::DEFAULT_MAX_ITEMS;
+
+    const DEFAULT_STAR_COLORS: [Color; 5] = [
+        Color(113, 119, 139),
+        Color(42, 143, 114),
+        Color(81, 127, 203),
+        Color(161, 86, 224),
+        Color(188, 105, 50),
+    ];
+
+    #[test]
+    fn test_classify_star_default_table() {
+        assert_eq!(
+            classify_star(Color(188, 105, 50), &DEFAULT_STAR_COLORS, ColorMetric::Euclidean),
+            (5, 0)
+        );
+        assert_eq!(
+            classify_star(Color(160, 88, 220), &DEFAULT_STAR_COLORS, ColorMetric::Euclidean).0,
+            4
+        );
+    }
+
+    #[test]
+    fn test_classify_star_tuned_table() {
+        // 在不同的伽马设置下，4星的采样颜色偏向5星颜色
+        let sampled = Color(190, 105, 90);
+        assert_eq!(classify_star(sampled, &DEFAULT_STAR_COLORS, ColorMetric::Euclidean).0, 5);
+
+        let mut tuned = DEFAULT_STAR_COLORS;
+        tuned[3] = Color(186, 104, 100);
+        let tuned_threshold = 2000.0;
+
+        let (star, distance) = classify_star(sampled, &tuned, ColorMetric::Euclidean);
+        assert_eq!(star, 4);
+        assert!(distance as f64 <= tuned_threshold);
+        assert_eq!(classify_star(Color(188, 105, 50), &tuned, ColorMetric::Euclidean).0, 5);
+    }
+
+    #[test]
+    fn test_classify_star_weighted_metric() {
+        assert_eq!(
+            classify_star(Color(188, 105, 50), &DEFAULT_STAR_COLORS, ColorMetric::Weighted),
+            (5, 0)
+        );
+        assert_eq!(
+            classify_star(Color(160, 88, 220), &DEFAULT_STAR_COLORS, ColorMetric::Weighted).0,
+            4
+        );
+    }
+
+    #[test]
+    fn test_classify_star_samples_majority() {
+        let purple = DEFAULT_STAR_COLORS[3];
+        // 图标边缘的抗锯齿像素，单独判断时偏向5星
+        let blended = Color(190, 105, 90);
+        assert_eq!(classify_star(blended, &DEFAULT_STAR_COLORS, ColorMetric::Euclidean).0, 5);
+
+        let mut image = RgbImage::from_pixel(20, 8, Rgb([purple.0, purple.1, purple.2]));
+        image.put_pixel(10, 4, Rgb([blended.0, blended.1, blended.2]));
+        image.put_pixel(3, 1, Rgb([blended.0, blended.1, blended.2]));
+        let colors = star_sample_colors(&image);
+        assert_eq!(colors.len(), 9);
+        assert_eq!(colors[4], blended);
+        assert_eq!(
+            classify_star_samples(&colors, &DEFAULT_STAR_COLORS, ColorMetric::Euclidean),
+            (4, 0)
+        );
+
+        // 票数相同时取距离更近的星级
+        let tied = [Color(160, 88, 220), Color(188, 105, 50)];
+        assert_eq!(
+            classify_star_samples(&tied, &DEFAULT_STAR_COLORS, ColorMetric::Euclidean),
+            (5, 0)
+        );
+        assert_eq!(
+            classify_star_samples(&[], &DEFAULT_STAR_COLORS, ColorMetric::Euclidean),
+            (1, u32::MAX)
+        );
+        assert!(star_sample_colors(&RgbImage::new(0, 0)).is_empty());
+    }
+
+    fn fixture_scanner(window_image: RgbImage) -> GenshinArtifactScanner {
+        let config = GenshinArtifactScannerConfig { no_ocr: true, ..Default::default() };
+        // 不依赖 ./models 下的模型文件
+        let image_to_text = GenshinArtifactScanner::get_image_to_text(&config).unwrap();
+        fixture_scanner_with_model(window_image, config, image_to_text)
+    }
+
+    fn fixture_scanner_with_model(
+        window_image: RgbImage,
+        config: GenshinArtifactScannerConfig,
+        image_to_text: Box<dyn ImageToText<RgbImage> + Send>,
+    ) -> GenshinArtifactScanner {
+        use furina_core::capture::StaticImageCapturer;
+        use furina_core::game_info::{Platform, ResolutionFamily, UI};
+        use furina_core::positioning::{Rect, Size};
+        use furina_core::system_control::NullSystemControl;
+        use furina_core::window_info::WindowInfoTemplatePerSize;
+
+        use crate::scanner_controller::repository_layout::GenshinRepositoryScanControllerWindowInfo;
+
+        let mut repo = WindowInfoRepository::new();
+        let template: WindowInfoTemplatePerSize =
+            serde_json::from_str(include_str!("../../../window_info/windows1600x900.json"))
+                .unwrap();
+        template.inject_into_window_info_repo(&mut repo);
+        let size = Size::new(1600, 900);
+        let game_info = GameInfo {
+            window: Rect::new(0, 0, 1600, 900),
+            resolution_family: ResolutionFamily::Windows16x9,
+            is_cloud: false,
+            ui: UI::Desktop,
+            platform: Platform::Windows,
+        };
+        let capturer: Rc<dyn Capturer<RgbImage>> = Rc::new(StaticImageCapturer::new(window_image));
+
+        let controller = GenshinRepositoryScanController::with_backends(
+            GenshinRepositoryScanControllerWindowInfo::from_window_info_repository(
+                size,
+                UI::Desktop,
+                Platform::Windows,
+                &repo,
+            )
+            .unwrap(),
+            GenshinRepositoryScannerLogicConfig::default(),
+            game_info.clone(),
+            true,
+            Box::new(NullSystemControl::new()),
+            capturer.clone(),
+        );
+        GenshinArtifactScanner::with_backends(
+            config,
+            ArtifactScannerWindowInfo::from_window_info_repository(
+                size,
+                UI::Desktop,
+                Platform::Windows,
+                &repo,
+            )
+            .unwrap(),
+            game_info,
+            controller,
+            image_to_text,
+            capturer,
+        )
+    }
+
+    /// 总是返回固定文本并统计调用次数的模拟模型
+    struct FixedTextModel {
+        text: &'static str,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl ImageToText<RgbImage> for FixedTextModel {
+        fn image_to_text(&self, _image: &RgbImage, _is_preprocessed: bool) -> Result<String> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(self.text.to_string())
+        }
+
+        fn get_average_inference_time(&self) -> Option<Duration> {
+            None
+        }
+    }
+
+    /// 快速模式下按字段顺序（名称、主属性名称、主属性数值、等级、装备）返回一件圣遗物的文本，
+    /// 前 `failures` 次识别物品时名称只识别出一半，模拟偶发的OCR识别错误
+    struct FlakyArtifactModel {
+        calls: AtomicUsize,
+        failures: usize,
+    }
+
+    impl ImageToText<RgbImage> for FlakyArtifactModel {
+        fn image_to_text(&self, _image: &RgbImage, _is_preprocessed: bool) -> Result<String> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            let text = match call % 5 {
+                0 if call / 5 < self.failures => "金铜",
+                0 => "金铜时晷",
+                1 => "攻击力",
+                2 => "46.6%",
+                3 => "+20",
+                _ => "",
+            };
+            Ok(text.to_string())
+        }
+
+        fn get_average_inference_time(&self) -> Option<Duration> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_rescan_recovers_flaky_item() {
+        use crate::artifact::GenshinArtifact;
+
+        let config = GenshinArtifactScannerConfig { quick: true, ..Default::default() };
+        let model = FlakyArtifactModel { calls: AtomicUsize::new(0), failures: 1 };
+        let mut scanner =
+            fixture_scanner_with_model(RgbImage::new(1600, 900), config, Box::new(model));
+        let position = ItemPosition { index: 12, scrolled_rows: 0, row: 1, col: 4 };
+        let mut item = GenshinArtifactScanResult::new(
+            String::new(),
+            String::new(),
+            String::new(),
+            Default::default(),
+            String::new(),
+            0,
+            0,
+            true,
+        );
+        item.position = Some(position);
+
+        // 第一次识别该位置的物品时名称识别错误，转换失败
+        let first = scanner.rescan(&[item]);
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].name, "金铜");
+        assert!(GenshinArtifact::from_scan_result(&first[0], false).is_err());
+
+        // 按记录的位置重新扫描后转换成功，锁定状态沿用之前的结果
+        let retried = scanner.rescan(&first);
+        assert_eq!(retried.len(), 1);
+        assert_eq!(retried[0].position, Some(position));
+        let artifact = GenshinArtifact::from_scan_result(&retried[0], false).unwrap();
+        assert_eq!(artifact.level, 20);
+        assert!(artifact.lock);
+
+        // 没有位置信息的物品不会重新扫描
+        let mut unknown = retried[0].clone();
+        unknown.position = None;
+        assert!(scanner.rescan(&[unknown]).is_empty());
+    }
+
+    #[test]
+    fn test_scanner_uses_injected_model() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let model = FixedTextModel { text: "圣遗物 1234/2100", calls: calls.clone() };
+        let scanner = fixture_scanner_with_model(
+            RgbImage::new(1600, 900),
+            GenshinArtifactScannerConfig::default(),
+            Box::new(model),
+        );
+
+        assert_eq!(scanner.get_item_count().unwrap(), 1234);
+        // 识别线程使用的识别器与扫描器共享同一个模型
+        assert_eq!(
+            scanner.ocr_recognizer.clone().recognize(&RgbImage::new(4, 4)).unwrap(),
+            "圣遗物 1234/2100"
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_get_star_samples_region() {
+        let info = fixture_scanner(RgbImage::new(1600, 900)).window_info;
+        let purple = info.star_colors()[3];
+        let blended = Rgb([190, 105, 90]);
+
+        // 采样区域为4星颜色，只有原来单点采样的 star_pos 处是偏向5星的抗锯齿像素
+        let mut image = RgbImage::new(1600, 900);
+        let rect = info.star_sample_rect.to_rect_i32();
+        for y in rect.top..rect.top + rect.height {
+            for x in rect.left..rect.left + rect.width {
+                image.put_pixel(x as u32, y as u32, Rgb([purple.0, purple.1, purple.2]));
+            }
+        }
+        let star_pos = info.star_pos.to_pos_u32();
+        image.put_pixel(star_pos.x, star_pos.y, blended);
+        assert_eq!(
+            classify_star(
+                (*image.get_pixel(star_pos.x, star_pos.y)).into(),
+                &info.star_colors(),
+                ColorMetric::Euclidean
+            )
+            .0,
+            5
+        );
+
+        let scanner = fixture_scanner(image);
+        assert_eq!(scanner.get_star().unwrap(), 4);
+    }
+
+    #[test]
+    fn test_scanner_in_no_ocr_mode() {
+        let scanner = fixture_scanner(RgbImage::new(1600, 900));
+
+        // 截图正常，识别结果为空时物品数量退回到最大值
+        assert!(scanner.capture_panel().is_ok());
+        assert!(scanner.get_star().is_ok());
+        assert_eq!(scanner.get_item_count().unwrap(), DEFAULT_MAX_ITEMS as i32);
+    }
+
+    #[test]
+    fn test_configured_max_items() {
+        let mut scanner = fixture_scanner(RgbImage::new(1600, 900));
+        scanner.scanner_config.max_items = 3000;
+
+        // 识别不到背包数量时使用配置的上限
+        assert_eq!(scanner.get_item_count().unwrap(), 3000);
+
+        // 指定的数量同样不超过上限
+        scanner.scanner_config.number = 2800;
+        assert_eq!(scanner.get_item_count().unwrap(), 2800);
+        scanner.scanner_config.number = 5000;
+        assert_eq!(scanner.get_item_count().unwrap(), 3000);
+    }
+
+    #[test]
+    fn test_off_by_one_window_resolves_nearest_template() {
+        use furina_core::game_info::{Platform, ResolutionFamily, UI};
+        use furina_core::positioning::{Rect, Size};
+        use furina_core::window_info::WindowInfoTemplatePerSize;
+
+        let mut repo = WindowInfoRepository::new();
+        let template: WindowInfoTemplatePerSize =
+            serde_json::from_str(include_str!("../../../window_info/windows1600x900.json"))
+                .unwrap();
+        template.inject_into_window_info_repo(&mut repo);
+        let game_info = GameInfo {
+            window: Rect::new(0, 0, 1601, 901),
+            resolution_family: ResolutionFamily::Windows16x9,
+            is_cloud: false,
+            ui: UI::Desktop,
+            platform: Platform::Windows,
+        };
+
+        let warning =
+            repo.size_mismatch_warning(Size::new(1601, 901), UI::Desktop, Platform::Windows);
+        assert!(warning.is_some_and(|w| w.contains("1600×900")));
+
+        let info = GenshinArtifactScanner::resolve_window_info(&repo, &game_info).unwrap();
+        let exact = ArtifactScannerWindowInfo::from_window_info_repository(
+            Size::new(1600, 900),
+            UI::Desktop,
+            Platform::Windows,
+            &repo,
+        )
+        .unwrap();
+        assert!((info.level_rect.left - exact.level_rect.left * 1601.0 / 1600.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_blank_capture_detected() {
+        let error =
+            fixture_scanner(RgbImage::new(1600, 900)).check_capture_not_blank().unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ArtifactScanError>(),
+            Some(ArtifactScanError::ImageCaptureFailed { region, .. }) if region == "圣遗物面板"
+        ));
+
+        let window =
+            RgbImage::from_fn(1600, 900, |x, y| Rgb([(x % 256) as u8, (y % 256) as u8, 80]));
+        assert!(fixture_scanner(window).check_capture_not_blank().is_ok());
+    }
+
+    #[test]
+    fn test_item_channel_blocks_producer_when_full() {
+        let (tx, rx) = item_channel(2);
+        let sent = Arc::new(AtomicUsize::new(0));
+
+        let producer = {
+            let sent = sent.clone();
+            std::thread::spawn(move || {
+                for star in 0..3 {
+                    let item =
+                        SendItem { panel_image: None, star, list_image: None, position: None };
+                    tx.send(Some(item)).unwrap();
+                    sent.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+        };
+
+        // 队列满后第 3 个物品在识别线程取走物品前无法发送
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        assert_eq!(sent.load(Ordering::SeqCst), 2);
+        assert!(!producer.is_finished());
+
+        assert_eq!(rx.recv().unwrap().unwrap().star, 0);
+        producer.join().unwrap();
+        assert_eq!(sent.load(Ordering::SeqCst), 3);
+        assert_eq!(rx.iter().flatten().map(|item| item.star).collect::<Vec<_>>(), vec![1, 2]);
     }
 }
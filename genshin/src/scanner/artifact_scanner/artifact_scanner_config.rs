@@ -1,4 +1,25 @@
-#[derive(Clone, clap::Args, Default)]
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use furina_core::positioning::Rect;
+
+use crate::locale::Locale;
+use crate::scanner::artifact_scanner::error::ErrorStatistics;
+use crate::scanner::artifact_scanner::performance_optimizations::ColorMetric;
+
+/// 默认最多扫描的物品数量，高于游戏内的背包上限，背包上限提高时可通过 `--max-items` 调整
+pub const DEFAULT_MAX_ITEMS: usize = 3000;
+
+/// 等待识别的截图队列的默认容量，1080p 下每张面板截图约 1.2MB，队列最多占用约 40MB
+pub const DEFAULT_QUEUE_CAPACITY: usize = 32;
+
+/// 默认允许的连续截图失败物品数量，超过后视为游戏窗口已关闭
+pub const DEFAULT_CAPTURE_FAILURE_LIMIT: usize = 5;
+
+/// 按 `--abort-below-success-rate` 判定前至少需要识别的物品数量，避免开头几个物品的偶然失败导致停止
+pub const SUCCESS_RATE_MIN_SAMPLE: usize = 20;
+
+#[derive(Clone, clap::Args)]
 pub struct GenshinArtifactScannerConfig {
     /// Items with stars less than this will be ignored
     #[arg(
@@ -10,6 +31,16 @@ pub struct GenshinArtifactScannerConfig {
     )]
     pub min_star: i32,
 
+    /// Items with stars greater than this will be skipped
+    #[arg(
+        id = "max-star",
+        long = "max-star",
+        help = "最大星级",
+        value_name = "MAX_STAR",
+        default_value_t = 5
+    )]
+    pub max_star: i32,
+
     /// Items with level less than this will be ignored
     #[arg(
         id = "min-level",
@@ -20,10 +51,203 @@ pub struct GenshinArtifactScannerConfig {
     )]
     pub min_level: i32,
 
+    /// Items with level greater than this will be skipped
+    #[arg(
+        id = "max-level",
+        long = "max-level",
+        help = "最大等级",
+        value_name = "MAX_LEVEL",
+        default_value_t = 20
+    )]
+    pub max_level: i32,
+
+    /// Stop scanning at the first item below min star / min level.
+    /// Set to false when the inventory is not sorted by rarity / level.
+    ///
+    /// Skipped items never take part in deduplication: they are not added to the
+    /// seen set and neither extend nor reset the consecutive-duplicate count, so a
+    /// run of skipped low-level items cannot trigger the page-turn error check.
+    /// Star is checked before OCR and level after it, so a skipped low-level item
+    /// still costs one recognition
+    #[arg(
+        id = "stop-on-below-min",
+        long = "stop-on-below-min",
+        help = "遇到低于最小星级/等级的物品时停止扫描（背包未按星级/等级排序时请设为 false）",
+        value_name = "BOOL",
+        action = clap::ArgAction::Set,
+        default_value_t = true
+    )]
+    pub stop_on_below_min: bool,
+
+    /// Only keep locked items
+    #[arg(
+        id = "only-locked",
+        long = "only-locked",
+        help = "仅保留已锁定的物品",
+        conflicts_with = "only-unlocked"
+    )]
+    pub only_locked: bool,
+
+    /// Only keep unlocked items
+    #[arg(id = "only-unlocked", long = "only-unlocked", help = "仅保留未锁定的物品")]
+    pub only_unlocked: bool,
+
     /// Ignore duplicated items
     #[arg(id = "ignore-dup", long = "ignore-dup", help = "忽略重复物品")]
     pub ignore_dup: bool,
 
+    /// Stop after this many consecutive duplicates, defaults to the column count of one page
+    #[arg(
+        id = "dup-threshold",
+        long = "dup-threshold",
+        help = "连续重复物品达到该数量时停止扫描，默认为背包每行的列数",
+        value_name = "DUP_THRESHOLD"
+    )]
+    pub dup_threshold: Option<usize>,
+
+    /// Correct equipped character names within one edit of a known name
+    #[arg(
+        id = "fuzzy-equip",
+        long = "fuzzy-equip",
+        help = "对装备角色名称进行模糊纠正（编辑距离不超过1）"
+    )]
+    pub fuzzy_equip: bool,
+
+    /// Snap near-miss stat names to the closest known stat name before parsing
+    #[arg(
+        id = "snap-stat-names",
+        long = "snap-stat-names",
+        help = "将识别有误的属性名称纠正为最接近的有效属性名称（编辑距离不超过1），减少单字识别错误导致的属性丢失"
+    )]
+    pub snap_stat_names: bool,
+
+    /// Skip the four sub-stat regions for a quick census of set, slot, main stat and level
+    #[arg(
+        id = "quick",
+        long = "quick",
+        help = "快速普查模式：不识别四条副属性，导出结果中副属性为空，识别次数约减少一半"
+    )]
+    pub quick: bool,
+
+    /// Enhance contrast and binarize crops before OCR, helps with dim screenshots
+    #[arg(
+        id = "preprocess",
+        long = "preprocess",
+        help = "识别前对截图进行对比度增强和二值化（适用于暗色主题或低亮度画面）"
+    )]
+    pub preprocess: bool,
+
+    /// Abort a single OCR inference after this many milliseconds, 0 disables the timeout
+    #[arg(
+        id = "ocr-timeout-ms",
+        long = "ocr-timeout-ms",
+        help = "单次OCR识别的超时时间（毫秒），0 表示不限制；启用后每个字段在独立线程中识别，会略微增加开销",
+        value_name = "OCR_TIMEOUT_MS",
+        default_value_t = 0
+    )]
+    pub ocr_timeout_ms: u64,
+
+    /// Color distance used by the star and lock detectors
+    #[arg(
+        id = "color-metric",
+        long = "color-metric",
+        help = "星级/锁定检测使用的颜色距离（euclidean: 欧氏距离，weighted: 按人眼敏感度加权）",
+        value_enum,
+        default_value_t = ColorMetric::Euclidean
+    )]
+    pub color_metric: ColorMetric,
+
+    /// Language of the game client
+    #[arg(
+        id = "locale",
+        long = "locale",
+        help = "游戏客户端语言，扫描时会根据前几个圣遗物名称自动检测，无法检测时使用该值（目前仅支持 zh-cn 简体中文）",
+        value_enum,
+        default_value_t = Locale::ZhCn
+    )]
+    pub locale: Locale,
+
+    /// Stop scanning and keep the results gathered so far after this many seconds
+    #[arg(
+        id = "max-scan-duration",
+        long = "max-scan-duration",
+        help = "最长扫描时间（秒），超时后停止扫描并保留已识别的结果，适用于无人值守的扫描",
+        value_name = "SECONDS",
+        value_parser = parse_duration_secs
+    )]
+    pub max_scan_duration: Option<Duration>,
+
+    /// Stop scanning when the running OCR success rate (in percent) drops below this value
+    #[arg(
+        id = "abort-below-success-rate",
+        long = "abort-below-success-rate",
+        help = "识别成功率（百分比）低于该值时停止扫描并保留已识别的结果，至少识别20个物品后才开始判定，用于及早发现设置错误",
+        value_name = "PERCENT",
+        value_parser = parse_percentage
+    )]
+    pub abort_below_success_rate: Option<f64>,
+
+    /// Save scan progress to this file after every page
+    #[arg(
+        id = "checkpoint",
+        long = "checkpoint",
+        help = "每扫描完一页将进度保存到该文件，扫描中断后可使用 --resume 继续",
+        value_name = "PATH"
+    )]
+    pub checkpoint: Option<PathBuf>,
+
+    /// Skip loading the OCR model; only capture and navigation are exercised
+    #[arg(
+        id = "no-ocr",
+        long = "no-ocr",
+        help = "不加载OCR模型，仅执行截图与翻页并记录日志，用于排查是截图还是识别的问题（不产生任何结果）"
+    )]
+    pub no_ocr: bool,
+
+    /// Write the crop of every field that fails OCR into this directory
+    #[arg(
+        id = "dump-crops",
+        long = "dump-crops",
+        help = "将识别失败的字段截图保存为PNG到该目录（按物品序号和字段命名，最多保存200张），用于排查识别问题",
+        value_name = "DIR"
+    )]
+    pub dump_crops: Option<PathBuf>,
+
+    /// OCR a single rectangle of the game window, print the result and exit
+    #[arg(
+        id = "ocr-rect",
+        long = "ocr-rect",
+        help = "截取游戏窗口内的指定区域（相对窗口左上角的坐标）识别一次并输出结果后退出，用于调试和核对窗口信息",
+        value_name = "LEFT,TOP,WIDTH,HEIGHT",
+        value_parser = parse_ocr_rect
+    )]
+    pub ocr_rect: Option<Rect<i32>>,
+
+    /// Print the window info resolved for the detected game window and exit
+    #[arg(
+        id = "print-window-info",
+        long = "print-window-info",
+        help = "输出当前游戏窗口分辨率下实际使用的窗口信息（各识别区域的坐标）后退出，用于排查裁剪问题和编写覆盖配置"
+    )]
+    pub print_window_info: bool,
+
+    /// Re-navigate to items whose conversion failed due to OCR and scan them once more
+    #[arg(
+        id = "retry-failed",
+        long = "retry-failed",
+        help = "扫描结束后重新定位到因OCR识别错误（名称过短、套装无法识别）而转换失败的物品并再识别一次，只重新扫描这些物品"
+    )]
+    pub retry_failed: bool,
+
+    /// Resume an interrupted scan from a checkpoint file
+    #[arg(
+        id = "resume",
+        long = "resume",
+        help = "从检查点文件继续之前中断的扫描（要求背包内容与保存时一致，并从背包顶部开始）",
+        value_name = "CHECKPOINT"
+    )]
+    pub resume: Option<PathBuf>,
+
     /// it will output very verbose messages
     #[arg(id = "verbose", long, help = "显示详细信息")]
     pub verbose: bool,
@@ -31,4 +255,299 @@ pub struct GenshinArtifactScannerConfig {
     /// the exact amount to scan
     #[arg(id = "number", long, help = "指定圣遗物数量", value_name = "NUMBER", default_value_t = -1)]
     pub number: i32,
+
+    /// Upper bound of the item count, both for --number and the count read from the game
+    #[arg(
+        id = "max-items",
+        long = "max-items",
+        help = "最多扫描的物品数量，识别到的背包数量和 --number 都不会超过该值",
+        value_name = "MAX_ITEMS",
+        default_value_t = DEFAULT_MAX_ITEMS
+    )]
+    pub max_items: usize,
+
+    /// Number of captured items that may wait for OCR before capturing blocks
+    #[arg(
+        id = "queue-capacity",
+        long = "queue-capacity",
+        help = "等待识别的截图数量上限，识别跟不上截图时暂停截图，避免占用过多内存",
+        value_name = "CAPACITY",
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+        default_value_t = DEFAULT_QUEUE_CAPACITY
+    )]
+    pub queue_capacity: usize,
+
+    /// Abort the scan after this many items in a row fail to capture
+    #[arg(
+        id = "capture-failure-limit",
+        long = "capture-failure-limit",
+        help = "连续截图失败的物品达到该数量时停止扫描并保留已识别的结果，用于区分偶发的截图失败和已关闭的游戏窗口",
+        value_name = "COUNT",
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+        default_value_t = DEFAULT_CAPTURE_FAILURE_LIMIT
+    )]
+    pub consecutive_capture_failure_limit: usize,
+}
+
+impl Default for GenshinArtifactScannerConfig {
+    fn default() -> Self {
+        GenshinArtifactScannerConfig {
+            min_star: 5,
+            max_star: 5,
+            min_level: 0,
+            max_level: 20,
+            stop_on_below_min: true,
+            only_locked: false,
+            only_unlocked: false,
+            ignore_dup: false,
+            dup_threshold: None,
+            fuzzy_equip: false,
+            snap_stat_names: false,
+            quick: false,
+            preprocess: false,
+            ocr_timeout_ms: 0,
+            color_metric: ColorMetric::Euclidean,
+            locale: Locale::ZhCn,
+            max_scan_duration: None,
+            abort_below_success_rate: None,
+            no_ocr: false,
+            dump_crops: None,
+            ocr_rect: None,
+            print_window_info: false,
+            checkpoint: None,
+            retry_failed: false,
+            resume: None,
+            verbose: false,
+            number: -1,
+            max_items: DEFAULT_MAX_ITEMS,
+            queue_capacity: DEFAULT_QUEUE_CAPACITY,
+            consecutive_capture_failure_limit: DEFAULT_CAPTURE_FAILURE_LIMIT,
+        }
+    }
+}
+
+/// 解析以秒为单位的时长，支持小数，必须大于 0
+pub fn parse_duration_secs(s: &str) -> Result<Duration, String> {
+    match s.trim().parse::<f64>() {
+        Ok(secs) if secs > 0.0 && secs.is_finite() => Ok(Duration::from_secs_f64(secs)),
+        _ => Err(format!("时长必须为大于 0 的秒数: '{s}'")),
+    }
+}
+
+/// 解析 0 到 100 之间的百分比，支持小数
+pub fn parse_percentage(s: &str) -> Result<f64, String> {
+    match s.trim().trim_end_matches('%').parse::<f64>() {
+        Ok(value) if (0.0..=100.0).contains(&value) => Ok(value),
+        _ => Err(format!("百分比必须为 0 到 100 之间的数: '{s}'")),
+    }
+}
+
+/// 解析 `left,top,width,height` 形式的区域，坐标不能为负，宽高必须大于 0
+pub fn parse_ocr_rect(s: &str) -> Result<Rect<i32>, String> {
+    let values = s
+        .split(',')
+        .map(|part| part.trim().parse::<i32>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("区域 '{s}' 中包含无效的数字: {e}"))?;
+    let [left, top, width, height] = values[..] else {
+        return Err(format!("区域 '{s}' 应为 left,top,width,height 四个整数"));
+    };
+    if left < 0 || top < 0 || width <= 0 || height <= 0 {
+        return Err(format!("区域 '{s}' 的坐标不能为负数，宽高必须大于 0"));
+    }
+    Ok(Rect::new(left, top, width, height))
+}
+
+/// 星级/等级过滤的判定结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterDecision {
+    /// 保留该物品
+    Accept,
+    /// 跳过该物品，继续扫描
+    Skip,
+    /// 停止扫描
+    Stop,
+}
+
+impl GenshinArtifactScannerConfig {
+    fn decide(&self, value: i32, min: i32, max: i32) -> FilterDecision {
+        if value < min {
+            if self.stop_on_below_min {
+                FilterDecision::Stop
+            } else {
+                FilterDecision::Skip
+            }
+        } else if value > max {
+            FilterDecision::Skip
+        } else {
+            FilterDecision::Accept
+        }
+    }
+
+    /// 根据星级判定物品是否保留
+    ///
+    /// 低于 `min_star` 时，若 `stop_on_below_min` 为 true 则停止扫描（假设背包按星级排序），
+    /// 否则仅跳过该物品；高于 `max_star` 的物品总是被跳过
+    pub fn star_decision(&self, star: i32) -> FilterDecision {
+        self.decide(star, self.min_star, self.max_star)
+    }
+
+    /// 根据等级判定物品是否保留，规则同 [`Self::star_decision`]
+    ///
+    /// 被跳过的物品不参与去重：既不会记入已扫描集合，也不会增加或重置连续重复计数
+    pub fn level_decision(&self, level: i32) -> FilterDecision {
+        self.decide(level, self.min_level, self.max_level)
+    }
+
+    /// 判定为翻页错误所需的连续重复物品数量
+    ///
+    /// 未指定 `--dup-threshold` 时使用背包每行的列数 `col`：一整行都是重复物品通常意味着翻页出错。
+    /// 列数较少的分辨率下，相似的圣遗物可能误触发，此时可调大该值
+    pub fn effective_dup_threshold(&self, col: usize) -> usize {
+        self.dup_threshold.unwrap_or(col)
+    }
+
+    /// 扫描过程中写入检查点的路径，未指定 `--checkpoint` 时沿用 `--resume` 的文件
+    pub fn checkpoint_path(&self) -> Option<&Path> {
+        self.checkpoint.as_deref().or(self.resume.as_deref())
+    }
+
+    /// 已扫描 `elapsed` 时是否已经超过最长扫描时间，未设置 `--max-scan-duration` 时总是返回 false
+    pub fn is_scan_timed_out(&self, elapsed: Duration) -> bool {
+        self.max_scan_duration.is_some_and(|max| elapsed >= max)
+    }
+
+    /// 已识别 `scanned` 个物品时，识别成功率是否低于 `--abort-below-success-rate`
+    ///
+    /// 未设置该选项或识别的物品少于 [`SUCCESS_RATE_MIN_SAMPLE`] 个时总是返回 false
+    pub fn is_success_rate_too_low(&self, stats: &ErrorStatistics, scanned: usize) -> bool {
+        self.abort_below_success_rate
+            .is_some_and(|min| scanned >= SUCCESS_RATE_MIN_SAMPLE && stats.get_success_rate() < min)
+    }
+
+    /// 根据锁定状态判定物品是否保留，不符合 `--only-locked` / `--only-unlocked` 的物品会被跳过
+    pub fn lock_decision(&self, lock: bool) -> FilterDecision {
+        if (self.only_locked && !lock) || (self.only_unlocked && lock) {
+            FilterDecision::Skip
+        } else {
+            FilterDecision::Accept
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::artifact_scanner::error::ArtifactScanError;
+
+    #[test]
+    fn test_parse_duration_secs() {
+        assert_eq!(parse_duration_secs("90"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration_secs("0.5"), Ok(Duration::from_millis(500)));
+        assert!(parse_duration_secs("0").is_err());
+        assert!(parse_duration_secs("-3").is_err());
+        assert!(parse_duration_secs("10m").is_err());
+    }
+
+    #[test]
+    fn test_parse_ocr_rect() {
+        assert_eq!(parse_ocr_rect("1308,120,492,840"), Ok(Rect::new(1308, 120, 492, 840)));
+        assert_eq!(parse_ocr_rect(" 10, 20 ,30,40 "), Ok(Rect::new(10, 20, 30, 40)));
+        assert!(parse_ocr_rect("10,20,30").is_err());
+        assert!(parse_ocr_rect("10,20,30,40,50").is_err());
+        assert!(parse_ocr_rect("10,20,0,40").is_err());
+        assert!(parse_ocr_rect("-1,20,30,40").is_err());
+        assert!(parse_ocr_rect("a,20,30,40").is_err());
+    }
+
+    #[test]
+    fn test_scan_timeout() {
+        let elapsed = Duration::from_secs(2);
+        assert!(!GenshinArtifactScannerConfig::default().is_scan_timed_out(elapsed));
+
+        let config = GenshinArtifactScannerConfig {
+            max_scan_duration: Some(Duration::from_secs(1)),
+            ..Default::default()
+        };
+        assert!(config.is_scan_timed_out(elapsed));
+        assert!(config.is_scan_timed_out(Duration::from_secs(1)));
+        assert!(!config.is_scan_timed_out(Duration::from_millis(999)));
+    }
+
+    #[test]
+    fn test_parse_percentage() {
+        assert_eq!(parse_percentage("50"), Ok(50.0));
+        assert_eq!(parse_percentage("62.5%"), Ok(62.5));
+        assert_eq!(parse_percentage("0"), Ok(0.0));
+        assert!(parse_percentage("100.1").is_err());
+        assert!(parse_percentage("-1").is_err());
+        assert!(parse_percentage("half").is_err());
+    }
+
+    #[test]
+    fn test_success_rate_abort_needs_min_sample() {
+        let mut stats = ErrorStatistics::new();
+        stats.add_success();
+        for _ in 0..3 {
+            stats.add_error(&ArtifactScanError::Unknown { error_msg: "识别失败".to_string() });
+        }
+        assert!(!GenshinArtifactScannerConfig::default()
+            .is_success_rate_too_low(&stats, SUCCESS_RATE_MIN_SAMPLE));
+
+        let config = GenshinArtifactScannerConfig {
+            abort_below_success_rate: Some(50.0),
+            ..Default::default()
+        };
+        assert!(!config.is_success_rate_too_low(&stats, SUCCESS_RATE_MIN_SAMPLE - 1));
+        assert!(config.is_success_rate_too_low(&stats, SUCCESS_RATE_MIN_SAMPLE));
+
+        stats.add_success();
+        stats.add_success();
+        // 3/6 = 50% 不低于阈值
+        assert!(!config.is_success_rate_too_low(&stats, SUCCESS_RATE_MIN_SAMPLE));
+    }
+
+    #[test]
+    fn test_effective_dup_threshold() {
+        let config = GenshinArtifactScannerConfig::default();
+        assert_eq!(config.effective_dup_threshold(8), 8);
+
+        let config = GenshinArtifactScannerConfig { dup_threshold: Some(20), ..Default::default() };
+        assert_eq!(config.effective_dup_threshold(8), 20);
+    }
+
+    #[test]
+    fn test_lock_decision() {
+        let config = GenshinArtifactScannerConfig::default();
+        assert_eq!(config.lock_decision(true), FilterDecision::Accept);
+        assert_eq!(config.lock_decision(false), FilterDecision::Accept);
+
+        let config = GenshinArtifactScannerConfig { only_locked: true, ..Default::default() };
+        assert_eq!(config.lock_decision(true), FilterDecision::Accept);
+        assert_eq!(config.lock_decision(false), FilterDecision::Skip);
+
+        let config = GenshinArtifactScannerConfig { only_unlocked: true, ..Default::default() };
+        assert_eq!(config.lock_decision(true), FilterDecision::Skip);
+        assert_eq!(config.lock_decision(false), FilterDecision::Accept);
+    }
+
+    #[test]
+    fn test_filter_decisions() {
+        let config = GenshinArtifactScannerConfig { min_level: 8, ..Default::default() };
+        assert!(config.stop_on_below_min);
+        assert_eq!(config.level_decision(4), FilterDecision::Stop);
+        assert_eq!(config.star_decision(4), FilterDecision::Stop);
+
+        let config = GenshinArtifactScannerConfig {
+            min_level: 8,
+            max_level: 16,
+            stop_on_below_min: false,
+            ..Default::default()
+        };
+        assert_eq!(config.level_decision(4), FilterDecision::Skip);
+        assert_eq!(config.level_decision(12), FilterDecision::Accept);
+        // 高于最大值的物品总是被跳过
+        assert_eq!(config.level_decision(20), FilterDecision::Skip);
+        assert_eq!(config.star_decision(3), FilterDecision::Skip);
+    }
 }
@@ -1,4 +1,5 @@
 use derive::FurinaWindowInfo;
+use furina_core::common::color::Color;
 use furina_core::positioning::{Pos, Rect, Size};
 
 #[derive(Clone, FurinaWindowInfo, Debug)]
@@ -41,6 +42,27 @@ pub struct ArtifactScannerWindowInfo {
     #[window_info(rename = "genshin_artifact_star_pos")]
     pub star_pos: Pos<f64>,
 
+    /// the region around `star_pos` in which the star color is sampled at several points,
+    /// relative to window
+    #[window_info(rename = "genshin_artifact_star_sample_rect")]
+    pub star_sample_rect: Rect<f64>,
+
+    /// the sampled colors of 1-5 star items at `star_pos`
+    #[window_info(rename = "genshin_artifact_star_color_1")]
+    pub star_color_1: Color,
+    #[window_info(rename = "genshin_artifact_star_color_2")]
+    pub star_color_2: Color,
+    #[window_info(rename = "genshin_artifact_star_color_3")]
+    pub star_color_3: Color,
+    #[window_info(rename = "genshin_artifact_star_color_4")]
+    pub star_color_4: Color,
+    #[window_info(rename = "genshin_artifact_star_color_5")]
+    pub star_color_5: Color,
+
+    /// squared color distance above which the star recognition is considered unreliable
+    #[window_info(rename = "genshin_artifact_star_color_threshold")]
+    pub star_color_threshold: f64,
+
     /// the whole panel of the artifact, relative to window
     #[window_info(rename = "genshin_repository_panel_rect")]
     pub panel_rect: Rect<f64>,
@@ -65,7 +87,168 @@ pub struct ArtifactScannerWindowInfo {
     #[window_info(rename = "genshin_repository_lock_pos")]
     pub lock_pos: Pos<f64>,
 
+    /// the color of the lock icon, sampled as the average of the 3x3 pixels around `lock_pos`
+    #[window_info(rename = "genshin_repository_lock_color")]
+    pub lock_color: Color,
+
+    /// squared color distance below which the item is considered locked
+    #[window_info(rename = "genshin_repository_lock_color_threshold")]
+    pub lock_color_threshold: f64,
+
     /// 祝圣之霜圣遗物的偏移量
     #[window_info(rename = "genshin_artifact_hoarfrost_offset")]
     pub hoarfrost_offset: Size<f64>,
+
+    /// OCR 裁剪前各字段区域向四周扩展的像素数，避免文字贴边被截断（如「暴击伤害」识别为「暴击伤」）
+    ///
+    /// 随窗口尺寸缩放；内置配置在 1920×1080 下为 3 像素，约为一个字宽的十分之一，
+    /// 设得过大会把相邻字段的文字也裁进来
+    #[window_info(rename = "genshin_artifact_ocr_padding")]
+    pub ocr_padding: f64,
+}
+
+impl ArtifactScannerWindowInfo {
+    /// 1-5星对应的采样颜色
+    pub fn star_colors(&self) -> [Color; 5] {
+        [
+            self.star_color_1,
+            self.star_color_2,
+            self.star_color_3,
+            self.star_color_4,
+            self.star_color_5,
+        ]
+    }
+
+    /// 所有矩形区域及其配置键名，各字段在前，包含这些字段的详情面板在最后
+    fn named_rects(&self) -> [(&'static str, Rect<f64>); 11] {
+        [
+            ("genshin_artifact_title_rect", self.title_rect),
+            ("genshin_artifact_main_stat_name_rect", self.main_stat_name_rect),
+            ("genshin_artifact_main_stat_value_rect", self.main_stat_value_rect),
+            ("genshin_artifact_sub_stat1_rect", self.sub_stat_1),
+            ("genshin_artifact_sub_stat2_rect", self.sub_stat_2),
+            ("genshin_artifact_sub_stat3_rect", self.sub_stat_3),
+            ("genshin_artifact_sub_stat4_rect", self.sub_stat_4),
+            ("genshin_artifact_level_rect", self.level_rect),
+            ("genshin_artifact_item_equip_rect", self.item_equip_rect),
+            ("genshin_artifact_item_count_rect", self.item_count_rect),
+            ("genshin_repository_panel_rect", self.panel_rect),
+        ]
+    }
+
+    /// 返回包含 `pos`（窗口坐标）的区域的配置键名，如 `genshin_artifact_level_rect`，
+    /// 不在任何区域内时返回 `None`，用于调试时将鼠标位置对应到具体区域
+    ///
+    /// 多个区域重叠时返回靠前的区域
+    pub fn field_at(&self, pos: Pos<f64>) -> Option<&'static str> {
+        self.named_rects().into_iter().find(|(_, rect)| rect.contains(pos)).map(|(key, _)| key)
+    }
+
+    /// 每个字段一行，格式为 `配置键名: 值`，用于 `--print-window-info` 核对各区域和编写覆盖配置
+    pub fn dump(&self) -> Vec<String> {
+        let mut rects = self.named_rects().to_vec();
+        rects.push(("genshin_artifact_star_sample_rect", self.star_sample_rect));
+        let positions = [
+            ("genshin_artifact_star_pos", self.star_pos),
+            ("genshin_repository_scan_margin_pos", self.scan_margin_pos),
+            ("genshin_repository_lock_pos", self.lock_pos),
+        ];
+        let sizes = [
+            ("genshin_repository_item_gap_size", self.item_gap_size),
+            ("genshin_repository_item_size", self.item_size),
+            ("genshin_artifact_hoarfrost_offset", self.hoarfrost_offset),
+        ];
+
+        let mut lines = Vec::new();
+        lines.extend(rects.iter().map(|(key, rect)| format!("{key}: {rect}")));
+        lines.extend(positions.iter().map(|(key, pos)| format!("{key}: {pos}")));
+        lines.extend(sizes.iter().map(|(key, size)| format!("{key}: {size}")));
+        for (i, color) in self.star_colors().iter().enumerate() {
+            lines.push(format!("genshin_artifact_star_color_{}: {color:?}", i + 1));
+        }
+        lines.push(format!("genshin_artifact_star_color_threshold: {}", self.star_color_threshold));
+        lines.push(format!("genshin_repository_lock_color: {:?}", self.lock_color));
+        lines.push(format!(
+            "genshin_repository_lock_color_threshold: {}",
+            self.lock_color_threshold
+        ));
+        lines.push(format!("genshin_repository_item_col: {}", self.col));
+        lines.push(format!("genshin_repository_item_row: {}", self.row));
+        lines.push(format!("genshin_artifact_ocr_padding: {}", self.ocr_padding));
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use furina_core::game_info::{Platform, UI};
+    use furina_core::window_info::{
+        FromWindowInfoRepository, WindowInfoRepository, WindowInfoTemplatePerSize,
+    };
+
+    use super::*;
+
+    fn window_info_1080p() -> ArtifactScannerWindowInfo {
+        let mut repo = WindowInfoRepository::new();
+        let template: WindowInfoTemplatePerSize =
+            serde_json::from_str(include_str!("../../../window_info/windows1920x1080.json"))
+                .unwrap();
+        template.inject_into_window_info_repo(&mut repo);
+        ArtifactScannerWindowInfo::from_window_info_repository(
+            Size::new(1920, 1080),
+            UI::Desktop,
+            Platform::Windows,
+            &repo,
+        )
+        .unwrap()
+    }
+
+    fn center(rect: Rect<f64>) -> Pos<f64> {
+        Pos::new(rect.left + rect.width / 2.0, rect.top + rect.height / 2.0)
+    }
+
+    #[test]
+    fn test_dump_contains_all_rects() {
+        let info = window_info_1080p();
+
+        let dump = info.dump();
+        for key in [
+            "genshin_artifact_title_rect",
+            "genshin_artifact_main_stat_name_rect",
+            "genshin_artifact_main_stat_value_rect",
+            "genshin_artifact_sub_stat1_rect",
+            "genshin_artifact_sub_stat2_rect",
+            "genshin_artifact_sub_stat3_rect",
+            "genshin_artifact_sub_stat4_rect",
+            "genshin_artifact_level_rect",
+            "genshin_artifact_item_equip_rect",
+            "genshin_artifact_item_count_rect",
+            "genshin_repository_panel_rect",
+            "genshin_artifact_star_sample_rect",
+        ] {
+            let line = dump.iter().find(|line| line.starts_with(&format!("{key}: ")));
+            assert!(line.is_some_and(|line| line.contains("Rect")), "缺少 {key}");
+        }
+        assert!(dump.contains(&format!("genshin_repository_item_col: {}", info.col)));
+        assert_eq!(dump.len(), 29);
+    }
+
+    #[test]
+    fn test_field_at() {
+        let info = window_info_1080p();
+
+        assert_eq!(info.field_at(center(info.level_rect)), Some("genshin_artifact_level_rect"));
+        assert_eq!(info.field_at(center(info.title_rect)), Some("genshin_artifact_title_rect"));
+        assert_eq!(info.field_at(center(info.sub_stat_3)), Some("genshin_artifact_sub_stat3_rect"));
+        assert_eq!(
+            info.field_at(center(info.item_equip_rect)),
+            Some("genshin_artifact_item_equip_rect")
+        );
+        // 面板内不属于任何字段的位置
+        assert_eq!(info.field_at(Pos::new(1790.0, 800.0)), Some("genshin_repository_panel_rect"));
+
+        // 窗口左上角和右下角不在任何区域内
+        assert_eq!(info.field_at(Pos::new(0.0, 0.0)), None);
+        assert_eq!(info.field_at(Pos::new(1919.0, 1079.0)), None);
+    }
 }
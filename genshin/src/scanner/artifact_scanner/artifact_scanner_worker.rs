@@ -1,24 +1,37 @@
-use std::collections::HashSet;
-use std::sync::mpsc::Receiver;
+use std::collections::{HashMap, HashSet};
+use std::sync::mpsc::{Receiver, Sender};
 use std::thread::JoinHandle;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
-use furina_core::positioning::{Pos, Rect};
+use furina_core::capture::{CaptureError, Capturer, StaticImageCapturer};
+use furina_core::ocr::{DefaultPreprocessor, ImageToText, NullOcrModel};
+use furina_core::positioning::{Rect, Size};
+use furina_core::utils::check_level_range;
 use image::{Rgb, RgbImage};
 use log::{error, info, warn};
 
+use crate::artifact::snap_stat_text;
+use crate::scanner::artifact_scanner::artifact_scanner::{
+    classify_star_samples, star_sample_colors,
+};
+use crate::scanner::artifact_scanner::artifact_scanner_config::FilterDecision;
 use crate::scanner::artifact_scanner::artifact_scanner_window_info::ArtifactScannerWindowInfo;
+use crate::scanner::artifact_scanner::checkpoint::{CheckpointRecorder, ScanCheckpoint};
+use crate::scanner::artifact_scanner::crop_dump::{CropDumper, DEFAULT_CROP_DUMP_LIMIT};
 use crate::scanner::artifact_scanner::error::{
     get_error_suggestion, ArtifactScanError, ErrorStatistics,
 };
 use crate::scanner::artifact_scanner::message_items::SendItem;
 use crate::scanner::artifact_scanner::performance_optimizations::{
-    AdaptiveDelayManager, OptimizedImageProcessor, OptimizedOCRRecognizer, PerformanceMonitor,
+    AdaptiveDelayManager, OcrTiming, OptimizedImageProcessor, OptimizedOCRRecognizer,
+    PerformanceMonitor,
 };
+use crate::scanner::artifact_scanner::scan_report::ScanStopReason;
 use crate::scanner::artifact_scanner::scan_result::GenshinArtifactScanResult;
 use crate::scanner::artifact_scanner::GenshinArtifactScannerConfig;
 
+/// 解析等级文本，如 `+20`；超出圣遗物等级范围时返回错误，避免错误的等级影响最低等级提前结束的判断
 fn parse_level(s: &str) -> Result<i32> {
     let pos = s.find('+');
 
@@ -26,14 +39,38 @@ fn parse_level(s: &str) -> Result<i32> {
         let level = s
             .parse::<i32>()
             .map_err(|e| anyhow::anyhow!("等级解析失败: 无法解析数字 '{}', 错误: {}", s, e))?;
-        return anyhow::Ok(level);
+        return check_level_range(level).map_err(|e| anyhow::anyhow!("等级解析失败: {e}"));
     }
 
     let level_str = &s[pos.unwrap()..];
     let level = level_str.parse::<i32>().map_err(|e| {
         anyhow::anyhow!("等级解析失败: 无法解析带+号的等级 '{}', 错误: {}", level_str, e)
     })?;
-    anyhow::Ok(level)
+    check_level_range(level).map_err(|e| anyhow::anyhow!("等级解析失败: {e}"))
+}
+
+/// 按完整相等去重地加入识别结果，返回是否为新的圣遗物
+///
+/// `kept` 记录已保留结果在 `results` 中的下标。同一件圣遗物可能被识别多次（如翻页边界处），
+/// 重复的结果置信度更高时替换之前保留的结果，位置不变，避免保留识别较差的一次
+fn keep_best(
+    results: &mut Vec<GenshinArtifactScanResult>,
+    kept: &mut HashMap<GenshinArtifactScanResult, usize>,
+    result: GenshinArtifactScanResult,
+) -> bool {
+    match kept.get(&result) {
+        Some(&index) => {
+            if result.confidence_score > results[index].confidence_score {
+                results[index] = result;
+            }
+            false
+        },
+        None => {
+            kept.insert(result.clone(), results.len());
+            results.push(result);
+            true
+        },
+    }
 }
 
 /// 修正祝圣之霜圣遗物OCR识别结果的文本问题
@@ -59,6 +96,38 @@ fn fix_hoarfrost_ocr_text(text: &str, is_hoarfrost: bool, window_size: (u32, u32
     text.to_string()
 }
 
+/// 以 `(x, y)` 为中心的 3×3 像素的平均颜色，超出图像的像素不计入；中心在图像外时返回 `None`
+///
+/// 取平均而不是单个像素，减少画面噪点对颜色判断的影响
+fn sample_average(image: &RgbImage, x: u32, y: u32) -> Option<Rgb<u8>> {
+    if x >= image.width() || y >= image.height() {
+        return None;
+    }
+
+    let mut sum = [0u32; 3];
+    let mut count = 0;
+    for sy in y.saturating_sub(1)..=(y + 1).min(image.height() - 1) {
+        for sx in x.saturating_sub(1)..=(x + 1).min(image.width() - 1) {
+            let pixel = image.get_pixel(sx, sy);
+            for (total, channel) in sum.iter_mut().zip(pixel.0) {
+                *total += channel as u32;
+            }
+            count += 1;
+        }
+    }
+    Some(Rgb(sum.map(|total| ((total + count / 2) / count) as u8)))
+}
+
+/// 面板上所有字段的识别结果
+pub struct ArtifactFields {
+    pub title: Result<String>,
+    pub main_stat_name: Result<String>,
+    pub main_stat_value: Result<String>,
+    pub level: Result<String>,
+    pub equip: Result<String>,
+    pub sub_stats: [Result<String>; 4],
+}
+
 /// 优化版本的扫描工作器，使用优化的OCR识别和性能监控
 pub struct ArtifactScannerWorker {
     ocr_recognizer: OptimizedOCRRecognizer,
@@ -67,7 +136,19 @@ pub struct ArtifactScannerWorker {
     error_stats: ErrorStatistics,
     performance_monitor: PerformanceMonitor,
     adaptive_delay: AdaptiveDelayManager,
+    preprocessor: DefaultPreprocessor,
     window_size: (u32, u32), // 窗口的真实尺寸 (width, height)
+    checkpoint: Option<CheckpointRecorder>,
+    // 从检查点恢复时，之前已扫描的物品数量和已识别的结果
+    start_index: usize,
+    resumed_results: Vec<GenshinArtifactScanResult>,
+    // 指定 --dump-crops 时写入识别失败的字段截图，文件名中使用当前物品序号
+    crop_dumper: Option<CropDumper>,
+    item_index: usize,
+    // 每得到一个结果就发送一份，见 `with_result_sender`
+    result_sender: Option<Sender<GenshinArtifactScanResult>>,
+    // 返回已扫描时长的时钟，见 `with_scan_clock`；未设置时使用真实时间
+    scan_clock: Option<Box<dyn FnMut() -> Duration + Send>>,
 }
 
 impl ArtifactScannerWorker {
@@ -76,242 +157,378 @@ impl ArtifactScannerWorker {
         config: GenshinArtifactScannerConfig,
         window_size: (u32, u32),
     ) -> Result<Self> {
-        Ok(ArtifactScannerWorker {
-            ocr_recognizer: OptimizedOCRRecognizer::new()?,
+        // --no-ocr 时识别线程不做识别，无需加载模型
+        if config.no_ocr {
+            return Ok(Self::with_model(Box::new(NullOcrModel), window_info, config, window_size));
+        }
+        Ok(Self::with_recognizer(OptimizedOCRRecognizer::new()?, window_info, config, window_size))
+    }
+
+    /// 使用指定的OCR识别器创建工作器
+    pub fn with_recognizer(
+        ocr_recognizer: OptimizedOCRRecognizer,
+        window_info: ArtifactScannerWindowInfo,
+        config: GenshinArtifactScannerConfig,
+        window_size: (u32, u32),
+    ) -> Self {
+        let crop_dumper =
+            config.dump_crops.clone().map(|dir| CropDumper::new(dir, DEFAULT_CROP_DUMP_LIMIT));
+        ArtifactScannerWorker {
+            ocr_recognizer,
             window_info,
             config,
             error_stats: ErrorStatistics::new(),
             performance_monitor: PerformanceMonitor::new(),
             adaptive_delay: AdaptiveDelayManager::new(10), // 基础延时10ms
+            preprocessor: DefaultPreprocessor::new(),
             window_size,
-        })
+            checkpoint: None,
+            start_index: 0,
+            resumed_results: Vec::new(),
+            crop_dumper,
+            item_index: 0,
+            result_sender: None,
+            scan_clock: None,
+        }
     }
 
-    /// 优化版本的OCR推理，使用性能监控
-    fn model_inference_optimized(
-        &mut self,
-        rect: Rect<f64>,
-        captured_img: &RgbImage,
-        field_name: &str,
-    ) -> Result<String> {
-        let start_time = Instant::now();
+    /// 使用指定的OCR模型创建工作器，便于在测试和基准测试中替换为模拟模型
+    pub fn with_model(
+        model: Box<dyn ImageToText<RgbImage> + Send>,
+        window_info: ArtifactScannerWindowInfo,
+        config: GenshinArtifactScannerConfig,
+        window_size: (u32, u32),
+    ) -> Self {
+        Self::with_recognizer(
+            OptimizedOCRRecognizer::from_model(model),
+            window_info,
+            config,
+            window_size,
+        )
+    }
 
-        let relative_rect = rect.translate(Pos {
-            x: -self.window_info.panel_rect.left,
-            y: -self.window_info.panel_rect.top,
-        });
+    /// 每识别完一页写入检查点
+    pub fn with_checkpoint(mut self, recorder: CheckpointRecorder) -> Self {
+        self.checkpoint = Some(recorder);
+        self
+    }
+
+    /// 从检查点恢复：物品序号接着检查点继续，检查点中的结果参与去重并包含在最终结果中
+    pub fn resume_from(mut self, checkpoint: ScanCheckpoint) -> Self {
+        self.start_index = checkpoint.scanned_count;
+        self.resumed_results = checkpoint.results;
+        self
+    }
+
+    /// 每得到一个最终结果（已通过过滤和去重）就发送到 `sender`，从检查点恢复时先发送检查点中的结果
+    ///
+    /// 发送端归识别线程所有，识别线程结束时释放，接收端的迭代随之结束；
+    /// 接收端被提前丢弃时只是不再发送，不影响识别
+    pub fn with_result_sender(mut self, sender: Sender<GenshinArtifactScanResult>) -> Self {
+        self.result_sender = Some(sender);
+        self
+    }
+
+    /// 使用 `clock` 计算已扫描的时长，判断是否超过 `--max-scan-duration`
+    ///
+    /// 每处理一个物品前调用一次，便于在测试中用可控的时钟代替真实时间
+    pub fn with_scan_clock(mut self, clock: impl FnMut() -> Duration + Send + 'static) -> Self {
+        self.scan_clock = Some(Box::new(clock));
+        self
+    }
 
-        // 使用优化的图像裁剪
-        let cropped_img = OptimizedImageProcessor::crop_optimized(captured_img, &relative_rect);
+    /// 识别过程的性能统计
+    pub fn performance_monitor(&self) -> &PerformanceMonitor {
+        &self.performance_monitor
+    }
 
-        let inference_result = self
-            .ocr_recognizer
-            .recognize(&cropped_img)
-            .map_err(|e| anyhow::anyhow!("OCR识别失败 - 字段: {}, 错误: {}", field_name, e))?;
+    /// 计算所有需要识别的字段区域，祝圣之霜圣遗物的等级和副属性区域会向下偏移 `hoarfrost_offset`
+    ///
+    /// 快速模式下不包含四条副属性
+    fn field_regions(&self, hoarfrost_offset: f64) -> Vec<(Rect<f64>, &'static str)> {
+        let info = &self.window_info;
+        let shifted = |rect: Rect<f64>| Rect { top: rect.top + hoarfrost_offset, ..rect };
+
+        let mut regions = vec![
+            (info.title_rect, "圣遗物名称"),
+            (info.main_stat_name_rect, "主属性名称"),
+            (info.main_stat_value_rect, "主属性数值"),
+            (shifted(info.level_rect), "等级"),
+            (info.item_equip_rect, "装备状态"),
+        ];
+        if !self.config.quick {
+            regions.extend([
+                (shifted(info.sub_stat_1), "副属性1"),
+                (shifted(info.sub_stat_2), "副属性2"),
+                (shifted(info.sub_stat_3), "副属性3"),
+                (shifted(info.sub_stat_4), "副属性4"),
+            ]);
+        }
+        regions
+    }
 
-        let ocr_time = start_time.elapsed();
-        self.performance_monitor.record_ocr_time(ocr_time);
+    /// 一次性裁剪并批量识别面板上的所有字段
+    ///
+    /// 祝圣之霜圣遗物的区域偏移和副属性文本修正都在这里完成
+    pub fn recognize_all_fields(&mut self, panel_image: &RgbImage) -> ArtifactFields {
+        // 检测祝圣之霜圣遗物
+        let is_hoarfrost = self.check_consecration_of_hoarfrost(panel_image);
+        let hoarfrost_offset = if is_hoarfrost {
+            info!("✨ 检测到祝圣之霜圣遗物");
+            self.get_hoarfrost_offset()
+        } else {
+            0.0
+        };
+
+        let regions = self.field_regions(hoarfrost_offset);
+        let mut results = self.batch_model_inference(regions, panel_image).into_iter();
+        let mut next = || results.next().unwrap_or_else(|| Err(anyhow::anyhow!("缺少识别结果")));
+
+        let title = next();
+        let main_stat_name = next();
+        let main_stat_value = next();
+        let level = next();
+        let equip = next();
+        let window_size = self.window_size;
+        let sub_stats = if self.config.quick {
+            std::array::from_fn(|_| Ok(String::new()))
+        } else {
+            [next(), next(), next(), next()]
+                .map(|r| r.map(|text| fix_hoarfrost_ocr_text(&text, is_hoarfrost, window_size)))
+        };
 
-        Ok(inference_result)
+        ArtifactFields { title, main_stat_name, main_stat_value, level, equip, sub_stats }
     }
 
     /// 批量OCR识别，提高效率
+    ///
+    /// 裁剪耗时记为捕获时间，每个字段的识别耗时会单独记录到性能监控器中
     fn batch_model_inference(
         &mut self,
-        rects_and_names: Vec<(Rect<f64>, &str)>,
+        rects_and_names: Vec<(Rect<f64>, &'static str)>,
         captured_img: &RgbImage,
     ) -> Vec<Result<String>> {
-        let start_time = Instant::now();
+        let capture_start = Instant::now();
 
+        let bounds = Size::new(captured_img.width() as f64, captured_img.height() as f64);
         let cropped_images: Vec<RgbImage> = rects_and_names
             .iter()
             .map(|(rect, _)| {
-                let relative_rect = rect.translate(Pos {
-                    x: -self.window_info.panel_rect.left,
-                    y: -self.window_info.panel_rect.top,
-                });
-                OptimizedImageProcessor::crop_optimized(captured_img, &relative_rect)
+                let relative_rect = OptimizedImageProcessor::pad_rect(
+                    &(*rect - self.window_info.panel_rect.origin()),
+                    self.window_info.ocr_padding,
+                    bounds,
+                );
+                let cropped = OptimizedImageProcessor::crop_optimized(captured_img, &relative_rect);
+                if !self.config.preprocess {
+                    return cropped;
+                }
+                self.preprocessor.preprocess(&cropped).unwrap_or_else(|e| {
+                    warn!("图像预处理失败，使用原始截图: {e}");
+                    cropped
+                })
+            })
+            .collect();
+
+        self.performance_monitor.record_capture_time(capture_start.elapsed());
+
+        let ocr_start = Instant::now();
+        let timeout = Duration::from_millis(self.config.ocr_timeout_ms);
+        let results: Vec<Result<String>> = cropped_images
+            .iter()
+            .zip(rects_and_names.iter())
+            .map(|(image, &(_, name))| {
+                let field_start = Instant::now();
+                let result = if timeout.is_zero() {
+                    self.ocr_recognizer.recognize(image)
+                } else {
+                    self.ocr_recognizer.recognize_with_timeout(image, timeout)
+                };
+                self.performance_monitor.record_field_time(name, field_start.elapsed());
+                result
             })
             .collect();
 
-        let results = self.ocr_recognizer.batch_recognize(&cropped_images);
+        self.performance_monitor.record_ocr_time(ocr_start.elapsed());
 
-        let batch_ocr_time = start_time.elapsed();
-        self.performance_monitor.record_ocr_time(batch_ocr_time);
+        if let Some(dumper) = self.crop_dumper.as_mut() {
+            for ((image, &(_, name)), result) in
+                cropped_images.iter().zip(rects_and_names.iter()).zip(results.iter())
+            {
+                if result.is_err() {
+                    dumper.dump(image, self.item_index, name);
+                }
+            }
+        }
 
         results
     }
 
-    /// 优化版本的物品扫描，使用批量处理
-    fn scan_item_image_optimized(
+    /// 从一张完整的窗口截图中识别当前显示的圣遗物，不需要游戏窗口和鼠标控制
+    ///
+    /// 截图坐标以窗口左上角为原点；单张截图中没有背包列表，锁定状态视为未锁定
+    pub fn scan_from_image(
         &mut self,
-        item: SendItem,
-        lock: bool,
+        capturer: &dyn Capturer<RgbImage>,
     ) -> Result<GenshinArtifactScanResult> {
-        let image = &item.panel_image;
-        let mut result_errors = Vec::new();
-
-        // 检测祝圣之霜圣遗物
-        let is_hoarfrost = self.check_consecration_of_hoarfrost(image);
-        let hoarfrost_offset = if is_hoarfrost {
-            let offset = self.get_hoarfrost_offset();
-            info!("✨ 检测到祝圣之霜圣遗物");
-            offset
-        } else {
-            0.0
+        let capture_error = |region: &str, e: CaptureError| {
+            let error = ArtifactScanError::ImageCaptureFailed {
+                region: region.to_string(),
+                error_msg: e.to_string(),
+            };
+            warn!("截图读取失败: {error}");
+            anyhow::anyhow!(error)
         };
 
-        // 计算调整后的识别区域（如果检测到祝圣之霜则向下偏移）
-        let adjusted_level_rect = if is_hoarfrost {
-            Rect {
-                left: self.window_info.level_rect.left,
-                top: self.window_info.level_rect.top + hoarfrost_offset,
-                width: self.window_info.level_rect.width,
-                height: self.window_info.level_rect.height,
-            }
-        } else {
-            self.window_info.level_rect
-        };
+        let panel_image = capturer
+            .capture_rect(self.window_info.panel_rect.to_rect_i32())
+            .map_err(|e| capture_error("圣遗物面板", e))?;
+        let star_image = capturer
+            .capture_rect(self.window_info.star_sample_rect.to_rect_i32())
+            .map_err(|e| capture_error("星级颜色采样", e))?;
+        let (star, _) = classify_star_samples(
+            &star_sample_colors(&star_image),
+            &self.window_info.star_colors(),
+            self.config.color_metric,
+        );
 
-        let adjusted_sub_stat_1 = if is_hoarfrost {
-            Rect {
-                left: self.window_info.sub_stat_1.left,
-                top: self.window_info.sub_stat_1.top + hoarfrost_offset,
-                width: self.window_info.sub_stat_1.width,
-                height: self.window_info.sub_stat_1.height,
-            }
-        } else {
-            self.window_info.sub_stat_1
-        };
+        self.scan_item_image_optimized(
+            SendItem { panel_image: Some(panel_image), star, list_image: None, position: None },
+            false,
+        )
+    }
 
-        let adjusted_sub_stat_2 = if is_hoarfrost {
-            Rect {
-                left: self.window_info.sub_stat_2.left,
-                top: self.window_info.sub_stat_2.top + hoarfrost_offset,
-                width: self.window_info.sub_stat_2.width,
-                height: self.window_info.sub_stat_2.height,
-            }
-        } else {
-            self.window_info.sub_stat_2
-        };
+    /// 识别一张已裁剪好的圣遗物面板截图，截图尺寸需与窗口信息中的面板区域一致
+    ///
+    /// 星级颜色在面板截图内采样；没有背包列表，锁定状态视为未锁定
+    pub fn scan_panel_image(&mut self, panel_image: RgbImage) -> Result<GenshinArtifactScanResult> {
+        let panel = self.window_info.panel_rect;
+        let expected = panel.size().to_size_u32();
+        if panel_image.dimensions() != (expected.width, expected.height) {
+            return Err(anyhow::anyhow!(ArtifactScanError::ImageCaptureFailed {
+                region: "圣遗物面板".to_string(),
+                error_msg: format!(
+                    "面板截图尺寸 {}x{} 与窗口信息中的面板区域 {}x{} 不一致",
+                    panel_image.width(),
+                    panel_image.height(),
+                    expected.width,
+                    expected.height
+                ),
+            }));
+        }
 
-        let adjusted_sub_stat_3 = if is_hoarfrost {
-            Rect {
-                left: self.window_info.sub_stat_3.left,
-                top: self.window_info.sub_stat_3.top + hoarfrost_offset,
-                width: self.window_info.sub_stat_3.width,
-                height: self.window_info.sub_stat_3.height,
-            }
-        } else {
-            self.window_info.sub_stat_3
-        };
+        let star_rect = (self.window_info.star_sample_rect - panel.origin()).to_rect_i32();
+        CaptureError::check_bounds(star_rect, panel_image.width(), panel_image.height()).map_err(
+            |e| {
+                anyhow::anyhow!(ArtifactScanError::ImageCaptureFailed {
+                    region: "星级颜色采样".to_string(),
+                    error_msg: format!("星级采样区域不在面板截图内: {e}"),
+                })
+            },
+        )?;
+        let star_image = image::imageops::crop_imm(
+            &panel_image,
+            star_rect.left as u32,
+            star_rect.top as u32,
+            star_rect.width as u32,
+            star_rect.height as u32,
+        )
+        .to_image();
+        let colors = star_sample_colors(&star_image);
+        let (star, _) = classify_star_samples(
+            &colors,
+            &self.window_info.star_colors(),
+            self.config.color_metric,
+        );
 
-        let adjusted_sub_stat_4 = if is_hoarfrost {
-            Rect {
-                left: self.window_info.sub_stat_4.left,
-                top: self.window_info.sub_stat_4.top + hoarfrost_offset,
-                width: self.window_info.sub_stat_4.width,
-                height: self.window_info.sub_stat_4.height,
-            }
-        } else {
-            self.window_info.sub_stat_4
-        };
+        self.scan_item_image_optimized(
+            SendItem { panel_image: Some(panel_image), star, list_image: None, position: None },
+            false,
+        )
+    }
 
-        // 准备批量OCR识别的区域
-        let ocr_regions = vec![
-            (self.window_info.title_rect, "圣遗物名称"),
-            (self.window_info.main_stat_name_rect, "主属性名称"),
-            (self.window_info.main_stat_value_rect, "主属性数值"),
-            (adjusted_level_rect, "等级"),
-            (self.window_info.item_equip_rect, "装备状态"),
-        ];
+    /// 从录制背包翻页过程的视频或 GIF 的帧中识别圣遗物，不需要游戏窗口和鼠标控制
+    ///
+    /// 每一帧都视为一张完整的窗口截图，按 [`Self::scan_from_image`] 识别，帧尺寸应与窗口信息的分辨率一致。
+    /// 这里假设每个不同的帧只显示一个圣遗物面板：录制的帧率通常远高于切换物品的速度，
+    /// 同一件圣遗物会出现在连续多帧中，因此与导出时一样按完整相等去重，保留其中置信度最高的一次。
+    /// 无法截取面板的帧会被跳过；切换物品时的过渡帧可能产生带有识别错误的结果，需要时请检查 `scan_errors`
+    pub fn scan_from_frames(
+        &mut self,
+        frames: impl Iterator<Item = RgbImage>,
+    ) -> Vec<GenshinArtifactScanResult> {
+        let mut results = Vec::new();
+        let mut kept = HashMap::new();
+
+        for (i, frame) in frames.enumerate() {
+            self.item_index = i + 1;
+            let result = match self.scan_from_image(&StaticImageCapturer::new(frame)) {
+                Ok(v) => v,
+                Err(e) => {
+                    warn!("跳过无法识别的第{}帧: {e}", i + 1);
+                    continue;
+                },
+            };
 
-        // 批量进行主要字段的OCR识别
-        let ocr_results = self.batch_model_inference(ocr_regions, image);
+            if keep_best(&mut results, &mut kept, result) {
+                self.emit_result(results.last().unwrap());
+            }
+        }
 
-        // 处理主要字段结果
-        let str_title = match &ocr_results[0] {
-            Ok(text) => text.clone(),
-            Err(e) => {
-                let error = ArtifactScanError::OcrRecognitionFailed {
-                    field: "圣遗物名称".to_string(),
-                    raw_text: "".to_string(),
-                    error_msg: e.to_string(),
-                };
-                result_errors.push(error);
-                "未识别".to_string()
-            },
-        };
+        info!("共识别 {} 个不同的圣遗物", results.len());
+        results
+    }
 
-        let str_main_stat_name = match &ocr_results[1] {
-            Ok(text) => text.clone(),
-            Err(e) => {
-                let error = ArtifactScanError::OcrRecognitionFailed {
-                    field: "主属性名称".to_string(),
-                    raw_text: "".to_string(),
-                    error_msg: e.to_string(),
-                };
-                result_errors.push(error);
-                "未识别".to_string()
-            },
-        };
+    /// 优化版本的物品扫描，使用批量处理
+    pub fn scan_item_image_optimized(
+        &mut self,
+        item: SendItem,
+        lock: bool,
+    ) -> Result<GenshinArtifactScanResult> {
+        let panel_image = item.panel_image.as_ref().ok_or_else(|| {
+            anyhow::anyhow!(ArtifactScanError::ImageCaptureFailed {
+                region: "圣遗物面板".to_string(),
+                error_msg: "没有可识别的面板截图".to_string(),
+            })
+        })?;
+        let mut result_errors = Vec::new();
+        let fields = self.recognize_all_fields(panel_image);
 
-        let str_main_stat_value = match &ocr_results[2] {
-            Ok(text) => text.clone(),
+        // 处理主要字段结果
+        let mut take_field = |field: Result<String>, name: &str, default: &str| match field {
+            Ok(text) => text,
             Err(e) => {
                 let error = ArtifactScanError::OcrRecognitionFailed {
-                    field: "主属性数值".to_string(),
+                    field: name.to_string(),
                     raw_text: "".to_string(),
                     error_msg: e.to_string(),
                 };
                 result_errors.push(error);
-                "0".to_string()
+                default.to_string()
             },
         };
 
-        let str_level = match &ocr_results[3] {
-            Ok(text) => text.clone(),
-            Err(e) => {
-                let error = ArtifactScanError::OcrRecognitionFailed {
-                    field: "等级".to_string(),
-                    raw_text: "".to_string(),
-                    error_msg: e.to_string(),
-                };
-                result_errors.push(error);
-                "0".to_string()
-            },
-        };
+        let str_title = take_field(fields.title, "圣遗物名称", "未识别");
+        let mut str_main_stat_name = take_field(fields.main_stat_name, "主属性名称", "未识别");
+        let str_main_stat_value = take_field(fields.main_stat_value, "主属性数值", "0");
+        let str_level = take_field(fields.level, "等级", "0");
 
-        let str_equip = match &ocr_results[4] {
-            Ok(text) => text.clone(),
+        let str_equip = match fields.equip {
+            Ok(text) => text,
             Err(e) => {
                 warn!("装备状态识别失败，使用默认值: {e}");
                 String::new()
             },
         };
 
-        // 副属性仍使用单独识别（通常文本较短，批量处理收益不大）
-        let str_sub_stat0 = self
-            .model_inference_optimized(adjusted_sub_stat_1, image, "副属性1")
-            .unwrap_or_default();
-        let str_sub_stat0 = fix_hoarfrost_ocr_text(&str_sub_stat0, is_hoarfrost, self.window_size);
-
-        let str_sub_stat1 =
-            match self.model_inference_optimized(adjusted_sub_stat_2, image, "副属性2") {
-                Ok(text) => fix_hoarfrost_ocr_text(&text, is_hoarfrost, self.window_size),
-                Err(_) => String::new(),
-            };
-
-        let str_sub_stat2 =
-            match self.model_inference_optimized(adjusted_sub_stat_3, image, "副属性3") {
-                Ok(text) => fix_hoarfrost_ocr_text(&text, is_hoarfrost, self.window_size),
-                Err(_) => String::new(),
-            };
+        // 副属性可能不足四条，识别失败时视为空
+        let mut sub_stats = fields.sub_stats.map(|r| r.unwrap_or_default());
 
-        let str_sub_stat3 = self
-            .model_inference_optimized(adjusted_sub_stat_4, image, "副属性4")
-            .unwrap_or_default();
-        let str_sub_stat3 = fix_hoarfrost_ocr_text(&str_sub_stat3, is_hoarfrost, self.window_size);
+        // 在转换为属性之前纠正单字识别错误的属性名称
+        if self.config.snap_stat_names {
+            str_main_stat_name = snap_stat_text(&str_main_stat_name);
+            sub_stats = sub_stats.map(|text| snap_stat_text(&text));
+        }
 
         // 解析等级
         let level = match parse_level(&str_level) {
@@ -331,16 +548,18 @@ impl ArtifactScannerWorker {
             str_title,
             str_main_stat_name,
             str_main_stat_value,
-            [str_sub_stat0, str_sub_stat1, str_sub_stat2, str_sub_stat3],
+            sub_stats,
             str_equip,
             level,
             item.star as i32,
             lock,
         );
+        result.position = item.position;
 
-        // 添加所有错误到结果中
+        // 添加所有错误到结果中，并计入错误统计
         for error in &result_errors {
             result.add_error(error);
+            self.error_stats.add_error(error);
         }
 
         // 更新自适应延时统计
@@ -355,16 +574,18 @@ impl ArtifactScannerWorker {
 
     /// 优化版本的锁定状态检测，使用批量颜色距离计算
     fn get_page_locks_optimized(&self, list_image: &RgbImage) -> Vec<bool> {
-        let mut result = Vec::new();
-        let mut colors_to_check = Vec::new();
-
         let row = self.window_info.row;
         let col = self.window_info.col;
         let gap = self.window_info.item_gap_size;
         let size = self.window_info.item_size;
         let lock_pos = self.window_info.lock_pos;
+        let lock_color = self.window_info.lock_color;
+        let target_color = Rgb([lock_color.0, lock_color.1, lock_color.2]);
+        let metric = self.config.color_metric;
+        // 阈值按欧氏距离标定
+        let threshold = self.window_info.lock_color_threshold * metric.threshold_scale();
 
-        // 收集所有需要检查的颜色位置
+        let mut result = Vec::new();
         for r in 0..row {
             if ((gap.height + size.height) * (r as f64)) as u32 > list_image.height() {
                 break;
@@ -373,25 +594,16 @@ impl ArtifactScannerWorker {
                 let pos_x = (gap.width + size.width) * (c as f64) + lock_pos.x;
                 let pos_y = (gap.height + size.height) * (r as f64) + lock_pos.y;
 
-                if (pos_x as u32) < list_image.width() && (pos_y as u32) < list_image.height() {
-                    let color = *list_image.get_pixel(pos_x as u32, pos_y as u32);
-                    colors_to_check.push(color);
-                } else {
-                    result.push(false);
-                }
+                let locked =
+                    sample_average(list_image, pos_x as u32, pos_y as u32).is_some_and(|color| {
+                        let distance =
+                            OptimizedImageProcessor::color_distance(&color, &target_color, metric);
+                        (distance as f64) < threshold
+                    });
+                result.push(locked);
             }
         }
 
-        // 批量计算颜色距离
-        let target_color = Rgb([255, 138, 117]);
-        let distances =
-            OptimizedImageProcessor::batch_color_distance(&colors_to_check, &target_color);
-
-        // 根据距离判断锁定状态
-        for distance in distances {
-            result.push(distance < 900); // 30*30 = 900
-        }
-
         result
     }
 
@@ -453,119 +665,907 @@ impl ArtifactScannerWorker {
         self.window_info.hoarfrost_offset.height
     }
 
-    pub fn run(
+    pub fn run(self, rx: Receiver<Option<SendItem>>) -> JoinHandle<Vec<GenshinArtifactScanResult>> {
+        std::thread::spawn(move || self.process(rx).0)
+    }
+
+    /// 与 [`Self::run`] 相同，同时返回识别过程中的错误统计和识别耗时
+    pub fn run_with_stats(
+        self,
+        rx: Receiver<Option<SendItem>>,
+    ) -> JoinHandle<(Vec<GenshinArtifactScanResult>, ErrorStatistics, OcrTiming)> {
+        std::thread::spawn(move || self.process(rx))
+    }
+
+    fn emit_result(&mut self, result: &GenshinArtifactScanResult) {
+        if let Some(sender) = &self.result_sender {
+            if sender.send(result.clone()).is_err() {
+                self.result_sender = None;
+            }
+        }
+    }
+
+    fn process(
         mut self,
         rx: Receiver<Option<SendItem>>,
-    ) -> JoinHandle<Vec<GenshinArtifactScanResult>> {
-        std::thread::spawn(move || {
-            let mut results = Vec::new();
-            let mut hash: HashSet<GenshinArtifactScanResult> = HashSet::new();
-            let mut consecutive_dup_count = 0;
-
-            let min_level = self.config.min_level;
-            let info = self.window_info.clone();
-
-            let mut locks = Vec::new();
-            let mut artifact_index: i32 = 0;
-
-            for item in rx.into_iter() {
-                let item = match item {
-                    Some(v) => v,
-                    None => break,
+    ) -> (Vec<GenshinArtifactScanResult>, ErrorStatistics, OcrTiming) {
+        let mut results = std::mem::take(&mut self.resumed_results);
+        for result in &results {
+            self.emit_result(result);
+        }
+        // 导出去重使用完整相等（含锁定状态和装备），翻页错误检测使用不含这两项的标识哈希
+        let mut kept: HashMap<GenshinArtifactScanResult, usize> =
+            results.iter().cloned().enumerate().map(|(i, r)| (r, i)).collect();
+        let mut identities: HashSet<u64> = results.iter().map(|r| r.identity_hash()).collect();
+        let mut consecutive_dups: Vec<String> = Vec::new();
+
+        let info = self.window_info.clone();
+        let dup_threshold = self.config.effective_dup_threshold(info.col as usize);
+
+        let mut locks = Vec::new();
+        let start_index = self.start_index;
+        let mut artifact_index = start_index as i32;
+        let start = Instant::now();
+
+        for item in rx.into_iter() {
+            let item = match item {
+                Some(v) => v,
+                None => break,
+            };
+
+            // 此前的物品已全部处理，恰好处理完整页时写入检查点
+            if let Some(recorder) = &self.checkpoint {
+                if artifact_index as usize > start_index {
+                    recorder.on_item_processed(artifact_index as usize, &results);
+                }
+            }
+
+            let elapsed = match self.scan_clock.as_mut() {
+                Some(clock) => clock(),
+                None => start.elapsed(),
+            };
+            if self.config.is_scan_timed_out(elapsed) {
+                let error = ArtifactScanError::ScanInterrupted {
+                    reason: "timeout".to_string(),
+                    scanned_count: artifact_index as usize,
                 };
+                self.error_stats.add_error(&error);
+                warn!("超过最长扫描时间，停止识别并保留已识别的结果: {error}");
+                self.error_stats.stop_reason = Some(ScanStopReason::Timeout);
+                break;
+            }
 
-                // 使用优化版本的锁定状态检测
-                if let Some(v) = item.list_image.as_ref() {
-                    locks = [locks, self.get_page_locks_optimized(v)].concat()
+            let scanned = artifact_index as usize - start_index;
+            if self.config.is_success_rate_too_low(&self.error_stats, scanned) {
+                let reason = format!(
+                    "识别成功率 {:.1}% 低于 {:.1}%",
+                    self.error_stats.get_success_rate(),
+                    self.config.abort_below_success_rate.unwrap_or_default()
+                );
+                let error = ArtifactScanError::ScanInterrupted {
+                    reason: reason.clone(),
+                    scanned_count: artifact_index as usize,
                 };
+                self.error_stats.add_error(&error);
+                self.error_stats.abort_reason = Some(reason);
+                self.error_stats.stop_reason = Some(ScanStopReason::LowSuccessRate);
+                error!("识别成功率过低，停止扫描并保留已识别的结果: {error}");
+                error!("建议: 请检查游戏语言、分辨率和亮度设置后重新扫描");
+                break;
+            }
 
-                artifact_index += 1;
-                let result = match self.scan_item_image_optimized(
-                    item,
-                    locks.get(artifact_index as usize - 1).copied().unwrap_or(false),
-                ) {
-                    Ok(v) => {
-                        self.error_stats.add_success();
-                        v
-                    },
-                    Err(e) => {
-                        let scan_error = ArtifactScanError::Unknown { error_msg: e.to_string() };
-                        self.error_stats.add_error(&scan_error);
-                        error!("识别错误: {e}");
-                        error!("建议: {}", get_error_suggestion(&scan_error));
-                        continue;
-                    },
+            // 使用优化版本的锁定状态检测
+            if let Some(v) = item.list_image.as_ref() {
+                locks = [locks, self.get_page_locks_optimized(v)].concat()
+            };
+
+            artifact_index += 1;
+
+            if item.panel_image.is_none() {
+                let capture_error = ArtifactScanError::ImageCaptureFailed {
+                    region: "圣遗物面板".to_string(),
+                    error_msg: format!("第{artifact_index}个物品截图失败"),
                 };
+                self.error_stats.add_error(&capture_error);
+                warn!("跳过截图失败的物品: {capture_error}");
+                continue;
+            }
 
-                // 记录结果中的错误
-                for error_msg in &result.scan_errors {
-                    warn!("扫描警告: {error_msg}");
-                }
+            // 星级过滤，跳过的物品仍需计入索引以保持锁定状态对齐
+            match self.config.star_decision(item.star as i32) {
+                FilterDecision::Accept => {},
+                FilterDecision::Skip => continue,
+                FilterDecision::Stop => {
+                    info!(
+                        "找到满足最低星级要求 {} 的物品({})，准备退出……",
+                        self.config.min_star, item.star
+                    );
+                    self.error_stats.stop_reason = Some(ScanStopReason::MinStar);
+                    break;
+                },
+            }
+
+            if self.config.no_ocr {
+                let (width, height) = item.panel_image.as_ref().unwrap().dimensions();
+                info!(
+                    "[no-ocr] 第{artifact_index}个物品: 面板截图 {width}x{height}，星级 {}",
+                    item.star
+                );
+                continue;
+            }
+
+            // 锁定状态按页获取，通过物品序号取得当前物品的锁定状态
+            // 列表截图从恢复的位置开始，锁定状态的下标需要减去之前已扫描的物品数量
+            let lock =
+                locks.get(artifact_index as usize - 1 - start_index).copied().unwrap_or(false);
+            if self.config.lock_decision(lock) == FilterDecision::Skip {
+                continue;
+            }
+
+            self.item_index = artifact_index as usize;
+            let result = match self.scan_item_image_optimized(item, lock) {
+                Ok(v) => {
+                    // 字段的识别错误已计入统计，只有没有错误的物品才算识别成功
+                    if !v.has_errors() {
+                        self.error_stats.add_success();
+                    }
+                    v
+                },
+                Err(e) => {
+                    let scan_error = ArtifactScanError::Unknown { error_msg: e.to_string() };
+                    self.error_stats.add_error(&scan_error);
+                    error!("识别错误: {e}");
+                    error!("建议: {}", get_error_suggestion(&scan_error));
+                    continue;
+                },
+            };
 
-                if result.level < min_level {
+            // 记录结果中的错误
+            for error_msg in &result.scan_errors {
+                warn!("扫描警告: {error_msg}");
+            }
+
+            // 被跳过的物品不参与去重，也不影响连续重复计数
+            match self.config.level_decision(result.level) {
+                FilterDecision::Accept => {},
+                FilterDecision::Skip => continue,
+                FilterDecision::Stop => {
                     info!(
                         "找到满足最低等级要求 {} 的物品({})，准备退出……",
-                        min_level, result.level
+                        self.config.min_level, result.level
                     );
+                    self.error_stats.stop_reason = Some(ScanStopReason::MinLevel);
                     break;
-                }
+                },
+            }
 
-                if hash.contains(&result) {
-                    consecutive_dup_count += 1;
-                    let dup_error = ArtifactScanError::ConsecutiveDuplicateItems {
-                        count: consecutive_dup_count,
-                        threshold: info.col as usize,
-                    };
-                    self.error_stats.add_error(&dup_error);
-                    warn!("检测到重复物品");
-                } else {
-                    consecutive_dup_count = 0;
-                    hash.insert(result.clone());
-                    results.push(result);
-                }
+            if identities.insert(result.identity_hash()) {
+                consecutive_dups.clear();
+            } else {
+                let description = format!(
+                    "第{artifact_index}个物品 {} {} +{}",
+                    result.name, result.main_stat_name, result.level
+                );
+                consecutive_dups.push(description);
+                let dup_error = ArtifactScanError::ConsecutiveDuplicateItems {
+                    count: consecutive_dups.len(),
+                    threshold: dup_threshold,
+                };
+                self.error_stats.add_error(&dup_error);
+                warn!(
+                    "检测到重复物品: {}（连续 {}/{}）",
+                    consecutive_dups.last().unwrap(),
+                    consecutive_dups.len(),
+                    dup_threshold
+                );
+            }
 
-                if consecutive_dup_count >= info.col as usize && !self.config.ignore_dup {
-                    error!("识别到连续多个重复物品，可能为翻页错误，或者为非背包顶部开始扫描");
-                    error!("建议: 请确保从背包顶部开始扫描，避免在扫描过程中手动翻页");
-                    break;
-                }
+            // 锁定状态或装备发生变化的同一件圣遗物仍按完整相等保留在导出结果中；
+            // 重复的结果只替换保留的结果，不再发送给调用方
+            if keep_best(&mut results, &mut kept, result) {
+                self.emit_result(results.last().unwrap());
+            }
 
-                // 应用自适应延时
-                let current_delay = self.adaptive_delay.get_current_delay();
-                if current_delay > 0 {
-                    std::thread::sleep(std::time::Duration::from_millis(current_delay as u64));
-                }
+            if consecutive_dups.len() >= dup_threshold && !self.config.ignore_dup {
+                error!("识别到连续多个重复物品，可能为翻页错误，或者为非背包顶部开始扫描");
+                error!("重复的物品: {}", consecutive_dups.join("、"));
+                error!(
+                    "若确实存在相似的圣遗物，可使用 --dup-threshold 调大阈值或使用 --ignore-dup"
+                );
+                error!("建议: 请确保从背包顶部开始扫描，避免在扫描过程中手动翻页");
+                self.error_stats.stop_reason = Some(ScanStopReason::DuplicateItems);
+                break;
+            }
+
+            // 应用自适应延时
+            let current_delay = self.adaptive_delay.get_current_delay();
+            if current_delay > 0 {
+                std::thread::sleep(std::time::Duration::from_millis(current_delay as u64));
             }
+        }
 
-            info!("识别结束，共扫描 {} 个圣遗物", hash.len());
+        self.error_stats.scanned_items = artifact_index as usize;
+        info!("识别结束，共扫描 {} 个圣遗物", results.len());
 
-            // 输出错误统计报告
-            if self.error_stats.total_errors > 0 || results.iter().any(|r| r.has_errors()) {
-                let items_with_errors = results.iter().filter(|r| r.has_errors()).count();
-                warn!("扫描过程中发现问题，详细统计如下:");
-                for line in self.error_stats.get_error_summary().lines() {
-                    warn!("{line}");
-                }
-                if items_with_errors > 0 {
-                    warn!("- 存在错误的物品: {items_with_errors} 个");
+        // 输出错误统计报告
+        if self.error_stats.total_errors > 0 || results.iter().any(|r| r.has_errors()) {
+            let items_with_errors = results.iter().filter(|r| r.has_errors()).count();
+            warn!("扫描过程中发现问题，详细统计如下:");
+            for line in self.error_stats.get_error_summary().lines() {
+                warn!("{line}");
+            }
+            if items_with_errors > 0 {
+                warn!("- 存在错误的物品: {items_with_errors} 个");
+            }
+
+            if self.error_stats.get_success_rate() < 80.0 {
+                error!(
+                    "识别成功率较低 ({:.1}%)，建议检查游戏设置和环境",
+                    self.error_stats.get_success_rate()
+                );
+                error!("常见解决方案:");
+                error!("1. 确保游戏语言设置为简体中文");
+                error!("2. 检查游戏分辨率是否为16:9比例");
+                error!("3. 确保游戏界面清晰，无遮挡");
+                error!("4. 检查游戏亮度设置");
+            }
+        } else {
+            info!("扫描完成，未发现错误！");
+        }
+
+        for line in self.performance_monitor.get_performance_summary().lines() {
+            info!("{line}");
+        }
+
+        let ocr_timing =
+            self.performance_monitor.ocr_timing(self.ocr_recognizer.average_inference_time());
+        (results, self.error_stats, ocr_timing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use furina_core::common::color::Color;
+    use furina_core::game_info::{Platform, UI};
+    use furina_core::ocr::ImageToText;
+    use furina_core::positioning::Pos;
+    use furina_core::window_info::{
+        FromWindowInfoRepository, WindowInfoRepository, WindowInfoTemplatePerSize,
+    };
+
+    use super::*;
+    use crate::scanner::artifact_scanner::artifact_scanner_config::SUCCESS_RATE_MIN_SAMPLE;
+
+    #[test]
+    fn test_parse_level_range() {
+        assert_eq!(parse_level("+20").unwrap(), 20);
+        assert_eq!(parse_level("16").unwrap(), 16);
+        assert!(parse_level("+200").is_err());
+        assert!(parse_level("+99999999999").is_err());
+        assert!(parse_level("等级").is_err());
+    }
+
+    /// 以图像尺寸和像素校验和作为"识别结果"的模拟模型
+    struct ChecksumModel;
+
+    impl ImageToText<RgbImage> for ChecksumModel {
+        fn image_to_text(&self, image: &RgbImage, _is_preprocessed: bool) -> Result<String> {
+            let sum: u64 = image.pixels().map(|p| p.0.iter().map(|&c| c as u64).sum::<u64>()).sum();
+            Ok(format!("{}x{}:{}", image.width(), image.height(), sum))
+        }
+
+        fn get_average_inference_time(&self) -> Option<Duration> {
+            None
+        }
+    }
+
+    /// 总是识别失败的模拟模型
+    struct FailingModel;
+
+    impl ImageToText<RgbImage> for FailingModel {
+        fn image_to_text(&self, _image: &RgbImage, _is_preprocessed: bool) -> Result<String> {
+            Err(anyhow::anyhow!("模拟识别失败"))
+        }
+
+        fn get_average_inference_time(&self) -> Option<Duration> {
+            None
+        }
+    }
+
+    /// 按字段截图左上角像素返回 `{绿色通道}+{红色通道}` 的模拟模型
+    ///
+    /// 配合 [`level_panel`] 使用：纯色面板上所有字段的文本相同，等级解析为红色通道的值，
+    /// 绿色通道不同的物品识别为不同的圣遗物
+    struct PixelModel;
+
+    impl ImageToText<RgbImage> for PixelModel {
+        fn image_to_text(&self, image: &RgbImage, _is_preprocessed: bool) -> Result<String> {
+            let pixel = image.get_pixel(0, 0);
+            Ok(format!("{}+{}", pixel.0[1], pixel.0[0]))
+        }
+
+        fn get_average_inference_time(&self) -> Option<Duration> {
+            None
+        }
+    }
+
+    /// 1600×900 下使用 `model` 识别的识别线程
+    fn fixture_worker(
+        model: Box<dyn ImageToText<RgbImage> + Send>,
+        config: GenshinArtifactScannerConfig,
+    ) -> ArtifactScannerWorker {
+        let mut repo = WindowInfoRepository::new();
+        let template: WindowInfoTemplatePerSize =
+            serde_json::from_str(include_str!("../../../window_info/windows1600x900.json"))
+                .unwrap();
+        template.inject_into_window_info_repo(&mut repo);
+        let window_info = ArtifactScannerWindowInfo::from_window_info_repository(
+            Size::new(1600, 900),
+            UI::Desktop,
+            Platform::Windows,
+            &repo,
+        )
+        .unwrap();
+
+        ArtifactScannerWorker::with_recognizer(
+            OptimizedOCRRecognizer::from_model(model),
+            window_info,
+            config,
+            (1600, 900),
+        )
+    }
+
+    fn fixture_panel(worker: &ArtifactScannerWorker) -> RgbImage {
+        let panel = worker.window_info.panel_rect;
+        RgbImage::from_fn(panel.width as u32, panel.height as u32, |x, y| {
+            Rgb([(x % 251) as u8, (y % 241) as u8, ((x * 7 + y * 3) % 127) as u8])
+        })
+    }
+
+    /// 第 `variant` 种面板，不同的变体识别为不同的圣遗物
+    fn panel_variant(worker: &ArtifactScannerWorker, variant: u8) -> RgbImage {
+        let mut panel = fixture_panel(worker);
+        panel.pixels_mut().for_each(|p| p.0[2] = p.0[2].wrapping_add(variant));
+        panel
+    }
+
+    fn panel_item(panel: RgbImage, star: usize) -> SendItem {
+        SendItem { panel_image: Some(panel), star, list_image: None, position: None }
+    }
+
+    /// 第 `id` 件、等级为 `level` 的纯色面板，由 [`PixelModel`] 识别
+    fn level_panel(worker: &ArtifactScannerWorker, id: u8, level: u8) -> RgbImage {
+        let panel = worker.window_info.panel_rect;
+        RgbImage::from_pixel(panel.width as u32, panel.height as u32, Rgb([level, id, 0]))
+    }
+
+    /// 由 [`PixelModel`] 识别出的名称取回物品编号
+    fn item_ids(results: &[GenshinArtifactScanResult]) -> Vec<u8> {
+        results.iter().map(|r| r.name.split('+').next().unwrap().parse::<u8>().unwrap()).collect()
+    }
+
+    /// 从 0 开始、每次读取后前进 `step` 的时钟，用于代替真实时间
+    fn stepping_clock(step: Duration) -> impl FnMut() -> Duration + Send {
+        let mut elapsed = Duration::ZERO;
+        move || {
+            let now = elapsed;
+            elapsed += step;
+            now
+        }
+    }
+
+    /// 依次发送物品并等待识别线程结束；识别线程提前停止后发送失败的物品被忽略
+    fn scan_items(
+        worker: ArtifactScannerWorker,
+        items: impl IntoIterator<Item = SendItem>,
+    ) -> (Vec<GenshinArtifactScanResult>, ErrorStatistics) {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let handle = worker.run_with_stats(rx);
+        for item in items {
+            let _ = tx.send(Some(item));
+        }
+        let _ = tx.send(None);
+        let (results, stats, _) = handle.join().unwrap();
+        (results, stats)
+    }
+
+    /// 旧的逐字段识别路径：每个字段单独裁剪（含相同的 OCR 边距）并识别
+    fn recognize_per_field(
+        worker: &ArtifactScannerWorker,
+        image: &RgbImage,
+        rects: &[Rect<f64>],
+    ) -> Vec<String> {
+        let origin =
+            Pos { x: -worker.window_info.panel_rect.left, y: -worker.window_info.panel_rect.top };
+        let bounds = Size::new(image.width() as f64, image.height() as f64);
+        rects
+            .iter()
+            .map(|rect| {
+                let padded = OptimizedImageProcessor::pad_rect(
+                    &rect.translate(origin),
+                    worker.window_info.ocr_padding,
+                    bounds,
+                );
+                let cropped = OptimizedImageProcessor::crop_optimized(image, &padded);
+                worker.ocr_recognizer.recognize(&cropped).unwrap()
+            })
+            .collect()
+    }
+
+    fn field_texts(fields: ArtifactFields) -> Vec<String> {
+        let mut texts = vec![
+            fields.title.unwrap(),
+            fields.main_stat_name.unwrap(),
+            fields.main_stat_value.unwrap(),
+            fields.level.unwrap(),
+            fields.equip.unwrap(),
+        ];
+        texts.extend(fields.sub_stats.map(|r| r.unwrap()));
+        texts
+    }
+
+    #[test]
+    fn test_recognize_all_fields_matches_per_field_path() {
+        let mut worker = fixture_worker(Box::new(ChecksumModel), Default::default());
+        let image = fixture_panel(&worker);
+        let info = worker.window_info.clone();
+
+        let expected = recognize_per_field(
+            &worker,
+            &image,
+            &[
+                info.title_rect,
+                info.main_stat_name_rect,
+                info.main_stat_value_rect,
+                info.level_rect,
+                info.item_equip_rect,
+                info.sub_stat_1,
+                info.sub_stat_2,
+                info.sub_stat_3,
+                info.sub_stat_4,
+            ],
+        );
+
+        assert_eq!(field_texts(worker.recognize_all_fields(&image)), expected);
+    }
+
+    #[test]
+    fn test_recognize_all_fields_applies_hoarfrost_offset() {
+        let mut worker = fixture_worker(Box::new(ChecksumModel), Default::default());
+        let mut image = fixture_panel(&worker);
+        let info = worker.window_info.clone();
+
+        // 在检测位置放置祝圣之霜的特征颜色
+        let x = info.level_rect.left - 10.0 - info.panel_rect.left;
+        let y = info.level_rect.top - 15.0 - info.panel_rect.top;
+        image.put_pixel(x as u32, y as u32, Rgb([220, 192, 255]));
+
+        let offset = info.hoarfrost_offset.height;
+        let shifted = |rect: Rect<f64>| Rect { top: rect.top + offset, ..rect };
+        let expected = recognize_per_field(
+            &worker,
+            &image,
+            &[
+                info.title_rect,
+                info.main_stat_name_rect,
+                info.main_stat_value_rect,
+                shifted(info.level_rect),
+                info.item_equip_rect,
+                shifted(info.sub_stat_1),
+                shifted(info.sub_stat_2),
+                shifted(info.sub_stat_3),
+                shifted(info.sub_stat_4),
+            ],
+        );
+
+        assert_eq!(field_texts(worker.recognize_all_fields(&image)), expected);
+    }
+
+    /// 一次识别线程运行的预期结果
+    struct StreamCase {
+        name: &'static str,
+        model: Box<dyn ImageToText<RgbImage> + Send>,
+        config: GenshinArtifactScannerConfig,
+        /// 设置时使用每处理一个物品前进该时长的时钟，见 [`stepping_clock`]
+        clock_step: Option<Duration>,
+        /// 依次发送的面板变体
+        panels: Vec<u8>,
+        /// 保留的结果数量
+        kept: std::ops::RangeInclusive<usize>,
+        /// 识别线程处理过的物品数量
+        scanned: std::ops::RangeInclusive<usize>,
+        stop_reason: Option<ScanStopReason>,
+        abort_reason: Option<&'static str>,
+    }
+
+    #[test]
+    fn test_stream_stop_conditions() {
+        let min = SUCCESS_RATE_MIN_SAMPLE;
+        // 一件物品后紧跟 8 个（1600×900 下的列数）重复物品，随后是一件新物品
+        let duplicates: Vec<u8> = [0; 9].into_iter().chain([1]).collect();
+        let cases = vec![
+            StreamCase {
+                name: "连续重复达到列数时停止",
+                model: Box::new(ChecksumModel),
+                config: GenshinArtifactScannerConfig::default(),
+                clock_step: None,
+                panels: duplicates.clone(),
+                kept: 1..=1,
+                scanned: 9..=9,
+                stop_reason: Some(ScanStopReason::DuplicateItems),
+                abort_reason: None,
+            },
+            StreamCase {
+                name: "调大 --dup-threshold 后继续扫描",
+                model: Box::new(ChecksumModel),
+                config: GenshinArtifactScannerConfig {
+                    dup_threshold: Some(9),
+                    ..Default::default()
+                },
+                clock_step: None,
+                panels: duplicates,
+                kept: 2..=2,
+                scanned: 10..=10,
+                stop_reason: None,
+                abort_reason: None,
+            },
+            StreamCase {
+                // 第 k 个物品处理前时钟为 (k-1)×100ms，第 5 个物品处理前达到 400ms
+                name: "超过最长扫描时间时保留部分结果",
+                model: Box::new(ChecksumModel),
+                config: GenshinArtifactScannerConfig {
+                    max_scan_duration: Some(Duration::from_millis(400)),
+                    ..Default::default()
+                },
+                clock_step: Some(Duration::from_millis(100)),
+                panels: (0..6).collect(),
+                kept: 4..=4,
+                scanned: 4..=4,
+                stop_reason: Some(ScanStopReason::Timeout),
+                abort_reason: None,
+            },
+            StreamCase {
+                // 识别失败的物品仍保留在结果中，停止前的结果不会丢失
+                name: "识别成功率过低时停止",
+                model: Box::new(FailingModel),
+                config: GenshinArtifactScannerConfig {
+                    abort_below_success_rate: Some(50.0),
+                    ignore_dup: true,
+                    ..Default::default()
+                },
+                clock_step: None,
+                panels: vec![0; min * 2],
+                kept: 1..=1,
+                scanned: min..=min * 2 - 1,
+                stop_reason: Some(ScanStopReason::LowSuccessRate),
+                abort_reason: Some("识别成功率 0.0% 低于 50.0%"),
+            },
+            StreamCase {
+                name: "未设置成功率下限时识别失败不停止",
+                model: Box::new(FailingModel),
+                config: GenshinArtifactScannerConfig { ignore_dup: true, ..Default::default() },
+                clock_step: None,
+                panels: vec![0; min * 2],
+                kept: 1..=1,
+                scanned: min * 2..=min * 2,
+                stop_reason: None,
+                abort_reason: None,
+            },
+        ];
+
+        for case in cases {
+            let mut worker = fixture_worker(case.model, case.config);
+            if let Some(step) = case.clock_step {
+                worker = worker.with_scan_clock(stepping_clock(step));
+            }
+            let items: Vec<SendItem> =
+                case.panels.iter().map(|&v| panel_item(panel_variant(&worker, v), 5)).collect();
+            let (results, stats) = scan_items(worker, items);
+
+            assert!(
+                case.kept.contains(&results.len()),
+                "{}: 保留了 {} 个",
+                case.name,
+                results.len()
+            );
+            assert!(
+                case.scanned.contains(&stats.scanned_items),
+                "{}: 处理了 {} 个",
+                case.name,
+                stats.scanned_items
+            );
+            assert_eq!(stats.stop_reason, case.stop_reason, "{}", case.name);
+            assert_eq!(stats.abort_reason.as_deref(), case.abort_reason, "{}", case.name);
+        }
+    }
+
+    /// 未按星级/等级排序的背包中的 (编号, 星级, 等级)
+    const UNSORTED_STREAM: [(u8, usize, u8); 6] =
+        [(0, 5, 20), (1, 5, 4), (2, 4, 20), (3, 5, 16), (4, 5, 0), (5, 5, 12)];
+
+    #[test]
+    fn test_star_and_level_filters_on_unsorted_stream() {
+        let low_level_duplicates: Vec<(u8, usize, u8)> =
+            [(0, 5, 20)].into_iter().chain([(1, 5, 4); 9]).chain([(2, 5, 12)]).collect();
+        let cases = [
+            (
+                "默认遇到低于最低星级的物品时停止",
+                GenshinArtifactScannerConfig::default(),
+                UNSORTED_STREAM.to_vec(),
+                vec![0, 1],
+                Some(ScanStopReason::MinStar),
+            ),
+            (
+                "默认遇到低于最低等级的物品时停止",
+                GenshinArtifactScannerConfig { min_level: 8, ..Default::default() },
+                UNSORTED_STREAM.to_vec(),
+                vec![0],
+                Some(ScanStopReason::MinLevel),
+            ),
+            (
+                "关闭 stop_on_below_min 后跳过并继续扫描",
+                GenshinArtifactScannerConfig {
+                    min_level: 8,
+                    stop_on_below_min: false,
+                    ..Default::default()
+                },
+                UNSORTED_STREAM.to_vec(),
+                vec![0, 3, 5],
+                None,
+            ),
+            (
+                "高于最大等级的物品总是被跳过",
+                GenshinArtifactScannerConfig {
+                    max_level: 16,
+                    min_star: 4,
+                    stop_on_below_min: false,
+                    ..Default::default()
+                },
+                UNSORTED_STREAM.to_vec(),
+                vec![1, 3, 4, 5],
+                None,
+            ),
+            (
+                // 跳过的物品不参与去重，超过列数的连续跳过不会被当作翻页错误
+                "连续跳过的重复物品不触发翻页错误检测",
+                GenshinArtifactScannerConfig {
+                    min_level: 8,
+                    stop_on_below_min: false,
+                    ..Default::default()
+                },
+                low_level_duplicates,
+                vec![0, 2],
+                None,
+            ),
+        ];
+
+        for (name, config, stream, kept, stop_reason) in cases {
+            let worker = fixture_worker(Box::new(PixelModel), config);
+            let items: Vec<SendItem> = stream
+                .iter()
+                .map(|&(id, star, level)| panel_item(level_panel(&worker, id, level), star))
+                .collect();
+            let (results, stats) = scan_items(worker, items);
+
+            assert_eq!(item_ids(&results), kept, "{name}");
+            assert_eq!(stats.stop_reason, stop_reason, "{name}");
+        }
+    }
+
+    /// 一页背包的列表截图，`locks[i]` 为 true 的位置画上锁定图标
+    fn list_page(worker: &ArtifactScannerWorker, locks: &[bool]) -> RgbImage {
+        let info = &worker.window_info;
+        let (step_x, step_y) = (
+            info.item_gap_size.width + info.item_size.width,
+            info.item_gap_size.height + info.item_size.height,
+        );
+        let mut image =
+            RgbImage::new((step_x * info.col as f64) as u32, (step_y * info.row as f64) as u32);
+        let color = Rgb([info.lock_color.0, info.lock_color.1, info.lock_color.2]);
+        for (index, &locked) in locks.iter().enumerate() {
+            if !locked {
+                continue;
+            }
+            let (r, c) = (index / info.col as usize, index % info.col as usize);
+            let x = (step_x * c as f64 + info.lock_pos.x) as u32;
+            let y = (step_y * r as f64 + info.lock_pos.y) as u32;
+            for py in y - 1..=y + 1 {
+                for px in x - 1..=x + 1 {
+                    image.put_pixel(px, py, color);
                 }
+            }
+        }
+        image
+    }
 
-                if self.error_stats.get_success_rate() < 80.0 {
-                    error!(
-                        "识别成功率较低 ({:.1}%)，建议检查游戏设置和环境",
-                        self.error_stats.get_success_rate()
-                    );
-                    error!("常见解决方案:");
-                    error!("1. 确保游戏语言设置为简体中文");
-                    error!("2. 检查游戏分辨率是否为16:9比例");
-                    error!("3. 确保游戏界面清晰，无遮挡");
-                    error!("4. 检查游戏亮度设置");
+    #[test]
+    fn test_lock_filter_across_pages() {
+        let worker = fixture_worker(Box::new(PixelModel), Default::default());
+        let page_size = (worker.window_info.row * worker.window_info.col) as usize;
+        // 一整页加上最后一页的 6 个物品
+        let count = page_size + 6;
+        let locked = |index: usize| index % 3 == 0;
+        // 截图失败和低于最低星级的物品不识别，但仍占用锁定状态的下标
+        let capture_failed = |index: usize| index == 5 || index == page_size + 1;
+        let low_star = |index: usize| index == 7 || index == page_size + 3;
+
+        let make_items = |worker: &ArtifactScannerWorker| -> Vec<SendItem> {
+            (0..count)
+                .map(|index| {
+                    let list_image = (index % page_size == 0).then(|| {
+                        let page: Vec<bool> =
+                            (index..(index + page_size).min(count)).map(locked).collect();
+                        list_page(worker, &page)
+                    });
+                    let panel_image =
+                        (!capture_failed(index)).then(|| level_panel(worker, index as u8, 20));
+                    let star = if low_star(index) { 4 } else { 5 };
+                    SendItem { panel_image, star, list_image, position: None }
+                })
+                .collect()
+        };
+        let scanned: Vec<usize> =
+            (0..count).filter(|&i| !capture_failed(i) && !low_star(i)).collect();
+
+        let cases = [
+            ("不过滤", GenshinArtifactScannerConfig::default(), scanned.clone()),
+            (
+                "只保留已锁定",
+                GenshinArtifactScannerConfig { only_locked: true, ..Default::default() },
+                scanned.iter().copied().filter(|&i| locked(i)).collect(),
+            ),
+            (
+                "只保留未锁定",
+                GenshinArtifactScannerConfig { only_unlocked: true, ..Default::default() },
+                scanned.iter().copied().filter(|&i| !locked(i)).collect(),
+            ),
+        ];
+
+        for (name, config, kept) in cases {
+            // 低于最低星级的物品被跳过而不是停止扫描
+            let config = GenshinArtifactScannerConfig { stop_on_below_min: false, ..config };
+            let worker = fixture_worker(Box::new(PixelModel), config);
+            let items = make_items(&worker);
+            let (results, _) = scan_items(worker, items);
+
+            let ids: Vec<usize> = item_ids(&results).into_iter().map(usize::from).collect();
+            assert_eq!(ids, kept, "{name}");
+            for (result, id) in results.iter().zip(ids) {
+                assert_eq!(result.lock, locked(id), "{name}: 第 {id} 个物品");
+            }
+        }
+    }
+
+    #[test]
+    fn test_resume_from_checkpoint_keeps_and_dedups_results() {
+        let worker = fixture_worker(Box::new(ChecksumModel), Default::default());
+        let first = panel_variant(&worker, 0);
+        let second = panel_variant(&worker, 1);
+
+        let checkpoint = ScanCheckpoint {
+            scanned_count: 40,
+            results: scan_items(worker, [panel_item(first.clone(), 5)]).0,
+            ..ScanCheckpoint::new(100)
+        };
+        let resumed = checkpoint.results[0].clone();
+
+        // 检查点之后再次出现的同一件物品按重复处理，不会重复导出
+        let worker =
+            fixture_worker(Box::new(ChecksumModel), Default::default()).resume_from(checkpoint);
+        let (results, _) = scan_items(worker, [panel_item(first, 5), panel_item(second, 5)]);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0], resumed);
+        assert_ne!(results[1], resumed);
+    }
+
+    #[test]
+    fn test_result_sender_streams_same_results() {
+        let run = |worker: ArtifactScannerWorker| {
+            let items: Vec<SendItem> =
+                [0, 1, 0].into_iter().map(|v| panel_item(panel_variant(&worker, v), 5)).collect();
+            scan_items(worker, items).0
+        };
+
+        let expected = run(fixture_worker(Box::new(ChecksumModel), Default::default()));
+        assert_eq!(expected.len(), 2);
+
+        let (result_tx, result_rx) = std::sync::mpsc::channel();
+        let results = run(fixture_worker(Box::new(ChecksumModel), Default::default())
+            .with_result_sender(result_tx));
+        // 识别线程结束后发送端已释放，迭代会结束
+        let streamed: Vec<_> = result_rx.into_iter().collect();
+        assert_eq!(streamed, expected);
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn test_lock_color_tolerance() {
+        let worker = fixture_worker(Box::new(ChecksumModel), Default::default());
+        let info = worker.window_info.clone();
+        let (lock_x, lock_y) = (info.lock_pos.x as u32, info.lock_pos.y as u32);
+        let list_image = |offset: u8| {
+            let mut image = RgbImage::new(1200, 600);
+            let color = Rgb([255, 138 - offset, 117]);
+            for y in lock_y - 1..=lock_y + 1 {
+                for x in lock_x - 1..=lock_x + 1 {
+                    image.put_pixel(x, y, color);
                 }
-            } else {
-                info!("扫描完成，未发现错误！");
             }
+            image
+        };
 
-            results
-        })
+        // 阈值 900 即距离 30，29 以内视为锁定
+        assert_eq!(info.lock_color, Color(255, 138, 117));
+        assert_eq!(info.lock_color_threshold, 900.0);
+        assert!(worker.get_page_locks_optimized(&list_image(29))[0]);
+        assert!(!worker.get_page_locks_optimized(&list_image(30))[0]);
+
+        // 单个噪点像素被周围像素平均后不影响判断
+        let mut noisy = list_image(0);
+        noisy.put_pixel(lock_x, lock_y, Rgb([255, 255, 255]));
+        assert!(worker.get_page_locks_optimized(&noisy)[0]);
+    }
+
+    #[test]
+    fn test_sample_average_clips_to_image() {
+        let image = RgbImage::from_fn(2, 2, |x, _| Rgb([if x == 0 { 0 } else { 100 }, 0, 0]));
+        assert_eq!(sample_average(&image, 0, 0), Some(Rgb([50, 0, 0])));
+        assert_eq!(sample_average(&image, 2, 0), None);
+    }
+
+    #[test]
+    fn test_keep_best_prefers_higher_confidence() {
+        let sample = || {
+            GenshinArtifactScanResult::new(
+                "明威之镡".to_string(),
+                "生命值".to_string(),
+                "4,780".to_string(),
+                Default::default(),
+                String::new(),
+                20,
+                5,
+                false,
+            )
+        };
+        let mut low = sample();
+        low.add_error(&ArtifactScanError::StarRecognitionFailed {
+            detected_color: "RGB(120, 100, 90)".to_string(),
+            confidence: 0.5,
+        });
+        let high = sample();
+        assert_eq!(low, high);
+        assert!(low.confidence_score < high.confidence_score);
+
+        let mut results = Vec::new();
+        let mut kept = HashMap::new();
+        assert!(keep_best(&mut results, &mut kept, low.clone()));
+        assert!(!keep_best(&mut results, &mut kept, high.clone()));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].confidence_score, high.confidence_score);
+
+        // 之后置信度较低的重复结果不会替换已保留的结果
+        assert!(!keep_best(&mut results, &mut kept, low));
+        assert_eq!(results[0].confidence_score, high.confidence_score);
+        assert!(results[0].scan_errors.is_empty());
+    }
+
+    #[test]
+    fn test_dump_crops_writes_failed_fields() {
+        let dir =
+            std::env::temp_dir().join(format!("furina_worker_dump_crops_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let config =
+            GenshinArtifactScannerConfig { dump_crops: Some(dir.clone()), ..Default::default() };
+        let worker = fixture_worker(Box::new(FailingModel), config);
+        let panel = fixture_panel(&worker);
+        scan_items(worker, [panel_item(panel, 5)]);
+
+        // 物品序号从 1 开始，每个识别失败的字段写入一张截图
+        let crop = image::open(dir.join("1_主属性数值.png")).unwrap();
+        assert!(crop.width() > 0 && crop.height() > 0);
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 9);
+        std::fs::remove_dir_all(&dir).unwrap();
     }
 }
@@ -0,0 +1,229 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, Result};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+
+use crate::scanner::artifact_scanner::scan_result::GenshinArtifactScanResult;
+use crate::scanner_controller::repository_layout::ScrollCheckpoint;
+
+/// 检查点文件的格式版本，格式不兼容时递增
+pub const CHECKPOINT_VERSION: u32 = 1;
+
+/// 扫描检查点
+///
+/// 记录已扫描的物品数量、控制器的滚动状态和已识别的结果，只在整页扫描完成后写入，
+/// 恢复时从下一页开始扫描。恢复假定背包内容与保存时一致：期间获得、分解或强化圣遗物
+/// 会使物品在背包中错位，恢复后的结果将不可靠，此时应重新完整扫描
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanCheckpoint {
+    pub version: u32,
+    /// 背包中的物品总数，恢复时用于检查背包是否发生变化
+    pub item_count: usize,
+    /// 已扫描的物品数量，总是整页物品数的倍数
+    pub scanned_count: usize,
+    pub scroll: ScrollCheckpoint,
+    pub results: Vec<GenshinArtifactScanResult>,
+}
+
+impl ScanCheckpoint {
+    pub fn new(item_count: usize) -> Self {
+        ScanCheckpoint {
+            version: CHECKPOINT_VERSION,
+            item_count,
+            scanned_count: 0,
+            scroll: ScrollCheckpoint::default(),
+            results: Vec::new(),
+        }
+    }
+
+    /// 写入检查点文件
+    ///
+    /// 先写入临时文件再重命名，避免程序在写入途中退出时留下损坏的检查点
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec(self)?)?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content =
+            fs::read(path).map_err(|e| anyhow!("无法读取检查点文件 {}: {e}", path.display()))?;
+        let checkpoint: ScanCheckpoint = serde_json::from_slice(&content)
+            .map_err(|e| anyhow!("检查点文件 {} 格式错误: {e}", path.display()))?;
+        if checkpoint.version != CHECKPOINT_VERSION {
+            return Err(anyhow!(
+                "检查点文件版本 {} 与当前版本 {CHECKPOINT_VERSION} 不兼容，请重新扫描",
+                checkpoint.version
+            ));
+        }
+        Ok(checkpoint)
+    }
+
+    /// 检查背包物品数量是否与保存检查点时一致
+    pub fn check_item_count(&self, item_count: usize) -> Result<()> {
+        if self.item_count != item_count {
+            return Err(anyhow!(
+                "背包中的物品数量（{item_count}）与检查点（{}）不一致，背包内容已变化，请重新扫描",
+                self.item_count
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 在扫描过程中按页写入检查点
+///
+/// 滚动状态由扫描线程更新，已识别的结果由识别线程在每页结束时传入；
+/// 克隆出的实例共享同一份滚动状态
+#[derive(Clone)]
+pub struct CheckpointRecorder {
+    path: PathBuf,
+    item_count: usize,
+    page_size: usize,
+    scroll: Arc<Mutex<ScrollCheckpoint>>,
+}
+
+impl CheckpointRecorder {
+    pub fn new(path: PathBuf, item_count: usize, page_size: usize) -> Self {
+        CheckpointRecorder {
+            path,
+            item_count,
+            page_size: page_size.max(1),
+            scroll: Arc::new(Mutex::new(ScrollCheckpoint::default())),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn update_scroll(&self, scroll: ScrollCheckpoint) {
+        *self.scroll.lock().unwrap() = scroll;
+    }
+
+    /// 识别完前 `scanned_count` 个物品后调用，恰好扫描完整页时写入检查点，返回是否写入
+    ///
+    /// 写入失败只记录警告，不影响扫描
+    pub fn on_item_processed(
+        &self,
+        scanned_count: usize,
+        results: &[GenshinArtifactScanResult],
+    ) -> bool {
+        if scanned_count == 0 || scanned_count % self.page_size != 0 {
+            return false;
+        }
+
+        let checkpoint = ScanCheckpoint {
+            scanned_count,
+            scroll: *self.scroll.lock().unwrap(),
+            results: results.to_vec(),
+            ..ScanCheckpoint::new(self.item_count)
+        };
+        match checkpoint.save(&self.path) {
+            Ok(()) => {
+                info!("已保存检查点: {scanned_count}/{} 个物品", self.item_count);
+                true
+            },
+            Err(e) => {
+                warn!("检查点保存失败: {e}");
+                false
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_checkpoint() -> ScanCheckpoint {
+        let mut result = GenshinArtifactScanResult::new(
+            "明威之镡".to_string(),
+            "攻击力".to_string(),
+            "311".to_string(),
+            ["暴击率+3.9%".to_string(), "暴击伤害+7.8%".to_string(), String::new(), String::new()],
+            "胡桃".to_string(),
+            20,
+            5,
+            true,
+        );
+        result.scan_errors.push("副属性识别失败".to_string());
+
+        ScanCheckpoint {
+            scanned_count: 80,
+            scroll: ScrollCheckpoint { scrolled_rows: 10, avg_scroll_one_row: 5.3 },
+            results: vec![result],
+            ..ScanCheckpoint::new(1234)
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("furina_checkpoint_{}_{name}.json", std::process::id()))
+    }
+
+    #[test]
+    fn test_checkpoint_round_trip() {
+        let checkpoint = fixture_checkpoint();
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        let restored: ScanCheckpoint = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, checkpoint);
+        assert_eq!(restored.scroll.avg_scroll_one_row, 5.3);
+        assert!(restored.results[0].lock);
+        assert_eq!(restored.results[0].scan_errors, checkpoint.results[0].scan_errors);
+    }
+
+    #[test]
+    fn test_checkpoint_save_and_load() {
+        let path = temp_path("save_load");
+        let checkpoint = fixture_checkpoint();
+        checkpoint.save(&path).unwrap();
+
+        assert_eq!(ScanCheckpoint::load(&path).unwrap(), checkpoint);
+        assert!(!path.with_extension("tmp").exists());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_checkpoint_rejects_other_version() {
+        let path = temp_path("version");
+        let checkpoint = ScanCheckpoint { version: CHECKPOINT_VERSION + 1, ..fixture_checkpoint() };
+        checkpoint.save(&path).unwrap();
+
+        assert!(ScanCheckpoint::load(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_check_item_count() {
+        let checkpoint = fixture_checkpoint();
+        assert!(checkpoint.check_item_count(1234).is_ok());
+        assert!(checkpoint.check_item_count(1235).is_err());
+    }
+
+    #[test]
+    fn test_recorder_saves_on_page_boundary() {
+        let path = temp_path("recorder");
+        let recorder = CheckpointRecorder::new(path.clone(), 100, 40);
+        let results = fixture_checkpoint().results;
+
+        assert!(!recorder.on_item_processed(0, &results));
+        assert!(!recorder.on_item_processed(39, &results));
+        assert!(!path.exists());
+
+        let scroll = ScrollCheckpoint { scrolled_rows: 4, avg_scroll_one_row: 5.0 };
+        recorder.clone().update_scroll(scroll);
+        assert!(recorder.on_item_processed(40, &results));
+
+        let saved = ScanCheckpoint::load(&path).unwrap();
+        assert_eq!(saved.item_count, 100);
+        assert_eq!(saved.scanned_count, 40);
+        assert_eq!(saved.scroll, scroll);
+        assert_eq!(saved.results, results);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,104 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use image::RgbImage;
+use log::{info, warn};
+
+/// 一次扫描最多写入的截图数量，避免大量识别失败时占满磁盘
+pub const DEFAULT_CROP_DUMP_LIMIT: usize = 200;
+
+/// 将识别失败的字段截图写入目录，便于排查新版本游戏上的识别问题
+///
+/// 截图即模型实际看到的图像（启用预处理时为预处理后的图像），
+/// 文件名为 `{物品序号}_{字段名}.png`
+pub struct CropDumper {
+    dir: PathBuf,
+    limit: usize,
+    written: usize,
+}
+
+impl CropDumper {
+    pub fn new(dir: PathBuf, limit: usize) -> Self {
+        CropDumper { dir, limit, written: 0 }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 已写入的截图数量
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// 写入一张识别失败的字段截图，返回写入的路径
+    ///
+    /// 达到数量上限后不再写入；写入失败只记录警告，不影响扫描
+    pub fn dump(&mut self, image: &RgbImage, item_index: usize, field: &str) -> Option<PathBuf> {
+        if self.written >= self.limit {
+            return None;
+        }
+
+        match self.write(image, item_index, field) {
+            Ok(path) => {
+                self.written += 1;
+                if self.written == self.limit {
+                    info!("识别失败截图已达到上限 {} 张，之后不再写入", self.limit);
+                }
+                Some(path)
+            },
+            Err(e) => {
+                warn!("识别失败截图写入失败: {e}");
+                None
+            },
+        }
+    }
+
+    fn write(&self, image: &RgbImage, item_index: usize, field: &str) -> Result<PathBuf> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.dir.join(format!("{item_index}_{field}.png"));
+        image.save(&path)?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("furina_crop_dump_{}_{name}", std::process::id()))
+    }
+
+    #[test]
+    fn test_dump_writes_png_named_by_item_and_field() {
+        let dir = temp_dir("named");
+        let _ = fs::remove_dir_all(&dir);
+        let mut dumper = CropDumper::new(dir.clone(), DEFAULT_CROP_DUMP_LIMIT);
+
+        let path = dumper.dump(&RgbImage::new(8, 4), 12, "主属性数值").unwrap();
+
+        assert_eq!(path, dir.join("12_主属性数值.png"));
+        let saved = image::open(&path).unwrap();
+        assert_eq!((saved.width(), saved.height()), (8, 4));
+        assert_eq!(dumper.written(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_dump_stops_at_limit() {
+        let dir = temp_dir("limit");
+        let _ = fs::remove_dir_all(&dir);
+        let mut dumper = CropDumper::new(dir.clone(), 2);
+        let image = RgbImage::new(4, 4);
+
+        assert!(dumper.dump(&image, 1, "等级").is_some());
+        assert!(dumper.dump(&image, 2, "等级").is_some());
+        assert!(dumper.dump(&image, 3, "等级").is_none());
+
+        assert_eq!(dumper.written(), 2);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -2,6 +2,8 @@ use std::fmt;
 
 use furina_core::error_recovery::{ErrorCategory, RecoverableError};
 
+use crate::scanner::artifact_scanner::scan_report::ScanStopReason;
+
 /// 圣遗物扫描错误类型
 #[derive(Debug, Clone, PartialEq)]
 pub enum ArtifactScanError {
@@ -104,6 +106,12 @@ pub struct ErrorStatistics {
     pub window_info_errors: usize,
     pub interruption_errors: usize,
     pub unknown_errors: usize,
+    /// 识别线程因识别成功率过低等原因提前停止时的原因
+    pub abort_reason: Option<String>,
+    /// 识别线程结束时已处理的物品序号，包含被过滤和识别失败的物品
+    pub scanned_items: usize,
+    /// 识别线程主动停止的原因，处理完所有发送的物品时为 `None`
+    pub stop_reason: Option<ScanStopReason>,
 }
 
 impl ErrorStatistics {
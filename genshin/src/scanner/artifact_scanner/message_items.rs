@@ -1,8 +1,13 @@
 use image::RgbImage;
 
+use crate::scanner_controller::repository_layout::ItemPosition;
+
 /// this is constructed by the capturing thread, and sent to the worker thread
 pub struct SendItem {
-    pub panel_image: RgbImage,
+    /// 截图重试后仍然失败时为 `None`，此时物品仅用于保持锁定状态的索引对齐
+    pub panel_image: Option<RgbImage>,
     pub star: usize,
     pub list_image: Option<RgbImage>,
+    /// 物品在背包中的位置，会记录到识别结果中供 `--retry-failed` 使用
+    pub position: Option<ItemPosition>,
 }
@@ -1,7 +1,19 @@
-pub use artifact_scanner::GenshinArtifactScanner;
-pub use artifact_scanner_config::GenshinArtifactScannerConfig;
+pub use artifact_scanner::{capture_with_recovery, CaptureFailureTracker, GenshinArtifactScanner};
+pub use artifact_scanner_config::{
+    GenshinArtifactScannerConfig, DEFAULT_CAPTURE_FAILURE_LIMIT, DEFAULT_MAX_ITEMS,
+    DEFAULT_QUEUE_CAPACITY, SUCCESS_RATE_MIN_SAMPLE,
+};
 pub use artifact_scanner_window_info::ArtifactScannerWindowInfo;
+pub use artifact_scanner_worker::ArtifactScannerWorker;
+pub use checkpoint::{CheckpointRecorder, ScanCheckpoint, CHECKPOINT_VERSION};
+pub use crop_dump::{CropDumper, DEFAULT_CROP_DUMP_LIMIT};
 pub use error::{get_error_suggestion, ArtifactScanError, ErrorStatistics};
+pub use message_items::SendItem;
+pub use performance_optimizations::{
+    ColorMetric, OcrTiming, OptimizedImageProcessor, PerformanceMonitor,
+};
+pub use scan_metrics::{format_metrics, write_metrics_file, METRICS_PREFIX};
+pub use scan_report::{ScanReport, ScanStopReason, HEALTHY_SCAN_SCORE};
 pub use scan_result::GenshinArtifactScanResult;
 
 #[allow(clippy::module_inception)]
@@ -9,7 +21,11 @@ mod artifact_scanner;
 mod artifact_scanner_config;
 mod artifact_scanner_window_info;
 mod artifact_scanner_worker;
+mod checkpoint;
+mod crop_dump;
 mod error;
 mod message_items;
 mod performance_optimizations;
+mod scan_metrics;
+mod scan_report;
 mod scan_result;
@@ -1,8 +1,16 @@
-use anyhow::Result;
+use std::collections::HashMap;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, MutexGuard, TryLockError};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
 use furina_core::ocr::{ImageToText, OcrModel};
 use furina_core::ocr_model;
-use furina_core::positioning::Rect;
-use image::{ImageBuffer, Luma, RgbImage};
+use furina_core::positioning::{Rect, Size};
+use image::{ImageBuffer, Luma, Rgb, RgbImage};
+use log::{info, warn};
+
+use crate::scanner::artifact_scanner::error::ArtifactScanError;
 
 /// 性能优化模块
 ///
@@ -79,25 +87,158 @@ thread_local! {
     static IMAGE_POOL: std::cell::RefCell<ImagePool> = std::cell::RefCell::new(ImagePool::new());
 }
 
+/// 预热推理使用的图像边长
+const WARM_UP_IMAGE_SIZE: u32 = 32;
+
 /// 性能优化的OCR识别器
+///
+/// 克隆得到的识别器共享同一个模型，扫描器和识别线程因此只需加载一次模型
+#[derive(Clone)]
 pub struct OptimizedOCRRecognizer {
-    model: Box<dyn ImageToText<RgbImage> + Send>,
+    model: Arc<Mutex<Box<dyn ImageToText<RgbImage> + Send>>>,
 }
 
 impl OptimizedOCRRecognizer {
-    /// 创建新的优化OCR识别器
+    /// 创建新的优化OCR识别器，创建后会先做一次预热推理
     pub fn new() -> Result<Self> {
-        Ok(Self { model: create_ocr_model()? })
+        let recognizer = Self::from_model(create_ocr_model()?);
+        recognizer.warm_up();
+        Ok(recognizer)
+    }
+
+    /// 使用指定的模型创建识别器
+    pub fn from_model(model: Box<dyn ImageToText<RgbImage> + Send>) -> Self {
+        Self { model: Arc::new(Mutex::new(model)) }
     }
 
     /// 批量OCR识别，提高处理效率
     pub fn batch_recognize(&self, images: &[RgbImage]) -> Vec<Result<String>> {
-        images.iter().map(|img| self.model.image_to_text(img, false)).collect()
+        let model = lock_model(&self.model);
+        images.iter().map(|img| model.image_to_text(img, false)).collect()
+    }
+
+    /// 模型统计的单次推理平均耗时；模型不统计或仍被超时的识别占用时返回 `None`
+    pub fn average_inference_time(&self) -> Option<Duration> {
+        match self.model.try_lock() {
+            Ok(model) => model.get_average_inference_time(),
+            Err(TryLockError::Poisoned(e)) => e.into_inner().get_average_inference_time(),
+            Err(TryLockError::WouldBlock) => None,
+        }
+    }
+
+    /// 用一张小图做一次推理，避免模型初始化的耗时算到第一件物品上，影响自适应延迟和切换等待的估计
+    ///
+    /// 纯色图像在预处理阶段会直接返回空结果而不经过推理，因此图像中间有一条黑色竖线。
+    /// 预热失败只记录警告，不影响之后的识别
+    pub fn warm_up(&self) {
+        let image = RgbImage::from_fn(WARM_UP_IMAGE_SIZE, WARM_UP_IMAGE_SIZE, |x, _| {
+            if x == WARM_UP_IMAGE_SIZE / 2 {
+                Rgb([0, 0, 0])
+            } else {
+                Rgb([255, 255, 255])
+            }
+        });
+
+        let start = Instant::now();
+        match self.recognize(&image) {
+            Ok(_) => info!("OCR模型预热完成，耗时 {:?}", start.elapsed()),
+            Err(e) => warn!("OCR模型预热失败，继续扫描: {e}"),
+        }
     }
 
     /// 单次OCR识别
     pub fn recognize(&self, image: &RgbImage) -> Result<String> {
-        self.model.image_to_text(image, false)
+        lock_model(&self.model).image_to_text(image, false)
+    }
+
+    /// 带超时的单次OCR识别，超时后返回 OCR 类别的 [`ArtifactScanError`]
+    ///
+    /// 推理在独立线程中进行。这里不使用作用域线程，因为作用域结束时必须等待线程退出，
+    /// 无法在超时后提前返回；超时的线程只持有模型的 `Arc` 和图像副本，推理结束后自行退出。
+    /// 上一次超时的推理仍在占用模型时，本次识别在剩余时间内等待模型空闲，
+    /// 等到截止时间仍未拿到模型时放弃并同样按超时处理，不会在之后再占用模型；
+    /// 推理过程中 panic 导致的锁中毒会被忽略，后续识别不受影响
+    pub fn recognize_with_timeout(&self, image: &RgbImage, timeout: Duration) -> Result<String> {
+        let deadline = Instant::now() + timeout;
+        let model = Arc::clone(&self.model);
+        let image = image.clone();
+        let (tx, rx) = mpsc::channel();
+
+        std::thread::spawn(move || {
+            let result = match lock_model_until(&model, deadline) {
+                Some(model) => model.image_to_text(&image, false),
+                None => Err(ocr_timeout_error(timeout)),
+            };
+            let _ = tx.send(result);
+        });
+
+        match rx.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => Err(ocr_timeout_error(timeout)),
+            Err(RecvTimeoutError::Disconnected) => Err(anyhow!("OCR识别线程异常退出")),
+        }
+    }
+}
+
+/// 等待模型锁时轮询的间隔
+const MODEL_LOCK_POLL_INTERVAL: Duration = Duration::from_millis(2);
+
+/// 在截止时间之前等待模型锁，超过截止时间仍被占用时返回 `None`；同样忽略锁中毒
+fn lock_model_until(
+    model: &Mutex<Box<dyn ImageToText<RgbImage> + Send>>,
+    deadline: Instant,
+) -> Option<MutexGuard<'_, Box<dyn ImageToText<RgbImage> + Send>>> {
+    loop {
+        match model.try_lock() {
+            Ok(model) => return Some(model),
+            Err(TryLockError::Poisoned(e)) => return Some(e.into_inner()),
+            Err(TryLockError::WouldBlock) => {
+                let now = Instant::now();
+                if now >= deadline {
+                    return None;
+                }
+                std::thread::sleep(MODEL_LOCK_POLL_INTERVAL.min(deadline - now));
+            },
+        }
+    }
+}
+
+/// OCR 识别超时的错误，包括等待模型超时
+fn ocr_timeout_error(timeout: Duration) -> anyhow::Error {
+    anyhow::Error::new(ArtifactScanError::OcrRecognitionFailed {
+        field: String::new(),
+        raw_text: String::new(),
+        error_msg: format!("OCR识别超时（超过 {timeout:?}）"),
+    })
+}
+
+/// 获取模型锁，忽略之前推理 panic 造成的锁中毒
+fn lock_model(
+    model: &Mutex<Box<dyn ImageToText<RgbImage> + Send>>,
+) -> MutexGuard<'_, Box<dyn ImageToText<RgbImage> + Send>> {
+    model.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// 颜色距离的计算方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ColorMetric {
+    /// RGB 欧氏距离的平方，速度最快
+    #[default]
+    Euclidean,
+    /// 按人眼敏感度对 R/G/B 通道以 2/4/3 加权的距离平方
+    Weighted,
+}
+
+impl ColorMetric {
+    /// 距离阈值的缩放倍数
+    ///
+    /// 加权距离的权重和为 9，对各通道均匀的色差约为欧氏距离的 3 倍，
+    /// 按欧氏距离标定的阈值需要相应放大
+    pub fn threshold_scale(&self) -> f64 {
+        match self {
+            ColorMetric::Euclidean => 1.0,
+            ColorMetric::Weighted => 3.0,
+        }
     }
 }
 
@@ -105,6 +246,15 @@ impl OptimizedOCRRecognizer {
 pub struct OptimizedImageProcessor;
 
 impl OptimizedImageProcessor {
+    /// 将区域向四周各扩展 `padding`，并限制在 `bounds` 大小的图像范围内
+    pub fn pad_rect(rect: &Rect<f64>, padding: f64, bounds: Size<f64>) -> Rect<f64> {
+        let left = (rect.left - padding).max(0.0);
+        let top = (rect.top - padding).max(0.0);
+        let right = (rect.left + rect.width + padding).min(bounds.width);
+        let bottom = (rect.top + rect.height + padding).min(bounds.height);
+        Rect::new(left, top, (right - left).max(0.0), (bottom - top).max(0.0))
+    }
+
     /// 优化的图像裁剪，减少内存分配
     pub fn crop_optimized(image: &RgbImage, rect: &Rect<f64>) -> RgbImage {
         let x = rect.left.max(0.0) as u32;
@@ -141,9 +291,56 @@ impl OptimizedImageProcessor {
         (dr * dr + dg * dg + db * db) as u32
     }
 
+    /// 感知加权的颜色距离（平方），R/G/B 通道权重分别为 2/4/3
+    ///
+    /// 人眼对绿色最敏感、对红色最不敏感，加权后更接近肉眼的区分程度
+    #[inline(always)]
+    pub fn color_distance_weighted(c1: &image::Rgb<u8>, c2: &image::Rgb<u8>) -> u32 {
+        let dr = c1.0[0] as i32 - c2.0[0] as i32;
+        let dg = c1.0[1] as i32 - c2.0[1] as i32;
+        let db = c1.0[2] as i32 - c2.0[2] as i32;
+
+        (2 * dr * dr + 4 * dg * dg + 3 * db * db) as u32
+    }
+
+    /// 按指定方式计算颜色距离
+    #[inline(always)]
+    pub fn color_distance(c1: &image::Rgb<u8>, c2: &image::Rgb<u8>, metric: ColorMetric) -> u32 {
+        match metric {
+            ColorMetric::Euclidean => Self::color_distance_fast(c1, c2),
+            ColorMetric::Weighted => Self::color_distance_weighted(c1, c2),
+        }
+    }
+
     /// 批量颜色距离计算
-    pub fn batch_color_distance(colors: &[image::Rgb<u8>], target: &image::Rgb<u8>) -> Vec<u32> {
-        colors.iter().map(|c| Self::color_distance_fast(c, target)).collect()
+    pub fn batch_color_distance(
+        colors: &[image::Rgb<u8>],
+        target: &image::Rgb<u8>,
+        metric: ColorMetric,
+    ) -> Vec<u32> {
+        colors.iter().map(|c| Self::color_distance(c, target, metric)).collect()
+    }
+}
+
+/// 一次扫描的识别耗时统计
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OcrTiming {
+    /// 识别的字段数
+    pub field_count: usize,
+    /// 所有字段识别的总耗时，包含等待模型和超时处理
+    pub total: Duration,
+    /// 模型统计的单次推理平均耗时
+    pub model_average: Option<Duration>,
+}
+
+impl OcrTiming {
+    /// 每个字段的平均识别耗时
+    pub fn average(&self) -> Option<Duration> {
+        if self.field_count == 0 {
+            None
+        } else {
+            Some(self.total / self.field_count as u32)
+        }
     }
 }
 
@@ -152,6 +349,7 @@ pub struct PerformanceMonitor {
     start_time: std::time::Instant,
     ocr_times: Vec<std::time::Duration>,
     capture_times: Vec<std::time::Duration>,
+    field_times: HashMap<&'static str, Vec<std::time::Duration>>,
 }
 
 #[allow(dead_code)]
@@ -161,6 +359,7 @@ impl PerformanceMonitor {
             start_time: std::time::Instant::now(),
             ocr_times: Vec::new(),
             capture_times: Vec::new(),
+            field_times: HashMap::new(),
         }
     }
 
@@ -172,23 +371,68 @@ impl PerformanceMonitor {
         self.capture_times.push(duration);
     }
 
+    /// 记录单个字段（如圣遗物名称、等级、副属性）的识别耗时
+    pub fn record_field_time(&mut self, field: &'static str, duration: std::time::Duration) {
+        self.field_times.entry(field).or_default().push(duration);
+    }
+
+    /// 所有字段累计的识别次数
+    pub fn field_call_count(&self) -> usize {
+        self.field_times.values().map(Vec::len).sum()
+    }
+
+    /// 获取某个字段的平均识别耗时，未记录过时返回 `None`
+    pub fn get_field_average(&self, field: &str) -> Option<std::time::Duration> {
+        self.field_times.get(field).and_then(|times| average(times))
+    }
+
+    /// 按平均耗时从高到低输出各字段的耗时明细，每个字段一行
+    pub fn get_field_breakdown(&self) -> String {
+        let mut averages: Vec<(&str, std::time::Duration, usize)> = self
+            .field_times
+            .iter()
+            .filter_map(|(&field, times)| average(times).map(|avg| (field, avg, times.len())))
+            .collect();
+        averages.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+
+        averages
+            .iter()
+            .map(|(field, avg, count)| format!("  {field}: 平均 {avg:?} ({count} 次)"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// 汇总所有字段的识别耗时，`model_average` 为模型自身统计的平均推理耗时
+    pub fn ocr_timing(&self, model_average: Option<Duration>) -> OcrTiming {
+        OcrTiming {
+            field_count: self.field_call_count(),
+            total: self.field_times.values().flatten().sum(),
+            model_average,
+        }
+    }
+
     pub fn get_performance_summary(&self) -> String {
         let total_time = self.start_time.elapsed();
-        let avg_ocr_time = if !self.ocr_times.is_empty() {
-            self.ocr_times.iter().sum::<std::time::Duration>() / self.ocr_times.len() as u32
-        } else {
-            std::time::Duration::from_millis(0)
-        };
-        let avg_capture_time = if !self.capture_times.is_empty() {
-            self.capture_times.iter().sum::<std::time::Duration>() / self.capture_times.len() as u32
-        } else {
-            std::time::Duration::from_millis(0)
-        };
+        let avg_ocr_time = average(&self.ocr_times).unwrap_or_default();
+        let avg_capture_time = average(&self.capture_times).unwrap_or_default();
 
-        format!(
+        let mut summary = format!(
             "性能统计 - 总时间: {:?}, 平均OCR时间: {:?}, 平均捕获时间: {:?}, OCR次数: {}, 捕获次数: {}",
             total_time, avg_ocr_time, avg_capture_time, self.ocr_times.len(), self.capture_times.len()
-        )
+        );
+        if !self.field_times.is_empty() {
+            summary.push_str("\n各字段识别耗时:\n");
+            summary.push_str(&self.get_field_breakdown());
+        }
+        summary
+    }
+}
+
+fn average(times: &[std::time::Duration]) -> Option<std::time::Duration> {
+    if times.is_empty() {
+        None
+    } else {
+        Some(times.iter().sum::<std::time::Duration>() / times.len() as u32)
     }
 }
 
@@ -259,3 +503,231 @@ impl AdaptiveDelayManager {
         self.last_adjustment = std::time::Instant::now();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use furina_core::error_recovery::{ErrorCategory, RecoverableError};
+
+    use super::*;
+
+    #[test]
+    fn test_pad_rect() {
+        let bounds = Size::new(100.0, 80.0);
+
+        // 四周对称扩展
+        let padded =
+            OptimizedImageProcessor::pad_rect(&Rect::new(10.0, 20.0, 30.0, 5.0), 3.0, bounds);
+        assert_eq!(padded, Rect::new(7.0, 17.0, 36.0, 11.0));
+
+        // 靠近图像边缘时被截断，不会超出图像范围
+        let padded =
+            OptimizedImageProcessor::pad_rect(&Rect::new(1.0, 0.0, 97.0, 78.0), 3.0, bounds);
+        assert_eq!(padded, Rect::new(0.0, 0.0, 100.0, 80.0));
+
+        let rect = Rect::new(10.0, 20.0, 30.0, 5.0);
+        assert_eq!(OptimizedImageProcessor::pad_rect(&rect, 0.0, bounds), rect);
+    }
+
+    #[test]
+    fn test_weighted_distance_separates_star_colors() {
+        let purple = image::Rgb([161, 86, 224]);
+        let orange = image::Rgb([188, 105, 50]);
+
+        let euclidean = OptimizedImageProcessor::color_distance_fast(&purple, &orange);
+        let weighted = OptimizedImageProcessor::color_distance_weighted(&purple, &orange);
+        assert_eq!(euclidean, 31366);
+        assert_eq!(weighted, 93730);
+        assert!(weighted > euclidean);
+    }
+
+    #[test]
+    fn test_color_distance_metric_dispatch() {
+        let a = image::Rgb([10, 20, 30]);
+        let b = image::Rgb([11, 22, 33]);
+        assert_eq!(OptimizedImageProcessor::color_distance(&a, &b, ColorMetric::Euclidean), 14);
+        assert_eq!(OptimizedImageProcessor::color_distance(&a, &b, ColorMetric::Weighted), 45);
+        assert_eq!(
+            OptimizedImageProcessor::batch_color_distance(&[a, b], &a, ColorMetric::Weighted),
+            vec![0, 45]
+        );
+    }
+
+    /// 推理前先休眠指定时间的模型
+    struct SleepyModel(Duration);
+
+    impl ImageToText<RgbImage> for SleepyModel {
+        fn image_to_text(&self, _image: &RgbImage, _is_preprocessed: bool) -> Result<String> {
+            std::thread::sleep(self.0);
+            Ok("识别结果".to_string())
+        }
+
+        fn get_average_inference_time(&self) -> Option<Duration> {
+            None
+        }
+    }
+
+    /// 第一次推理时 panic，之后正常返回的模型
+    struct PanicOnceModel(std::sync::atomic::AtomicBool);
+
+    impl ImageToText<RgbImage> for PanicOnceModel {
+        fn image_to_text(&self, _image: &RgbImage, _is_preprocessed: bool) -> Result<String> {
+            if !self.0.swap(true, std::sync::atomic::Ordering::SeqCst) {
+                panic!("模型崩溃");
+            }
+            Ok("识别结果".to_string())
+        }
+
+        fn get_average_inference_time(&self) -> Option<Duration> {
+            None
+        }
+    }
+
+    /// 统计推理次数，推理过至少一次后才报告平均耗时的模型
+    struct CountingModel(Arc<std::sync::atomic::AtomicUsize>);
+
+    impl ImageToText<RgbImage> for CountingModel {
+        fn image_to_text(&self, image: &RgbImage, _is_preprocessed: bool) -> Result<String> {
+            // 与真实模型一致：纯色图像不经过推理
+            if image.pixels().all(|p| p == image.get_pixel(0, 0)) {
+                return Ok(String::new());
+            }
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(String::new())
+        }
+
+        fn get_average_inference_time(&self) -> Option<Duration> {
+            match self.0.load(std::sync::atomic::Ordering::SeqCst) {
+                0 => None,
+                _ => Some(Duration::from_millis(5)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_warm_up_populates_inference_time() {
+        let count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let recognizer = OptimizedOCRRecognizer::from_model(Box::new(CountingModel(count.clone())));
+        assert_eq!(recognizer.average_inference_time(), None);
+
+        recognizer.warm_up();
+
+        assert_eq!(count.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(recognizer.average_inference_time(), Some(Duration::from_millis(5)));
+    }
+
+    /// 只有第一次推理失败的模型
+    struct FailOnceModel(std::sync::atomic::AtomicBool);
+
+    impl ImageToText<RgbImage> for FailOnceModel {
+        fn image_to_text(&self, _image: &RgbImage, _is_preprocessed: bool) -> Result<String> {
+            if !self.0.swap(true, std::sync::atomic::Ordering::SeqCst) {
+                return Err(anyhow!("模型初始化失败"));
+            }
+            Ok("识别结果".to_string())
+        }
+
+        fn get_average_inference_time(&self) -> Option<Duration> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_warm_up_failure_is_not_fatal() {
+        let recognizer = OptimizedOCRRecognizer::from_model(Box::new(FailOnceModel(
+            std::sync::atomic::AtomicBool::new(false),
+        )));
+        recognizer.warm_up();
+        assert_eq!(recognizer.recognize(&RgbImage::new(4, 4)).unwrap(), "识别结果");
+    }
+
+    #[test]
+    fn test_recognize_with_timeout() {
+        let image = RgbImage::new(4, 4);
+        let recognizer =
+            OptimizedOCRRecognizer::from_model(Box::new(SleepyModel(Duration::from_millis(200))));
+
+        let err = recognizer.recognize_with_timeout(&image, Duration::from_millis(20)).unwrap_err();
+        let scan_error = err.downcast_ref::<ArtifactScanError>().unwrap();
+        assert_eq!(scan_error.error_category(), ErrorCategory::OCR);
+
+        // 超时的推理仍在占用模型，剩余时间内等不到模型时同样是 OCR 类别的超时错误
+        let err = recognizer.recognize_with_timeout(&image, Duration::from_millis(20)).unwrap_err();
+        let scan_error = err.downcast_ref::<ArtifactScanError>().unwrap();
+        assert_eq!(scan_error.error_category(), ErrorCategory::OCR);
+
+        // 时间足够时等待超时的推理结束后继续识别，而不是立即失败
+        assert_eq!(
+            recognizer.recognize_with_timeout(&image, Duration::from_secs(2)).unwrap(),
+            "识别结果"
+        );
+    }
+
+    #[test]
+    fn test_panicking_inference_does_not_poison_model() {
+        let image = RgbImage::new(4, 4);
+        let recognizer = OptimizedOCRRecognizer::from_model(Box::new(PanicOnceModel(
+            std::sync::atomic::AtomicBool::new(false),
+        )));
+        assert!(recognizer.recognize_with_timeout(&image, Duration::from_secs(1)).is_err());
+        assert_eq!(
+            recognizer.recognize_with_timeout(&image, Duration::from_secs(1)).unwrap(),
+            "识别结果"
+        );
+        assert_eq!(recognizer.recognize(&image).unwrap(), "识别结果");
+    }
+
+    #[test]
+    fn test_field_averages() {
+        let mut monitor = PerformanceMonitor::new();
+        monitor.record_field_time("圣遗物名称", Duration::from_millis(10));
+        monitor.record_field_time("圣遗物名称", Duration::from_millis(20));
+        monitor.record_field_time("等级", Duration::from_millis(4));
+        monitor.record_field_time("副属性1", Duration::from_millis(30));
+        monitor.record_field_time("副属性1", Duration::from_millis(40));
+        monitor.record_field_time("副属性1", Duration::from_millis(50));
+
+        assert_eq!(monitor.get_field_average("圣遗物名称"), Some(Duration::from_millis(15)));
+        assert_eq!(monitor.get_field_average("等级"), Some(Duration::from_millis(4)));
+        assert_eq!(monitor.get_field_average("副属性1"), Some(Duration::from_millis(40)));
+        assert_eq!(monitor.get_field_average("装备状态"), None);
+
+        // 明细按平均耗时从高到低排列
+        let breakdown = monitor.get_field_breakdown();
+        let lines: Vec<&str> = breakdown.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains("副属性1") && lines[0].contains("3 次"));
+        assert!(lines[2].contains("等级"));
+        assert!(monitor.get_performance_summary().contains("各字段识别耗时"));
+    }
+
+    #[test]
+    fn test_ocr_timing_matches_samples() {
+        let mut monitor = PerformanceMonitor::new();
+        assert_eq!(monitor.ocr_timing(None).average(), None);
+
+        let samples = [7, 11, 13, 20];
+        for (field, ms) in ["圣遗物名称", "等级", "副属性1", "副属性1"].into_iter().zip(samples)
+        {
+            monitor.record_field_time(field, Duration::from_millis(ms));
+        }
+
+        let timing = monitor.ocr_timing(Some(Duration::from_millis(9)));
+        assert_eq!(timing.field_count, 4);
+        assert_eq!(timing.total, Duration::from_millis(51));
+        assert_eq!(timing.model_average, Some(Duration::from_millis(9)));
+        // 51ms / 4 = 12.75ms
+        let average = timing.average().unwrap().as_secs_f64() * 1000.0;
+        assert!((average - 12.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_summary_counts_captures() {
+        let mut monitor = PerformanceMonitor::new();
+        monitor.record_capture_time(Duration::from_millis(2));
+        monitor.record_ocr_time(Duration::from_millis(8));
+        let summary = monitor.get_performance_summary();
+        assert!(summary.contains("OCR次数: 1"));
+        assert!(summary.contains("捕获次数: 1"));
+        assert!(!summary.contains("各字段识别耗时"));
+    }
+}
@@ -0,0 +1,209 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::scanner::artifact_scanner::scan_report::{ScanReport, ScanStopReason};
+
+/// 指标名称的前缀
+pub const METRICS_PREFIX: &str = "furina_scan";
+
+fn stop_reason_label(reason: ScanStopReason) -> &'static str {
+    match reason {
+        ScanStopReason::Finished => "finished",
+        ScanStopReason::Interrupted => "interrupted",
+        ScanStopReason::MinStar => "min_star",
+        ScanStopReason::MinLevel => "min_level",
+        ScanStopReason::DuplicateItems => "duplicate_items",
+        ScanStopReason::Timeout => "timeout",
+        ScanStopReason::LowSuccessRate => "low_success_rate",
+        ScanStopReason::ControllerError => "controller_error",
+        ScanStopReason::CaptureLost => "capture_lost",
+    }
+}
+
+/// 按 Prometheus 文本格式输出一次扫描的指标，可由 node_exporter 的 textfile collector 读取
+///
+/// `converted_count` 为成功转换为导出格式的圣遗物数量，与 [`ScanReport::health_score`] 相同。
+/// 所有指标均为 gauge，错误数按类别以 `category` 标签区分，结束原因以 `reason` 标签区分；
+/// 没有识别任何字段时不输出平均识别耗时
+pub fn format_metrics(report: &ScanReport, converted_count: usize) -> String {
+    let mut out = String::new();
+    let mut gauge = |name: &str, help: &str, samples: &[(&str, f64)]| {
+        let _ = writeln!(out, "# HELP {METRICS_PREFIX}_{name} {help}");
+        let _ = writeln!(out, "# TYPE {METRICS_PREFIX}_{name} gauge");
+        for (labels, value) in samples {
+            let _ = writeln!(out, "{METRICS_PREFIX}_{name}{labels} {value}");
+        }
+    };
+
+    let stats = &report.error_stats;
+    gauge("item_count", "Items in the inventory", &[("", report.item_count as f64)]);
+    gauge("items_scanned", "Items scanned", &[("", report.scanned_count as f64)]);
+    gauge("artifacts_recognized", "Artifacts recognized", &[("", report.len() as f64)]);
+    gauge("artifacts_converted", "Artifacts converted for export", &[("", converted_count as f64)]);
+    gauge(
+        "error_items",
+        "Artifacts with recognition errors",
+        &[("", report.error_item_count() as f64)],
+    );
+    gauge(
+        "errors",
+        "Errors by category",
+        &[
+            ("{category=\"ocr\"}", stats.ocr_errors as f64),
+            ("{category=\"image_capture\"}", stats.image_capture_errors as f64),
+            ("{category=\"parsing\"}", stats.parsing_errors as f64),
+            ("{category=\"star_recognition\"}", stats.star_recognition_errors as f64),
+            ("{category=\"level_parsing\"}", stats.level_parsing_errors as f64),
+            ("{category=\"duplicate_items\"}", stats.duplicate_items as f64),
+            ("{category=\"model_load\"}", stats.model_load_errors as f64),
+            ("{category=\"window_info\"}", stats.window_info_errors as f64),
+            ("{category=\"interruption\"}", stats.interruption_errors as f64),
+            ("{category=\"unknown\"}", stats.unknown_errors as f64),
+        ],
+    );
+    gauge("success_rate", "Recognition success rate in percent", &[("", stats.get_success_rate())]);
+    gauge("elapsed_seconds", "Scan duration", &[("", report.elapsed.as_secs_f64())]);
+    if let Some(average) = report.ocr_timing.average() {
+        gauge("ocr_average_seconds", "Average OCR time per field", &[("", average.as_secs_f64())]);
+    }
+    gauge(
+        "health_score",
+        "Scan health score from 0 to 100",
+        &[("", report.health_score(converted_count))],
+    );
+    gauge(
+        "stop_reason",
+        "Reason the scan stopped",
+        &[(&format!("{{reason=\"{}\"}}", stop_reason_label(report.stop_reason)), 1.0)],
+    );
+
+    out
+}
+
+/// 将 [`format_metrics`] 的结果写入 `--metrics-file` 指定的文件
+///
+/// 先写入临时文件再重命名，避免 textfile collector 读到写了一半的文件
+pub fn write_metrics_file(path: &Path, report: &ScanReport, converted_count: usize) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, format_metrics(report, converted_count))?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::scanner::artifact_scanner::error::{ArtifactScanError, ErrorStatistics};
+    use crate::scanner::artifact_scanner::performance_optimizations::OcrTiming;
+    use crate::scanner::artifact_scanner::scan_result::GenshinArtifactScanResult;
+
+    fn sample_report() -> ScanReport {
+        let mut error_stats = ErrorStatistics::new();
+        for _ in 0..8 {
+            error_stats.add_success();
+        }
+        error_stats.add_error(&ArtifactScanError::OcrRecognitionFailed {
+            field: "圣遗物名称".to_string(),
+            raw_text: String::new(),
+            error_msg: "识别失败".to_string(),
+        });
+        error_stats.add_error(&ArtifactScanError::ImageCaptureFailed {
+            region: "圣遗物面板".to_string(),
+            error_msg: "截图失败".to_string(),
+        });
+
+        let mut artifacts: Vec<GenshinArtifactScanResult> = (0..9)
+            .map(|_| {
+                GenshinArtifactScanResult::new(
+                    "明威之镡".to_string(),
+                    "生命值".to_string(),
+                    "4,780".to_string(),
+                    Default::default(),
+                    String::new(),
+                    20,
+                    5,
+                    false,
+                )
+            })
+            .collect();
+        artifacts[0].name = "明威".to_string();
+        artifacts[0].add_error(&ArtifactScanError::OcrRecognitionFailed {
+            field: "圣遗物名称".to_string(),
+            raw_text: String::new(),
+            error_msg: "识别失败".to_string(),
+        });
+
+        ScanReport {
+            artifacts,
+            error_stats,
+            elapsed: Duration::from_millis(12_500),
+            ocr_timing: OcrTiming {
+                field_count: 80,
+                total: Duration::from_secs(2),
+                model_average: None,
+            },
+            interrupted: false,
+            item_count: 10,
+            scanned_count: 10,
+            stop_reason: ScanStopReason::Finished,
+        }
+    }
+
+    #[test]
+    fn test_metric_lines() {
+        let report = sample_report();
+        let metrics = format_metrics(&report, 8);
+        let lines: Vec<&str> = metrics.lines().collect();
+
+        for expected in [
+            "furina_scan_item_count 10",
+            "furina_scan_items_scanned 10",
+            "furina_scan_artifacts_recognized 9",
+            "furina_scan_artifacts_converted 8",
+            "furina_scan_error_items 1",
+            "furina_scan_errors{category=\"ocr\"} 1",
+            "furina_scan_errors{category=\"image_capture\"} 1",
+            "furina_scan_errors{category=\"unknown\"} 0",
+            "furina_scan_success_rate 80",
+            "furina_scan_elapsed_seconds 12.5",
+            "furina_scan_ocr_average_seconds 0.025",
+            "furina_scan_stop_reason{reason=\"finished\"} 1",
+            "# TYPE furina_scan_health_score gauge",
+        ] {
+            assert!(lines.contains(&expected), "缺少指标行: {expected}\n{metrics}");
+        }
+        assert!(lines
+            .contains(&format!("furina_scan_health_score {}", report.health_score(8)).as_str()));
+
+        // 每个样本行都是 `名称{标签} 数值`，数值可以解析
+        for line in lines.iter().filter(|line| !line.starts_with('#')) {
+            let (name, value) = line.rsplit_once(' ').unwrap();
+            assert!(name.starts_with(METRICS_PREFIX));
+            assert!(value.parse::<f64>().is_ok(), "{line}");
+        }
+    }
+
+    #[test]
+    fn test_write_metrics_file() {
+        let path = std::env::temp_dir()
+            .join(format!("furina-metrics-{}", std::process::id()))
+            .join("furina.prom");
+        let mut report = sample_report();
+        report.ocr_timing = OcrTiming::default();
+        write_metrics_file(&path, &report, 8).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        assert_eq!(content, format_metrics(&report, 8));
+        // 没有识别任何字段时不输出平均识别耗时
+        assert!(!content.contains("ocr_average_seconds"));
+    }
+}
@@ -0,0 +1,333 @@
+use std::time::Duration;
+
+use crate::scanner::artifact_scanner::error::ErrorStatistics;
+use crate::scanner::artifact_scanner::performance_optimizations::OcrTiming;
+use crate::scanner::artifact_scanner::scan_result::GenshinArtifactScanResult;
+
+/// 低于该置信度的圣遗物视为识别不可靠
+pub const LOW_CONFIDENCE_THRESHOLD: f64 = 0.8;
+
+/// 扫描健康度（[`ScanReport::health_score`]）低于该值时提示结果可能不可靠
+pub const HEALTHY_SCAN_SCORE: f64 = 80.0;
+
+/// 已扫描的物品数量比识别到的背包数量少超过该比例时，视为扫描不完整并给出警告
+pub const SHORTFALL_WARNING_RATIO: f64 = 0.1;
+
+/// 扫描结束的原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanStopReason {
+    /// 背包中的物品已全部扫描
+    Finished,
+    /// 用户使用鼠标右键中断
+    Interrupted,
+    /// 找到低于 `--min-star` 的物品
+    MinStar,
+    /// 找到低于 `--min-level` 的物品
+    MinLevel,
+    /// 连续识别到多个重复物品
+    DuplicateItems,
+    /// 超过 `--max-scan-duration`
+    Timeout,
+    /// 识别成功率低于 `--abort-below-success-rate`
+    LowSuccessRate,
+    /// 翻页或截图过程中发生错误
+    ControllerError,
+    /// 连续多个物品截图失败，达到 `--capture-failure-limit`
+    CaptureLost,
+}
+
+impl ScanStopReason {
+    /// 扫描数量明显少于背包数量时，该结束原因对应的可能解释
+    pub fn probable_cause(&self) -> &'static str {
+        match self {
+            ScanStopReason::Finished => {
+                "翻页时可能跳过了部分物品，或背包数量识别有误，请确认游戏窗口未被遮挡且扫描中未操作鼠标"
+            },
+            ScanStopReason::Interrupted => "扫描被用户中断",
+            ScanStopReason::MinStar => {
+                "遇到低于最低星级的物品后提前结束，若已设置 --min-star 则属正常"
+            },
+            ScanStopReason::MinLevel => {
+                "遇到低于最低等级的物品后提前结束，若已设置 --min-level 则属正常"
+            },
+            ScanStopReason::DuplicateItems => {
+                "连续识别到重复物品后停止，可能为翻页错误或未从背包顶部开始扫描"
+            },
+            ScanStopReason::Timeout => "超过最长扫描时间后停止，可调大 --max-scan-duration",
+            ScanStopReason::LowSuccessRate => {
+                "识别成功率过低后停止，请检查游戏语言、分辨率和亮度设置"
+            },
+            ScanStopReason::ControllerError => "翻页或截图发生错误，请查看之前的错误日志",
+            ScanStopReason::CaptureLost => {
+                "连续多个物品截图失败后停止，游戏可能已关闭或最小化，请保持游戏窗口在前台"
+            },
+        }
+    }
+}
+
+/// 一次扫描的结果及统计
+///
+/// 物品相关的统计（存在错误、置信度较低的数量）按 `artifacts` 即时计算，
+/// 调用方在扫描后追加的错误（如主属性数值校验）也会计入
+#[derive(Debug, Clone)]
+pub struct ScanReport {
+    /// 已识别的圣遗物，已按等级范围过滤
+    pub artifacts: Vec<GenshinArtifactScanResult>,
+    /// 识别线程记录的错误统计，包含截图失败等未产生结果的错误
+    pub error_stats: ErrorStatistics,
+    /// 扫描耗时
+    pub elapsed: Duration,
+    /// 识别耗时统计
+    pub ocr_timing: OcrTiming,
+    /// 扫描是否被用户（鼠标右键）中断，中断时 `artifacts` 仅包含中断前识别的部分
+    pub interrupted: bool,
+    /// 识别到的背包物品数量（已按 `--number`、`--max-items` 限制）
+    pub item_count: usize,
+    /// 实际扫描的物品数量，包含被过滤和识别失败的物品
+    pub scanned_count: usize,
+    /// 扫描结束的原因
+    pub stop_reason: ScanStopReason,
+}
+
+impl ScanReport {
+    pub fn len(&self) -> usize {
+        self.artifacts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.artifacts.is_empty()
+    }
+
+    /// 存在识别错误的圣遗物数量
+    pub fn error_item_count(&self) -> usize {
+        self.artifacts.iter().filter(|r| r.has_errors()).count()
+    }
+
+    /// 置信度低于 [`LOW_CONFIDENCE_THRESHOLD`] 的圣遗物数量
+    pub fn low_confidence_count(&self) -> usize {
+        self.artifacts.iter().filter(|r| !r.is_reliable(LOW_CONFIDENCE_THRESHOLD)).count()
+    }
+
+    /// 识别线程提前停止的原因（如 `--abort-below-success-rate`），正常结束或用户中断时为 `None`
+    pub fn abort_reason(&self) -> Option<&str> {
+        self.error_stats.abort_reason.as_deref()
+    }
+
+    /// 背包中未扫描到的物品数量
+    pub fn shortfall(&self) -> usize {
+        self.item_count.saturating_sub(self.scanned_count)
+    }
+
+    /// 未扫描的物品超过背包数量的 [`SHORTFALL_WARNING_RATIO`] 时，返回包含可能原因的警告
+    pub fn shortfall_warning(&self) -> Option<String> {
+        let shortfall = self.shortfall();
+        if shortfall == 0 || shortfall as f64 <= self.item_count as f64 * SHORTFALL_WARNING_RATIO {
+            return None;
+        }
+
+        Some(format!(
+            "仅扫描了 {}/{} 个物品，有 {shortfall} 个物品未扫描。可能原因: {}",
+            self.scanned_count,
+            self.item_count,
+            self.stop_reason.probable_cause()
+        ))
+    }
+
+    /// 圣遗物的平均置信度，没有识别结果时为 0
+    pub fn average_confidence(&self) -> f64 {
+        if self.artifacts.is_empty() {
+            return 0.0;
+        }
+        self.artifacts.iter().map(|r| r.confidence_score).sum::<f64>() / self.artifacts.len() as f64
+    }
+
+    /// 扫描健康度，0~100，越高表示扫描结果越可靠
+    ///
+    /// `converted_count` 为成功转换为导出格式的圣遗物数量，转换在扫描之后由调用方进行。
+    /// 各项按比例（0~1）加权求和：
+    /// - 识别成功率（`error_stats`）：40%
+    /// - 平均置信度：20%
+    /// - 转换成功率（`converted_count / len`）：20%
+    /// - 完整度（已扫描 / 背包数量）：20%，因 `--min-star`、`--min-level` 提前结束时按完整计算
+    ///
+    /// 没有任何识别结果时，平均置信度和转换成功率均按 0 计算
+    pub fn health_score(&self, converted_count: usize) -> f64 {
+        let success_rate = self.error_stats.get_success_rate() / 100.0;
+        let conversion_rate = if self.artifacts.is_empty() {
+            0.0
+        } else {
+            (converted_count as f64 / self.artifacts.len() as f64).min(1.0)
+        };
+        let completeness = match self.stop_reason {
+            ScanStopReason::MinStar | ScanStopReason::MinLevel => 1.0,
+            _ if self.item_count == 0 => 1.0,
+            _ => 1.0 - self.shortfall() as f64 / self.item_count as f64,
+        };
+
+        let score = 0.4 * success_rate
+            + 0.2 * self.average_confidence()
+            + 0.2 * conversion_rate
+            + 0.2 * completeness;
+        (score * 100.0).clamp(0.0, 100.0)
+    }
+
+    /// 4星及以上的圣遗物数量
+    pub fn high_quality_count(&self) -> usize {
+        self.artifacts.iter().filter(|r| r.star >= 4).count()
+    }
+
+    /// 只取识别结果，兼容之前直接使用 `Vec` 的调用方
+    pub fn into_artifacts(self) -> Vec<GenshinArtifactScanResult> {
+        self.artifacts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::artifact_scanner::error::ArtifactScanError;
+
+    fn sample(name: &str, star: i32) -> GenshinArtifactScanResult {
+        GenshinArtifactScanResult::new(
+            name.to_string(),
+            "生命值".to_string(),
+            "4,780".to_string(),
+            Default::default(),
+            String::new(),
+            20,
+            star,
+            false,
+        )
+    }
+
+    #[test]
+    fn test_counts_match_artifacts() {
+        let mut level_error = sample("明威之镡", 5);
+        level_error.add_error(&ArtifactScanError::LevelParsingFailed {
+            raw_text: "+2O".to_string(),
+            error_msg: "无法解析".to_string(),
+        });
+        let mut parsing_errors = sample("祭雷之人", 4);
+        for _ in 0..2 {
+            parsing_errors.add_error(&ArtifactScanError::ArtifactParsingFailed {
+                field: "主属性数值".to_string(),
+                value: "4,78".to_string(),
+                expected_format: "约 4780".to_string(),
+            });
+        }
+        let artifacts = vec![sample("异国之盏", 3), level_error, parsing_errors];
+
+        let report = ScanReport {
+            artifacts: artifacts.clone(),
+            error_stats: ErrorStatistics::new(),
+            elapsed: Duration::from_secs(3),
+            ocr_timing: OcrTiming::default(),
+            interrupted: false,
+            item_count: 3,
+            scanned_count: 3,
+            stop_reason: ScanStopReason::Finished,
+        };
+
+        assert_eq!(report.len(), artifacts.len());
+        assert_eq!(report.error_item_count(), artifacts.iter().filter(|r| r.has_errors()).count());
+        assert_eq!(report.error_item_count(), 2);
+        // 0.9 仍可靠，0.7 * 0.7 = 0.49 不可靠
+        assert_eq!(report.low_confidence_count(), 1);
+        assert_eq!(report.high_quality_count(), 2);
+        assert_eq!(report.shortfall_warning(), None);
+        assert_eq!(report.into_artifacts(), artifacts);
+    }
+
+    fn fixture_partial_report(scanned_count: usize, stop_reason: ScanStopReason) -> ScanReport {
+        ScanReport {
+            artifacts: Vec::new(),
+            error_stats: ErrorStatistics::new(),
+            elapsed: Duration::from_secs(60),
+            ocr_timing: OcrTiming::default(),
+            interrupted: false,
+            item_count: 1500,
+            scanned_count,
+            stop_reason,
+        }
+    }
+
+    #[test]
+    fn test_large_shortfall_warns_with_probable_cause() {
+        let report = fixture_partial_report(320, ScanStopReason::DuplicateItems);
+
+        assert_eq!(report.shortfall(), 1180);
+        let warning = report.shortfall_warning().unwrap();
+        assert!(warning.contains("320/1500"), "{warning}");
+        assert!(warning.contains("1180"), "{warning}");
+        assert!(warning.contains(ScanStopReason::DuplicateItems.probable_cause()), "{warning}");
+    }
+
+    #[test]
+    fn test_clean_scan_scores_high() {
+        let mut report = fixture_partial_report(1500, ScanStopReason::Finished);
+        report.artifacts = (0..1500).map(|_| sample("异国之盏", 5)).collect();
+        for _ in 0..1500 {
+            report.error_stats.add_success();
+        }
+
+        assert_eq!(report.health_score(1500), 100.0);
+        // 少量物品无法转换时仍接近满分
+        assert!(report.health_score(1480) > 99.0);
+    }
+
+    #[test]
+    fn test_error_heavy_scan_scores_low() {
+        let mut report = fixture_partial_report(300, ScanStopReason::DuplicateItems);
+        let mut failed = sample("异国之盏", 5);
+        failed.add_error(&ArtifactScanError::OcrRecognitionFailed {
+            field: "主属性".to_string(),
+            raw_text: String::new(),
+            error_msg: "超时".to_string(),
+        });
+        failed.add_error(&ArtifactScanError::LevelParsingFailed {
+            raw_text: "+2O".to_string(),
+            error_msg: "无法解析".to_string(),
+        });
+        report.artifacts = vec![failed; 300];
+        for _ in 0..100 {
+            report.error_stats.add_success();
+        }
+        for _ in 0..400 {
+            report
+                .error_stats
+                .add_error(&ArtifactScanError::Unknown { error_msg: "失败".to_string() });
+        }
+
+        let score = report.health_score(100);
+        assert!(score < 40.0, "{score}");
+    }
+
+    #[test]
+    fn test_min_level_stop_is_not_penalized() {
+        let mut report = fixture_partial_report(300, ScanStopReason::MinLevel);
+        report.artifacts = vec![sample("异国之盏", 5); 300];
+        for _ in 0..300 {
+            report.error_stats.add_success();
+        }
+
+        assert_eq!(report.health_score(300), 100.0);
+        report.stop_reason = ScanStopReason::Interrupted;
+        assert!((report.health_score(300) - 84.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_small_shortfall_does_not_warn() {
+        // 未扫描的物品不超过 10% 时视为正常，例如扫描过程中背包数量发生变化
+        assert_eq!(
+            fixture_partial_report(1350, ScanStopReason::Finished).shortfall_warning(),
+            None
+        );
+        assert_eq!(
+            fixture_partial_report(1500, ScanStopReason::MinLevel).shortfall_warning(),
+            None
+        );
+        assert!(fixture_partial_report(1349, ScanStopReason::Finished)
+            .shortfall_warning()
+            .is_some());
+    }
+}
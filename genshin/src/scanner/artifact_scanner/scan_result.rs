@@ -1,8 +1,12 @@
+use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
+use serde::{Deserialize, Serialize};
+
 use super::error::ArtifactScanError;
+use crate::scanner_controller::repository_layout::ItemPosition;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenshinArtifactScanResult {
     pub name: String,
     pub main_stat_name: String,
@@ -16,9 +20,13 @@ pub struct GenshinArtifactScanResult {
     pub scan_errors: Vec<String>,
     /// 识别置信度评分 (0.0-1.0)
     pub confidence_score: f64,
+    /// 物品在背包中的位置，扫描游戏窗口时记录，用于 `--retry-failed` 重新定位
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<ItemPosition>,
 }
 
 // 手动实现Hash，只对核心字段进行哈希，忽略错误信息和置信度
+// 完整哈希包含锁定状态和装备角色，用于识别线程中导出结果的去重；翻页错误检测使用 `identity_hash`
 impl Hash for GenshinArtifactScanResult {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.name.hash(state);
@@ -29,7 +37,7 @@ impl Hash for GenshinArtifactScanResult {
         self.level.hash(state);
         self.star.hash(state);
         self.lock.hash(state);
-        // 不对 scan_errors 和 confidence_score 进行哈希
+        // 不对 scan_errors、confidence_score 和 position 进行哈希
     }
 }
 
@@ -44,7 +52,7 @@ impl PartialEq for GenshinArtifactScanResult {
             && self.level == other.level
             && self.star == other.star
             && self.lock == other.lock
-        // 不比较 scan_errors 和 confidence_score
+        // 不比较 scan_errors、confidence_score 和 position
     }
 }
 
@@ -74,9 +82,27 @@ impl GenshinArtifactScanResult {
             lock,
             scan_errors: Vec::new(),
             confidence_score: 1.0,
+            position: None,
         }
     }
 
+    /// 圣遗物本身的标识哈希，仅包含名称（决定套装和部位）、星级、等级和主副属性，
+    /// 不包含锁定状态和装备角色
+    ///
+    /// 识别线程用它检测翻页错误：同一件圣遗物在扫描中途被加解锁或换装后再次出现时，
+    /// 完整哈希不同而标识哈希相同。导出结果的去重仍使用完整的 `Hash` / `PartialEq`，
+    /// 以免属性相同但装备在不同角色身上的两件圣遗物被合并
+    pub fn identity_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.name.hash(&mut hasher);
+        self.main_stat_name.hash(&mut hasher);
+        self.main_stat_value.hash(&mut hasher);
+        self.sub_stat.hash(&mut hasher);
+        self.level.hash(&mut hasher);
+        self.star.hash(&mut hasher);
+        hasher.finish()
+    }
+
     /// 添加扫描错误
     pub fn add_error(&mut self, error: &ArtifactScanError) {
         self.scan_errors.push(error.to_string());
@@ -106,3 +132,85 @@ impl GenshinArtifactScanResult {
         self.confidence_score >= threshold
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn sample(equip: &str, lock: bool) -> GenshinArtifactScanResult {
+        GenshinArtifactScanResult::new(
+            "明威之镡".to_string(),
+            "生命值".to_string(),
+            "4,780".to_string(),
+            [
+                "暴击率+3.9%".to_string(),
+                "暴击伤害+21.8%".to_string(),
+                "攻击力+5.8%".to_string(),
+                "元素充能效率+11.0%".to_string(),
+            ],
+            equip.to_string(),
+            20,
+            5,
+            lock,
+        )
+    }
+
+    /// 扫描中途切换了锁定状态：导出时视为不同结果，但标识相同，可被识别为重复出现
+    #[test]
+    fn test_identity_hash_ignores_lock() {
+        let before = sample("胡桃已装备", false);
+        let after = sample("胡桃已装备", true);
+
+        assert_ne!(before, after);
+        assert_eq!(before.identity_hash(), after.identity_hash());
+    }
+
+    /// 属性相同但装备在不同角色身上的两件圣遗物：导出去重不会将其合并
+    #[test]
+    fn test_full_equality_keeps_distinct_equip() {
+        let first = sample("胡桃已装备", false);
+        let second = sample("纳西妲已装备", false);
+
+        let export_set: HashSet<_> = [first.clone(), second.clone()].into_iter().collect();
+        assert_eq!(export_set.len(), 2);
+        assert_eq!(first.identity_hash(), second.identity_hash());
+    }
+
+    #[test]
+    fn test_identity_hash_covers_stats() {
+        let base = sample("", false);
+        let mut upgraded = base.clone();
+        upgraded.level = 16;
+        assert_ne!(base.identity_hash(), upgraded.identity_hash());
+
+        let mut other_sub = base.clone();
+        other_sub.sub_stat[0] = "暴击率+7.0%".to_string();
+        assert_ne!(base.identity_hash(), other_sub.identity_hash());
+
+        // 错误信息和置信度不影响任何一种哈希
+        let mut with_error = base.clone();
+        with_error.add_error(&ArtifactScanError::Unknown { error_msg: "测试".to_string() });
+        assert_eq!(base, with_error);
+        assert_eq!(base.identity_hash(), with_error.identity_hash());
+    }
+
+    /// `--export-raw` 导出的 JSON 能还原所有字段，包括相等比较忽略的错误信息和置信度
+    #[test]
+    fn test_serde_round_trip() {
+        let mut result = sample("胡桃已装备", true);
+        result.add_error(&ArtifactScanError::LevelParsingFailed {
+            raw_text: "+2O".to_string(),
+            error_msg: "无法解析".to_string(),
+        });
+
+        let json = serde_json::to_string(&result).unwrap();
+        let restored: GenshinArtifactScanResult = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, result);
+        assert_eq!(restored.scan_errors, result.scan_errors);
+        assert_eq!(restored.confidence_score, result.confidence_score);
+        assert!(restored.confidence_score < 1.0);
+    }
+}
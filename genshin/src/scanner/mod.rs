@@ -1,6 +1,11 @@
 pub use artifact_scanner::{
-    get_error_suggestion, ArtifactScanError, ErrorStatistics, GenshinArtifactScanResult,
-    GenshinArtifactScanner, GenshinArtifactScannerConfig,
+    capture_with_recovery, format_metrics, get_error_suggestion, write_metrics_file,
+    ArtifactScanError, ArtifactScannerWindowInfo, ArtifactScannerWorker, CaptureFailureTracker,
+    CheckpointRecorder, ColorMetric, CropDumper, ErrorStatistics, GenshinArtifactScanResult,
+    GenshinArtifactScanner, GenshinArtifactScannerConfig, OcrTiming, OptimizedImageProcessor,
+    PerformanceMonitor, ScanCheckpoint, ScanReport, ScanStopReason, SendItem, CHECKPOINT_VERSION,
+    DEFAULT_CAPTURE_FAILURE_LIMIT, DEFAULT_CROP_DUMP_LIMIT, DEFAULT_MAX_ITEMS,
+    DEFAULT_QUEUE_CAPACITY, HEALTHY_SCAN_SCORE, METRICS_PREFIX, SUCCESS_RATE_MIN_SAMPLE,
 };
 
 mod artifact_scanner;
@@ -1,4 +1,14 @@
+use anyhow::{bail, Result};
 use clap::arg;
+use log::warn;
+
+use crate::scanner_controller::repository_layout::{parse_scroll_strategy, ScrollStrategy};
+
+/// 翻页滚轮停顿时间超过该值（ms）时发出警告
+const SCROLL_DELAY_WARN_MS: i32 = 1000;
+
+/// 切换物品等待时间超过该值（ms）时发出警告
+const SWITCH_WAIT_WARN_MS: i32 = 5000;
 
 #[derive(Clone, clap::Args)]
 pub struct GenshinRepositoryScannerLogicConfig {
@@ -15,6 +25,17 @@ pub struct GenshinRepositoryScannerLogicConfig {
     )]
     pub scroll_delay: i32,
 
+    /// How to scroll between pages: adaptive, detect, or fixed:<ticks per row>
+    #[arg(
+        id = "scroll-strategy",
+        long = "scroll-strategy",
+        help = "翻页滚动策略（adaptive: 测得平均滚动量后估算，detect: 始终逐行检测，fixed:<格数>: 每行固定滚动的滚轮格数）",
+        value_name = "STRATEGY",
+        value_parser = parse_scroll_strategy,
+        default_value_t = ScrollStrategy::Adaptive
+    )]
+    pub scroll_strategy: ScrollStrategy,
+
     /// The maximum time to wait for switching to the next item
     #[arg(
         id = "max-wait-switch-item",
@@ -33,6 +54,51 @@ pub struct GenshinRepositoryScannerLogicConfig {
     )]
     pub cloud_wait_switch_item: i32,
 
+    /// Verify item switches in cloud game by detecting panel changes instead of a fixed wait
+    #[arg(
+        id = "cloud-verify",
+        long = "cloud-verify",
+        help = "云游戏切换物品时与本地一样检测面板变化，而不是固定等待 --cloud-wait-switch-item 毫秒（串流延迟不稳定时可以考虑开启）"
+    )]
+    pub cloud_verify: bool,
+
+    /// Number of unchanged frames after a change before the item counts as switched
+    #[arg(
+        id = "switch-stable-frames",
+        long = "switch-stable-frames",
+        help = "切换物品时画面变化后需要连续保持不变的帧数（云串流画面噪点多导致误判时可以考虑加大该选项）",
+        value_parser = clap::value_parser!(u32).range(1..),
+        default_value_t = 1
+    )]
+    pub switch_stable_frames: u32,
+
+    /// Minimal pool difference that counts as a panel change
+    #[arg(
+        id = "switch-diff-epsilon",
+        long = "switch-diff-epsilon",
+        help = "判定物品面板发生变化的最小像素池差值（画面噪点导致误判时可以考虑加大该选项）",
+        value_name = "EPSILON",
+        default_value_t = 0.000001
+    )]
+    pub switch_diff_epsilon: f64,
+
+    /// The time to wait between moving the cursor and clicking an item
+    #[arg(
+        id = "click-delay-ms",
+        long = "click-delay-ms",
+        help = "移动鼠标到物品后、点击前的等待时间（ms）（远程桌面或触控板点击不生效时可以考虑加大该选项）",
+        default_value_t = 0
+    )]
+    pub click_delay_ms: u32,
+
+    /// Click each item twice
+    #[arg(
+        id = "double-click",
+        long = "double-click",
+        help = "每个物品点击两次，适用于偶尔丢失点击的输入设备"
+    )]
+    pub double_click: bool,
+
     /// Enable fast mode with reduced delays
     #[arg(id = "fast-mode", long = "fast-mode", help = "启用快速模式，减少等待时间")]
     pub fast_mode: bool,
@@ -51,8 +117,14 @@ impl Default for GenshinRepositoryScannerLogicConfig {
         GenshinRepositoryScannerLogicConfig {
             max_row: -1,
             scroll_delay: 50,
+            scroll_strategy: ScrollStrategy::Adaptive,
             max_wait_switch_item: 600,
             cloud_wait_switch_item: 200,
+            cloud_verify: false,
+            switch_stable_frames: 1,
+            switch_diff_epsilon: 0.000001,
+            click_delay_ms: 0,
+            double_click: false,
             fast_mode: false,
             adaptive_timing: true,
             performance_monitor: false,
@@ -87,4 +159,105 @@ impl GenshinRepositoryScannerLogicConfig {
             self.cloud_wait_switch_item
         }
     }
+
+    /// 云游戏检测切换时的最长等待时间，串流延迟波动大，为本地的 3 倍
+    pub fn get_cloud_verify_wait(&self) -> i32 {
+        self.get_optimized_switch_wait() * 3
+    }
+
+    /// 云游戏检测切换时需要连续保持不变的帧数，串流画面有压缩噪点，至少为 2 帧
+    pub fn get_cloud_verify_stable_frames(&self) -> u32 {
+        self.switch_stable_frames.max(2)
+    }
+
+    /// 检查各等待时间是否合理，创建扫描控制器时调用
+    ///
+    /// 等待时间（含快速模式缩短后的值）必须大于 0，否则翻页和切换物品时不会等待画面更新，
+    /// 扫描会变成忙等并读到错误的画面；过长的等待只记录警告
+    pub fn validate(&self) -> Result<()> {
+        let waits = [
+            (
+                "--scroll-delay",
+                self.scroll_delay,
+                self.get_optimized_scroll_delay(),
+                SCROLL_DELAY_WARN_MS,
+            ),
+            (
+                "--max-wait-switch-item",
+                self.max_wait_switch_item,
+                self.get_optimized_switch_wait(),
+                SWITCH_WAIT_WARN_MS,
+            ),
+            (
+                "--cloud-wait-switch-item",
+                self.cloud_wait_switch_item,
+                self.get_optimized_cloud_wait(),
+                SWITCH_WAIT_WARN_MS,
+            ),
+        ];
+
+        for (name, value, effective, warn_above) in waits {
+            if value <= 0 {
+                bail!("{name} 必须大于 0（当前为 {value}ms）");
+            }
+            if effective <= 0 {
+                bail!("{name} 为 {value}ms 时，快速模式缩短后的等待时间为 0，请调大该值或关闭 --fast-mode");
+            }
+            if value > warn_above {
+                warn!("{name} 为 {value}ms，超过 {warn_above}ms，扫描会非常慢");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_default_config() {
+        assert!(GenshinRepositoryScannerLogicConfig::default().validate().is_ok());
+
+        // 过长的等待只警告
+        let slow = GenshinRepositoryScannerLogicConfig {
+            scroll_delay: 2000,
+            max_wait_switch_item: 10_000,
+            ..Default::default()
+        };
+        assert!(slow.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_nonpositive_waits() {
+        let zero_max_wait =
+            GenshinRepositoryScannerLogicConfig { max_wait_switch_item: 0, ..Default::default() };
+        let err = zero_max_wait.validate().unwrap_err();
+        assert!(err.to_string().contains("--max-wait-switch-item"), "{err}");
+
+        let negative_scroll_delay =
+            GenshinRepositoryScannerLogicConfig { scroll_delay: -50, ..Default::default() };
+        let err = negative_scroll_delay.validate().unwrap_err();
+        assert!(err.to_string().contains("--scroll-delay"), "{err}");
+
+        let zero_cloud_wait =
+            GenshinRepositoryScannerLogicConfig { cloud_wait_switch_item: 0, ..Default::default() };
+        assert!(zero_cloud_wait.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_fast_mode_rounds_to_zero() {
+        // 1ms * 0.7 取整后为 0
+        let config = GenshinRepositoryScannerLogicConfig {
+            scroll_delay: 1,
+            fast_mode: true,
+            ..Default::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("--fast-mode"), "{err}");
+
+        let config = GenshinRepositoryScannerLogicConfig { fast_mode: false, ..config };
+        assert!(config.validate().is_ok());
+    }
 }
@@ -7,12 +7,12 @@ use anyhow::{anyhow, Result};
 use clap::{ArgMatches, FromArgMatches};
 use furina_core::capture::{Capturer, GenericCapturer};
 use furina_core::game_info::GameInfo;
-use furina_core::positioning::Pos;
-use furina_core::system_control::SystemControl;
+use furina_core::system_control::{SystemControl, SystemControlApi};
 use furina_core::utils;
 use furina_core::window_info::{FromWindowInfoRepository, WindowInfoRepository};
 use image::RgbImage;
-use log::{error, info};
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
 
 use crate::scanner_controller::repository_layout::{
     GenshinRepositoryScanControllerWindowInfo, GenshinRepositoryScannerLogicConfig, ScrollResult,
@@ -35,6 +35,8 @@ struct ScanState {
     total_row: usize,
     /// 最后一行的列数
     last_row_col: usize,
+    /// 已翻过的行数
+    scrolled_rows: usize,
 }
 
 impl ScanState {
@@ -43,7 +45,15 @@ impl ScanState {
         let total_row = (item_count + col - 1) / col;
         let last_row_col = if item_count % col == 0 { col } else { item_count % col };
 
-        Self { scanned_row: 0, scanned_count: 0, start_row: 0, item_count, total_row, last_row_col }
+        Self {
+            scanned_row: 0,
+            scanned_count: 0,
+            start_row: 0,
+            item_count,
+            total_row,
+            last_row_col,
+            scrolled_rows: 0,
+        }
     }
 
     /// 检查是否完成扫描
@@ -66,6 +76,27 @@ impl ScanState {
     }
 }
 
+/// 控制器的滚动状态，保存在检查点中以便恢复扫描时沿用已测得的滚动量
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct ScrollCheckpoint {
+    /// 已测量滚动量的行数
+    pub scrolled_rows: u32,
+    /// 平均每行需要的滚轮格数
+    pub avg_scroll_one_row: f64,
+}
+
+/// 物品在背包中的位置，用于 `--retry-failed` 重新定位到第一次扫描时识别失败的物品
+///
+/// 从背包顶部翻过 `scrolled_rows` 行后，物品位于当前页面的第 `row` 行第 `col` 列（均从 0 开始）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemPosition {
+    /// 物品在背包中的序号（从 0 开始）
+    pub index: usize,
+    pub scrolled_rows: usize,
+    pub row: usize,
+    pub col: usize,
+}
+
 pub struct GenshinRepositoryScanController {
     // to detect whether an item changes
     pool: f64,
@@ -79,6 +110,10 @@ pub struct GenshinRepositoryScanController {
     avg_switch_time: f64,
     scanned_count: usize,
 
+    // 背包当前相对顶部翻过的行数，以及正在扫描的物品的位置
+    scroll_offset_rows: usize,
+    current_position: Option<ItemPosition>,
+
     game_info: GameInfo,
 
     // row and column in one page
@@ -87,7 +122,7 @@ pub struct GenshinRepositoryScanController {
 
     config: GenshinRepositoryScannerLogicConfig,
     window_info: GenshinRepositoryScanControllerWindowInfo,
-    system_control: SystemControl,
+    system_control: Box<dyn SystemControlApi>,
     capturer: Rc<dyn Capturer<RgbImage>>,
 
     // artifact panel have different layout
@@ -114,6 +149,39 @@ fn calc_pool(row: &[u8]) -> f32 {
     pool
 }
 
+/// 根据像素池值判断物品面板是否已经切换完成
+///
+/// 池值与上一帧相差超过 `epsilon` 视为画面仍在变化；出现变化后，
+/// 连续 `stable_frames` 帧不再变化即视为切换完成
+struct SwitchDetector {
+    pool: f64,
+    epsilon: f64,
+    stable_frames: u32,
+    changed: bool,
+    stable_count: u32,
+}
+
+impl SwitchDetector {
+    fn new(pool: f64, epsilon: f64, stable_frames: u32) -> Self {
+        SwitchDetector { pool, epsilon, stable_frames, changed: false, stable_count: 0 }
+    }
+
+    /// 输入一帧的池值，返回切换是否已经完成
+    fn observe(&mut self, pool: f64) -> bool {
+        if (pool - self.pool).abs() > self.epsilon {
+            self.pool = pool;
+            self.changed = true;
+            self.stable_count = 0;
+            return false;
+        }
+        if !self.changed {
+            return false;
+        }
+        self.stable_count += 1;
+        self.stable_count >= self.stable_frames
+    }
+}
+
 /// 获取屏幕捕获器实例
 ///
 /// 创建一个通用的屏幕捕获器，用于截图和颜色采样
@@ -141,6 +209,26 @@ fn color_distance(c1: &image::Rgb<u8>, c2: &image::Rgb<u8>) -> usize {
     (x * x + y * y + z * z) as usize
 }
 
+/// 移动到物品后的点击序列
+///
+/// 每次点击前等待 `click_delay_ms`，开启 `double_click` 时点击两次；
+/// 远程桌面、触控板等输入方式在移动后立即点击容易丢失点击
+fn click_sequence(
+    click_delay_ms: u32,
+    double_click: bool,
+    mut click: impl FnMut() -> Result<()>,
+    mut sleep: impl FnMut(u32),
+) -> Result<()> {
+    let clicks = if double_click { 2 } else { 1 };
+    for _ in 0..clicks {
+        if click_delay_ms > 0 {
+            sleep(click_delay_ms);
+        }
+        click()?;
+    }
+    Ok(())
+}
+
 // constructor
 impl GenshinRepositoryScanController {
     pub fn new(
@@ -149,17 +237,39 @@ impl GenshinRepositoryScanController {
         game_info: GameInfo,
         is_artifact: bool,
     ) -> Result<Self> {
+        config.validate()?;
+
         let window_info = GenshinRepositoryScanControllerWindowInfo::from_window_info_repository(
             game_info.window.to_rect_usize().size(),
             game_info.ui,
             game_info.platform,
             window_info_repo,
         )?;
+
+        Ok(Self::with_backends(
+            window_info,
+            config,
+            game_info,
+            is_artifact,
+            Box::new(SystemControl::new()),
+            get_capturer()?,
+        ))
+    }
+
+    /// 使用指定的鼠标控制与截图实现创建控制器，用于测试或无界面运行
+    pub fn with_backends(
+        window_info: GenshinRepositoryScanControllerWindowInfo,
+        config: GenshinRepositoryScannerLogicConfig,
+        game_info: GameInfo,
+        is_artifact: bool,
+        system_control: Box<dyn SystemControlApi>,
+        capturer: Rc<dyn Capturer<RgbImage>>,
+    ) -> Self {
         let row = window_info.genshin_repository_item_row;
         let col = window_info.genshin_repository_item_col;
 
-        Ok(GenshinRepositoryScanController {
-            system_control: SystemControl::new(),
+        GenshinRepositoryScanController {
+            system_control,
 
             row: row as usize,
             col: col as usize,
@@ -179,10 +289,13 @@ impl GenshinRepositoryScanController {
             game_info,
             scanned_count: 0,
 
-            capturer: get_capturer()?,
+            scroll_offset_rows: 0,
+            current_position: None,
+
+            capturer,
 
             is_artifact,
-        })
+        }
     }
 
     pub fn from_arg_matches(
@@ -212,7 +325,7 @@ impl GenshinRepositoryScanController {
         utils::sleep(20);
 
         // 点击界面激活
-        object.borrow_mut().system_control.mouse_click()?;
+        object.borrow_mut().click_item()?;
         utils::sleep(1000);
 
         // 采样初始颜色用于检测界面变化
@@ -241,6 +354,8 @@ impl GenshinRepositoryScanController {
             },
             _ => (),
         }
+        state.scrolled_rows += scroll_row;
+        object.borrow_mut().scroll_offset_rows += scroll_row;
 
         utils::sleep(100);
         Ok(())
@@ -256,6 +371,17 @@ impl GenshinRepositoryScanController {
     pub fn get_generator(
         object: Rc<RefCell<GenshinRepositoryScanController>>,
         item_count: usize,
+    ) -> impl Coroutine<Yield = (), Return = Result<ReturnResult>> {
+        Self::get_generator_from(object, item_count, 0)
+    }
+
+    /// 从第 `skip_count` 个物品开始扫描，用于从检查点恢复
+    ///
+    /// 之前的物品只推进扫描状态而不点击，翻页逻辑与完整扫描相同
+    pub fn get_generator_from(
+        object: Rc<RefCell<GenshinRepositoryScanController>>,
+        item_count: usize,
+        skip_count: usize,
     ) -> impl Coroutine<Yield = (), Return = Result<ReturnResult>> {
         let generator = #[coroutine]
         move || {
@@ -294,9 +420,23 @@ impl GenshinRepositoryScanController {
                             return Ok(ReturnResult::Interrupted);
                         }
 
+                        // 恢复扫描时跳过检查点之前已识别的物品
+                        if state.scanned_count < skip_count {
+                            state.scanned_count += 1;
+                            object.borrow_mut().scanned_count = state.scanned_count;
+                            continue;
+                        }
+
+                        object.borrow_mut().current_position = Some(ItemPosition {
+                            index: state.scanned_count,
+                            scrolled_rows: state.scrolled_rows,
+                            row,
+                            col,
+                        });
+
                         // 准备扫描：移动和点击
                         object.borrow_mut().move_to(row, col);
-                        object.borrow_mut().system_control.mouse_click()?;
+                        object.borrow_mut().click_item()?;
 
                         #[cfg(target_os = "macos")]
                         utils::sleep(20);
@@ -340,18 +480,89 @@ impl GenshinRepositoryScanController {
         generator
     }
 
+    /// 回到背包顶部，再按第一次扫描记录的位置依次定位到 `positions` 中的物品，
+    /// 每切换到一个物品 yield 一次该物品的位置
+    ///
+    /// 物品按序号从小到大访问，只需向下翻页。重新定位到当前已显示的物品时面板不会变化，
+    /// 因此等待切换超时只记录警告，仍然 yield 该物品
+    pub fn get_retry_generator(
+        object: Rc<RefCell<GenshinRepositoryScanController>>,
+        mut positions: Vec<ItemPosition>,
+    ) -> impl Coroutine<Yield = ItemPosition, Return = Result<ReturnResult>> {
+        positions.sort_by_key(|position| position.index);
+
+        let generator = #[coroutine]
+        move || {
+            object.borrow_mut().scroll_to_top();
+
+            for position in positions {
+                if utils::is_rmb_down() {
+                    return Ok(ReturnResult::Interrupted);
+                }
+
+                let scrolled_rows = object.borrow().scroll_offset_rows;
+                if position.scrolled_rows > scrolled_rows {
+                    let rows = position.scrolled_rows - scrolled_rows;
+                    match object.borrow_mut().scroll_rows(rows as i32) {
+                        ScrollResult::TimeLimitExceeded => {
+                            return Err(anyhow!("翻页超时，重新扫描终止……"));
+                        },
+                        ScrollResult::Interrupt => return Ok(ReturnResult::Interrupted),
+                        _ => (),
+                    }
+                    object.borrow_mut().scroll_offset_rows = position.scrolled_rows;
+                    utils::sleep(100);
+                }
+
+                object.borrow_mut().move_to(position.row, position.col);
+                object.borrow_mut().click_item()?;
+
+                #[cfg(target_os = "macos")]
+                utils::sleep(20);
+
+                if let Err(e) = object.borrow_mut().wait_until_switched() {
+                    warn!("等待切换到第{}个物品超时，仍尝试识别: {e}", position.index + 1);
+                }
+
+                yield position;
+            }
+
+            Ok(ReturnResult::Finished)
+        };
+
+        generator
+    }
+
+    /// 正在扫描的物品在背包中的位置，在生成器 yield 时对应刚切换到的物品
+    pub fn current_position(&self) -> Option<ItemPosition> {
+        self.current_position
+    }
+
+    /// 向上滚动回到背包顶部
+    ///
+    /// 滚动量按已翻过的行数和测得的每行滚动量估算，并多滚一页，在顶部继续向上滚动不会有影响
+    pub fn scroll_to_top(&mut self) {
+        if self.scroll_offset_rows == 0 {
+            return;
+        }
+
+        let rows = (self.scroll_offset_rows + self.row) as i32;
+        let length = self
+            .estimate_scroll_length(rows)
+            .unwrap_or_else(|| (self.avg_scroll_one_row * rows as f64).ceil() as i32);
+        self.mouse_scroll(-length, false);
+        utils::sleep(self.config.scroll_delay.try_into().unwrap());
+
+        self.scroll_offset_rows = 0;
+    }
+
     #[inline(always)]
     pub fn get_flag_color(&self) -> Result<image::Rgb<u8>> {
-        let mut pos_f64 = Pos {
-            x: self.window_info.flag_pos.x + self.game_info.window.left as f64,
-            y: self.window_info.flag_pos.y + self.game_info.window.top as f64,
-        };
+        let mut pos_f64 = self.window_info.flag_pos + self.game_info.window.to_rect_f64().origin();
         if self.is_artifact {
-            pos_f64.x += self.window_info.artifact_panel_offset.width;
-            pos_f64.y += self.window_info.artifact_panel_offset.height;
+            pos_f64 = pos_f64 + self.window_info.artifact_panel_offset;
         }
-        let pos_i32 = Pos { x: pos_f64.x as i32, y: pos_f64.y as i32 };
-        self.capturer.capture_color(pos_i32)
+        Ok(self.capturer.capture_color(pos_f64.to_pos_i32())?)
     }
 
     #[inline(always)]
@@ -397,6 +608,17 @@ impl GenshinRepositoryScanController {
         utils::sleep(20);
     }
 
+    /// 按配置的延时与次数点击当前鼠标位置的物品
+    pub fn click_item(&mut self) -> Result<()> {
+        let system_control = &mut self.system_control;
+        click_sequence(
+            self.config.click_delay_ms,
+            self.config.double_click,
+            || system_control.mouse_click(),
+            utils::sleep,
+        )
+    }
+
     pub fn scroll_one_row(&mut self) -> ScrollResult {
         let mut state = 0;
         let mut count = 0;
@@ -429,9 +651,7 @@ impl GenshinRepositoryScanController {
     }
 
     pub fn scroll_rows(&mut self, count: i32) -> ScrollResult {
-        if cfg!(windows) && self.scrolled_rows >= 5 {
-            let length = self.estimate_scroll_length(count);
-
+        if let Some(length) = self.estimate_scroll_length(count).filter(|_| cfg!(windows)) {
             for _ in 0..length {
                 if self.system_control.mouse_scroll(1, false).is_err() {
                     return ScrollResult::Failed;
@@ -459,38 +679,37 @@ impl GenshinRepositoryScanController {
     }
 
     pub fn wait_until_switched(&mut self) -> Result<()> {
-        if self.game_info.is_cloud {
+        if self.game_info.is_cloud && !self.config.cloud_verify {
             let wait_time = self.config.get_optimized_cloud_wait();
             utils::sleep(wait_time as u32);
             return anyhow::Ok(());
         }
 
+        let (max_wait, stable_frames) = if self.game_info.is_cloud {
+            (self.config.get_cloud_verify_wait(), self.config.get_cloud_verify_stable_frames())
+        } else {
+            (self.config.get_optimized_switch_wait(), self.config.switch_stable_frames)
+        };
+
         let now = SystemTime::now();
-        let max_wait = self.config.get_optimized_switch_wait() as u128;
+        let max_wait = max_wait as u128;
 
-        let mut consecutive_time = 0;
-        let mut diff_flag = false;
+        let mut detector =
+            SwitchDetector::new(self.pool, self.config.switch_diff_epsilon, stable_frames);
         while now.elapsed().unwrap().as_millis() < max_wait {
             let im = self.capturer.capture_relative_to(
                 self.window_info.pool_rect.to_rect_i32(),
                 self.game_info.window.origin(),
             )?;
 
-            let pool = calc_pool(im.as_raw()) as f64;
-
-            if (pool - self.pool).abs() > 0.000001 {
-                self.pool = pool;
-                diff_flag = true;
-                consecutive_time = 0;
-            } else if diff_flag {
-                consecutive_time += 1;
-                if consecutive_time == 1 {
-                    self.avg_switch_time = (self.avg_switch_time * self.scanned_count as f64
-                        + now.elapsed().unwrap().as_millis() as f64)
-                        / (self.scanned_count as f64 + 1.0);
-                    self.scanned_count += 1;
-                    return anyhow::Ok(());
-                }
+            let switched = detector.observe(calc_pool(im.as_raw()) as f64);
+            self.pool = detector.pool;
+            if switched {
+                self.avg_switch_time = (self.avg_switch_time * self.scanned_count as f64
+                    + now.elapsed().unwrap().as_millis() as f64)
+                    / (self.scanned_count as f64 + 1.0);
+                self.scanned_count += 1;
+                return anyhow::Ok(());
             }
 
             // 减少等待检查的频率，降低CPU使用率
@@ -506,27 +725,26 @@ impl GenshinRepositoryScanController {
 
     #[inline(always)]
     pub fn mouse_scroll(&mut self, length: i32, try_find: bool) {
-        #[cfg(windows)]
         self.system_control.mouse_scroll(length, try_find).unwrap();
 
         #[cfg(target_os = "macos")]
-        {
-            match self.game_info.ui {
-                crate::common::UI::Desktop => {
-                    self.system_control.mouse_scroll(length);
-                    utils::sleep(20);
-                },
-                crate::common::UI::Mobile => {
-                    if try_find {
-                        self.system_control.mac_scroll_fast(length);
-                    } else {
-                        self.system_control.mac_scroll_slow(length);
-                    }
-                },
-            }
+        utils::sleep(20);
+    }
+
+    /// 当前的滚动状态
+    pub fn scroll_checkpoint(&self) -> ScrollCheckpoint {
+        ScrollCheckpoint {
+            scrolled_rows: self.scrolled_rows,
+            avg_scroll_one_row: self.avg_scroll_one_row,
         }
     }
 
+    /// 恢复检查点中保存的滚动状态
+    pub fn restore_scroll(&mut self, scroll: ScrollCheckpoint) {
+        self.scrolled_rows = scroll.scrolled_rows;
+        self.avg_scroll_one_row = scroll.avg_scroll_one_row;
+    }
+
     #[inline(always)]
     fn update_avg_row(&mut self, count: i32) {
         let current = self.avg_scroll_one_row * self.scrolled_rows as f64 + count as f64;
@@ -534,8 +752,308 @@ impl GenshinRepositoryScanController {
         self.avg_scroll_one_row = current / self.scrolled_rows as f64;
     }
 
+    /// 按滚动策略估算翻 `count` 行需要的滚轮格数，`None` 表示逐行滚动并检测
     #[inline(always)]
-    fn estimate_scroll_length(&self, count: i32) -> i32 {
-        ((self.avg_scroll_one_row * count as f64 - 2.0).round() as i32).max(0)
+    fn estimate_scroll_length(&self, count: i32) -> Option<i32> {
+        self.config.scroll_strategy.scroll_length(
+            self.avg_scroll_one_row,
+            self.scrolled_rows,
+            count,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use furina_core::capture::{CaptureError, StaticImageCapturer};
+    use furina_core::game_info::{Platform, ResolutionFamily, UI};
+    use furina_core::positioning::{Pos, Rect, Size};
+    use furina_core::system_control::{ControlCall, NullSystemControl};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Event {
+        Sleep(u32),
+        Click,
+    }
+
+    fn record(click_delay_ms: u32, double_click: bool) -> Vec<Event> {
+        let events = RefCell::new(Vec::new());
+        click_sequence(
+            click_delay_ms,
+            double_click,
+            || {
+                events.borrow_mut().push(Event::Click);
+                Ok(())
+            },
+            |ms| events.borrow_mut().push(Event::Sleep(ms)),
+        )
+        .unwrap();
+        events.into_inner()
+    }
+
+    #[test]
+    fn test_click_sequence_default_has_no_delay() {
+        let config = GenshinRepositoryScannerLogicConfig::default();
+        assert_eq!(record(config.click_delay_ms, config.double_click), vec![Event::Click]);
+    }
+
+    #[test]
+    fn test_click_sequence_honors_delay() {
+        assert_eq!(record(30, false), vec![Event::Sleep(30), Event::Click]);
+        assert_eq!(
+            record(30, true),
+            vec![Event::Sleep(30), Event::Click, Event::Sleep(30), Event::Click]
+        );
+    }
+
+    #[test]
+    fn test_click_sequence_stops_on_error() {
+        let mut clicks = 0;
+        let result = click_sequence(
+            0,
+            true,
+            || {
+                clicks += 1;
+                Err(anyhow!("点击失败"))
+            },
+            |_| {},
+        );
+        assert!(result.is_err());
+        assert_eq!(clicks, 1);
+    }
+
+    #[test]
+    fn test_calc_pool_sums_red_channel() {
+        let row = [10, 200, 200, 20, 0, 0, 255, 1, 2];
+        assert_eq!(calc_pool(&row), 285.0);
+        assert_eq!(calc_pool(&[]), 0.0);
+    }
+
+    /// 依次输入池值，返回判定切换完成时的帧序号
+    fn switched_at(detector: &mut SwitchDetector, pools: &[f64]) -> Option<usize> {
+        pools.iter().position(|&pool| detector.observe(pool))
+    }
+
+    #[test]
+    fn test_switch_detector_default_waits_one_stable_frame() {
+        let config = GenshinRepositoryScannerLogicConfig::default();
+        let mut detector =
+            SwitchDetector::new(100.0, config.switch_diff_epsilon, config.switch_stable_frames);
+        // 画面变化前不会判定完成，变化后第一帧不变即完成
+        assert_eq!(switched_at(&mut detector, &[100.0, 100.0, 250.0, 300.0, 300.0]), Some(4));
+        assert_eq!(detector.pool, 300.0);
+    }
+
+    #[test]
+    fn test_switch_detector_requires_stable_frames() {
+        let mut detector = SwitchDetector::new(100.0, 0.000001, 3);
+        // 中途再次变化会重新计数
+        let pools = [200.0, 200.0, 200.0, 210.0, 210.0, 210.0, 210.0];
+        assert_eq!(switched_at(&mut detector, &pools), Some(6));
+    }
+
+    #[test]
+    fn test_switch_detector_ignores_noise_below_epsilon() {
+        let mut noisy = SwitchDetector::new(100.0, 0.000001, 1);
+        assert_eq!(switched_at(&mut noisy, &[100.5, 101.0, 100.5]), None);
+
+        let mut tolerant = SwitchDetector::new(100.0, 2.0, 1);
+        assert_eq!(switched_at(&mut tolerant, &[100.5, 101.0, 100.5]), None);
+        assert_eq!(switched_at(&mut tolerant, &[200.0, 201.0]), Some(1));
+    }
+
+    /// 1920x1080 窗口信息中背包布局相关的数值
+    fn window_info_1920x1080() -> GenshinRepositoryScanControllerWindowInfo {
+        GenshinRepositoryScanControllerWindowInfo {
+            panel_rect: Rect::new(1308.0, 120.0, 492.0, 840.0),
+            flag_pos: Pos::new(325.3, 107.8),
+            item_gap_size: Size::new(24.0, 24.0),
+            item_size: Size::new(122.0, 151.0),
+            scan_margin_pos: Pos::new(119.0, 121.0),
+            pool_rect: Rect::new(1373.6, 141.8, 18.0, 470.5),
+            artifact_panel_offset: Size::new(0.0, 58.2),
+            genshin_repository_item_row: 5,
+            genshin_repository_item_col: 8,
+        }
+    }
+
+    fn fixture_controller(
+        control: &NullSystemControl,
+        window: Rect<i32>,
+        is_artifact: bool,
+    ) -> GenshinRepositoryScanController {
+        let game_info = GameInfo {
+            window,
+            resolution_family: ResolutionFamily::Windows16x9,
+            is_cloud: false,
+            ui: UI::Desktop,
+            platform: Platform::Windows,
+        };
+        GenshinRepositoryScanController::with_backends(
+            window_info_1920x1080(),
+            GenshinRepositoryScannerLogicConfig::default(),
+            game_info,
+            is_artifact,
+            Box::new(control.clone()),
+            Rc::new(StaticImageCapturer::new(RgbImage::new(1, 1))),
+        )
+    }
+
+    /// 依次返回给定红色通道值的 1x1 截图，用完后重复最后一帧
+    struct PoolSequenceCapturer {
+        frames: RefCell<Vec<u8>>,
+        captures: RefCell<usize>,
+    }
+
+    impl Capturer<RgbImage> for PoolSequenceCapturer {
+        fn capture_rect(&self, _rect: Rect<i32>) -> Result<RgbImage, CaptureError> {
+            *self.captures.borrow_mut() += 1;
+            let mut frames = self.frames.borrow_mut();
+            let red = if frames.len() > 1 { frames.remove(0) } else { frames[0] };
+            Ok(RgbImage::from_pixel(1, 1, image::Rgb([red, 0, 0])))
+        }
+    }
+
+    fn fixture_cloud_controller(
+        config: GenshinRepositoryScannerLogicConfig,
+        capturer: Rc<PoolSequenceCapturer>,
+    ) -> GenshinRepositoryScanController {
+        let game_info = GameInfo {
+            window: Rect::new(0, 0, 1920, 1080),
+            resolution_family: ResolutionFamily::Windows16x9,
+            is_cloud: true,
+            ui: UI::Desktop,
+            platform: Platform::Windows,
+        };
+        GenshinRepositoryScanController::with_backends(
+            window_info_1920x1080(),
+            config,
+            game_info,
+            true,
+            Box::new(NullSystemControl::new()),
+            capturer,
+        )
+    }
+
+    #[test]
+    fn test_cloud_verify_detects_switch_with_cloud_thresholds() {
+        let capturer = Rc::new(PoolSequenceCapturer {
+            frames: RefCell::new(vec![0, 120, 120, 120]),
+            captures: RefCell::new(0),
+        });
+        let config =
+            GenshinRepositoryScannerLogicConfig { cloud_verify: true, ..Default::default() };
+        let mut controller = fixture_cloud_controller(config, capturer.clone());
+
+        controller.wait_until_switched().unwrap();
+
+        // 云游戏至少需要连续 2 帧不变：第 2 帧变化，第 3、4 帧不变后完成
+        assert_eq!(*capturer.captures.borrow(), 4);
+        assert_eq!(controller.pool, 120.0);
+    }
+
+    #[test]
+    fn test_cloud_without_verify_does_not_capture() {
+        let capturer = Rc::new(PoolSequenceCapturer {
+            frames: RefCell::new(vec![0]),
+            captures: RefCell::new(0),
+        });
+        let config =
+            GenshinRepositoryScannerLogicConfig { cloud_wait_switch_item: 0, ..Default::default() };
+        let mut controller = fixture_cloud_controller(config, capturer.clone());
+
+        controller.wait_until_switched().unwrap();
+        assert_eq!(*capturer.captures.borrow(), 0);
+    }
+
+    #[test]
+    fn test_retry_generator_returns_to_top_and_visits_positions_in_order() {
+        use std::ops::CoroutineState;
+        use std::pin::Pin;
+
+        let control = NullSystemControl::new();
+        let game_info = GameInfo {
+            window: Rect::new(0, 0, 1920, 1080),
+            resolution_family: ResolutionFamily::Windows16x9,
+            is_cloud: true,
+            ui: UI::Desktop,
+            platform: Platform::Windows,
+        };
+        let config =
+            GenshinRepositoryScannerLogicConfig { cloud_wait_switch_item: 0, ..Default::default() };
+        let mut controller = GenshinRepositoryScanController::with_backends(
+            window_info_1920x1080(),
+            config,
+            game_info,
+            true,
+            Box::new(control.clone()),
+            Rc::new(StaticImageCapturer::new(RgbImage::new(1, 1))),
+        );
+        // 第一次扫描结束时翻过了 10 行，平均每行 5 格滚轮
+        controller.scroll_offset_rows = 10;
+        controller.restore_scroll(ScrollCheckpoint { scrolled_rows: 3, avg_scroll_one_row: 5.0 });
+        let object = Rc::new(RefCell::new(controller));
+
+        let positions = vec![
+            ItemPosition { index: 9, scrolled_rows: 0, row: 1, col: 1 },
+            ItemPosition { index: 2, scrolled_rows: 0, row: 0, col: 2 },
+        ];
+        let mut generator =
+            GenshinRepositoryScanController::get_retry_generator(object.clone(), positions);
+        let mut visited = Vec::new();
+        loop {
+            match Pin::new(&mut generator).resume(()) {
+                CoroutineState::Yielded(position) => visited.push(position.index),
+                CoroutineState::Complete(result) => {
+                    assert!(matches!(result, Ok(ReturnResult::Finished)));
+                    break;
+                },
+            }
+        }
+
+        assert_eq!(visited, vec![2, 9]);
+        assert_eq!(object.borrow().scroll_offset_rows, 0);
+        // 向上滚动 (10 + 5) 行 × 5 格，再按序号依次点击
+        assert_eq!(
+            control.calls(),
+            vec![
+                ControlCall::Scroll { amount: -75, try_find: false },
+                ControlCall::MoveTo { x: 472, y: 216 },
+                ControlCall::Click,
+                ControlCall::MoveTo { x: 326, y: 391 },
+                ControlCall::Click,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_move_to_coordinates() {
+        let control = NullSystemControl::new();
+        let mut controller = fixture_controller(&control, Rect::new(0, 0, 1920, 1080), false);
+
+        controller.move_to(0, 0);
+        controller.move_to(1, 2);
+
+        // 物品中心偏上的位置：margin + (gap + size) * index + (size.width / 2, size.height / 4)
+        assert_eq!(
+            control.calls(),
+            vec![ControlCall::MoveTo { x: 180, y: 158 }, ControlCall::MoveTo { x: 472, y: 333 }]
+        );
+    }
+
+    #[test]
+    fn test_move_to_applies_window_origin_and_artifact_offset() {
+        let control = NullSystemControl::new();
+        let mut controller = fixture_controller(&control, Rect::new(100, 50, 1920, 1080), true);
+
+        controller.move_to(0, 0);
+        controller.click_item().unwrap();
+
+        assert_eq!(
+            control.calls(),
+            vec![ControlCall::MoveTo { x: 280, y: 266 }, ControlCall::Click]
+        );
     }
 }
@@ -1,10 +1,14 @@
 pub use config::GenshinRepositoryScannerLogicConfig;
-pub use controller::{GenshinRepositoryScanController, ReturnResult};
+pub use controller::{
+    GenshinRepositoryScanController, ItemPosition, ReturnResult, ScrollCheckpoint,
+};
 pub use scroll_result::ScrollResult;
+pub use scroll_strategy::{parse_scroll_strategy, ScrollStrategy};
 pub use window_info::GenshinRepositoryScanControllerWindowInfo;
 
 mod config;
 mod controller;
 
 mod scroll_result;
+mod scroll_strategy;
 mod window_info;
@@ -0,0 +1,113 @@
+use std::fmt::{Display, Formatter};
+
+/// 自适应策略开始使用估算滚动量之前，需要逐行检测的行数
+const ADAPTIVE_WARMUP_ROWS: u32 = 5;
+
+/// 翻页时的滚动策略
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ScrollStrategy {
+    /// 先逐行滚动并检测行边界，测得若干行后按平均每行滚动量一次性滚动，再对齐到行首
+    #[default]
+    Adaptive,
+    /// 每行固定滚动 `ticks` 格滚轮，不做检测，滚动后对齐到行首
+    FixedStep { ticks: u32 },
+    /// 始终逐行滚动并检测行边界，最慢但不依赖估算
+    Detect,
+}
+
+impl ScrollStrategy {
+    /// 计算翻 `count` 行需要一次性滚动的滚轮格数，返回 `None` 表示应逐行滚动并检测
+    ///
+    /// 自适应策略在估算值上少滚 2 格，剩余部分由对齐逻辑补足，避免滚过头
+    pub fn scroll_length(
+        &self,
+        avg_scroll_one_row: f64,
+        scrolled_rows: u32,
+        count: i32,
+    ) -> Option<i32> {
+        match *self {
+            ScrollStrategy::Adaptive if scrolled_rows >= ADAPTIVE_WARMUP_ROWS => {
+                Some(((avg_scroll_one_row * count as f64 - 2.0).round() as i32).max(0))
+            },
+            ScrollStrategy::Adaptive | ScrollStrategy::Detect => None,
+            ScrollStrategy::FixedStep { ticks } => Some(ticks as i32 * count.max(0)),
+        }
+    }
+}
+
+impl Display for ScrollStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScrollStrategy::Adaptive => write!(f, "adaptive"),
+            ScrollStrategy::FixedStep { ticks } => write!(f, "fixed:{ticks}"),
+            ScrollStrategy::Detect => write!(f, "detect"),
+        }
+    }
+}
+
+/// 解析 `--scroll-strategy`：`adaptive`、`detect` 或 `fixed:<每行滚轮格数>`
+pub fn parse_scroll_strategy(s: &str) -> Result<ScrollStrategy, String> {
+    match s.trim().to_ascii_lowercase().as_str() {
+        "adaptive" => Ok(ScrollStrategy::Adaptive),
+        "detect" => Ok(ScrollStrategy::Detect),
+        other => {
+            let ticks = other.strip_prefix("fixed:").ok_or_else(|| {
+                format!("未知的滚动策略 '{s}'，可选: adaptive、detect、fixed:<格数>")
+            })?;
+            match ticks.parse::<u32>() {
+                Ok(ticks) if ticks > 0 => Ok(ScrollStrategy::FixedStep { ticks }),
+                _ => Err(format!("固定滚动的格数必须为正整数: '{ticks}'")),
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adaptive_detects_until_warmed_up() {
+        let strategy = ScrollStrategy::Adaptive;
+        assert_eq!(strategy.scroll_length(5.0, 0, 5), None);
+        assert_eq!(strategy.scroll_length(5.0, 4, 5), None);
+        // 平均每行 5 格，翻 5 行：25 - 2
+        assert_eq!(strategy.scroll_length(5.0, 5, 5), Some(23));
+        assert_eq!(strategy.scroll_length(4.6, 12, 3), Some(12));
+        // 估算值不足 2 格时不会变为负数
+        assert_eq!(strategy.scroll_length(0.5, 5, 1), Some(0));
+    }
+
+    #[test]
+    fn test_fixed_step_ignores_measurements() {
+        let strategy = ScrollStrategy::FixedStep { ticks: 4 };
+        assert_eq!(strategy.scroll_length(0.0, 0, 5), Some(20));
+        assert_eq!(strategy.scroll_length(7.5, 20, 5), Some(20));
+        assert_eq!(strategy.scroll_length(7.5, 20, 0), Some(0));
+    }
+
+    #[test]
+    fn test_detect_never_estimates() {
+        let strategy = ScrollStrategy::Detect;
+        assert_eq!(strategy.scroll_length(5.0, 0, 5), None);
+        assert_eq!(strategy.scroll_length(5.0, 100, 5), None);
+    }
+
+    #[test]
+    fn test_parse_scroll_strategy() {
+        assert_eq!(parse_scroll_strategy("adaptive"), Ok(ScrollStrategy::Adaptive));
+        assert_eq!(parse_scroll_strategy("Detect"), Ok(ScrollStrategy::Detect));
+        assert_eq!(parse_scroll_strategy("fixed:6"), Ok(ScrollStrategy::FixedStep { ticks: 6 }));
+        assert!(parse_scroll_strategy("fixed:0").is_err());
+        assert!(parse_scroll_strategy("fixed:").is_err());
+        assert!(parse_scroll_strategy("fast").is_err());
+
+        for strategy in [
+            ScrollStrategy::Adaptive,
+            ScrollStrategy::Detect,
+            ScrollStrategy::FixedStep { ticks: 3 },
+        ] {
+            assert_eq!(parse_scroll_strategy(&strategy.to_string()), Ok(strategy));
+        }
+    }
+}
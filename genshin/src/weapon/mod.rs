@@ -0,0 +1,5 @@
+pub use weapon_key::{WeaponKey, WeaponType};
+pub use weapon_names::WEAPON_NAMES;
+
+mod weapon_key;
+mod weapon_names;
@@ -0,0 +1,673 @@
+use strum::IntoEnumIterator;
+
+/// 武器类型
+#[derive(
+    Debug, Hash, Clone, Copy, PartialEq, Eq, strum_macros::Display, strum_macros::EnumIter,
+)]
+#[strum(serialize_all = "PascalCase")]
+pub enum WeaponType {
+    Sword,    // 单手剑
+    Claymore, // 双手剑
+    Polearm,  // 长柄武器
+    Catalyst, // 法器
+    Bow,      // 弓
+}
+
+impl WeaponType {
+    pub fn to_zh_cn(&self) -> &'static str {
+        match *self {
+            WeaponType::Sword => "单手剑",
+            WeaponType::Claymore => "双手剑",
+            WeaponType::Polearm => "长柄武器",
+            WeaponType::Catalyst => "法器",
+            WeaponType::Bow => "弓",
+        }
+    }
+}
+
+/// 武器键名枚举，变体名即 GOOD 格式中的 `key`
+#[derive(
+    Debug,
+    Hash,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    strum_macros::Display,
+    strum_macros::EnumString,
+    strum_macros::EnumIter,
+    strum_macros::IntoStaticStr,
+)]
+pub enum WeaponKey {
+    // 单手剑
+    AquilaFavonia,            // 风鹰剑
+    SkywardBlade,             // 天空之刃
+    SummitShaper,             // 斫峰之刃
+    PrimordialJadeCutter,     // 磐岩结绿
+    FreedomSworn,             // 苍古自由之誓
+    MistsplitterReforged,     // 雾切之回光
+    HaranGeppakuFutsu,        // 波乱月白经津
+    KeyOfKhajNisut,           // 圣显之钥
+    LightOfFoliarIncision,    // 裁叶萃光
+    SplendorOfTranquilWaters, // 静水流涌之辉
+    UrakuMisugiri,            // 有乐御簾切
+    Absolution,               // 赦免
+    PeakPatrolSong,           // 岩峰巡歌
+    TheFlute,                 // 笛剑
+    TheBlackSword,            // 黑剑
+    TheAlleyFlash,            // 暗巷闪光
+    SwordOfDescension,        // 降临之剑
+    FavoniusSword,            // 西风剑
+    SacrificialSword,         // 祭礼剑
+    LionsRoar,                // 匣里龙吟
+    PrototypeRancour,         // 试作斩岩
+    IronSting,                // 铁蜂刺
+    RoyalLongsword,           // 宗室长剑
+    BlackcliffLongsword,      // 黑岩长剑
+    FesteringDesire,          // 腐殖之剑
+    AmenomaKageuchi,          // 天目影打刀
+    CinnabarSpindle,          // 辰砂之纺锤
+    KagotsurubeIsshin,        // 笼钓瓶一心
+    SapwoodBlade,             // 原木刀
+    XiphosMoonlight,          // 西福斯的月光
+    ToukabouShigure,          // 东花坊时雨
+    WolfFang,                 // 狼牙
+    FinaleOfTheDeep,          // 海渊终曲
+    FleuveCendreFerryman,     // 灰河渡手
+    TheDockhandsAssistant,    // 船坞长剑
+    SwordOfNarzissenkreuz,    // 水仙十字之剑
+    CoolSteel,                // 冷刃
+    HarbingerOfDawn,          // 黎明神剑
+    TravelersHandySword,      // 旅行剑
+    DarkIronSword,            // 暗铁剑
+    FilletBlade,              // 吃虎鱼刀
+    SkyriderSword,            // 飞天御剑
+    SilverSword,              // 银剑
+    DullBlade,                // 无锋剑
+
+    // 双手剑
+    WolfsGravestone,        // 狼的末路
+    SkywardPride,           // 天空之傲
+    TheUnforged,            // 无工之剑
+    SongOfBrokenPines,      // 松籁响起之时
+    RedhornStonethresher,   // 赤角石溃杵
+    BeaconOfTheReedSea,     // 苇海信标
+    Verdict,                // 裁断
+    FangOfTheMountainKing,  // 山王长牙
+    FavoniusGreatsword,     // 西风大剑
+    SacrificialGreatsword,  // 祭礼大剑
+    TheBell,                // 钟剑
+    Rainslasher,            // 雨裁
+    PrototypeArchaic,       // 试作古华
+    Whiteblind,             // 白影剑
+    SerpentSpine,           // 螭骨剑
+    BlackcliffSlasher,      // 黑岩斩刀
+    RoyalGreatsword,        // 宗室大剑
+    LithicBlade,            // 千岩古剑
+    SnowTombedStarsilver,   // 雪葬的星银
+    LuxuriousSeaLord,       // 衔珠海皇
+    Akuoumaru,              // 恶王丸
+    KatsuragikiriNagamasa,  // 桂木斩长正
+    ForestRegalia,          // 森林王器
+    MakhairaAquamarine,     // 玛海菈的水色
+    TidalShadow,            // 浪影阔剑
+    MailedFlower,           // 饰铁之花
+    PortablePowerSaw,       // 便携动力锯
+    FruitfulHook,           // 硕果钩
+    FerrousShadow,          // 铁影阔剑
+    DebateClub,             // 以理服人
+    BloodtaintedGreatsword, // 沐浴龙血的剑
+    WhiteIronGreatsword,    // 白铁大剑
+    SkyriderGreatsword,     // 飞天大御剑
+    OldMercsPal,            // 佣兵重剑
+    WasterGreatsword,       // 训练大剑
+
+    // 长柄武器
+    PrimordialJadeWingedSpear, // 和璞鸢
+    SkywardSpine,              // 天空之脊
+    VortexVanquisher,          // 贯虹之槊
+    StaffOfHoma,               // 护摩之杖
+    CalamityQueller,           // 息灾
+    EngulfingLightning,        // 薙草之稻光
+    StaffOfTheScarletSands,    // 赤沙之杖
+    CrimsonMoonsSemblance,     // 赤月之形
+    LumidouceElegy,            // 柔灯挽歌
+    DragonsBane,               // 匣里灭辰
+    PrototypeStarglitter,      // 试作星镰
+    CrescentPike,              // 流月针
+    Deathmatch,                // 决斗之枪
+    BlackcliffPole,            // 黑岩刺枪
+    RoyalSpear,                // 宗室猎枪
+    FavoniusLance,             // 西风长枪
+    LithicSpear,               // 千岩长枪
+    DragonspineSpear,          // 龙脊长枪
+    TheCatch,                  // 「渔获」
+    WavebreakersFin,           // 断浪长鳍
+    KitainCrossSpear,          // 喜多院十文字
+    Moonpiercer,               // 贯月矢
+    MissiveWindspear,          // 风信之锋
+    BalladOfTheFjords,         // 峡湾长歌
+    RightfulReward,            // 公义的酬报
+    DialoguesOfTheDesertSages, // 沙中伟贤的对答
+    ProspectorsDrill,          // 勘探钻机
+    WhiteTassel,               // 白缨枪
+    Halberd,                   // 钺矛
+    BlackTassel,               // 黑缨枪
+    IronPoint,                 // 铁尖枪
+    BeginnersProtector,        // 新手长枪
+
+    // 法器
+    SkywardAtlas,                  // 天空之卷
+    LostPrayerToTheSacredWinds,    // 四风原典
+    MemoryOfDust,                  // 尘世之锁
+    EverlastingMoonglow,           // 不灭月华
+    KagurasVerity,                 // 神乐之真意
+    AThousandFloatingDreams,       // 千夜浮梦
+    TulaytullahsRemembrance,       // 图莱杜拉的回忆
+    JadefallsSplendor,             // 碧落之珑
+    TomeOfTheEternalFlow,          // 万世流涌大典
+    CashflowSupervision,           // 金流监督
+    CranesEchoingCall,             // 鹤鸣余音
+    SurfsUp,                       // 冲浪时光
+    TheWidsith,                    // 流浪乐章
+    SacrificialFragments,          // 祭礼残章
+    FavoniusCodex,                 // 西风秘典
+    MappaMare,                     // 万国诸海图谱
+    SolarPearl,                    // 匣里日月
+    PrototypeAmber,                // 试作金珀
+    Frostbearer,                   // 忍冬之果
+    BlackcliffAgate,               // 黑岩绯玉
+    RoyalGrimoire,                 // 宗室秘法录
+    EyeOfPerception,               // 昭心
+    WineAndSong,                   // 暗巷的酒与诗
+    DodocoTales,                   // 嘟嘟可故事集
+    HakushinRing,                  // 白辰之环
+    OathswornEye,                  // 证誓之明瞳
+    WanderingEvenstar,             // 流浪的晚星
+    FruitOfFulfillment,            // 满悦之实
+    FlowingPurity,                 // 纯水流华
+    BalladOfTheBoundlessBlue,      // 无垠蔚蓝之歌
+    MagicGuide,                    // 魔导绪论
+    ThrillingTalesOfDragonSlayers, // 讨龙英杰谭
+    OtherworldlyStory,             // 异世界行记
+    EmeraldOrb,                    // 翡玉法球
+    TwinNephrite,                  // 甲级宝珏
+    PocketGrimoire,                // 口袋魔导书
+    ApprenticesNotes,              // 学徒笔记
+
+    // 弓
+    AmosBow,                      // 阿莫斯之弓
+    SkywardHarp,                  // 天空之翼
+    ElegyForTheEnd,               // 终末嗟叹之诗
+    PolarStar,                    // 冬极白星
+    ThunderingPulse,              // 飞雷之弦振
+    AquaSimulacra,                // 若水
+    HuntersPath,                  // 猎人之径
+    TheFirstGreatMagic,           // 最初的大魔术
+    SilvershowerHeartstrings,     // 白雨心弦
+    AstralVulturesCrimsonPlumage, // 星鹫赤羽
+    TheStringless,                // 绝弦
+    FavoniusWarbow,               // 西风猎弓
+    SacrificialBow,               // 祭礼弓
+    Rust,                         // 弓藏
+    PrototypeCrescent,            // 试作澹月
+    CompoundBow,                  // 钢轮弓
+    TheViridescentHunt,           // 苍翠猎弓
+    BlackcliffWarbow,             // 黑岩战弓
+    RoyalBow,                     // 宗室长弓
+    AlleyHunter,                  // 暗巷猎手
+    WindblumeOde,                 // 风花之颂
+    MitternachtsWaltz,            // 幽夜华尔兹
+    Hamayumi,                     // 破魔之弓
+    MouunsMoon,                   // 曚云之月
+    Predator,                     // 掠食者
+    FadingTwilight,               // 落霞
+    KingsSquire,                  // 王下近侍
+    EndOfTheLine,                 // 竭泽
+    IbisPiercer,                  // 鹮穿之喙
+    ScionOfTheBlazingSun,         // 烈阳之嗣
+    SongOfStillness,              // 静谧之曲
+    RangeGauge,                   // 测距规
+    RavenBow,                     // 鸦羽弓
+    SharpshootersOath,            // 神射手之誓
+    RecurveBow,                   // 反曲弓
+    Slingshot,                    // 弹弓
+    Messenger,                    // 信使
+    SeasonedHuntersBow,           // 历练的猎弓
+    HuntersBow,                   // 猎弓
+}
+
+impl WeaponKey {
+    /// 根据游戏内的中文名称查找武器
+    pub fn from_zh_cn(name: &str) -> Option<WeaponKey> {
+        WeaponKey::iter().find(|key| key.to_zh_cn() == name)
+    }
+
+    /// 转换为GOOD格式的武器键名
+    pub fn to_good(&self) -> &'static str {
+        self.into()
+    }
+
+    pub fn to_zh_cn(&self) -> &'static str {
+        match *self {
+            WeaponKey::AquilaFavonia => "风鹰剑",
+            WeaponKey::SkywardBlade => "天空之刃",
+            WeaponKey::SummitShaper => "斫峰之刃",
+            WeaponKey::PrimordialJadeCutter => "磐岩结绿",
+            WeaponKey::FreedomSworn => "苍古自由之誓",
+            WeaponKey::MistsplitterReforged => "雾切之回光",
+            WeaponKey::HaranGeppakuFutsu => "波乱月白经津",
+            WeaponKey::KeyOfKhajNisut => "圣显之钥",
+            WeaponKey::LightOfFoliarIncision => "裁叶萃光",
+            WeaponKey::SplendorOfTranquilWaters => "静水流涌之辉",
+            WeaponKey::UrakuMisugiri => "有乐御簾切",
+            WeaponKey::Absolution => "赦免",
+            WeaponKey::PeakPatrolSong => "岩峰巡歌",
+            WeaponKey::TheFlute => "笛剑",
+            WeaponKey::TheBlackSword => "黑剑",
+            WeaponKey::TheAlleyFlash => "暗巷闪光",
+            WeaponKey::SwordOfDescension => "降临之剑",
+            WeaponKey::FavoniusSword => "西风剑",
+            WeaponKey::SacrificialSword => "祭礼剑",
+            WeaponKey::LionsRoar => "匣里龙吟",
+            WeaponKey::PrototypeRancour => "试作斩岩",
+            WeaponKey::IronSting => "铁蜂刺",
+            WeaponKey::RoyalLongsword => "宗室长剑",
+            WeaponKey::BlackcliffLongsword => "黑岩长剑",
+            WeaponKey::FesteringDesire => "腐殖之剑",
+            WeaponKey::AmenomaKageuchi => "天目影打刀",
+            WeaponKey::CinnabarSpindle => "辰砂之纺锤",
+            WeaponKey::KagotsurubeIsshin => "笼钓瓶一心",
+            WeaponKey::SapwoodBlade => "原木刀",
+            WeaponKey::XiphosMoonlight => "西福斯的月光",
+            WeaponKey::ToukabouShigure => "东花坊时雨",
+            WeaponKey::WolfFang => "狼牙",
+            WeaponKey::FinaleOfTheDeep => "海渊终曲",
+            WeaponKey::FleuveCendreFerryman => "灰河渡手",
+            WeaponKey::TheDockhandsAssistant => "船坞长剑",
+            WeaponKey::SwordOfNarzissenkreuz => "水仙十字之剑",
+            WeaponKey::CoolSteel => "冷刃",
+            WeaponKey::HarbingerOfDawn => "黎明神剑",
+            WeaponKey::TravelersHandySword => "旅行剑",
+            WeaponKey::DarkIronSword => "暗铁剑",
+            WeaponKey::FilletBlade => "吃虎鱼刀",
+            WeaponKey::SkyriderSword => "飞天御剑",
+            WeaponKey::SilverSword => "银剑",
+            WeaponKey::DullBlade => "无锋剑",
+            WeaponKey::WolfsGravestone => "狼的末路",
+            WeaponKey::SkywardPride => "天空之傲",
+            WeaponKey::TheUnforged => "无工之剑",
+            WeaponKey::SongOfBrokenPines => "松籁响起之时",
+            WeaponKey::RedhornStonethresher => "赤角石溃杵",
+            WeaponKey::BeaconOfTheReedSea => "苇海信标",
+            WeaponKey::Verdict => "裁断",
+            WeaponKey::FangOfTheMountainKing => "山王长牙",
+            WeaponKey::FavoniusGreatsword => "西风大剑",
+            WeaponKey::SacrificialGreatsword => "祭礼大剑",
+            WeaponKey::TheBell => "钟剑",
+            WeaponKey::Rainslasher => "雨裁",
+            WeaponKey::PrototypeArchaic => "试作古华",
+            WeaponKey::Whiteblind => "白影剑",
+            WeaponKey::SerpentSpine => "螭骨剑",
+            WeaponKey::BlackcliffSlasher => "黑岩斩刀",
+            WeaponKey::RoyalGreatsword => "宗室大剑",
+            WeaponKey::LithicBlade => "千岩古剑",
+            WeaponKey::SnowTombedStarsilver => "雪葬的星银",
+            WeaponKey::LuxuriousSeaLord => "衔珠海皇",
+            WeaponKey::Akuoumaru => "恶王丸",
+            WeaponKey::KatsuragikiriNagamasa => "桂木斩长正",
+            WeaponKey::ForestRegalia => "森林王器",
+            WeaponKey::MakhairaAquamarine => "玛海菈的水色",
+            WeaponKey::TidalShadow => "浪影阔剑",
+            WeaponKey::MailedFlower => "饰铁之花",
+            WeaponKey::PortablePowerSaw => "便携动力锯",
+            WeaponKey::FruitfulHook => "硕果钩",
+            WeaponKey::FerrousShadow => "铁影阔剑",
+            WeaponKey::DebateClub => "以理服人",
+            WeaponKey::BloodtaintedGreatsword => "沐浴龙血的剑",
+            WeaponKey::WhiteIronGreatsword => "白铁大剑",
+            WeaponKey::SkyriderGreatsword => "飞天大御剑",
+            WeaponKey::OldMercsPal => "佣兵重剑",
+            WeaponKey::WasterGreatsword => "训练大剑",
+            WeaponKey::PrimordialJadeWingedSpear => "和璞鸢",
+            WeaponKey::SkywardSpine => "天空之脊",
+            WeaponKey::VortexVanquisher => "贯虹之槊",
+            WeaponKey::StaffOfHoma => "护摩之杖",
+            WeaponKey::CalamityQueller => "息灾",
+            WeaponKey::EngulfingLightning => "薙草之稻光",
+            WeaponKey::StaffOfTheScarletSands => "赤沙之杖",
+            WeaponKey::CrimsonMoonsSemblance => "赤月之形",
+            WeaponKey::LumidouceElegy => "柔灯挽歌",
+            WeaponKey::DragonsBane => "匣里灭辰",
+            WeaponKey::PrototypeStarglitter => "试作星镰",
+            WeaponKey::CrescentPike => "流月针",
+            WeaponKey::Deathmatch => "决斗之枪",
+            WeaponKey::BlackcliffPole => "黑岩刺枪",
+            WeaponKey::RoyalSpear => "宗室猎枪",
+            WeaponKey::FavoniusLance => "西风长枪",
+            WeaponKey::LithicSpear => "千岩长枪",
+            WeaponKey::DragonspineSpear => "龙脊长枪",
+            WeaponKey::TheCatch => "「渔获」",
+            WeaponKey::WavebreakersFin => "断浪长鳍",
+            WeaponKey::KitainCrossSpear => "喜多院十文字",
+            WeaponKey::Moonpiercer => "贯月矢",
+            WeaponKey::MissiveWindspear => "风信之锋",
+            WeaponKey::BalladOfTheFjords => "峡湾长歌",
+            WeaponKey::RightfulReward => "公义的酬报",
+            WeaponKey::DialoguesOfTheDesertSages => "沙中伟贤的对答",
+            WeaponKey::ProspectorsDrill => "勘探钻机",
+            WeaponKey::WhiteTassel => "白缨枪",
+            WeaponKey::Halberd => "钺矛",
+            WeaponKey::BlackTassel => "黑缨枪",
+            WeaponKey::IronPoint => "铁尖枪",
+            WeaponKey::BeginnersProtector => "新手长枪",
+            WeaponKey::SkywardAtlas => "天空之卷",
+            WeaponKey::LostPrayerToTheSacredWinds => "四风原典",
+            WeaponKey::MemoryOfDust => "尘世之锁",
+            WeaponKey::EverlastingMoonglow => "不灭月华",
+            WeaponKey::KagurasVerity => "神乐之真意",
+            WeaponKey::AThousandFloatingDreams => "千夜浮梦",
+            WeaponKey::TulaytullahsRemembrance => "图莱杜拉的回忆",
+            WeaponKey::JadefallsSplendor => "碧落之珑",
+            WeaponKey::TomeOfTheEternalFlow => "万世流涌大典",
+            WeaponKey::CashflowSupervision => "金流监督",
+            WeaponKey::CranesEchoingCall => "鹤鸣余音",
+            WeaponKey::SurfsUp => "冲浪时光",
+            WeaponKey::TheWidsith => "流浪乐章",
+            WeaponKey::SacrificialFragments => "祭礼残章",
+            WeaponKey::FavoniusCodex => "西风秘典",
+            WeaponKey::MappaMare => "万国诸海图谱",
+            WeaponKey::SolarPearl => "匣里日月",
+            WeaponKey::PrototypeAmber => "试作金珀",
+            WeaponKey::Frostbearer => "忍冬之果",
+            WeaponKey::BlackcliffAgate => "黑岩绯玉",
+            WeaponKey::RoyalGrimoire => "宗室秘法录",
+            WeaponKey::EyeOfPerception => "昭心",
+            WeaponKey::WineAndSong => "暗巷的酒与诗",
+            WeaponKey::DodocoTales => "嘟嘟可故事集",
+            WeaponKey::HakushinRing => "白辰之环",
+            WeaponKey::OathswornEye => "证誓之明瞳",
+            WeaponKey::WanderingEvenstar => "流浪的晚星",
+            WeaponKey::FruitOfFulfillment => "满悦之实",
+            WeaponKey::FlowingPurity => "纯水流华",
+            WeaponKey::BalladOfTheBoundlessBlue => "无垠蔚蓝之歌",
+            WeaponKey::MagicGuide => "魔导绪论",
+            WeaponKey::ThrillingTalesOfDragonSlayers => "讨龙英杰谭",
+            WeaponKey::OtherworldlyStory => "异世界行记",
+            WeaponKey::EmeraldOrb => "翡玉法球",
+            WeaponKey::TwinNephrite => "甲级宝珏",
+            WeaponKey::PocketGrimoire => "口袋魔导书",
+            WeaponKey::ApprenticesNotes => "学徒笔记",
+            WeaponKey::AmosBow => "阿莫斯之弓",
+            WeaponKey::SkywardHarp => "天空之翼",
+            WeaponKey::ElegyForTheEnd => "终末嗟叹之诗",
+            WeaponKey::PolarStar => "冬极白星",
+            WeaponKey::ThunderingPulse => "飞雷之弦振",
+            WeaponKey::AquaSimulacra => "若水",
+            WeaponKey::HuntersPath => "猎人之径",
+            WeaponKey::TheFirstGreatMagic => "最初的大魔术",
+            WeaponKey::SilvershowerHeartstrings => "白雨心弦",
+            WeaponKey::AstralVulturesCrimsonPlumage => "星鹫赤羽",
+            WeaponKey::TheStringless => "绝弦",
+            WeaponKey::FavoniusWarbow => "西风猎弓",
+            WeaponKey::SacrificialBow => "祭礼弓",
+            WeaponKey::Rust => "弓藏",
+            WeaponKey::PrototypeCrescent => "试作澹月",
+            WeaponKey::CompoundBow => "钢轮弓",
+            WeaponKey::TheViridescentHunt => "苍翠猎弓",
+            WeaponKey::BlackcliffWarbow => "黑岩战弓",
+            WeaponKey::RoyalBow => "宗室长弓",
+            WeaponKey::AlleyHunter => "暗巷猎手",
+            WeaponKey::WindblumeOde => "风花之颂",
+            WeaponKey::MitternachtsWaltz => "幽夜华尔兹",
+            WeaponKey::Hamayumi => "破魔之弓",
+            WeaponKey::MouunsMoon => "曚云之月",
+            WeaponKey::Predator => "掠食者",
+            WeaponKey::FadingTwilight => "落霞",
+            WeaponKey::KingsSquire => "王下近侍",
+            WeaponKey::EndOfTheLine => "竭泽",
+            WeaponKey::IbisPiercer => "鹮穿之喙",
+            WeaponKey::ScionOfTheBlazingSun => "烈阳之嗣",
+            WeaponKey::SongOfStillness => "静谧之曲",
+            WeaponKey::RangeGauge => "测距规",
+            WeaponKey::RavenBow => "鸦羽弓",
+            WeaponKey::SharpshootersOath => "神射手之誓",
+            WeaponKey::RecurveBow => "反曲弓",
+            WeaponKey::Slingshot => "弹弓",
+            WeaponKey::Messenger => "信使",
+            WeaponKey::SeasonedHuntersBow => "历练的猎弓",
+            WeaponKey::HuntersBow => "猎弓",
+        }
+    }
+
+    pub fn weapon_type(&self) -> WeaponType {
+        match *self {
+            WeaponKey::AquilaFavonia
+            | WeaponKey::SkywardBlade
+            | WeaponKey::SummitShaper
+            | WeaponKey::PrimordialJadeCutter
+            | WeaponKey::FreedomSworn
+            | WeaponKey::MistsplitterReforged
+            | WeaponKey::HaranGeppakuFutsu
+            | WeaponKey::KeyOfKhajNisut
+            | WeaponKey::LightOfFoliarIncision
+            | WeaponKey::SplendorOfTranquilWaters
+            | WeaponKey::UrakuMisugiri
+            | WeaponKey::Absolution
+            | WeaponKey::PeakPatrolSong
+            | WeaponKey::TheFlute
+            | WeaponKey::TheBlackSword
+            | WeaponKey::TheAlleyFlash
+            | WeaponKey::SwordOfDescension
+            | WeaponKey::FavoniusSword
+            | WeaponKey::SacrificialSword
+            | WeaponKey::LionsRoar
+            | WeaponKey::PrototypeRancour
+            | WeaponKey::IronSting
+            | WeaponKey::RoyalLongsword
+            | WeaponKey::BlackcliffLongsword
+            | WeaponKey::FesteringDesire
+            | WeaponKey::AmenomaKageuchi
+            | WeaponKey::CinnabarSpindle
+            | WeaponKey::KagotsurubeIsshin
+            | WeaponKey::SapwoodBlade
+            | WeaponKey::XiphosMoonlight
+            | WeaponKey::ToukabouShigure
+            | WeaponKey::WolfFang
+            | WeaponKey::FinaleOfTheDeep
+            | WeaponKey::FleuveCendreFerryman
+            | WeaponKey::TheDockhandsAssistant
+            | WeaponKey::SwordOfNarzissenkreuz
+            | WeaponKey::CoolSteel
+            | WeaponKey::HarbingerOfDawn
+            | WeaponKey::TravelersHandySword
+            | WeaponKey::DarkIronSword
+            | WeaponKey::FilletBlade
+            | WeaponKey::SkyriderSword
+            | WeaponKey::SilverSword
+            | WeaponKey::DullBlade => WeaponType::Sword,
+            WeaponKey::WolfsGravestone
+            | WeaponKey::SkywardPride
+            | WeaponKey::TheUnforged
+            | WeaponKey::SongOfBrokenPines
+            | WeaponKey::RedhornStonethresher
+            | WeaponKey::BeaconOfTheReedSea
+            | WeaponKey::Verdict
+            | WeaponKey::FangOfTheMountainKing
+            | WeaponKey::FavoniusGreatsword
+            | WeaponKey::SacrificialGreatsword
+            | WeaponKey::TheBell
+            | WeaponKey::Rainslasher
+            | WeaponKey::PrototypeArchaic
+            | WeaponKey::Whiteblind
+            | WeaponKey::SerpentSpine
+            | WeaponKey::BlackcliffSlasher
+            | WeaponKey::RoyalGreatsword
+            | WeaponKey::LithicBlade
+            | WeaponKey::SnowTombedStarsilver
+            | WeaponKey::LuxuriousSeaLord
+            | WeaponKey::Akuoumaru
+            | WeaponKey::KatsuragikiriNagamasa
+            | WeaponKey::ForestRegalia
+            | WeaponKey::MakhairaAquamarine
+            | WeaponKey::TidalShadow
+            | WeaponKey::MailedFlower
+            | WeaponKey::PortablePowerSaw
+            | WeaponKey::FruitfulHook
+            | WeaponKey::FerrousShadow
+            | WeaponKey::DebateClub
+            | WeaponKey::BloodtaintedGreatsword
+            | WeaponKey::WhiteIronGreatsword
+            | WeaponKey::SkyriderGreatsword
+            | WeaponKey::OldMercsPal
+            | WeaponKey::WasterGreatsword => WeaponType::Claymore,
+            WeaponKey::PrimordialJadeWingedSpear
+            | WeaponKey::SkywardSpine
+            | WeaponKey::VortexVanquisher
+            | WeaponKey::StaffOfHoma
+            | WeaponKey::CalamityQueller
+            | WeaponKey::EngulfingLightning
+            | WeaponKey::StaffOfTheScarletSands
+            | WeaponKey::CrimsonMoonsSemblance
+            | WeaponKey::LumidouceElegy
+            | WeaponKey::DragonsBane
+            | WeaponKey::PrototypeStarglitter
+            | WeaponKey::CrescentPike
+            | WeaponKey::Deathmatch
+            | WeaponKey::BlackcliffPole
+            | WeaponKey::RoyalSpear
+            | WeaponKey::FavoniusLance
+            | WeaponKey::LithicSpear
+            | WeaponKey::DragonspineSpear
+            | WeaponKey::TheCatch
+            | WeaponKey::WavebreakersFin
+            | WeaponKey::KitainCrossSpear
+            | WeaponKey::Moonpiercer
+            | WeaponKey::MissiveWindspear
+            | WeaponKey::BalladOfTheFjords
+            | WeaponKey::RightfulReward
+            | WeaponKey::DialoguesOfTheDesertSages
+            | WeaponKey::ProspectorsDrill
+            | WeaponKey::WhiteTassel
+            | WeaponKey::Halberd
+            | WeaponKey::BlackTassel
+            | WeaponKey::IronPoint
+            | WeaponKey::BeginnersProtector => WeaponType::Polearm,
+            WeaponKey::SkywardAtlas
+            | WeaponKey::LostPrayerToTheSacredWinds
+            | WeaponKey::MemoryOfDust
+            | WeaponKey::EverlastingMoonglow
+            | WeaponKey::KagurasVerity
+            | WeaponKey::AThousandFloatingDreams
+            | WeaponKey::TulaytullahsRemembrance
+            | WeaponKey::JadefallsSplendor
+            | WeaponKey::TomeOfTheEternalFlow
+            | WeaponKey::CashflowSupervision
+            | WeaponKey::CranesEchoingCall
+            | WeaponKey::SurfsUp
+            | WeaponKey::TheWidsith
+            | WeaponKey::SacrificialFragments
+            | WeaponKey::FavoniusCodex
+            | WeaponKey::MappaMare
+            | WeaponKey::SolarPearl
+            | WeaponKey::PrototypeAmber
+            | WeaponKey::Frostbearer
+            | WeaponKey::BlackcliffAgate
+            | WeaponKey::RoyalGrimoire
+            | WeaponKey::EyeOfPerception
+            | WeaponKey::WineAndSong
+            | WeaponKey::DodocoTales
+            | WeaponKey::HakushinRing
+            | WeaponKey::OathswornEye
+            | WeaponKey::WanderingEvenstar
+            | WeaponKey::FruitOfFulfillment
+            | WeaponKey::FlowingPurity
+            | WeaponKey::BalladOfTheBoundlessBlue
+            | WeaponKey::MagicGuide
+            | WeaponKey::ThrillingTalesOfDragonSlayers
+            | WeaponKey::OtherworldlyStory
+            | WeaponKey::EmeraldOrb
+            | WeaponKey::TwinNephrite
+            | WeaponKey::PocketGrimoire
+            | WeaponKey::ApprenticesNotes => WeaponType::Catalyst,
+            WeaponKey::AmosBow
+            | WeaponKey::SkywardHarp
+            | WeaponKey::ElegyForTheEnd
+            | WeaponKey::PolarStar
+            | WeaponKey::ThunderingPulse
+            | WeaponKey::AquaSimulacra
+            | WeaponKey::HuntersPath
+            | WeaponKey::TheFirstGreatMagic
+            | WeaponKey::SilvershowerHeartstrings
+            | WeaponKey::AstralVulturesCrimsonPlumage
+            | WeaponKey::TheStringless
+            | WeaponKey::FavoniusWarbow
+            | WeaponKey::SacrificialBow
+            | WeaponKey::Rust
+            | WeaponKey::PrototypeCrescent
+            | WeaponKey::CompoundBow
+            | WeaponKey::TheViridescentHunt
+            | WeaponKey::BlackcliffWarbow
+            | WeaponKey::RoyalBow
+            | WeaponKey::AlleyHunter
+            | WeaponKey::WindblumeOde
+            | WeaponKey::MitternachtsWaltz
+            | WeaponKey::Hamayumi
+            | WeaponKey::MouunsMoon
+            | WeaponKey::Predator
+            | WeaponKey::FadingTwilight
+            | WeaponKey::KingsSquire
+            | WeaponKey::EndOfTheLine
+            | WeaponKey::IbisPiercer
+            | WeaponKey::ScionOfTheBlazingSun
+            | WeaponKey::SongOfStillness
+            | WeaponKey::RangeGauge
+            | WeaponKey::RavenBow
+            | WeaponKey::SharpshootersOath
+            | WeaponKey::RecurveBow
+            | WeaponKey::Slingshot
+            | WeaponKey::Messenger
+            | WeaponKey::SeasonedHuntersBow
+            | WeaponKey::HuntersBow => WeaponType::Bow,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weapon::WEAPON_NAMES;
+
+    #[test]
+    fn test_signature_weapons() {
+        let cases = [
+            ("雾切之回光", "MistsplitterReforged", WeaponType::Sword),
+            ("赤角石溃杵", "RedhornStonethresher", WeaponType::Claymore),
+            ("护摩之杖", "StaffOfHoma", WeaponType::Polearm),
+            ("神乐之真意", "KagurasVerity", WeaponType::Catalyst),
+            ("飞雷之弦振", "ThunderingPulse", WeaponType::Bow),
+        ];
+        for (zh_cn, good, weapon_type) in cases {
+            let key = WeaponKey::from_zh_cn(zh_cn).unwrap();
+            assert_eq!(key.to_good(), good);
+            assert_eq!(key.weapon_type(), weapon_type);
+            assert_eq!(key.to_good().parse::<WeaponKey>().unwrap(), key);
+        }
+    }
+
+    #[test]
+    fn test_common_weapons() {
+        assert_eq!(WeaponKey::from_zh_cn("西风剑").unwrap().to_good(), "FavoniusSword");
+        assert_eq!(WeaponKey::from_zh_cn("祭礼弓").unwrap().weapon_type(), WeaponType::Bow);
+        assert_eq!(WeaponKey::from_zh_cn("「渔获」").unwrap().to_good(), "TheCatch");
+        assert_eq!(WeaponKey::from_zh_cn("不存在的武器"), None);
+    }
+
+    #[test]
+    fn test_weapon_names() {
+        assert!(WEAPON_NAMES.contains("天空之翼"));
+        assert!(WEAPON_NAMES.contains("无锋剑"));
+        assert!(!WEAPON_NAMES.contains("胡桃"));
+        assert_eq!(WEAPON_NAMES.len(), WeaponKey::iter().count());
+    }
+}
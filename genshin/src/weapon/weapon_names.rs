@@ -0,0 +1,12 @@
+use std::collections::HashSet;
+
+use lazy_static::lazy_static;
+use strum::IntoEnumIterator;
+
+use crate::weapon::WeaponKey;
+
+// 原神武器名称集合，用于验证武器扫描识别出的名称
+lazy_static! {
+    pub static ref WEAPON_NAMES: HashSet<&'static str> =
+        WeaponKey::iter().map(|key| key.to_zh_cn()).collect();
+}
@@ -0,0 +1,509 @@
+use std::cell::Cell;
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use furina_core::capture::{CaptureError, Capturer, StaticImageCapturer};
+use furina_core::game_info::{Platform, UI};
+use furina_core::ocr::ImageToText;
+use furina_core::positioning::{Pos, Rect, Size};
+use furina_core::window_info::{
+    FromWindowInfoRepository, WindowInfoRepository, WindowInfoTemplatePerSize,
+};
+use genshin::artifact::{parse_panel, ArtifactSetName, ArtifactSlot, GenshinArtifact};
+use genshin::export::artifact::good::GOODFormat;
+use genshin::locale::Locale;
+use genshin::scanner::{
+    capture_with_recovery, ArtifactScanError, ArtifactScannerWindowInfo, ArtifactScannerWorker,
+    CaptureFailureTracker, GenshinArtifactScanner, GenshinArtifactScannerConfig, SendItem,
+};
+use image::{Rgb, RgbImage};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// 背景噪声的随机种子，固定后每次生成的截图完全相同
+const SEED: u64 = 0x5EED_1811;
+
+/// 祝圣之霜的特征颜色，位于等级区域左上方 (left-10, top-15)
+const HOARFROST_COLOR: Rgb<u8> = Rgb([220, 192, 255]);
+
+/// 一件圣遗物的预设识别文本，顺序与工作器识别字段的顺序一致
+struct CannedArtifact {
+    star: usize,
+    hoarfrost: bool,
+    /// 名称、主属性名称、主属性数值、等级、装备状态、副属性1-4
+    texts: [&'static str; 9],
+}
+
+/// 第 `index` 个字段区域的填充颜色，模拟模型据此返回对应的预设文本
+fn field_color(index: usize) -> Rgb<u8> {
+    Rgb([12, 30 + index as u8 * 20, 250])
+}
+
+/// 根据字段区域中心的颜色返回预设文本的模拟模型
+struct CannedModel {
+    texts: Arc<Mutex<[&'static str; 9]>>,
+}
+
+impl ImageToText<RgbImage> for CannedModel {
+    fn image_to_text(&self, image: &RgbImage, _is_preprocessed: bool) -> Result<String> {
+        let pixel = image.get_pixel(image.width() / 2, image.height() / 2);
+        let index = (0..9)
+            .find(|&i| field_color(i) == *pixel)
+            .ok_or_else(|| anyhow!("区域中心不是任何字段的颜色: {pixel:?}"))?;
+        Ok(self.texts.lock().unwrap()[index].to_string())
+    }
+
+    fn get_average_inference_time(&self) -> Option<Duration> {
+        None
+    }
+}
+
+fn window_info_1920x1080() -> ArtifactScannerWindowInfo {
+    let mut repo = WindowInfoRepository::new();
+    let template: WindowInfoTemplatePerSize =
+        serde_json::from_str(include_str!("../../genshin/window_info/windows1920x1080.json"))
+            .unwrap();
+    template.inject_into_window_info_repo(&mut repo);
+    ArtifactScannerWindowInfo::from_window_info_repository(
+        Size::new(1920, 1080),
+        UI::Desktop,
+        Platform::Windows,
+        &repo,
+    )
+    .unwrap()
+}
+
+fn fill_rect(image: &mut RgbImage, rect: Rect<f64>, color: Rgb<u8>) {
+    for y in rect.top as u32..(rect.top + rect.height) as u32 {
+        for x in rect.left as u32..(rect.left + rect.width) as u32 {
+            image.put_pixel(x, y, color);
+        }
+    }
+}
+
+/// 生成一张窗口截图：带种子的随机背景，各字段区域填充对应颜色，并设置星级和祝圣之霜标记
+fn render_window(
+    info: &ArtifactScannerWindowInfo,
+    artifact: &CannedArtifact,
+    seed: u64,
+) -> RgbImage {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut image = RgbImage::from_fn(1920, 1080, |_, _| Rgb([rng.gen(), rng.gen(), rng.gen()]));
+
+    let offset = if artifact.hoarfrost { info.hoarfrost_offset.height } else { 0.0 };
+    let shifted = |rect: Rect<f64>| Rect { top: rect.top + offset, ..rect };
+    let fields = [
+        info.title_rect,
+        info.main_stat_name_rect,
+        info.main_stat_value_rect,
+        shifted(info.level_rect),
+        info.item_equip_rect,
+        shifted(info.sub_stat_1),
+        shifted(info.sub_stat_2),
+        shifted(info.sub_stat_3),
+        shifted(info.sub_stat_4),
+    ];
+    for (i, rect) in fields.into_iter().enumerate() {
+        fill_rect(&mut image, rect, field_color(i));
+    }
+
+    let marker =
+        Pos { x: (info.level_rect.left - 10.0) as u32, y: (info.level_rect.top - 15.0) as u32 };
+    let marker_color = if artifact.hoarfrost { HOARFROST_COLOR } else { Rgb([0, 0, 0]) };
+    image.put_pixel(marker.x, marker.y, marker_color);
+
+    let star_color = info.star_colors()[artifact.star - 1];
+    fill_rect(&mut image, info.star_sample_rect, Rgb([star_color.0, star_color.1, star_color.2]));
+
+    image
+}
+
+fn canned_artifacts() -> Vec<CannedArtifact> {
+    vec![
+        CannedArtifact {
+            star: 5,
+            hoarfrost: false,
+            texts: [
+                "明威之镡",
+                "生命值",
+                "4,780",
+                "+20",
+                "胡桃已装备",
+                "暴击率+3.9%",
+                "暴击伤害+21.8%",
+                "攻击力+5.8%",
+                "元素充能效率+11.0%",
+            ],
+        },
+        CannedArtifact {
+            star: 4,
+            hoarfrost: false,
+            texts: [
+                "沉金的岁月",
+                "元素精通",
+                "126",
+                "+16",
+                "",
+                "暴击率+5.3%",
+                "攻击力+16",
+                "生命值+4.7%",
+                "",
+            ],
+        },
+        // 祝圣之霜：等级和副属性区域整体下移，1920×1080 下"暴击伤害"会被识别为"暴击伤"
+        CannedArtifact {
+            star: 5,
+            hoarfrost: true,
+            texts: [
+                "老兵的容颜",
+                "暴击率",
+                "4.7%",
+                "+0",
+                "纳西妲已装备",
+                "暴击伤+7.8%",
+                "攻击力+10.5%",
+                "元素精通+23",
+                "防御力+6.2%",
+            ],
+        },
+    ]
+}
+
+fn scan_to_good_json(seed: u64) -> String {
+    let info = window_info_1920x1080();
+    let texts = Arc::new(Mutex::new([""; 9]));
+    let model = CannedModel { texts: Arc::clone(&texts) };
+    let mut worker = ArtifactScannerWorker::with_model(
+        Box::new(model),
+        info.clone(),
+        GenshinArtifactScannerConfig::default(),
+        (1920, 1080),
+    );
+
+    let artifacts: Vec<GenshinArtifact> = canned_artifacts()
+        .iter()
+        .enumerate()
+        .map(|(i, canned)| {
+            *texts.lock().unwrap() = canned.texts;
+            let capturer = StaticImageCapturer::new(render_window(&info, canned, seed + i as u64));
+            let result = worker.scan_from_image(&capturer).unwrap();
+            assert!(
+                result.scan_errors.is_empty(),
+                "第{}件圣遗物识别出错: {:?}",
+                i + 1,
+                result.scan_errors
+            );
+            GenshinArtifact::try_from(&result).unwrap()
+        })
+        .collect();
+
+    serde_json::to_string(&GOODFormat::new(&artifacts)).unwrap()
+}
+
+const EXPECTED_GOOD_JSON: &str = concat!(
+    r#"{"format":"GOOD","version":1,"source":"furina","artifacts":["#,
+    r#"{"setKey":"EmblemOfSeveredFate","slotKey":"flower","level":20,"rarity":5,"#,
+    r#""mainStatKey":"hp","location":"HuTao","lock":false,"substats":["#,
+    r#"{"key":"critRate_","value":3.9},{"key":"critDMG_","value":21.8},"#,
+    r#"{"key":"atk_","value":5.8},{"key":"enerRech_","value":11.0}]},"#,
+    r#"{"setKey":"GildedDreams","slotKey":"sands","level":16,"rarity":4,"#,
+    r#""mainStatKey":"eleMas","location":"","lock":false,"substats":["#,
+    r#"{"key":"critRate_","value":5.3},{"key":"atk","value":16.0},"#,
+    r#"{"key":"hp_","value":4.7}]},"#,
+    r#"{"setKey":"MarechausseeHunter","slotKey":"circlet","level":0,"rarity":5,"#,
+    r#""mainStatKey":"critRate_","location":"Nahida","lock":false,"substats":["#,
+    r#"{"key":"critDMG_","value":7.8},{"key":"atk_","value":10.5},"#,
+    r#"{"key":"eleMas","value":23.0},{"key":"def_","value":6.2}]}"#,
+    r#"]}"#
+);
+
+/// 集成测试：截图 → 识别 → 转换 → GOOD 导出的完整流程
+#[test]
+fn test_scan_from_image_to_good_json() {
+    assert_eq!(scan_to_good_json(SEED), EXPECTED_GOOD_JSON);
+}
+
+/// 背景噪声不影响识别结果，不同种子得到相同的导出内容
+#[test]
+fn test_scan_is_independent_of_background_seed() {
+    assert_eq!(scan_to_good_json(SEED.wrapping_add(1000)), EXPECTED_GOOD_JSON);
+}
+
+/// 面板截图编码为 PNG 后按字节识别，与直接识别窗口截图的结果一致
+#[test]
+fn test_parse_panel_from_png_bytes() {
+    let info = window_info_1920x1080();
+    let canned = &canned_artifacts()[0];
+    let window = render_window(&info, canned, SEED);
+    let panel = info.panel_rect.to_rect_u32();
+    let panel_image =
+        image::imageops::crop_imm(&window, panel.left, panel.top, panel.width, panel.height)
+            .to_image();
+    let mut png = Vec::new();
+    panel_image
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageOutputFormat::Png)
+        .unwrap();
+
+    let model = || CannedModel { texts: Arc::new(Mutex::new(canned.texts)) };
+    let artifact = parse_panel(&png, (1920, 1080), Locale::ZhCn, model()).unwrap();
+
+    let capturer = StaticImageCapturer::new(window);
+    let mut worker = ArtifactScannerWorker::with_model(
+        Box::new(model()),
+        info.clone(),
+        GenshinArtifactScannerConfig::default(),
+        (1920, 1080),
+    );
+    let expected = GenshinArtifact::try_from(&worker.scan_from_image(&capturer).unwrap()).unwrap();
+    assert_eq!(artifact, expected);
+    assert_eq!(artifact.set_name, ArtifactSetName::EmblemOfSeveredFate);
+    assert_eq!(artifact.slot, ArtifactSlot::Flower);
+    assert_eq!(artifact.star, 5);
+
+    // 尺寸不符、无法解码和不支持的语言都返回错误
+    assert!(parse_panel(&png, (1600, 900), Locale::ZhCn, model()).is_err());
+    assert!(parse_panel(b"not a png", (1920, 1080), Locale::ZhCn, model()).is_err());
+    assert!(parse_panel(&png, (1920, 1080), Locale::En, model()).is_err());
+}
+
+/// 录制的帧中同一件圣遗物连续出现多次时只保留一次
+#[test]
+fn test_scan_from_frames_dedupes_repeated_panels() {
+    let info = window_info_1920x1080();
+    let canned = canned_artifacts();
+    let texts = Arc::new(Mutex::new([""; 9]));
+    let model = CannedModel { texts: Arc::clone(&texts) };
+    let mut worker = ArtifactScannerWorker::with_model(
+        Box::new(model),
+        info.clone(),
+        GenshinArtifactScannerConfig::default(),
+        (1920, 1080),
+    );
+
+    // 第二帧与第一帧是同一件圣遗物，只有背景噪声不同
+    let frames = [(0, SEED), (0, SEED + 1), (1, SEED + 2)];
+    let results = worker.scan_from_frames(frames.into_iter().map(|(index, seed)| {
+        // 帧按需逐个生成，识别前切换模型返回的文本
+        *texts.lock().unwrap() = canned[index].texts;
+        render_window(&info, &canned[index], seed)
+    }));
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].name, "明威之镡");
+    assert_eq!(results[1].name, canned[1].texts[0]);
+    assert!(results.iter().all(|r| r.scan_errors.is_empty()), "{results:?}");
+}
+
+/// 快速模式只识别名称、主属性、等级和装备状态，副属性留空
+#[test]
+fn test_quick_mode_skips_sub_stats() {
+    let info = window_info_1920x1080();
+    let canned = &canned_artifacts()[0];
+    let capturer = StaticImageCapturer::new(render_window(&info, canned, SEED));
+
+    let scan = |quick: bool| {
+        let model = CannedModel { texts: Arc::new(Mutex::new(canned.texts)) };
+        let config = GenshinArtifactScannerConfig { quick, ..Default::default() };
+        let mut worker =
+            ArtifactScannerWorker::with_model(Box::new(model), info.clone(), config, (1920, 1080));
+        let result = worker.scan_from_image(&capturer).unwrap();
+        (result, worker.performance_monitor().field_call_count())
+    };
+
+    let (full, full_calls) = scan(false);
+    let (quick, quick_calls) = scan(true);
+    assert_eq!(full_calls, 9);
+    assert_eq!(quick_calls, 5);
+
+    assert!(quick.scan_errors.is_empty(), "{:?}", quick.scan_errors);
+    assert_eq!(quick.sub_stat, <[String; 4]>::default());
+    let artifact = GenshinArtifact::try_from(&quick).unwrap();
+    assert!(artifact.sub_stat_1.is_none());
+    assert!(artifact.sub_stat_2.is_none());
+    assert!(artifact.sub_stat_3.is_none());
+    assert!(artifact.sub_stat_4.is_none());
+
+    // 除副属性外与完整识别的结果一致
+    let full_artifact = GenshinArtifact::try_from(&full).unwrap();
+    assert_eq!(artifact.set_name, full_artifact.set_name);
+    assert_eq!(artifact.slot, full_artifact.slot);
+    assert_eq!(artifact.main_stat, full_artifact.main_stat);
+    assert_eq!(artifact.level, full_artifact.level);
+    assert!(full_artifact.sub_stat_1.is_some());
+}
+
+/// 前 `failures` 次截图失败、之后正常返回的捕获器，模拟窗口重绘等瞬时故障
+struct FlakyCapturer {
+    inner: StaticImageCapturer,
+    failures: Cell<usize>,
+}
+
+impl Capturer<RgbImage> for FlakyCapturer {
+    fn capture_rect(&self, rect: Rect<i32>) -> Result<RgbImage, CaptureError> {
+        if self.failures.get() > 0 {
+            self.failures.set(self.failures.get() - 1);
+            return Err(CaptureError::Backend("模拟截图失败".to_string()));
+        }
+        self.inner.capture_rect(rect)
+    }
+}
+
+/// 截图失败一次后重试成功，物品仍然被识别
+#[test]
+fn test_flaky_capture_is_retried() {
+    let info = window_info_1920x1080();
+    let canned = &canned_artifacts()[0];
+    let capturer = FlakyCapturer {
+        inner: StaticImageCapturer::new(render_window(&info, canned, SEED)),
+        failures: Cell::new(1),
+    };
+    let manager = GenshinArtifactScanner::capture_recovery_manager();
+
+    let panel_image = capture_with_recovery(&manager, "圣遗物面板", || {
+        capturer.capture_rect(info.panel_rect.to_rect_i32())
+    });
+    assert!(panel_image.is_some());
+    assert_eq!(capturer.failures.get(), 0);
+    assert_eq!(manager.get_statistics().successful_recoveries, 1);
+
+    let model = CannedModel { texts: Arc::new(Mutex::new(canned.texts)) };
+    let worker = ArtifactScannerWorker::with_model(
+        Box::new(model),
+        info.clone(),
+        GenshinArtifactScannerConfig::default(),
+        (1920, 1080),
+    );
+    let (tx, rx) = mpsc::channel();
+    let handle = worker.run(rx);
+    tx.send(Some(SendItem { panel_image, star: canned.star, list_image: None, position: None }))
+        .unwrap();
+    tx.send(None).unwrap();
+
+    let results = handle.join().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].name, "明威之镡");
+    assert_eq!(results[0].level, 20);
+}
+
+/// 截图持续失败时跳过该物品而不是中止扫描，后续物品照常识别
+#[test]
+fn test_persistent_capture_failure_skips_item() {
+    let info = window_info_1920x1080();
+    let canned = &canned_artifacts()[0];
+    let capturer = FlakyCapturer {
+        inner: StaticImageCapturer::new(render_window(&info, canned, SEED)),
+        failures: Cell::new(usize::MAX),
+    };
+    let manager = GenshinArtifactScanner::capture_recovery_manager();
+
+    let failed = capture_with_recovery(&manager, "圣遗物面板", || {
+        capturer.capture_rect(info.panel_rect.to_rect_i32())
+    });
+    assert!(failed.is_none());
+    assert_eq!(manager.get_statistics().failed_recoveries, 1);
+
+    let model = CannedModel { texts: Arc::new(Mutex::new(canned.texts)) };
+    let worker = ArtifactScannerWorker::with_model(
+        Box::new(model),
+        info.clone(),
+        GenshinArtifactScannerConfig::default(),
+        (1920, 1080),
+    );
+    let (tx, rx) = mpsc::channel();
+    let handle = worker.run(rx);
+    tx.send(Some(SendItem { panel_image: failed, star: 0, list_image: None, position: None }))
+        .unwrap();
+    let panel_image = capturer.inner.capture_rect(info.panel_rect.to_rect_i32()).unwrap();
+    tx.send(Some(SendItem {
+        panel_image: Some(panel_image),
+        star: 5,
+        list_image: None,
+        position: None,
+    }))
+    .unwrap();
+    tx.send(None).unwrap();
+
+    let results = handle.join().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].name, "明威之镡");
+}
+
+/// 前 `successes` 次截图正常、之后总是失败的捕获器，模拟扫描途中关闭游戏
+struct ClosingCapturer {
+    inner: StaticImageCapturer,
+    successes: Cell<usize>,
+    attempts: Cell<usize>,
+}
+
+impl Capturer<RgbImage> for ClosingCapturer {
+    fn capture_rect(&self, rect: Rect<i32>) -> Result<RgbImage, CaptureError> {
+        self.attempts.set(self.attempts.get() + 1);
+        if self.successes.get() == 0 {
+            return Err(CaptureError::Backend("窗口已关闭".to_string()));
+        }
+        self.successes.set(self.successes.get() - 1);
+        self.inner.capture_rect(rect)
+    }
+}
+
+/// 连续截图失败达到上限时停止扫描，已识别的物品仍然保留
+#[test]
+fn test_consecutive_capture_failures_abort_scan() {
+    let info = window_info_1920x1080();
+    let canned = &canned_artifacts()[0];
+    let capturer = ClosingCapturer {
+        inner: StaticImageCapturer::new(render_window(&info, canned, SEED)),
+        successes: Cell::new(1),
+        attempts: Cell::new(0),
+    };
+    let manager = GenshinArtifactScanner::capture_recovery_manager();
+    let limit = 3;
+    let mut tracker = CaptureFailureTracker::new(limit);
+
+    let model = CannedModel { texts: Arc::new(Mutex::new(canned.texts)) };
+    let worker = ArtifactScannerWorker::with_model(
+        Box::new(model),
+        info.clone(),
+        GenshinArtifactScannerConfig::default(),
+        (1920, 1080),
+    );
+    let (tx, rx) = mpsc::channel();
+    let handle = worker.run(rx);
+
+    // 第一个物品正常截图，之后游戏关闭，剩下的 limit + 1 个物品全部截图失败
+    let mut aborted = None;
+    for index in 0..limit + 2 {
+        let panel_image = capture_with_recovery(&manager, "圣遗物面板", || {
+            capturer.capture_rect(info.panel_rect.to_rect_i32())
+        });
+        if let Err(error) = tracker.record(&panel_image, index) {
+            aborted = Some((index, error));
+            break;
+        }
+        let star = if panel_image.is_some() { canned.star } else { 0 };
+        tx.send(Some(SendItem { panel_image, star, list_image: None, position: None })).unwrap();
+    }
+    tx.send(None).unwrap();
+
+    let (index, error) = aborted.expect("连续截图失败后应停止扫描");
+    assert_eq!(index, limit);
+    assert_eq!(tracker.consecutive(), limit);
+    let ArtifactScanError::ScanInterrupted { reason, scanned_count } = error else {
+        panic!("应返回扫描中断错误: {error}");
+    };
+    assert_eq!(reason, "capture lost");
+    assert_eq!(scanned_count, limit);
+    // 前两个失败的物品各截图 1 次并重试 3 次；此时已连续记录 6 次截图错误，
+    // 超过恢复管理器的连续失败阈值（5 次），第三个失败的物品只截图 1 次不再重试；
+    // 第 limit + 1 个失败的物品没有再尝试截图
+    assert_eq!(capturer.attempts.get(), 1 + 4 + 4 + 1);
+
+    let results = handle.join().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].name, "明威之镡");
+
+    // 截图恢复后重新计数
+    tracker.record(&Some(()), limit).unwrap();
+    assert_eq!(tracker.consecutive(), 0);
+}
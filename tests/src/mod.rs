@@ -1,4 +1,6 @@
 #[cfg(test)]
+pub mod artifact_pipeline_tests;
+#[cfg(test)]
 pub mod artifact_stat_name_tests;
 #[cfg(test)]
 pub mod error_recovery_tests;